@@ -0,0 +1,62 @@
+#ifndef eip196_bindings_h
+#define eip196_bindings_h
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/* Non-zero return values carry the error code in the lowest byte and the byte offset
+   of the failure in the input plus one in the upper three bytes (zero if unknown) */
+#define ERROR_CODE_OTHER 1
+#define ERROR_CODE_INVALID_LENGTH 2
+#define ERROR_CODE_NON_CANONICAL_FIELD_ELEMENT 3
+#define ERROR_CODE_POINT_NOT_ON_CURVE 4
+#define ERROR_CODE_POINT_NOT_IN_SUBGROUP 5
+#define ERROR_CODE_NON_RESIDUE_CHECK_FAILED 6
+#define ERROR_CODE_LIMIT_EXCEEDED 7
+#define ERROR_CODE_INVALID_ENCODING 8
+#define ERROR_CODE_BUFFER_TOO_SMALL 9
+#define ERROR_CODE_NULL_POINTER 10
+#define ERROR_CODE_PANIC 11
+
+#define ERROR_CODE(result) ((result) & 0xff)
+#define ERROR_OFFSET(result) ((int64_t)((result) >> 8) - 1)
+
+#define EIP196_PREALLOCATE_FOR_ERROR_BYTES 256
+
+#define EIP196_PREALLOCATE_FOR_RESULT_BYTES 64
+
+#define BN254_ECADD_OPERATION_RAW_VALUE 6
+
+#define BN254_ECMUL_OPERATION_RAW_VALUE 7
+
+#define BN254_ECPAIRING_OPERATION_RAW_VALUE 8
+
+/**
+ * Performs ECADD, ECMUL or ECPAIRING, `op` is the address of the precompile. Bounds-checked in
+ * the same way as `eip2537_perform_operation_v2`. Returns zero on success or
+ * `ApiError::packed_code` of the failure
+ */
+uint32_t eip196_perform_operation(uint8_t op,
+                                  const uint8_t *i,
+                                  uint32_t i_len,
+                                  uint8_t *o,
+                                  uint32_t o_capacity,
+                                  uint32_t *o_len,
+                                  uint8_t *err,
+                                  uint32_t err_capacity,
+                                  uint32_t *err_len);
+
+/**
+ * Gas cost of `eip196_perform_operation` under the Istanbul schedule
+ */
+uint32_t eip196_meter_operation(uint8_t op,
+                                const uint8_t *i,
+                                uint32_t i_len,
+                                uint64_t *o,
+                                uint8_t *err,
+                                uint32_t err_capacity,
+                                uint32_t *err_len);
+
+#endif /* eip196_bindings_h */
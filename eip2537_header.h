@@ -6,18 +6,43 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/* Non-zero return values carry the error code in the lowest byte and the byte offset
+   of the failure in the input plus one in the upper three bytes (zero if unknown) */
+#define ERROR_CODE_OTHER 1
+#define ERROR_CODE_INVALID_LENGTH 2
+#define ERROR_CODE_NON_CANONICAL_FIELD_ELEMENT 3
+#define ERROR_CODE_POINT_NOT_ON_CURVE 4
+#define ERROR_CODE_POINT_NOT_IN_SUBGROUP 5
+#define ERROR_CODE_NON_RESIDUE_CHECK_FAILED 6
+#define ERROR_CODE_LIMIT_EXCEEDED 7
+#define ERROR_CODE_INVALID_ENCODING 8
+#define ERROR_CODE_BUFFER_TOO_SMALL 9
+#define ERROR_CODE_NULL_POINTER 10
+#define ERROR_CODE_PANIC 11
+
+#define ERROR_CODE(result) ((result) & 0xff)
+#define ERROR_OFFSET(result) ((int64_t)((result) >> 8) - 1)
+
 #define EIP2537_PREALLOCATE_FOR_ERROR_BYTES 256
 
-#define EIP2537_PREALLOCATE_FOR_RESULT_BYTES 256
+#define EIP2537_PREALLOCATE_FOR_RESULT_BYTES ((64 * 2) * 2)
 
 #define BLS12_G1ADD_OPERATION_RAW_VALUE 1
+
 #define BLS12_G1MUL_OPERATION_RAW_VALUE 2
+
 #define BLS12_G1MULTIEXP_OPERATION_RAW_VALUE 3
+
 #define BLS12_G2ADD_OPERATION_RAW_VALUE 4
+
 #define BLS12_G2MUL_OPERATION_RAW_VALUE 5
+
 #define BLS12_G2MULTIEXP_OPERATION_RAW_VALUE 6
+
 #define BLS12_PAIR_OPERATION_RAW_VALUE 7
+
 #define BLS12_MAP_FP_TO_G1_OPERATION_RAW_VALUE 8
+
 #define BLS12_MAP_FP2_TO_G2_OPERATION_RAW_VALUE 9
 
 uint32_t eip2537_perform_operation(char op,
@@ -28,4 +53,33 @@ uint32_t eip2537_perform_operation(char op,
                                    char *err,
                                    uint32_t *char_len);
 
+/**
+ * Bounds-checked version of `eip2537_perform_operation`. Capacities of the output and error
+ * buffers are passed explicitly and nothing is written past them. If the output buffer is too
+ * small `o_len` is set to the required length and `ERROR_CODE_BUFFER_TOO_SMALL` is returned.
+ * Error description is truncated to `err_capacity`, `err_len` is set to its full length. Returns
+ * zero on success or `ApiError::packed_code` of the failure
+ */
+uint32_t eip2537_perform_operation_v2(uint8_t op,
+                                      const uint8_t *i,
+                                      uint32_t i_len,
+                                      uint8_t *o,
+                                      uint32_t o_capacity,
+                                      uint32_t *o_len,
+                                      uint8_t *err,
+                                      uint32_t err_capacity,
+                                      uint32_t *err_len);
+
+/**
+ * C interface for gas metering, writes the gas cost of the operation into `o`. Buffers are
+ * bounds-checked in the same way as in `eip2537_perform_operation_v2`
+ */
+uint32_t eip2537_meter_operation_v2(uint8_t op,
+                                    const uint8_t *i,
+                                    uint32_t i_len,
+                                    uint64_t *o,
+                                    uint8_t *err,
+                                    uint32_t err_capacity,
+                                    uint32_t *err_len);
+
 #endif /* eip2537_bindings_h */
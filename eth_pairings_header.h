@@ -46,6 +46,27 @@
 
 #define MNT6PAIR 10
 
+#define BW6PAIR 11
+
+#define BLS24PAIR 12
+
+/* Non-zero return values carry the error code in the lowest byte and the byte offset
+   of the failure in the input plus one in the upper three bytes (zero if unknown) */
+#define ERROR_CODE_OTHER 1
+#define ERROR_CODE_INVALID_LENGTH 2
+#define ERROR_CODE_NON_CANONICAL_FIELD_ELEMENT 3
+#define ERROR_CODE_POINT_NOT_ON_CURVE 4
+#define ERROR_CODE_POINT_NOT_IN_SUBGROUP 5
+#define ERROR_CODE_NON_RESIDUE_CHECK_FAILED 6
+#define ERROR_CODE_LIMIT_EXCEEDED 7
+#define ERROR_CODE_INVALID_ENCODING 8
+#define ERROR_CODE_BUFFER_TOO_SMALL 9
+#define ERROR_CODE_NULL_POINTER 10
+#define ERROR_CODE_PANIC 11
+
+#define ERROR_CODE(result) ((result) & 0xff)
+#define ERROR_OFFSET(result) ((int64_t)((result) >> 8) - 1)
+
 uint32_t c_perform_operation(char op,
                              const char *i,
                              uint32_t i_len,
@@ -55,10 +76,38 @@ uint32_t c_perform_operation(char op,
                              uint32_t *char_len);
 
 uint32_t c_meter_operation(char op,
-                             const char *i,
-                             uint32_t i_len,
-                             uint64_t *o,
-                             char *err,
-                             uint32_t *char_len);
-
-#endif /* eth_pairings_bindings_h */
\ No newline at end of file
+                           const char *i,
+                           uint32_t i_len,
+                           uint64_t *o,
+                           char *err,
+                           uint32_t *char_len);
+
+/**
+ * Bounds-checked version of `c_perform_operation`. Capacities of the output and error buffers
+ * are passed explicitly and nothing is written past them. If the output buffer is too small
+ * `o_len` is set to the required length and `ERROR_CODE_BUFFER_TOO_SMALL` is returned. Error
+ * description is truncated to `err_capacity`, `err_len` is set to its full length. Returns zero
+ * on success or `ApiError::packed_code` of the failure
+ */
+uint32_t c_perform_operation_v2(uint8_t op,
+                                const uint8_t *i,
+                                uint32_t i_len,
+                                uint8_t *o,
+                                uint32_t o_capacity,
+                                uint32_t *o_len,
+                                uint8_t *err,
+                                uint32_t err_capacity,
+                                uint32_t *err_len);
+
+/**
+ * Bounds-checked version of `c_meter_operation`, see `c_perform_operation_v2`
+ */
+uint32_t c_meter_operation_v2(uint8_t op,
+                              const uint8_t *i,
+                              uint32_t i_len,
+                              uint64_t *o,
+                              uint8_t *err,
+                              uint32_t err_capacity,
+                              uint32_t *err_len);
+
+#endif /* eth_pairings_bindings_h */
@@ -1,2 +1,2 @@
 #!/bin/sh
-cbindgen --config cbindgen.toml --crate eth_pairings --output eth_pairings_header.h
\ No newline at end of file
+cbindgen --config cbindgen.toml --output eth_pairings_header.h src/public_interface/c_api.rs
\ No newline at end of file
@@ -0,0 +1,2 @@
+#!/bin/sh
+cbindgen --config eip196_c_bindgen.toml --output eip196_header.h src/public_interface/eip196/c_api.rs
\ No newline at end of file
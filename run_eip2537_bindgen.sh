@@ -1,2 +1,2 @@
 #!/bin/sh
-cbindgen --config eip2537_c_bindgen.toml --crate eth_pairings --output eip2537_header.h
\ No newline at end of file
+cbindgen --config eip2537_c_bindgen.toml --output eip2537_header.h src/public_interface/eip2537/c_api.rs
\ No newline at end of file
@@ -0,0 +1,34 @@
+extern crate test as rust_test;
+use self::rust_test::Bencher;
+
+use crate::weierstrass::Group;
+use crate::engines::bls12_381::*;
+
+// random scalar below the group order with a typical hamming weight
+const SCALAR: [u64; 4] = [0x9c4e1a7b3d5f2e61, 0x5a7c3e9b1d4f6a28, 0xe3b5d7f9a1c2e4b6, 0x4d2f6b8a9c1e3f57];
+
+#[bench]
+fn bench_bls12_381_g1_mul(b: &mut Bencher) {
+    let point = &BLS12_381_G1_GENERATOR;
+    b.iter(|| {
+        point.mul(&SCALAR[..])
+    });
+}
+
+#[bench]
+fn bench_bls12_381_g1_glv_mul_in_subgroup(b: &mut Bencher) {
+    let point = &BLS12_381_G1_GENERATOR;
+    b.iter(|| {
+        point.glv_mul(&SCALAR[..], true)
+    });
+}
+
+// membership is not known in advance, so it has to be checked before GLV can be used
+#[bench]
+fn bench_bls12_381_g1_subgroup_check_and_glv_mul(b: &mut Bencher) {
+    let point = &BLS12_381_G1_GENERATOR;
+    b.iter(|| {
+        let in_subgroup = check_correct_subgroup_fast_g1(point);
+        point.glv_mul(&SCALAR[..], in_subgroup)
+    });
+}
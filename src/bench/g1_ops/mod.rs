@@ -1 +1,2 @@
-mod bn254;
\ No newline at end of file
+mod bn254;
+mod glv;
\ No newline at end of file
@@ -0,0 +1,15 @@
+extern crate test as rust_test;
+use self::rust_test::Bencher;
+
+use crate::engines::bw6_761::*;
+use crate::pairings::PairingEngine;
+
+#[bench]
+fn bench_bw6_761_pairing(b: &mut Bencher) {
+    let p = BW6_761_G1_GENERATOR;
+    let q = BW6_761_G2_GENERATOR;
+
+    b.iter(|| {
+        BW6_761_PAIRING_ENGINE.pair(&[p.clone()], &[q.clone()]).unwrap();
+    });
+}
@@ -1,4 +1,5 @@
 mod bls12;
 mod bn;
 mod mnt6;
-mod mnt4;
\ No newline at end of file
+mod mnt4;
+mod bw6;
\ No newline at end of file
@@ -25,6 +25,17 @@ fn bench_bls12_377_pairing_4_through_the_api(b: &mut Bencher) {
     });
 }
 
+#[bench]
+fn bench_bls24_315_pairing_4_through_the_api(b: &mut Bencher) {
+    use crate::test::pairings::bls24::assemble_bls24_315;
+
+    let calldata = assemble_bls24_315(4);
+
+    b.iter(|| {
+        assert_eq!(crate::public_interface::PublicPairingApi::pair(&calldata).unwrap()[0], 1u8);
+    });
+}
+
 #[bench]
 fn bench_bn254_pairing_4_through_the_api(b: &mut Bencher) {
     use crate::test::pairings::bn::assemble_bn254;
@@ -0,0 +1,355 @@
+//! Command line tool to run, meter and explain EIP-1962 and EIP-2537 inputs and to validate test
+//! vector files in bulk. Run without arguments for usage.
+
+use std::time::{Duration, Instant};
+
+use eth_pairings::gas_meter::{GasMeter, meter_operation};
+use eth_pairings::public_interface::{API, ApiError, OperationType, perform_operation, decode_and_explain};
+use eth_pairings::public_interface::eip2537::{EIP2537Executor, EIP2537GasMeter};
+
+const USAGE: &str = "\
+Usage: eth_pairings <command> [arguments]
+
+Inputs are hex strings (with or without 0x prefix) or @<path> of a file with either hex or
+raw binary contents.
+
+Commands:
+    run <input>                      run an EIP-1962 input through API::run (operation byte first)
+    perform <operation> <input>      run an EIP-1962 input through perform_operation, operation is
+                                     one of g1add, g1mul, g1multiexp, g2add, g2mul, g2multiexp,
+                                     bls12pair, bnpair, mnt4pair, mnt6pair, bw6pair,
+                                     bls24pair
+    eip2537 <operation> <input>      run an EIP-2537 input, operation is one of g1_add, g1_mul,
+                                     g1_multiexp, g2_add, g2_mul, g2_multiexp, pairing, fp_to_g1,
+                                     fp2_to_g2
+    explain <input>                  print the layout of an EIP-1962 input and the first failure
+    vectors [--eip2537 [--operation <operation>]] <file.csv>...
+                                     validate CSV files with `input,result` columns. Inputs go
+                                     through API::run, or EIP-2537 executor with an operation
+                                     taken from the file name unless given explicitly. Results
+                                     that are not hex are expected failures
+";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+
+    let result = match args.split_first() {
+        Some((&"run", [input])) => {
+            read_input(input).and_then(|input| {
+                report(GasMeter::meter(&input), timed(|| API::run(&input)))
+            })
+        },
+        Some((&"perform", [operation, input])) => {
+            parse_operation(operation).and_then(|operation| {
+                let input = read_input(input)?;
+
+                report(meter_operation(operation, &input), timed(|| perform_operation(operation, &input)))
+            })
+        },
+        Some((&"eip2537", [operation, input])) => {
+            Eip2537Operation::from_name(operation).and_then(|operation| {
+                let input = read_input(input)?;
+
+                report(operation.meter(&input), timed(|| operation.run(&input)))
+            })
+        },
+        Some((&"explain", [input])) => {
+            read_input(input).map(|input| {
+                let explanation = decode_and_explain(&input);
+                println!("{}", explanation);
+
+                explanation.failure.is_none()
+            })
+        },
+        Some((&"vectors", rest)) if !rest.is_empty() => {
+            validate_vectors(rest)
+        },
+        _ => {
+            eprint!("{}", USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    match result {
+        Ok(true) => {},
+        Ok(false) => std::process::exit(1),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(2);
+        }
+    }
+}
+
+fn timed<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+
+    (result, start.elapsed())
+}
+
+// prints result of a single call, returns if it was successful
+fn report(gas: Result<u64, ApiError>, (result, elapsed): (Result<Vec<u8>, ApiError>, Duration)) -> Result<bool, String> {
+    match gas {
+        Ok(gas) => println!("gas: {}", gas),
+        Err(err) => println!("gas: failed to meter, {}", err),
+    }
+    println!("time: {:?}", elapsed);
+    match result {
+        Ok(result) => {
+            println!("result: 0x{}", encode_hex(&result));
+
+            Ok(true)
+        },
+        Err(err) => {
+            println!("failure: {} (code {:?})", err, err.code());
+
+            Ok(false)
+        }
+    }
+}
+
+fn parse_operation(name: &str) -> Result<OperationType, String> {
+    let operation = match name.to_lowercase().as_str() {
+        "g1add" => OperationType::G1ADD,
+        "g1mul" => OperationType::G1MUL,
+        "g1multiexp" => OperationType::G1MULTIEXP,
+        "g2add" => OperationType::G2ADD,
+        "g2mul" => OperationType::G2MUL,
+        "g2multiexp" => OperationType::G2MULTIEXP,
+        "bls12pair" => OperationType::BLS12PAIR,
+        "bnpair" => OperationType::BNPAIR,
+        "mnt4pair" => OperationType::MNT4PAIR,
+        "mnt6pair" => OperationType::MNT6PAIR,
+        "bw6pair" => OperationType::BW6PAIR,
+        "bls24pair" => OperationType::BLS24PAIR,
+        _ => {
+            return Err(format!("unknown operation {}", name));
+        }
+    };
+
+    Ok(operation)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Eip2537Operation {
+    G1Add,
+    G1Mul,
+    G1Multiexp,
+    G2Add,
+    G2Mul,
+    G2Multiexp,
+    Pairing,
+    FpToG1,
+    Fp2ToG2,
+}
+
+impl Eip2537Operation {
+    // names match the file names of test vectors
+    fn from_name(name: &str) -> Result<Self, String> {
+        let operation = match name {
+            "g1_add" => Eip2537Operation::G1Add,
+            "g1_mul" => Eip2537Operation::G1Mul,
+            "g1_multiexp" => Eip2537Operation::G1Multiexp,
+            "g2_add" => Eip2537Operation::G2Add,
+            "g2_mul" => Eip2537Operation::G2Mul,
+            "g2_multiexp" => Eip2537Operation::G2Multiexp,
+            "pairing" => Eip2537Operation::Pairing,
+            "fp_to_g1" => Eip2537Operation::FpToG1,
+            "fp2_to_g2" => Eip2537Operation::Fp2ToG2,
+            _ => {
+                return Err(format!("unknown EIP-2537 operation {}", name));
+            }
+        };
+
+        Ok(operation)
+    }
+
+    fn run(&self, input: &[u8]) -> Result<Vec<u8>, ApiError> {
+        match self {
+            Eip2537Operation::G1Add => EIP2537Executor::g1_add(input).map(|r| r.to_vec()),
+            Eip2537Operation::G1Mul => EIP2537Executor::g1_mul(input).map(|r| r.to_vec()),
+            Eip2537Operation::G1Multiexp => EIP2537Executor::g1_multiexp(input).map(|r| r.to_vec()),
+            Eip2537Operation::G2Add => EIP2537Executor::g2_add(input).map(|r| r.to_vec()),
+            Eip2537Operation::G2Mul => EIP2537Executor::g2_mul(input).map(|r| r.to_vec()),
+            Eip2537Operation::G2Multiexp => EIP2537Executor::g2_multiexp(input).map(|r| r.to_vec()),
+            Eip2537Operation::Pairing => EIP2537Executor::pair(input).map(|r| r.to_vec()),
+            Eip2537Operation::FpToG1 => EIP2537Executor::map_fp_to_g1(input).map(|r| r.to_vec()),
+            Eip2537Operation::Fp2ToG2 => EIP2537Executor::map_fp2_to_g2(input).map(|r| r.to_vec()),
+        }
+    }
+
+    fn meter(&self, input: &[u8]) -> Result<u64, ApiError> {
+        match self {
+            Eip2537Operation::G1Add => EIP2537GasMeter::g1_add(input),
+            Eip2537Operation::G1Mul => EIP2537GasMeter::g1_mul(input),
+            Eip2537Operation::G1Multiexp => EIP2537GasMeter::g1_multiexp(input),
+            Eip2537Operation::G2Add => EIP2537GasMeter::g2_add(input),
+            Eip2537Operation::G2Mul => EIP2537GasMeter::g2_mul(input),
+            Eip2537Operation::G2Multiexp => EIP2537GasMeter::g2_multiexp(input),
+            Eip2537Operation::Pairing => EIP2537GasMeter::pair(input),
+            Eip2537Operation::FpToG1 => EIP2537GasMeter::map_fp_to_g1(input),
+            Eip2537Operation::Fp2ToG2 => EIP2537GasMeter::map_fp2_to_g2(input),
+        }
+    }
+}
+
+fn validate_vectors(args: &[&str]) -> Result<bool, String> {
+    let mut eip2537 = false;
+    let mut operation = None;
+    let mut files = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match *arg {
+            "--eip2537" => eip2537 = true,
+            "--operation" => {
+                let name = args.next().ok_or("--operation requires a value".to_owned())?;
+                operation = Some(Eip2537Operation::from_name(name)?);
+            },
+            _ => files.push(*arg),
+        }
+    }
+    if files.is_empty() {
+        return Err("no vector files given".to_owned());
+    }
+
+    let mut all_passed = true;
+    for path in files.into_iter() {
+        let run: Box<dyn Fn(&[u8]) -> Result<Vec<u8>, ApiError>> = if eip2537 {
+            let operation = match operation {
+                Some(operation) => operation,
+                None => {
+                    let stem = std::path::Path::new(path).file_stem().and_then(|s| s.to_str()).unwrap_or("");
+                    Eip2537Operation::from_name(stem).map_err(|e| format!("{}, use --operation for {}", e, path))?
+                }
+            };
+
+            Box::new(move |input| operation.run(input))
+        } else {
+            Box::new(|input| API::run(input))
+        };
+
+        let contents = std::fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path, e))?;
+        let vectors = parse_vectors(&contents).map_err(|e| format!("{}: {}", path, e))?;
+
+        let mut failed = 0;
+        let mut total_time = Duration::default();
+        for (line, vector) in vectors.iter() {
+            let (result, elapsed) = timed(|| run(&vector.input));
+            total_time += elapsed;
+            let mismatch = match (&result, &vector.expected) {
+                (Ok(result), Some(expected)) if result == expected => None,
+                (Err(_), None) => None,
+                (Ok(result), _) => Some(format!("got 0x{}", encode_hex(result))),
+                (Err(err), _) => Some(format!("got failure {}", err)),
+            };
+            if let Some(mismatch) = mismatch {
+                failed += 1;
+                println!("{}:{}: {}", path, line, mismatch);
+            }
+        }
+        println!("{}: {} vectors, {} failed, {:?} total", path, vectors.len(), failed, total_time);
+        all_passed &= failed == 0;
+    }
+
+    Ok(all_passed)
+}
+
+struct Vector {
+    input: Vec<u8>,
+    /// `None` if the vector is expected to fail
+    expected: Option<Vec<u8>>,
+}
+
+// `input,result` CSV with a header, returns vectors with their line numbers
+fn parse_vectors(contents: &str) -> Result<Vec<(usize, Vector)>, String> {
+    let mut lines = contents.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == "input,result" => {},
+        _ => {
+            return Err("expected `input,result` header".to_owned());
+        }
+    }
+
+    let mut vectors = vec![];
+    for (i, line) in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line_number = i + 1;
+        let mut columns = line.splitn(2, ',');
+        let input = columns.next().unwrap_or("");
+        let result = columns.next().ok_or(format!("line {} has no result column", line_number))?;
+        let input = decode_hex(input).map_err(|e| format!("line {}: {}", line_number, e))?;
+        let expected = decode_hex(result).ok();
+        vectors.push((line_number, Vector { input, expected }));
+    }
+
+    Ok(vectors)
+}
+
+fn read_input(input: &str) -> Result<Vec<u8>, String> {
+    if input.starts_with('@') {
+        let path = &input[1..];
+        let contents = std::fs::read(path).map_err(|e| format!("failed to read {}: {}", path, e))?;
+
+        return Ok(std::str::from_utf8(&contents).ok().and_then(|s| decode_hex(s).ok()).unwrap_or(contents));
+    }
+
+    decode_hex(input)
+}
+
+fn decode_hex(encoding: &str) -> Result<Vec<u8>, String> {
+    let encoding = encoding.trim();
+    let encoding = encoding.strip_prefix("0x").unwrap_or(encoding);
+    if encoding.len() % 2 != 0 {
+        return Err("hex string has odd length".to_owned());
+    }
+
+    (0..encoding.len()).step_by(2).map(|i| {
+        encoding.get(i..(i + 2))
+            .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+            .ok_or(format!("invalid hex character at position {}", i))
+    }).collect()
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hex_and_vectors_parsing() {
+        assert_eq!(decode_hex("0x0aff").unwrap(), vec![0x0a, 0xff]);
+        assert_eq!(decode_hex("0AfF\n").unwrap(), vec![0x0a, 0xff]);
+        assert!(decode_hex("0x0").is_err());
+        assert!(decode_hex("zz").is_err());
+        assert_eq!(encode_hex(&[0x0a, 0xff]), "0aff");
+
+        let vectors = parse_vectors("input,result\n0x01,0x02\n\n03,\"invalid input parameters, Point is not on curve\"\n").unwrap();
+        assert_eq!(vectors.len(), 2);
+        assert_eq!(vectors[0].0, 2);
+        assert_eq!(vectors[0].1.input, vec![1]);
+        assert_eq!(vectors[0].1.expected, Some(vec![2]));
+        assert_eq!(vectors[1].0, 4);
+        assert_eq!(vectors[1].1.expected, None);
+
+        assert!(parse_vectors("a,b\n").is_err());
+    }
+
+    #[test]
+    fn test_eip2537_vector_files() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/src/test/test_vectors/eip2537/");
+        for name in ["g1_add", "g2_add", "fp_to_g1"].iter() {
+            let path = format!("{}{}.csv", dir, name);
+            assert!(validate_vectors(&["--eip2537", &path]).unwrap());
+        }
+
+        let path = format!("{}negative/g1_not_on_curve.csv", dir);
+        assert!(validate_vectors(&["--eip2537", "--operation", "g1_mul", &path]).unwrap());
+        assert!(validate_vectors(&["--eip2537", &path]).is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use sha2::{Sha256, Digest};
+
+use crate::errors::ApiError;
+use crate::integers::MaxGroupSizeUint;
+use crate::engines::bls12_381::BLS12_381_SUBGROUP_ORDER;
+
+const KEYGEN_SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-";
+const SHA256_BLOCK_BYTES: usize = 64;
+const MIN_IKM_BYTES: usize = 32;
+// ceil((3 * ceil(log2(r))) / 16)
+const OKM_BYTES: usize = 48;
+
+fn hmac_sha256(key: &[u8], message_parts: &[&[u8]]) -> [u8; 32] {
+    let mut padded_key = [0u8; SHA256_BLOCK_BYTES];
+    if key.len() > SHA256_BLOCK_BYTES {
+        padded_key[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        padded_key[..key.len()].copy_from_slice(key);
+    }
+
+    let mut ipad = [0x36u8; SHA256_BLOCK_BYTES];
+    let mut opad = [0x5cu8; SHA256_BLOCK_BYTES];
+    for ((i, o), k) in ipad.iter_mut().zip(opad.iter_mut()).zip(padded_key.iter()) {
+        *i ^= k;
+        *o ^= k;
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(&ipad[..]);
+    for part in message_parts.iter() {
+        inner.update(part);
+    }
+    let inner = inner.finalize();
+
+    let outer = Sha256::new().chain(&opad[..]).chain(&inner).finalize();
+
+    let mut result = [0u8; 32];
+    result.copy_from_slice(&outer);
+
+    result
+}
+
+// HKDF-Expand from RFC 5869 for the fixed output length used by KeyGen
+fn hkdf_expand(prk: &[u8], info: &[u8]) -> [u8; OKM_BYTES] {
+    let length = [(OKM_BYTES >> 8) as u8, OKM_BYTES as u8];
+    let mut okm = [0u8; OKM_BYTES];
+
+    let mut t: Vec<u8> = vec![];
+    for (i, chunk) in okm.chunks_mut(32).enumerate() {
+        let counter = [(i + 1) as u8];
+        let block = hmac_sha256(prk, &[&t, info, &length, &counter]);
+        chunk.copy_from_slice(&block[..chunk.len()]);
+        t = block.to_vec();
+    }
+
+    okm
+}
+
+/// KeyGen from the IETF BLS signature draft (also used by EIP-2333 for master keys).
+/// Returns little endian limbs of a non-zero scalar
+pub(crate) fn key_gen(ikm: &[u8], key_info: &[u8]) -> Result<[u64; 4], ApiError> {
+    if ikm.len() < MIN_IKM_BYTES {
+        return Err(ApiError::InputError("key material must be at least 32 bytes long".to_owned()));
+    }
+
+    let order = MaxGroupSizeUint::from(&BLS12_381_SUBGROUP_ORDER[..]);
+    let mut salt = Sha256::digest(KEYGEN_SALT);
+
+    loop {
+        // HKDF-Extract(salt, IKM || I2OSP(0, 1))
+        let prk = hmac_sha256(&salt, &[ikm, &[0u8]]);
+        let okm = hkdf_expand(&prk, key_info);
+
+        let (_, sk) = MaxGroupSizeUint::from_big_endian(&okm).div_mod(order);
+        if !sk.is_zero() {
+            let mut result = [0u64; 4];
+            result.copy_from_slice(&sk.as_ref()[..4]);
+
+            return Ok(result);
+        }
+
+        salt = Sha256::digest(&salt);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hmac_sha256() {
+        // RFC 4231, test case 2
+        let mac = hmac_sha256(b"Jefe", &[b"what do ya want ", b"for nothing?"]);
+        assert_eq!(hex::encode(&mac), "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+    }
+
+    #[test]
+    fn test_key_gen_eip2333_master_key() {
+        let seed = hex::decode("c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04").unwrap();
+        let sk = key_gen(&seed, b"").unwrap();
+
+        let bytes = crate::bls_signatures::scalar_to_be_bytes(&sk);
+        // 6083874454709270928345386274498605044986640685124978867557563392430687146096
+        assert_eq!(hex::encode(&bytes), "0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070");
+
+        assert!(key_gen(&seed[..31], b"").is_err());
+    }
+}
@@ -0,0 +1,461 @@
+//! BLS signatures over BLS12-381 following the ciphersuites of the IETF BLS signature draft
+//! (draft-irtf-cfrg-bls-signature). Messages are hashed to the curve with the RFC 9380 suites
+//! and points are encoded in the compressed ZCash format
+
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::weierstrass::Group;
+use crate::weierstrass::curve::CurvePoint;
+use crate::integers::MaxGroupSizeUint;
+use crate::errors::ApiError;
+use crate::engines::bls12_381::BLS12_381_SUBGROUP_ORDER;
+
+mod keygen;
+mod variants;
+
+pub use self::variants::{Variant, MinPublicKeySize, MinSignatureSize};
+
+pub const SECRET_KEY_BYTE_LENGTH: usize = 32;
+
+/// Ways to protect against rogue key attacks, one per ciphersuite of the draft
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scheme {
+    /// Messages in an aggregate must be distinct, NUL ciphersuites
+    Basic,
+    /// Public key is prepended to every message, AUG ciphersuites
+    MessageAugmentation,
+    /// Every public key comes with a proof of possession of the secret key, POP ciphersuites
+    ProofOfPossession,
+}
+
+impl Scheme {
+    fn tag(&self) -> &'static [u8] {
+        match self {
+            Scheme::Basic => b"NUL_",
+            Scheme::MessageAugmentation => b"AUG_",
+            Scheme::ProofOfPossession => b"POP_",
+        }
+    }
+}
+
+/// Domain separation tag for signatures of a given ciphersuite
+pub fn signature_dst<V: Variant>(scheme: Scheme) -> Vec<u8> {
+    let mut dst = b"BLS_SIG_".to_vec();
+    dst.extend_from_slice(V::HASH_TO_CURVE_SUITE);
+    dst.extend_from_slice(scheme.tag());
+
+    dst
+}
+
+/// Domain separation tag for proofs of possession
+pub fn proof_of_possession_dst<V: Variant>() -> Vec<u8> {
+    let mut dst = b"BLS_POP_".to_vec();
+    dst.extend_from_slice(V::HASH_TO_CURVE_SUITE);
+    dst.extend_from_slice(Scheme::ProofOfPossession.tag());
+
+    dst
+}
+
+pub(crate) fn scalar_to_be_bytes(scalar: &[u64; 4]) -> [u8; SECRET_KEY_BYTE_LENGTH] {
+    let mut bytes = [0u8; SECRET_KEY_BYTE_LENGTH];
+    for (chunk, limb) in bytes.chunks_mut(8).zip(scalar.iter().rev()) {
+        BigEndian::write_u64(chunk, *limb);
+    }
+
+    bytes
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretKey {
+    scalar: [u64; 4],
+}
+
+impl std::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretKey(..)")
+    }
+}
+
+impl SecretKey {
+    /// Derives a secret key from at least 32 bytes of key material
+    pub fn key_gen(ikm: &[u8], key_info: &[u8]) -> Result<Self, ApiError> {
+        let scalar = keygen::key_gen(ikm, key_info)?;
+
+        Ok(Self { scalar })
+    }
+
+    /// Parses a big endian encoding of a scalar, that must be non-zero and less than the group order
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ApiError> {
+        if bytes.len() != SECRET_KEY_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid secret key length".to_owned()));
+        }
+
+        let value = MaxGroupSizeUint::from_big_endian(bytes);
+        if value.is_zero() || value >= MaxGroupSizeUint::from(&BLS12_381_SUBGROUP_ORDER[..]) {
+            return Err(ApiError::InputError("secret key is not in the scalar field".to_owned()));
+        }
+
+        let mut scalar = [0u64; 4];
+        scalar.copy_from_slice(&value.as_ref()[..4]);
+
+        Ok(Self { scalar })
+    }
+
+    pub fn to_bytes(&self) -> [u8; SECRET_KEY_BYTE_LENGTH] {
+        scalar_to_be_bytes(&self.scalar)
+    }
+
+    pub fn public_key<V: Variant>(&self) -> PublicKey<V> {
+        let mut point = V::public_key_generator().mul(&self.scalar);
+        point.normalize();
+
+        PublicKey { point }
+    }
+
+    pub fn sign<V: Variant>(&self, scheme: Scheme, msg: &[u8]) -> Result<Signature<V>, ApiError> {
+        let dst = signature_dst::<V>(scheme);
+        let point = match scheme {
+            Scheme::MessageAugmentation => {
+                let mut augmented = self.public_key::<V>().to_bytes()?;
+                augmented.extend_from_slice(msg);
+
+                self.sign_with_dst::<V>(&augmented, &dst)?
+            },
+            _ => {
+                self.sign_with_dst::<V>(msg, &dst)?
+            }
+        };
+
+        Ok(Signature { point })
+    }
+
+    /// Signs an encoding of the public key, so others can check that it was not constructed
+    /// from other public keys. Required for `fast_aggregate_verify`
+    pub fn pop_prove<V: Variant>(&self) -> Result<Signature<V>, ApiError> {
+        let public_key = self.public_key::<V>().to_bytes()?;
+        let point = self.sign_with_dst::<V>(&public_key, &proof_of_possession_dst::<V>())?;
+
+        Ok(Signature { point })
+    }
+
+    fn sign_with_dst<V: Variant>(&self, msg: &[u8], dst: &[u8]) -> Result<CurvePoint<'static, V::SignatureCurve>, ApiError> {
+        let hashed = V::hash_to_signature_group(msg, dst)?;
+        let mut point = hashed.mul(&self.scalar);
+        point.normalize();
+
+        Ok(point)
+    }
+}
+
+pub struct PublicKey<V: Variant> {
+    pub(crate) point: CurvePoint<'static, V::PublicKeyCurve>,
+}
+
+impl<V: Variant> Clone for PublicKey<V> {
+    fn clone(&self) -> Self {
+        Self {
+            point: self.point.clone()
+        }
+    }
+}
+
+impl<V: Variant> PublicKey<V> {
+    /// Parses a compressed public key, that must be in the main subgroup
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ApiError> {
+        let point = V::decompress_public_key(bytes)?;
+
+        Ok(Self { point })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ApiError> {
+        V::compress_public_key(&self.point)
+    }
+
+    /// KeyValidate from the draft: key is not the identity and is in the main subgroup
+    pub fn is_valid(&self) -> bool {
+        !self.point.is_zero() && self.point.is_on_curve() && V::public_key_in_subgroup(&self.point)
+    }
+
+    pub fn verify(&self, scheme: Scheme, msg: &[u8], signature: &Signature<V>) -> Result<bool, ApiError> {
+        let dst = signature_dst::<V>(scheme);
+        match scheme {
+            Scheme::MessageAugmentation => {
+                let mut augmented = self.to_bytes()?;
+                augmented.extend_from_slice(msg);
+
+                core_verify(self, &augmented, &dst, signature)
+            },
+            _ => {
+                core_verify(self, msg, &dst, signature)
+            }
+        }
+    }
+
+    pub fn pop_verify(&self, proof: &Signature<V>) -> Result<bool, ApiError> {
+        let public_key = self.to_bytes()?;
+
+        core_verify(self, &public_key, &proof_of_possession_dst::<V>(), proof)
+    }
+}
+
+pub struct Signature<V: Variant> {
+    pub(crate) point: CurvePoint<'static, V::SignatureCurve>,
+}
+
+impl<V: Variant> Clone for Signature<V> {
+    fn clone(&self) -> Self {
+        Self {
+            point: self.point.clone()
+        }
+    }
+}
+
+impl<V: Variant> Signature<V> {
+    /// Parses a compressed signature, that must be in the main subgroup
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ApiError> {
+        let point = V::decompress_signature(bytes)?;
+
+        Ok(Self { point })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ApiError> {
+        V::compress_signature(&self.point)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.point.is_on_curve() && V::signature_in_subgroup(&self.point)
+    }
+
+    /// Combines signatures into one, that can be checked by `aggregate_verify`
+    /// or `fast_aggregate_verify`
+    pub fn aggregate(signatures: &[Self]) -> Result<Self, ApiError> {
+        let (first, rest) = signatures.split_first().ok_or_else(|| {
+            ApiError::InputError("no signatures to aggregate".to_owned())
+        })?;
+
+        let mut point = first.point.clone();
+        for s in rest.iter() {
+            point.add_assign(&s.point);
+        }
+        point.normalize();
+
+        Ok(Self { point })
+    }
+}
+
+fn core_verify<V: Variant>(public_key: &PublicKey<V>, msg: &[u8], dst: &[u8], signature: &Signature<V>) -> Result<bool, ApiError> {
+    if !signature.is_valid() || !public_key.is_valid() {
+        return Ok(false);
+    }
+
+    let hashed = V::hash_to_signature_group(msg, dst)?;
+    let mut generator = V::public_key_generator();
+    generator.negate();
+
+    // e(PK, H(m)) * e(-G, signature) == 1
+    V::pairing_product_is_one(
+        &[public_key.point.clone(), generator],
+        &[hashed, signature.point.clone()]
+    )
+}
+
+/// Checks an aggregate signature over (possibly) different messages signed by different keys.
+/// Under the basic scheme messages must be distinct
+pub fn aggregate_verify<V: Variant>(
+    scheme: Scheme,
+    public_keys: &[PublicKey<V>],
+    messages: &[&[u8]],
+    signature: &Signature<V>
+) -> Result<bool, ApiError> {
+    if public_keys.len() == 0 {
+        return Err(ApiError::InputError("no public keys to verify against".to_owned()));
+    }
+    if public_keys.len() != messages.len() {
+        return Err(ApiError::InputError("number of public keys and messages must be equal".to_owned()));
+    }
+
+    if scheme == Scheme::Basic {
+        let mut sorted = messages.to_vec();
+        sorted.sort();
+        sorted.dedup();
+        if sorted.len() != messages.len() {
+            return Ok(false);
+        }
+    }
+
+    if !signature.is_valid() {
+        return Ok(false);
+    }
+
+    let dst = signature_dst::<V>(scheme);
+
+    let mut public_key_points = Vec::with_capacity(public_keys.len() + 1);
+    let mut signature_points = Vec::with_capacity(public_keys.len() + 1);
+    for (pk, msg) in public_keys.iter().zip(messages.iter()) {
+        if !pk.is_valid() {
+            return Ok(false);
+        }
+
+        let hashed = if scheme == Scheme::MessageAugmentation {
+            let mut augmented = pk.to_bytes()?;
+            augmented.extend_from_slice(msg);
+
+            V::hash_to_signature_group(&augmented, &dst)?
+        } else {
+            V::hash_to_signature_group(msg, &dst)?
+        };
+
+        public_key_points.push(pk.point.clone());
+        signature_points.push(hashed);
+    }
+
+    let mut generator = V::public_key_generator();
+    generator.negate();
+    public_key_points.push(generator);
+    signature_points.push(signature.point.clone());
+
+    V::pairing_product_is_one(&public_key_points, &signature_points)
+}
+
+/// Checks an aggregate signature of the same message under the proof of possession scheme.
+/// Proofs of possession for all public keys must be verified in advance
+pub fn fast_aggregate_verify<V: Variant>(
+    public_keys: &[PublicKey<V>],
+    msg: &[u8],
+    signature: &Signature<V>
+) -> Result<bool, ApiError> {
+    let (first, rest) = public_keys.split_first().ok_or_else(|| {
+        ApiError::InputError("no public keys to verify against".to_owned())
+    })?;
+
+    let mut point = first.point.clone();
+    for pk in rest.iter() {
+        point.add_assign(&pk.point);
+    }
+    point.normalize();
+
+    let aggregated = PublicKey { point };
+
+    core_verify(&aggregated, msg, &signature_dst::<V>(Scheme::ProofOfPossession), signature)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn secret_key(hex_encoding: &str) -> SecretKey {
+        SecretKey::from_bytes(&hex::decode(hex_encoding).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_min_pk_basic_scheme_vectors() {
+        // (secret key, message, public key, signature)
+        let vectors = [
+            (
+                "3ce2e976962a07ab68ccfa29194968dbb6c917c041d44bfc1c9f1a671017f70e",
+                "",
+                "b2be11dc8e54ee74dbc07569fd74fe03b5f52ad71cd49a8579b6c6387891f5a20ad980ec2747618c1b9ad35846a68a3e",
+                "b53cfdf8b488a286df1ed20432e2bbc4e6361003757dfda3a4fd6cd98de95e5513f7c448d70b2681e14547a6ced47e7c10e28432e8abcb34de1dc28f39328fd2a13db12a4c6a30bd17b0e42881a429003e4c24583ba0f29a40fd836cf05e1a40"
+            ),
+            (
+                "6de2989580e8210501e005a7e45f645fc525518d4d2acf1b7fce5852d5d3fe5f",
+                "1234",
+                "981de2d88a80a2d7752ecda66443340a789ea62dd68dca6a3a8caf3b6c1e94248a8819a4f6ba554f50f5ccb8bc40e67c",
+                "84aa59cad078a34c3c1f876e924ee199cd8cf74857cebcad3037561964cfda50dce5f4d0709aa690dae7113b01a9c8c31557f5589c38eb720e86864ff0c4446fba21899d4cd0b2862ec395de1dfdb736bf38ca56d17019b257c5d4dd563bf5b7"
+            ),
+            (
+                "16696c2bb32968007ca92ec631286349ec19eddb461605c7eaa1d88e08dd356d",
+                "4fb3300556a1b2e3978f5da60cb0708b4d41385a6008976a88527d418d21cf9e",
+                "a31e3adf1fd542e00c8ae3d4dafea1133c09cd71723584b159cfb78ab5e1bad97f955b891f761c79fa8215b8c53addf7",
+                "8d4512fabe80f7fb68726794a86b2e20cd04b5787aecfbf43b02c8eb67e42b60b797eba0489c4eb1165e4391f31ee8600af43a3d57e6bfdbb950564916f6b1c57e4ef7fba312fd7db431aaec906fa38acefe90687d3755cb3d10b19901bd0074"
+            ),
+        ];
+
+        for (sk, msg, pk, sig) in vectors.iter() {
+            let sk = secret_key(sk);
+            let public_key = sk.public_key::<MinPublicKeySize>();
+            assert_eq!(hex::encode(public_key.to_bytes().unwrap()), *pk);
+
+            let signature = sk.sign::<MinPublicKeySize>(Scheme::Basic, msg.as_bytes()).unwrap();
+            assert_eq!(hex::encode(signature.to_bytes().unwrap()), *sig);
+
+            assert!(public_key.verify(Scheme::Basic, msg.as_bytes(), &signature).unwrap());
+            assert!(!public_key.verify(Scheme::Basic, b"other message", &signature).unwrap());
+            assert!(!public_key.verify(Scheme::ProofOfPossession, msg.as_bytes(), &signature).unwrap());
+
+            let public_key = PublicKey::<MinPublicKeySize>::from_bytes(&hex::decode(pk).unwrap()).unwrap();
+            let signature = Signature::<MinPublicKeySize>::from_bytes(&hex::decode(sig).unwrap()).unwrap();
+            assert!(public_key.verify(Scheme::Basic, msg.as_bytes(), &signature).unwrap());
+
+            // public key and signature have swapped lengths in the other variant
+            assert!(PublicKey::<MinSignatureSize>::from_bytes(&hex::decode(pk).unwrap()).is_err());
+            assert!(Signature::<MinPublicKeySize>::from_bytes(&hex::decode(pk).unwrap()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_min_pk_proof_of_possession_scheme_vector() {
+        let sk = secret_key("263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3");
+        let signature = sk.sign::<MinPublicKeySize>(Scheme::ProofOfPossession, &[0u8; 32]).unwrap();
+
+        assert_eq!(
+            hex::encode(signature.to_bytes().unwrap()),
+            "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55"
+        );
+    }
+
+    fn check_aggregation<V: Variant>() {
+        let keys: Vec<SecretKey> = (0u8..4).map(|i| SecretKey::key_gen(&[i; 32], b"").unwrap()).collect();
+        let public_keys: Vec<PublicKey<V>> = keys.iter().map(|sk| sk.public_key::<V>()).collect();
+
+        for (sk, pk) in keys.iter().zip(public_keys.iter()) {
+            let proof = sk.pop_prove::<V>().unwrap();
+            assert!(pk.pop_verify(&proof).unwrap());
+        }
+        let proof = keys[0].pop_prove::<V>().unwrap();
+        assert!(!public_keys[1].pop_verify(&proof).unwrap());
+
+        let msg = b"same message";
+        let signatures: Vec<Signature<V>> = keys.iter().map(|sk| sk.sign::<V>(Scheme::ProofOfPossession, msg).unwrap()).collect();
+        let aggregate = Signature::aggregate(&signatures).unwrap();
+        assert!(fast_aggregate_verify(&public_keys, msg, &aggregate).unwrap());
+        assert!(!fast_aggregate_verify(&public_keys[1..], msg, &aggregate).unwrap());
+
+        let messages: Vec<Vec<u8>> = (0u8..4).map(|i| vec![i; 5]).collect();
+        let messages: Vec<&[u8]> = messages.iter().map(|m| &m[..]).collect();
+        for &scheme in [Scheme::Basic, Scheme::MessageAugmentation, Scheme::ProofOfPossession].iter() {
+            let signatures: Vec<Signature<V>> = keys.iter().zip(messages.iter()).map(|(sk, m)| sk.sign::<V>(scheme, m).unwrap()).collect();
+            let aggregate = Signature::aggregate(&signatures).unwrap();
+            assert!(aggregate_verify(scheme, &public_keys, &messages, &aggregate).unwrap());
+
+            let mut swapped = messages.clone();
+            swapped.swap(0, 1);
+            assert!(!aggregate_verify(scheme, &public_keys, &swapped, &aggregate).unwrap());
+        }
+
+        // basic scheme rejects repeated messages
+        let signatures: Vec<Signature<V>> = keys[..2].iter().map(|sk| sk.sign::<V>(Scheme::Basic, msg).unwrap()).collect();
+        let aggregate = Signature::aggregate(&signatures).unwrap();
+        assert!(!aggregate_verify(Scheme::Basic, &public_keys[..2], &[&msg[..], &msg[..]], &aggregate).unwrap());
+        assert!(aggregate_verify(Scheme::Basic, &public_keys[..1], &[&msg[..], &msg[..]], &aggregate).is_err());
+    }
+
+    #[test]
+    fn test_aggregation_min_pk() {
+        check_aggregation::<MinPublicKeySize>();
+    }
+
+    #[test]
+    fn test_aggregation_min_sig() {
+        check_aggregation::<MinSignatureSize>();
+    }
+
+    #[test]
+    fn test_secret_key_encoding() {
+        let sk = SecretKey::key_gen(&[7u8; 32], b"").unwrap();
+        assert_eq!(SecretKey::from_bytes(&sk.to_bytes()).unwrap(), sk);
+
+        assert!(SecretKey::from_bytes(&[0u8; 32]).is_err());
+        assert!(SecretKey::from_bytes(&hex::decode("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001").unwrap()).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use crate::weierstrass::Group;
+use crate::weierstrass::curve::CurvePoint;
+use crate::weierstrass::{CurveParameters, CurveOverFpParameters, CurveOverFp2Parameters};
+use crate::field::{U384Repr, PrimeField};
+use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+use crate::traits::ZeroAndOne;
+use crate::pairings::PairingEngine;
+use crate::errors::ApiError;
+use crate::engines::bls12_381::{self, mapping};
+use crate::public_interface::zcash_encoding::{self, G1, G2, COMPRESSED_G1_BYTE_LENGTH, COMPRESSED_G2_BYTE_LENGTH};
+
+/// Placement of public keys and signatures in G1 and G2
+pub trait Variant {
+    type PublicKeyCurve: CurveParameters + 'static;
+    type SignatureCurve: CurveParameters + 'static;
+
+    /// Hash-to-curve suite that maps messages into the signature group
+    const HASH_TO_CURVE_SUITE: &'static [u8];
+    /// Length of the compressed encoding of a public key
+    const PUBLIC_KEY_BYTE_LENGTH: usize;
+    /// Length of the compressed encoding of a signature
+    const SIGNATURE_BYTE_LENGTH: usize;
+
+    fn public_key_generator() -> CurvePoint<'static, Self::PublicKeyCurve>;
+
+    fn hash_to_signature_group(msg: &[u8], dst: &[u8]) -> Result<CurvePoint<'static, Self::SignatureCurve>, ApiError>;
+
+    fn public_key_in_subgroup(point: &CurvePoint<'static, Self::PublicKeyCurve>) -> bool;
+
+    fn signature_in_subgroup(point: &CurvePoint<'static, Self::SignatureCurve>) -> bool;
+
+    /// Checks that the product of e(public_keys[i], signature_points[i]) with arguments
+    /// placed into the proper groups is equal to one
+    fn pairing_product_is_one(
+        public_key_points: &[CurvePoint<'static, Self::PublicKeyCurve>],
+        signature_points: &[CurvePoint<'static, Self::SignatureCurve>]
+    ) -> Result<bool, ApiError>;
+
+    fn compress_public_key(point: &CurvePoint<'static, Self::PublicKeyCurve>) -> Result<Vec<u8>, ApiError>;
+
+    fn compress_signature(point: &CurvePoint<'static, Self::SignatureCurve>) -> Result<Vec<u8>, ApiError>;
+
+    /// Decompresses a public key and checks that it's in the main subgroup
+    fn decompress_public_key(bytes: &[u8]) -> Result<CurvePoint<'static, Self::PublicKeyCurve>, ApiError>;
+
+    /// Decompresses a signature and checks that it's in the main subgroup
+    fn decompress_signature(bytes: &[u8]) -> Result<CurvePoint<'static, Self::SignatureCurve>, ApiError>;
+}
+
+/// Public keys in G1 (48 bytes) and signatures in G2 (96 bytes)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MinPublicKeySize;
+
+/// Public keys in G2 (96 bytes) and signatures in G1 (48 bytes)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MinSignatureSize;
+
+impl Variant for MinPublicKeySize {
+    type PublicKeyCurve = CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>;
+    type SignatureCurve = CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>;
+
+    const HASH_TO_CURVE_SUITE: &'static [u8] = b"BLS12381G2_XMD:SHA-256_SSWU_RO_";
+    const PUBLIC_KEY_BYTE_LENGTH: usize = COMPRESSED_G1_BYTE_LENGTH;
+    const SIGNATURE_BYTE_LENGTH: usize = COMPRESSED_G2_BYTE_LENGTH;
+
+    fn public_key_generator() -> G1 {
+        bls12_381::BLS12_381_G1_GENERATOR.clone()
+    }
+
+    fn hash_to_signature_group(msg: &[u8], dst: &[u8]) -> Result<G2, ApiError> {
+        mapping::hash_to_g2(msg, dst)
+    }
+
+    fn public_key_in_subgroup(point: &G1) -> bool {
+        bls12_381::check_correct_subgroup_fast_g1(point)
+    }
+
+    fn signature_in_subgroup(point: &G2) -> bool {
+        bls12_381::check_correct_subgroup_fast_g2(point)
+    }
+
+    fn pairing_product_is_one(public_key_points: &[G1], signature_points: &[G2]) -> Result<bool, ApiError> {
+        pairing_product_is_one(public_key_points, signature_points)
+    }
+
+    fn compress_public_key(point: &G1) -> Result<Vec<u8>, ApiError> {
+        zcash_encoding::serialize_g1(point, true)
+    }
+
+    fn compress_signature(point: &G2) -> Result<Vec<u8>, ApiError> {
+        zcash_encoding::serialize_g2(point, true)
+    }
+
+    fn decompress_public_key(bytes: &[u8]) -> Result<G1, ApiError> {
+        decompress(bytes, Self::PUBLIC_KEY_BYTE_LENGTH, zcash_encoding::deserialize_g1)
+    }
+
+    fn decompress_signature(bytes: &[u8]) -> Result<G2, ApiError> {
+        decompress(bytes, Self::SIGNATURE_BYTE_LENGTH, zcash_encoding::deserialize_g2)
+    }
+}
+
+impl Variant for MinSignatureSize {
+    type PublicKeyCurve = CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>;
+    type SignatureCurve = CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>;
+
+    const HASH_TO_CURVE_SUITE: &'static [u8] = b"BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    const PUBLIC_KEY_BYTE_LENGTH: usize = COMPRESSED_G2_BYTE_LENGTH;
+    const SIGNATURE_BYTE_LENGTH: usize = COMPRESSED_G1_BYTE_LENGTH;
+
+    fn public_key_generator() -> G2 {
+        bls12_381::BLS12_381_G2_GENERATOR.clone()
+    }
+
+    fn hash_to_signature_group(msg: &[u8], dst: &[u8]) -> Result<G1, ApiError> {
+        mapping::hash_to_g1(msg, dst)
+    }
+
+    fn public_key_in_subgroup(point: &G2) -> bool {
+        bls12_381::check_correct_subgroup_fast_g2(point)
+    }
+
+    fn signature_in_subgroup(point: &G1) -> bool {
+        bls12_381::check_correct_subgroup_fast_g1(point)
+    }
+
+    fn pairing_product_is_one(public_key_points: &[G2], signature_points: &[G1]) -> Result<bool, ApiError> {
+        pairing_product_is_one(signature_points, public_key_points)
+    }
+
+    fn compress_public_key(point: &G2) -> Result<Vec<u8>, ApiError> {
+        zcash_encoding::serialize_g2(point, true)
+    }
+
+    fn compress_signature(point: &G1) -> Result<Vec<u8>, ApiError> {
+        zcash_encoding::serialize_g1(point, true)
+    }
+
+    fn decompress_public_key(bytes: &[u8]) -> Result<G2, ApiError> {
+        decompress(bytes, Self::PUBLIC_KEY_BYTE_LENGTH, zcash_encoding::deserialize_g2)
+    }
+
+    fn decompress_signature(bytes: &[u8]) -> Result<G1, ApiError> {
+        decompress(bytes, Self::SIGNATURE_BYTE_LENGTH, zcash_encoding::deserialize_g1)
+    }
+}
+
+fn pairing_product_is_one(g1_points: &[G1], g2_points: &[G2]) -> Result<bool, ApiError> {
+    if g1_points.len() != g2_points.len() {
+        return Err(ApiError::InputError("number of G1 and G2 points must be equal".to_owned()));
+    }
+
+    let mut g1 = Vec::with_capacity(g1_points.len());
+    let mut g2 = Vec::with_capacity(g2_points.len());
+    for (p, q) in g1_points.iter().zip(g2_points.iter()) {
+        if !p.is_zero() && !q.is_zero() {
+            let mut p = p.clone();
+            p.normalize();
+            let mut q = q.clone();
+            q.normalize();
+
+            g1.push(p);
+            g2.push(q);
+        }
+    }
+
+    if g1.len() == 0 {
+        return Ok(true);
+    }
+
+    let result = bls12_381::BLS12_381_PAIRING_ENGINE.pair(&g1, &g2).ok_or_else(|| {
+        ApiError::UnknownParameter("Pairing engine returned no value".to_owned())
+    })?;
+
+    Ok(result == Fp12::one(&bls12_381::BLS12_381_EXTENSION_12_FIELD))
+}
+
+// only compressed encodings are accepted for keys and signatures
+fn decompress<P>(bytes: &[u8], expected_len: usize, deserialize: fn(&[u8]) -> Result<P, ApiError>) -> Result<P, ApiError> {
+    if bytes.len() != expected_len {
+        return Err(ApiError::InputError(format!("invalid length of compressed point, expected {} bytes", expected_len)));
+    }
+
+    deserialize(bytes)
+}
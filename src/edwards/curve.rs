@@ -0,0 +1,436 @@
+use crate::field::SizedPrimeField;
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::traits::{FieldElement, BitIterator, ZeroAndOne};
+use crate::weierstrass::Group;
+
+pub struct EdwardsCurve<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+    pub(crate) a: Fp<'a, FE, F>,
+    pub(crate) d: Fp<'a, FE, F>,
+    pub(crate) subgroup_order_repr: &'a [u64],
+    pub(crate) field: &'a F,
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for EdwardsCurve<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            d: self.d.clone(),
+            subgroup_order_repr: self.subgroup_order_repr,
+            field: self.field
+        }
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> EdwardsCurve<'a, FE, F> {
+    pub(crate) fn new(
+        subgroup_order: &'a [u64],
+        a: Fp<'a, FE, F>,
+        d: Fp<'a, FE, F>,
+        field: &'a F
+    ) -> Result<Self, ()> {
+        // curve is singular otherwise
+        if a.is_zero() || d.is_zero() || a == d {
+            return Err(());
+        }
+
+        Ok(Self {
+            a: a,
+            d: d,
+            subgroup_order_repr: subgroup_order,
+            field: field
+        })
+    }
+}
+
+pub struct EdwardsPoint<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+    pub(crate) curve: &'a EdwardsCurve<'a, FE, F>,
+    pub(crate) x: Fp<'a, FE, F>,
+    pub(crate) y: Fp<'a, FE, F>,
+    pub(crate) t: Fp<'a, FE, F>,
+    pub(crate) z: Fp<'a, FE, F>,
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for EdwardsPoint<'a, FE, F> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            curve: &self.curve,
+            x: self.x.clone(),
+            y: self.y.clone(),
+            t: self.t.clone(),
+            z: self.z.clone()
+        }
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> EdwardsPoint<'a, FE, F> {
+    /// Neutral element is the affine point (0, 1)
+    pub fn zero(curve: &'a EdwardsCurve<'a, FE, F>) -> Self {
+        Self {
+            curve: curve,
+            x: Fp::zero(curve.field),
+            y: Fp::one(curve.field),
+            t: Fp::zero(curve.field),
+            z: Fp::one(curve.field),
+        }
+    }
+
+    pub fn point_from_xy(
+        curve: &'a EdwardsCurve<'a, FE, F>,
+        x: Fp<'a, FE, F>,
+        y: Fp<'a, FE, F>,
+    ) -> Self {
+        let mut t = x.clone();
+        t.mul_assign(&y);
+
+        Self {
+            curve: curve,
+            x: x,
+            y: y,
+            t: t,
+            z: Fp::one(curve.field)
+        }
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        if self.z.is_zero() {
+            return false;
+        }
+
+        // (a*X^2 + Y^2)*Z^2 = Z^4 + d*X^2*Y^2
+        let mut x2 = self.x.clone();
+        x2.square();
+        let mut y2 = self.y.clone();
+        y2.square();
+        let mut z2 = self.z.clone();
+        z2.square();
+
+        let mut lhs = x2.clone();
+        lhs.mul_assign(&self.curve.a);
+        lhs.add_assign(&y2);
+        lhs.mul_assign(&z2);
+
+        let mut rhs = x2;
+        rhs.mul_assign(&y2);
+        rhs.mul_assign(&self.curve.d);
+        z2.square();
+        rhs.add_assign(&z2);
+
+        if lhs != rhs {
+            return false;
+        }
+
+        // X*Y = T*Z
+        let mut xy = self.x.clone();
+        xy.mul_assign(&self.y);
+        let mut tz = self.t.clone();
+        tz.mul_assign(&self.z);
+
+        xy == tz
+    }
+
+    pub fn is_normalized(&self) -> bool {
+        self.z == Fp::one(self.curve.field)
+    }
+
+    pub fn normalize(&mut self) {
+        if self.is_normalized() {
+            return;
+        }
+
+        if let Some(z_inv) = self.z.inverse() {
+            self.x.mul_assign(&z_inv);
+            self.y.mul_assign(&z_inv);
+            self.t = self.x.clone();
+            self.t.mul_assign(&self.y);
+            self.z = Fp::one(self.curve.field);
+        }
+    }
+
+    /// Affine coordinates, (0, 0) if the point can not be normalized
+    pub fn into_xy(&self) -> (Fp<'a, FE, F>, Fp<'a, FE, F>) {
+        let mut point = self.clone();
+        point.normalize();
+        if !point.is_normalized() {
+            return (Fp::zero(self.curve.field), Fp::zero(self.curve.field));
+        }
+
+        (point.x, point.y)
+    }
+
+    fn add_assign_impl(&mut self, other: &Self, other_is_normalized: bool) {
+        // http://www.hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#addition-add-2008-hwcd
+        // and madd-2008-hwcd for Z2 = 1
+
+        // A = X1*X2
+        let mut a = self.x.clone();
+        a.mul_assign(&other.x);
+
+        // B = Y1*Y2
+        let mut b = self.y.clone();
+        b.mul_assign(&other.y);
+
+        // C = T1*d*T2
+        let mut c = self.t.clone();
+        c.mul_assign(&self.curve.d);
+        c.mul_assign(&other.t);
+
+        // D = Z1*Z2
+        let mut d = self.z.clone();
+        if !other_is_normalized {
+            d.mul_assign(&other.z);
+        }
+
+        // E = (X1+Y1)*(X2+Y2)-A-B
+        let mut e = self.x.clone();
+        e.add_assign(&self.y);
+        let mut tmp = other.x.clone();
+        tmp.add_assign(&other.y);
+        e.mul_assign(&tmp);
+        e.sub_assign(&a);
+        e.sub_assign(&b);
+
+        // F = D-C
+        let mut f = d.clone();
+        f.sub_assign(&c);
+
+        // G = D+C
+        let mut g = d;
+        g.add_assign(&c);
+
+        // H = B-a*A
+        a.mul_assign(&self.curve.a);
+        let mut h = b;
+        h.sub_assign(&a);
+
+        // X3 = E*F
+        self.x = e.clone();
+        self.x.mul_assign(&f);
+
+        // Y3 = G*H
+        self.y = g.clone();
+        self.y.mul_assign(&h);
+
+        // T3 = E*H
+        self.t = e;
+        self.t.mul_assign(&h);
+
+        // Z3 = F*G
+        self.z = f;
+        self.z.mul_assign(&g);
+    }
+
+    fn double_impl(&mut self) {
+        // http://www.hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#doubling-dbl-2008-hwcd
+
+        // A = X1^2
+        let mut a = self.x.clone();
+        a.square();
+
+        // B = Y1^2
+        let mut b = self.y.clone();
+        b.square();
+
+        // C = 2*Z1^2
+        let mut c = self.z.clone();
+        c.square();
+        c.double();
+
+        // D = a*A
+        let mut d = a.clone();
+        d.mul_assign(&self.curve.a);
+
+        // E = (X1+Y1)^2-A-B
+        let mut e = self.x.clone();
+        e.add_assign(&self.y);
+        e.square();
+        e.sub_assign(&a);
+        e.sub_assign(&b);
+
+        // G = D+B
+        let mut g = d.clone();
+        g.add_assign(&b);
+
+        // F = G-C
+        let mut f = g.clone();
+        f.sub_assign(&c);
+
+        // H = D-B
+        let mut h = d;
+        h.sub_assign(&b);
+
+        // X3 = E*F
+        self.x = e.clone();
+        self.x.mul_assign(&f);
+
+        // Y3 = G*H
+        self.y = g.clone();
+        self.y.mul_assign(&h);
+
+        // T3 = E*H
+        self.t = e;
+        self.t.mul_assign(&h);
+
+        // Z3 = F*G
+        self.z = f;
+        self.z.mul_assign(&g);
+    }
+
+    fn negate_impl(&mut self) {
+        self.x.negate();
+        self.t.negate();
+    }
+
+    fn is_zero_impl(&self) -> bool {
+        self.x.is_zero() && self.y == self.z
+    }
+
+    pub(crate) fn mul_impl<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut res = Self::zero(&self.curve);
+        let base_is_normalized = self.is_normalized();
+
+        let mut found_one = false;
+
+        for i in BitIterator::new(exp)
+        {
+            if found_one {
+                res.double_impl();
+            } else {
+                found_one = i;
+            }
+
+            if i {
+                res.add_assign_impl(self, base_is_normalized);
+            }
+        }
+
+        res
+    }
+
+    pub(crate) fn wnaf_mul_impl<S: crate::representation::IntoWnaf>(&self, exp: S) -> Self {
+        const WINDOW_SIZE: u32 = 4;
+
+        self.wnaf_mul_with_window_size_impl(exp, WINDOW_SIZE)
+    }
+
+    pub(crate) fn wnaf_mul_with_window_size_impl<S: crate::representation::IntoWnaf>(&self, exp: S, window_size: u32) -> Self {
+        assert!(window_size >= 2u32);
+        let mut precomp_table = vec![Self::zero(&self.curve); (1 << (window_size-1)) as usize];
+
+        let index_for_positive = (1 << (window_size-2)) as usize;
+
+        let mut two_self = self.clone();
+        two_self.double_impl();
+
+        let mut precomp = self.clone();
+        precomp_table[index_for_positive] = precomp.clone();
+        let mut neg_precomp = precomp.clone();
+        neg_precomp.negate_impl();
+        precomp_table[index_for_positive-1] = neg_precomp;
+
+        for i in 1..index_for_positive {
+            precomp.add_assign_impl(&two_self, false);
+            precomp_table[index_for_positive+i] = precomp.clone();
+            let mut neg_precomp = precomp.clone();
+            neg_precomp.negate_impl();
+            precomp_table[index_for_positive-1-i] = neg_precomp;
+        }
+
+        let wnaf = exp.wnaf(window_size);
+
+        let mut res = Self::zero(&self.curve);
+        let mut found_nonzero = false;
+
+        for w in wnaf.into_iter().rev() {
+            if found_nonzero {
+                res.double_impl();
+            }
+            if w != 0 {
+                found_nonzero = true;
+                if w > 0 {
+                    let idx = (w >> 1) as usize;
+                    res.add_assign_impl(&precomp_table[index_for_positive + idx], false);
+                } else {
+                    let idx = ((-w) >> 1) as usize;
+                    res.add_assign_impl(&precomp_table[index_for_positive - 1 - idx], false);
+                }
+            }
+        }
+
+        res
+    }
+
+    fn check_correct_subgroup_impl(&self) -> bool {
+        let p = self.wnaf_mul_impl(self.curve.subgroup_order_repr);
+
+        p.is_zero_impl()
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> PartialEq for EdwardsPoint<'a, FE, F> {
+    fn eq(&self, other: &Self) -> bool {
+        // X1*Z2 = X2*Z1 and Y1*Z2 = Y2*Z1
+        let mut x1z2 = self.x.clone();
+        x1z2.mul_assign(&other.z);
+        let mut x2z1 = other.x.clone();
+        x2z1.mul_assign(&self.z);
+
+        let mut y1z2 = self.y.clone();
+        y1z2.mul_assign(&other.z);
+        let mut y2z1 = other.y.clone();
+        y2z1.mul_assign(&self.z);
+
+        x1z2 == x2z1 && y1z2 == y2z1
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Group for EdwardsPoint<'a, FE, F> {
+    fn add_assign(&mut self, other: &Self) {
+        self.add_assign_impl(other, false);
+    }
+
+    /// `other` is expected to be normalized
+    fn add_assign_mixed(&mut self, other: &Self) {
+        debug_assert!(other.is_normalized());
+        self.add_assign_impl(other, true);
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        let mut other_neg = other.clone();
+        other_neg.negate_impl();
+        self.add_assign_impl(&other_neg, false);
+    }
+
+    fn negate(&mut self) {
+        self.negate_impl();
+    }
+
+    fn mul<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        self.mul_impl(exp)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.is_zero_impl()
+    }
+
+    fn double(&mut self) {
+        self.double_impl();
+    }
+
+    fn wnaf_mul<S: crate::representation::IntoWnaf>(&self, exp: S) -> Self {
+        self.wnaf_mul_impl(exp)
+    }
+
+    fn wnaf_mul_with_window_size<S: crate::representation::IntoWnaf>(&self, exp: S, window_size: u32) -> Self {
+        self.wnaf_mul_with_window_size_impl(exp, window_size)
+    }
+
+    fn check_correct_subgroup(&self) -> bool {
+        self.check_correct_subgroup_impl()
+    }
+
+    fn normalize(&mut self) {
+        EdwardsPoint::normalize(self);
+    }
+}
@@ -0,0 +1,6 @@
+//! Twisted Edwards curves `a*x^2 + y^2 = 1 + d*x^2*y^2` over a prime field. Points are kept
+//! in extended coordinates (X : Y : T : Z) with `x = X/Z`, `y = Y/Z` and `x*y = T/Z`.
+//! Addition and doubling use the unified formulas of Hisil-Wong-Carter-Dawson, that are complete
+//! if `a` is a square and `d` is not, otherwise only on the odd order subgroup
+
+pub mod curve;
@@ -0,0 +1,102 @@
+//! Baby Jubjub curve (EIP-2494) over the BN254 scalar field, `168700*x^2 + y^2 = 1 + 168696*x^2*y^2`.
+//! Group order is 8 times a 251 bit prime. Since `a` is a square and `d` is not, addition formulas
+//! are complete on the whole curve
+
+use crate::field::*;
+use crate::fp::*;
+use crate::edwards::curve::*;
+use crate::engines::bn254::BN254_SCALAR_FIELD;
+
+const REPR_ONE: U256Repr = U256Repr([0xac96341c4ffffffb,0x36fc76959f60cd29,0x666ea36f7879462e,0x0e0a77c19a07df2f]);
+
+pub const BABY_JUBJUB_SUBGROUP_ORDER: [u64; 4] = [
+    0x677297dc392126f1,
+    0xab3eedb83920ee0a,
+    0x370a08b6d0302b0b,
+    0x060c89ce5c263405
+];
+
+pub const BABY_JUBJUB_COFACTOR: u64 = 8;
+
+// a = 168700
+const BABY_JUBJUB_A: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x95accf61fff261e0,0x24780d659df7d378,0xe0ac11b07e906ae8,0x0f35db2216d3def3]),
+    U256Repr,
+    BN254_SCALAR_FIELD
+);
+
+// d = 168696
+const BABY_JUBJUB_D: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x2735f484aff261f5,0x70ba1b579a2e0f63,0xff41c9a91e2caa8c,0x07704a8e8fe6025f]),
+    U256Repr,
+    BN254_SCALAR_FIELD
+);
+
+pub const BABY_JUBJUB_CURVE: EdwardsCurve<'static, U256Repr, PrimeField<U256Repr>> = 
+    EdwardsCurve::<'static, U256Repr, PrimeField<U256Repr>> {
+        a: BABY_JUBJUB_A,
+        d: BABY_JUBJUB_D,
+        subgroup_order_repr: &BABY_JUBJUB_SUBGROUP_ORDER,
+        field: &BN254_SCALAR_FIELD
+    };
+
+const BABY_JUBJUB_GENERATOR_X: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x0a8fc7bc1a89fa86,0xa7d9d786e9e48627,0xee6158b465bea369,0x14a0ff6d2f874519]),
+    U256Repr,
+    BN254_SCALAR_FIELD
+);
+
+const BABY_JUBJUB_GENERATOR_Y: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xb83342d20d0201aa,0x2ffef2f7cdcfeac7,0xbfa79a9425a6e625,0x0dfb859dc3a44b70]),
+    U256Repr,
+    BN254_SCALAR_FIELD
+);
+
+const BABY_JUBJUB_GENERATOR_T: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x772a59f816ea5a4b,0x741affa90241bdaf,0x99d6d544c95278f7,0x13f55de666de5aa7]),
+    U256Repr,
+    BN254_SCALAR_FIELD
+);
+
+pub const BABY_JUBJUB_GENERATOR: EdwardsPoint<'static, U256Repr, PrimeField<U256Repr>> = 
+    EdwardsPoint::<'static, U256Repr, PrimeField<U256Repr>> {
+        curve: &BABY_JUBJUB_CURVE,
+        x: BABY_JUBJUB_GENERATOR_X,
+        y: BABY_JUBJUB_GENERATOR_Y,
+        t: BABY_JUBJUB_GENERATOR_T,
+        z: repr_into_fp!(REPR_ONE, U256Repr, BN254_SCALAR_FIELD),
+    };
+
+#[cfg(test)]
+mod test {
+    use crate::weierstrass::Group;
+    use super::*;
+
+    #[test]
+    fn test_scalar_field_constants() {
+        let field = crate::field::new_field::<U256Repr>("21888242871839275222246405745257275088548364400416034343698204186575808495617", 10).unwrap();
+        assert!(field.mont_r == BN254_SCALAR_FIELD.mont_r);
+        assert!(field.mont_r2 == BN254_SCALAR_FIELD.mont_r2);
+        assert!(field.mont_inv == BN254_SCALAR_FIELD.mont_inv);
+        assert!(field.modulus_bits == BN254_SCALAR_FIELD.modulus_bits);
+    }
+
+    #[test]
+    fn test_generator_is_on_curve() {
+        assert!(BABY_JUBJUB_GENERATOR.is_on_curve());
+        assert!(BABY_JUBJUB_GENERATOR.check_correct_subgroup());
+        assert!(!BABY_JUBJUB_GENERATOR.is_zero());
+    }
+
+    #[test]
+    fn test_group_order() {
+        let mut p = BABY_JUBJUB_GENERATOR.mul(&[0x1234567890abcdef]);
+        p.normalize();
+        assert!(p.is_on_curve());
+        assert!(p.mul(&BABY_JUBJUB_SUBGROUP_ORDER).is_zero());
+
+        let mut sum = p.clone();
+        sum.add_assign(&BABY_JUBJUB_GENERATOR);
+        assert!(sum == BABY_JUBJUB_GENERATOR.mul(&[0x1234567890abcdf0]));
+    }
+}
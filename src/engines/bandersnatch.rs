@@ -0,0 +1,93 @@
+//! Bandersnatch curve over the BLS12-381 scalar field, `-5*x^2 + y^2 = 1 + d*x^2*y^2`.
+//! Group order is 4 times a 253 bit prime. Here `a = -5` is not a square, so addition formulas
+//! are only complete on the prime order subgroup and inputs should be checked to be in it
+
+use crate::field::*;
+use crate::fp::*;
+use crate::edwards::curve::*;
+use crate::engines::bls12_381::BLS12_381_SCALAR_FIELD;
+
+const REPR_ONE: U256Repr = U256Repr([0x00000001fffffffe,0x5884b7fa00034802,0x998c4fefecbc4ff5,0x1824b159acc5056f]);
+
+pub const BANDERSNATCH_SUBGROUP_ORDER: [u64; 4] = [
+    0x74fd06b52876e7e1,
+    0xff8f870074190471,
+    0x0cce760202687600,
+    0x1cfb69d4ca675f52
+];
+
+pub const BANDERSNATCH_COFACTOR: u64 = 4;
+
+// a = -5
+const BANDERSNATCH_A: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xfffffff40000000c,0xece3b023ffec4ff3,0x66b620607396203f,0x6f23d7e5f361df62]),
+    U256Repr,
+    BLS12_381_SCALAR_FIELD
+);
+
+// d = 138827208126141220649022263972958607803/171449701953573178309673572579671231137
+const BANDERSNATCH_D: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xa8dced1b47a2c730,0x381c065aad3cccc7,0x53ff52e1188351f8,0x362e8d63990fe940]),
+    U256Repr,
+    BLS12_381_SCALAR_FIELD
+);
+
+pub const BANDERSNATCH_CURVE: EdwardsCurve<'static, U256Repr, PrimeField<U256Repr>> = 
+    EdwardsCurve::<'static, U256Repr, PrimeField<U256Repr>> {
+        a: BANDERSNATCH_A,
+        d: BANDERSNATCH_D,
+        subgroup_order_repr: &BANDERSNATCH_SUBGROUP_ORDER,
+        field: &BLS12_381_SCALAR_FIELD
+    };
+
+const BANDERSNATCH_GENERATOR_X: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xec2627e1e7ab47f5,0x3e63de484f01aa9c,0xfe0f5c3b53946dc4,0x2d71920baeb2cfcd]),
+    U256Repr,
+    BLS12_381_SCALAR_FIELD
+);
+
+const BANDERSNATCH_GENERATOR_Y: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x4e30593e1895bd34,0x156d738f32afbe4b,0x45ef0b1ccdeb75f4,0x6a7cca0037d2e71f]),
+    U256Repr,
+    BLS12_381_SCALAR_FIELD
+);
+
+const BANDERSNATCH_GENERATOR_T: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x5a92e8f697adb6b9,0xf1388d4606b14609,0x101c783640a64516,0x1e9ae7073cc7a9fc]),
+    U256Repr,
+    BLS12_381_SCALAR_FIELD
+);
+
+pub const BANDERSNATCH_GENERATOR: EdwardsPoint<'static, U256Repr, PrimeField<U256Repr>> = 
+    EdwardsPoint::<'static, U256Repr, PrimeField<U256Repr>> {
+        curve: &BANDERSNATCH_CURVE,
+        x: BANDERSNATCH_GENERATOR_X,
+        y: BANDERSNATCH_GENERATOR_Y,
+        t: BANDERSNATCH_GENERATOR_T,
+        z: repr_into_fp!(REPR_ONE, U256Repr, BLS12_381_SCALAR_FIELD),
+    };
+
+#[cfg(test)]
+mod test {
+    use crate::weierstrass::Group;
+    use super::*;
+
+    #[test]
+    fn test_generator_is_on_curve() {
+        assert!(BANDERSNATCH_GENERATOR.is_on_curve());
+        assert!(BANDERSNATCH_GENERATOR.check_correct_subgroup());
+        assert!(!BANDERSNATCH_GENERATOR.is_zero());
+    }
+
+    #[test]
+    fn test_group_order() {
+        let mut p = BANDERSNATCH_GENERATOR.mul(&[0x1234567890abcdef]);
+        p.normalize();
+        assert!(p.is_on_curve());
+        assert!(p.mul(&BANDERSNATCH_SUBGROUP_ORDER).is_zero());
+
+        let mut sum = p.clone();
+        sum.add_assign(&BANDERSNATCH_GENERATOR);
+        assert!(sum == BANDERSNATCH_GENERATOR.mul(&[0x1234567890abcdf0]));
+    }
+}
@@ -5,6 +5,7 @@ use crate::extension_towers::fp6_as_3_over_2::*;
 use crate::extension_towers::fp12_as_2_over3_over_2::*;
 use crate::weierstrass::*;
 use crate::weierstrass::curve::*;
+use crate::weierstrass::glv::GlvParameters;
 use crate::pairings::bls12::*;
 use crate::pairings::TwistType;
 use crate::integers::MaxFieldUint;
@@ -24,6 +25,8 @@ pub const BLS12_377_MODULUS: U384Repr = U384Repr([0x8508c00000000001,0x170b5d443
 const BLS12_377_R: U384Repr = U384Repr([0x02cdffffffffff68,0x51409f837fffffb1,0x9f7db3a98a7d3ff2,0x7b4e97b76e7c6305,0x4cf495bf803c84e8,0x008d6661e2fdf49a]);
 const BLS12_377_R2: U384Repr = U384Repr([0xb786686c9400cd22,0x0329fcaab00431b1,0x22a5f11162d6b46d,0xbfdf7d03827dc3ac,0x837e92f041790bf9,0x006dfccb1e914b88]);
 const BLS12_377_MONT_INV: u64 = 0x8508bfffffffffff;
+const BLS12_377_TWO_ADICITY: u64 = 46;
+const BLS12_377_TWO_ADIC_ROOT_OF_UNITY: U384Repr = U384Repr([0x68f876aa8bb191f2,0x254e4780a6722e51,0xa818ea191f8a0eaf,0x2c1a6dd31d8d5057,0xcce5a0cba0df931b,0x00ba7904c8cf8495]);
 
 pub const BLS12_377_FIELD: PrimeField<U384Repr> = PrimeField::<U384Repr> {
     mont_power: 384,
@@ -32,6 +35,8 @@ pub const BLS12_377_FIELD: PrimeField<U384Repr> = PrimeField::<U384Repr> {
     mont_r: BLS12_377_R,
     mont_r2: BLS12_377_R2,
     mont_inv: BLS12_377_MONT_INV,  
+    two_adicity: BLS12_377_TWO_ADICITY,
+    two_adic_root_of_unity: BLS12_377_TWO_ADIC_ROOT_OF_UNITY,
 };
 
 
@@ -84,7 +89,7 @@ const BLS12_377_FP2_NON_RESIDUE_C0_REPR: U384Repr = U384Repr([0x0000000000000000
 const BLS12_377_FP2_NON_RESIDUE_C1_REPR: U384Repr = U384Repr([0x02cdffffffffff68,0x51409f837fffffb1,0x9f7db3a98a7d3ff2,0x7b4e97b76e7c6305,0x4cf495bf803c84e8,0x008d6661e2fdf49a]);
 
 const BLS12_377_FP2_NON_RESIDUE_C0: decl_fp!(U384Repr) = repr_into_fp!(
-    BLS12_377_FP2_NON_RESIDUE_C1_REPR, 
+    BLS12_377_FP2_NON_RESIDUE_C0_REPR, 
     U384Repr,
     BLS12_377_FIELD
 );
@@ -112,7 +117,7 @@ pub const BLS12_377_SUBGROUP_ORDER: [u64; 4] = [
 const BLS12_377_X: [u64; 1] = [0x8508c00000000001];
 const BLS12_377_X_IS_NEGATIVE: bool = false;
 
-const BLS12_377_B_FOR_G1_REPR: U384Repr = U384Repr([0x862f3ffffffffd9f,0x2df720c9cffffec3,0x5f036c766febb7c9,0xd31784eab8fc7887,0x6d97513d9450ca66,0x00875f417432c17e]);
+const BLS12_377_B_FOR_G1_REPR: U384Repr = U384Repr([0x02cdffffffffff68,0x51409f837fffffb1,0x9f7db3a98a7d3ff2,0x7b4e97b76e7c6305,0x4cf495bf803c84e8,0x008d6661e2fdf49a]);
 const BLS12_377_B_FOR_G1: Fp<'static, U384Repr, PrimeField<U384Repr>> = 
     Fp::<'static, U384Repr, PrimeField<U384Repr>> {
         field: &BLS12_377_FIELD,
@@ -120,7 +125,7 @@ const BLS12_377_B_FOR_G1: Fp<'static, U384Repr, PrimeField<U384Repr>> =
     };    
 
 const BLS12_377_B_FOR_G2_C0_REPR: U384Repr = U384Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]);
-const BLS12_377_B_FOR_G2_C1_REPR: U384Repr = U384Repr([0x01c8999999999a14,0x37d5649a266666a6,0xff91586b593cd33e,0xe5769b62db93c06d,0x2dd1f333f0509d0e,0x00e70fe9c3d27d0d]);
+const BLS12_377_B_FOR_G2_C1_REPR: U384Repr = U384Repr([0x8072266666666685,0x8df55926899999a9,0x7fe4561ad64f34cf,0xb95da6d8b6e4f01b,0x4b747cccfc142743,0x0039c3fa70f49f43]);
 
 const BLS12_377_B_FOR_G2_C0: Fp<'static, U384Repr, PrimeField<U384Repr>> = 
     Fp::<'static, U384Repr, PrimeField<U384Repr>> {
@@ -183,12 +188,12 @@ const BLS12_377_FP6_FROB_C1_0: decl_fp2!(U384Repr) = repr_into_fp2!(
 
 const BLS12_377_FP6_FROB_C1_1: decl_fp2!(U384Repr) = repr_into_fp2!(
     repr_into_fp!(
-        U384Repr([0x1dab52f567cdf753,0x275ba68225d3fcbf,0xd479033c31671c95,0xbaf5e01f45f5eeb2,0xc124ac0d60ff519a,0x012e21e26d3f27d6]), 
+        U384Repr([0x5892506da58478da,0x133366940ac2a74b,0x9b64a150cdf726cf,0x5cc426090a9c587e,0x5cf848adfdcd640c,0x004702bf3ac02380]), 
         U384Repr,
         BLS12_377_FIELD
     ), 
     repr_into_fp!(
-        U384Repr([0xe8175168f936f1a3,0xa1f9faad6e240880,0x0327ea0da32ce732,0xcbc495c1e2fe7cc2,0xbef5b5eec7980fde,0x00450861e49e5fb2]), 
+        U384Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
         U384Repr,
         BLS12_377_FIELD
     ),
@@ -198,7 +203,7 @@ const BLS12_377_FP6_FROB_C1_1: decl_fp2!(U384Repr) = repr_into_fp2!(
 
 const BLS12_377_FP6_FROB_C1_2: decl_fp2!(U384Repr) = repr_into_fp2!(
     repr_into_fp!(
-        U384Repr([0x2c766f925a7b8727,0x03d7f6b0253d58b5,0x838ec0deec122131,0xbd5eb3e9f658bb10,0x6942bd126ed3e52e,0x01673786dd04ed6a]), 
+        U384Repr([0xdacd106da5847973,0xd8fe2454bac2a79a,0x1ada4fd6fd832edc,0xfb9868449d150908,0xd63eb8aeea32285e,0x0167d6a36f873fd0]), 
         U384Repr,
         BLS12_377_FIELD
     ), 
@@ -213,12 +218,12 @@ const BLS12_377_FP6_FROB_C1_2: decl_fp2!(U384Repr) = repr_into_fp2!(
 
 const BLS12_377_FP6_FROB_C1_3: decl_fp2!(U384Repr) = repr_into_fp2!(
     repr_into_fp!(
-        U384Repr([0xe4a79fc4d4b5361f,0x3f77620e95796e81,0xc331b835dbe63ed4,0x7d9226378f678321,0xe8d237560a8dc3e4,0x008a6a848e739d69]), 
+        U384Repr([0x823ac00000000099,0xc5cabdc0b000004f,0x7f75ae862f8c080d,0x9ed4423b9278b089,0x79467000ec64c452,0x0120d3e434c71c50]), 
         U384Repr,
         BLS12_377_FIELD
     ), 
     repr_into_fp!(
-        U384Repr([0xa03b8e62d0fd012f,0x93c031ea435b3d76,0xafc6f5dbe5871aef,0x7cc296b19f5040e7,0xcd2bc9301cdc60ac,0x01310f7ced1c32b7]), 
+        U384Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
         U384Repr,
         BLS12_377_FIELD
     ),
@@ -246,12 +251,12 @@ const BLS12_377_FP6_FROB_C2_0: decl_fp2!(U384Repr) = repr_into_fp2!(
 
 const BLS12_377_FP6_FROB_C2_1: decl_fp2!(U384Repr) = repr_into_fp2!(
     repr_into_fp!(
-        U384Repr([0x88b5c17a7958b2fe,0x7f5e7e57ba07bc1c,0x6610c4919f5335d4,0x2662d66110d01894,0x528db91fde4912ee,0x01578d053afcb64e]), 
+        U384Repr([0xdacd106da5847973,0xd8fe2454bac2a79a,0x1ada4fd6fd832edc,0xfb9868449d150908,0xd63eb8aeea32285e,0x0167d6a36f873fd0]), 
         U384Repr,
         BLS12_377_FIELD
     ), 
     repr_into_fp!(
-        U384Repr([0x7ce7510b8d5e6d45,0x0623598c82c4f51b,0xc595749a3ca4dfb7,0x4089827645e7035a,0x1d4fa3a2051729e1,0x0028dcfa7aab9072]), 
+        U384Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
         U384Repr,
         BLS12_377_FIELD
     ),
@@ -261,7 +266,7 @@ const BLS12_377_FP6_FROB_C2_1: decl_fp2!(U384Repr) = repr_into_fp2!(
 
 const BLS12_377_FP6_FROB_C2_2: decl_fp2!(U384Repr) = repr_into_fp2!(
     repr_into_fp!(
-        U384Repr([0xdacd106da5847973,0xd8fe2454bac2a79a,0x1ada4fd6fd832edc,0xfb9868449d150908,0xd63eb8aeea32285e,0x0167d6a36f873fd0]), 
+        U384Repr([0x2c766f925a7b8727,0x03d7f6b0253d58b5,0x838ec0deec122131,0xbd5eb3e9f658bb10,0x6942bd126ed3e52e,0x01673786dd04ed6a]), 
         U384Repr,
         BLS12_377_FIELD
     ), 
@@ -276,12 +281,12 @@ const BLS12_377_FP6_FROB_C2_2: decl_fp2!(U384Repr) = repr_into_fp2!(
 
 const BLS12_377_FP6_FROB_C2_3: decl_fp2!(U384Repr) = repr_into_fp2!(
     repr_into_fp!(
-        U384Repr([0x6693d2d815e1849f,0xb8317b4d8c16fa8f,0x417a052262ed3938,0xbb4a9f4398ca0e30,0xf0a0eca1f774c413,0x0103be639ab8b9b1]), 
+        U384Repr([0x02cdffffffffff68,0x51409f837fffffb1,0x9f7db3a98a7d3ff2,0x7b4e97b76e7c6305,0x4cf495bf803c84e8,0x008d6661e2fdf49a]), 
         U384Repr,
         BLS12_377_FIELD
     ), 
     repr_into_fp!(
-        U384Repr([0x1e9a7f00446c4415,0xa2adab41fb145979,0x8974112b4fb7fd03,0x7ea657b93a6854e4,0xe5d71e58ba63a9d1,0x009d3c2719419801]), 
+        U384Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
         U384Repr,
         BLS12_377_FIELD
     ),
@@ -319,12 +324,12 @@ const BLS12_377_FP12_FROB_C1_0: decl_fp2!(U384Repr) = repr_into_fp2!(
 
 const BLS12_377_FP12_FROB_C1_1: decl_fp2!(U384Repr) = repr_into_fp2!(
     repr_into_fp!(
-        U384Repr([0x8e4881eda9715337,0x68933c1b14707f81,0xabe52b0749129354,0x039d6a8fb0acdc2e,0x77c4c0656b2dc79b,0x0102a8dbf4497b6a]), 
+        U384Repr([0x6ec47a04a3f7ca9e,0xa42e0cb968c1fa44,0x578d5187fbd2bd23,0x930eeb0ac79dd4bd,0xa24883de1e09a9ee,0x00daa7058067d46f]), 
         U384Repr,
         BLS12_377_FIELD
     ), 
     repr_into_fp!(
-        U384Repr([0xfe29bc6ac0baf987,0xcbd4ec5004bfc592,0x12f93242a0ff802b,0x6fbab242c943d0fe,0x0362fa6af0205e6e,0x013a4205b11a7684]), 
+        U384Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
         U384Repr,
         BLS12_377_FIELD
     ),
@@ -334,7 +339,7 @@ const BLS12_377_FP12_FROB_C1_1: decl_fp2!(U384Repr) = repr_into_fp2!(
 
 const BLS12_377_FP12_FROB_C1_2: decl_fp2!(U384Repr) = repr_into_fp2!(
     repr_into_fp!(
-        U384Repr([0xdacd106da5847973,0xd8fe2454bac2a79a,0x1ada4fd6fd832edc,0xfb9868449d150908,0xd63eb8aeea32285e,0x0167d6a36f873fd0]), 
+        U384Repr([0x5892506da58478da,0x133366940ac2a74b,0x9b64a150cdf726cf,0x5cc426090a9c587e,0x5cf848adfdcd640c,0x004702bf3ac02380]), 
         U384Repr,
         BLS12_377_FIELD
     ), 
@@ -349,12 +354,12 @@ const BLS12_377_FP12_FROB_C1_2: decl_fp2!(U384Repr) = repr_into_fp2!(
 
 const BLS12_377_FP12_FROB_C1_3: decl_fp2!(U384Repr) = repr_into_fp2!(
     repr_into_fp!(
-        U384Repr([0xc059cd145fe9bd8c,0xdb5711d0ca9b5c20,0xff5b91f6ceecf992,0x2c9e02f131e3fecb,0x6b1d1c9c56f21cd0,0x019d034f7fdacf4e]), 
+        U384Repr([0x982c13d9d084771f,0xfd49de0c6da34a32,0x61a530d183ab0e53,0xdf8fe44106dd9879,0x40f29b58d88472bc,0x0158723199046d5d]), 
         U384Repr,
         BLS12_377_FIELD
     ), 
     repr_into_fp!(
-        U384Repr([0xe5bf2331ea1a4ad9,0xfa109dcb130047d8,0x5322840482579c81,0xe07b400cda4647b3,0xcdbfbc15d4645c07,0x008f9a1e7e02e793]), 
+        U384Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
         U384Repr,
         BLS12_377_FIELD
     ),
@@ -367,7 +372,7 @@ const BLS12_377_FP12_FROB_C1_5: decl_fp2!(U384Repr) = BLS12_377_FP2_ZERO;
 
 const BLS12_377_FP12_FROB_C1_6: decl_fp2!(U384Repr) = repr_into_fp2!(
     repr_into_fp!(
-        U384Repr([0x02cdffffffffff68,0x51409f837fffffb1,0x9f7db3a98a7d3ff2,0x7b4e97b76e7c6305,0x4cf495bf803c84e8,0x008d6661e2fdf49a]), 
+        U384Repr([0x823ac00000000099,0xc5cabdc0b000004f,0x7f75ae862f8c080d,0x9ed4423b9278b089,0x79467000ec64c452,0x0120d3e434c71c50]), 
         U384Repr,
         BLS12_377_FIELD
     ), 
@@ -409,13 +414,38 @@ Extension2Over3Over2::<'static, U384Repr, PrimeField<U384Repr>> {
     frobenius_coeffs_are_calculated: true
 };   
 
+// endomorphism (x, y) -> (beta * x, y) on G1 and a short basis of the corresponding lattice for GLV multiplication
+const BLS12_377_G1_GLV_BETA: decl_fp!(U384Repr) = repr_into_fp!(
+    U384Repr([0x2c766f925a7b8727,0x03d7f6b0253d58b5,0x838ec0deec122131,0xbd5eb3e9f658bb10,0x6942bd126ed3e52e,0x01673786dd04ed6a]), 
+    U384Repr,
+    BLS12_377_FIELD
+);
+
+const BLS12_377_G1_GLV_A1: [u64; 1] = [0x0000000000000001];
+const BLS12_377_G1_GLV_B1: [u64; 2] = [0x0a11800000000000, 0x452217cc90000001];
+const BLS12_377_G1_GLV_A2: [u64; 2] = [0x0a11800000000001, 0x452217cc90000001];
+const BLS12_377_G1_GLV_B2: [u64; 1] = [0x0000000000000001];
+
+const BLS12_377_G1_GLV_PARAMETERS: GlvParameters<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>> = 
+    GlvParameters::<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>> {
+        beta: BLS12_377_G1_GLV_BETA,
+        basis: [
+            (&BLS12_377_G1_GLV_A1, false),
+            (&BLS12_377_G1_GLV_B1, true),
+            (&BLS12_377_G1_GLV_A2, false),
+            (&BLS12_377_G1_GLV_B2, false),
+        ],
+        cofactor_is_one: false
+    };
+
 pub const BLS12_377_G1_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>> = 
     WeierstrassCurve::<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>> {
         a: BLS12_377_FP_ZERO,
         b: BLS12_377_B_FOR_G1,
         curve_type: CurveType::AIsZero,
         subgroup_order_repr: &BLS12_377_SUBGROUP_ORDER,
-        params: &BLS12_377_G1_CURVE_PARAMETERS
+        params: &BLS12_377_G1_CURVE_PARAMETERS,
+        glv: Some(BLS12_377_G1_GLV_PARAMETERS)
     };   
 
 pub const BLS12_377_G2_CURVE: WeierstrassCurve<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>> = 
@@ -424,7 +454,8 @@ pub const BLS12_377_G2_CURVE: WeierstrassCurve<'static, CurveOverFp2Parameters<'
         b: BLS12_377_B_FOR_G2,
         curve_type: CurveType::AIsZero,
         subgroup_order_repr: &BLS12_377_SUBGROUP_ORDER,
-        params: &BLS12_377_G2_CURVE_PARAMETERS
+        params: &BLS12_377_G2_CURVE_PARAMETERS,
+        glv: None
     };   
 
 const BLS12_377_G1_GENERATOR_X: decl_fp!(U384Repr) = repr_into_fp!(
@@ -513,6 +544,57 @@ pub const BLS12_377_PAIRING_ENGINE: Bls12Instance<
     x_naf: Vec::new()
 };
 
+// cofactors of G1 and G2, (x - 1)^2 / 3 and #E'(Fp2) / r
+pub const BLS12_377_G1_COFACTOR: [u64; 2] = [0x0000000000000000,0x170b5d4430000000];
+pub const BLS12_377_G2_COFACTOR: [u64; 8] = [0x0000000000000001,0x452217cc90000000,0xa0f3622fba094800,0xd693e8c36676bd09,0x8c505634fae2e189,0xfbb36b00e1dcc40c,0xddd88d99a6f6a829,0x0026ba558ae9562a];
+
+/// Field-to-curve mappings used by EIP-2539. Both G1 and G2 have A = 0 and no isogeny is known
+/// in this crate, so the Shallue-van de Woestijne mapping is used instead of the simplified SWU,
+/// followed by multiplication by the cofactor
+#[cfg(feature = "mappings")]
+pub mod mapping {
+    use crate::weierstrass::Group;
+    use crate::errors::ApiError;
+    use super::*;
+    use crate::mapping::svdw::*;
+
+    pub fn fp_to_g1(
+        el: &Fp<'static, U384Repr, PrimeField<U384Repr>>
+    ) -> Result<CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
+    {
+        let params = &*crate::mapping::parameters::BLS12_377_G1_SVDW_PARAMS;
+        let (x, y) = svdw(el, params, &BLS12_377_G1_CURVE);
+
+        let point = CurvePoint::point_from_xy(&BLS12_377_G1_CURVE, x, y);
+        if point.is_on_curve() == false {
+            return Err(ApiError::InputError("mapped point is not on the curve".to_owned()));
+        }
+
+        let mut cofactor_cleared = point.mul(&BLS12_377_G1_COFACTOR[..]);
+        cofactor_cleared.normalize();
+
+        Ok(cofactor_cleared)
+    }
+
+    pub fn fp2_to_g2(
+        el: &Fp2<'static, U384Repr, PrimeField<U384Repr>>
+    ) -> Result<CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
+    {
+        let params = &*crate::mapping::parameters::BLS12_377_G2_SVDW_PARAMS;
+        let (x, y) = svdw(el, params, &BLS12_377_G2_CURVE);
+
+        let point = CurvePoint::point_from_xy(&BLS12_377_G2_CURVE, x, y);
+        if point.is_on_curve() == false {
+            return Err(ApiError::InputError("mapped point is not on the curve".to_owned()));
+        }
+
+        let mut cofactor_cleared = point.wnaf_mul_with_window_size(&BLS12_377_G2_COFACTOR[..], 5);
+        cofactor_cleared.normalize();
+
+        Ok(cofactor_cleared)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::traits::FieldElement;
@@ -542,6 +624,64 @@ mod test {
         assert!(ans1 == ans3);
     }
 
+    #[test]
+    fn test_generators_are_on_curve() {
+        assert!(BLS12_377_G1_GENERATOR.is_on_curve());
+        assert!(BLS12_377_G2_GENERATOR.is_on_curve());
+        assert!(BLS12_377_G1_GENERATOR.check_correct_subgroup());
+        assert!(BLS12_377_G2_GENERATOR.check_correct_subgroup());
+    }
+
+    #[test]
+    fn test_engine_matches_reference_pairing() {
+        use crate::pairings::PairingEngine;
+
+        // final exponentiation results in a cube of the reduced optimal ate pairing f_{x, Q}(P)^((p^12 - 1) / r),
+        // reference value is calculated with an independent implementation over Fp[w] / (w^12 + 5)
+        let result = BLS12_377_PAIRING_ENGINE.pair(&[BLS12_377_G1_GENERATOR.clone()], &[BLS12_377_G2_GENERATOR.clone()]).unwrap();
+        let expected = [
+            (&result.c0.c0, "0x00b718ff624a95f189bfb44bcd6d6556226837c1f74d1afbf4bea573b71c17d3a243cae41d966e2164aad0991fd790cc", "0x0197261459eb50c526a28ebbdbd4b5b33d4c55b759d8c926289c96e4ea032783da4f1994ed09ee68fd791367c8b54d87"),
+            (&result.c0.c1, "0x00756970de5e545d91121e151ce96c26ad820ebe4ffbc9dee234351401925eaa4193e377135ced4d3845057c0c39ecd6", "0x00373f07857759dbec3d57af8bfdc79d28f44db5103e523e28ea69c688af7c831e726417cb5123530fadb5540ac05763"),
+            (&result.c0.c2, "0x00ec2d5430932820eb74bd698a2d919cf7086335f235019815501b97fd833d90f07eb111885af785beb343ea1db8d4e7", "0x0051ae2dce91bcd2251abbaf8dfb67c7e5cf6d864c61f81a09aaeac3dfdcf6ae0b3168929ccc7d91abb8b4e13974b7db"),
+            (&result.c1.c0, "0x0095fcebb2a29b10d2f5283a40b147a82ea62114c9bae68e0d745c1afc70c6eeaf1b1c5bf6352d82931b6bdcbff8da47", "0x001fdad7541653e8ac2d735c24f472716122bb24a3e675c20ab2c23d7380c7a349d49dd0db11f95c08861744e3b19a8e"),
+            (&result.c1.c1, "0x00b3530a66bf5754b3e0b7b2c070a35c072bb613698c32db836cef1fcb77086125efd02528d4235f7d7b87e554174d82", "0x004064943ac5c2fc0ef854d8168c67f56adb2a5a16d900dba15be3ecb0172a9ecd96ebf6375d0262f5d43d0709dc8c5f"),
+            (&result.c1.c2, "0x0066910d06a91685179f1b448b9b198d5ed2eabc44d21580005e5f708a3c7858eb9b921691e40ba25804aced41190d34", "0x0008f3e3e451ff584f864ca1d53fc34562f2ebf3baa7c610d8a3b51a7fa9e8dfaac34399e40540e3bc57a73d11924c03"),
+        ];
+
+        for (el, c0, c1) in expected.iter() {
+            assert_eq!(format!("{}", el.c0), *c0);
+            assert_eq!(format!("{}", el.c1), *c1);
+        }
+    }
+
+    #[test]
+    fn test_constants_match_calculated_ones() {
+        use crate::extension_towers::Fp6Fp12FrobeniusBaseElements;
+        use crate::traits::ZeroAndOne;
+
+        // Fp6 and Fp12 are built over the non-residue u, where u^2 = -5
+        let mut u = BLS12_377_FP2_ZERO.clone();
+        u.c1 = BLS12_377_FP_ONE.clone();
+        assert!(BLS12_377_EXTENSION_6_FIELD.non_residue == u);
+
+        // b = 1 and b' = b / u
+        assert!(BLS12_377_G1_CURVE.b == BLS12_377_FP_ONE);
+        let mut b_twist = BLS12_377_G2_CURVE.b.clone();
+        b_twist.mul_assign(&u);
+        assert!(b_twist == BLS12_377_FP2_ONE);
+
+        let precomp = Fp6Fp12FrobeniusBaseElements::construct(&BLS12_377_MODULUS_UINT, &u).unwrap();
+
+        let mut extension_6 = Extension3Over2::new(u.clone());
+        extension_6.calculate_frobenius_coeffs_with_precomp(&precomp).unwrap();
+        assert!(extension_6.frobenius_coeffs_c1 == BLS12_377_EXTENSION_6_FIELD.frobenius_coeffs_c1);
+        assert!(extension_6.frobenius_coeffs_c2 == BLS12_377_EXTENSION_6_FIELD.frobenius_coeffs_c2);
+
+        let mut extension_12 = Extension2Over3Over2::new(Fp6::zero(&extension_6));
+        extension_12.calculate_frobenius_coeffs_with_precomp(&precomp).unwrap();
+        assert!(extension_12.frobenius_coeffs_c1 == BLS12_377_EXTENSION_12_FIELD.frobenius_coeffs_c1);
+    }
+
     fn output_test_vector(input: &[u8], output: &[u8]) {
         println!("Input: 0x{}", hex::encode(input));
         println!("Output: 0x{}", hex::encode(output));
@@ -575,4 +715,54 @@ mod test {
 
         output_test_vector(&input_encoding, &output_encoding);
     }
+
+    #[cfg(feature = "mappings")]
+    #[test]
+    fn test_mapping_lands_in_subgroup() {
+        use crate::weierstrass::Group;
+        use crate::traits::ZeroAndOne;
+
+        let mut u = Fp::one(&BLS12_377_FIELD);
+        let mut u2 = Fp2::one(&BLS12_377_EXTENSION_2_FIELD);
+        for _ in 0..10 {
+            u.double();
+            u.add_assign(&BLS12_377_FP_ONE);
+            u2.c1.add_assign(&u);
+
+            let p = mapping::fp_to_g1(&u).unwrap();
+            assert!(p.is_on_curve());
+            assert!(p.check_correct_subgroup());
+            assert!(!p.is_zero());
+
+            let q = mapping::fp2_to_g2(&u2).unwrap();
+            assert!(q.is_on_curve());
+            assert!(q.check_correct_subgroup());
+            assert!(!q.is_zero());
+        }
+
+        // exceptional case of the mapping
+        let zero = Fp::zero(&BLS12_377_FIELD);
+        assert!(mapping::fp_to_g1(&zero).unwrap().check_correct_subgroup());
+    }
+
+    #[cfg(feature = "mappings")]
+    #[test]
+    fn test_mapping_against_reference() {
+        use crate::traits::ZeroAndOne;
+
+        // reference values are calculated with an independent implementation of the SvdW mapping and cofactor clearing
+        let u = Fp::from_repr(&BLS12_377_FIELD, U384Repr::from(42)).unwrap();
+        let (x, y) = mapping::fp_to_g1(&u).unwrap().into_xy();
+        assert_eq!(format!("{}", x), "0x002b1b94e39fb7060e5f0a684ae37cf203b1e898cec194f4e00c4637c3b199cf5a1a84ee10c77b7baa6b01069c6ca9b7");
+        assert_eq!(format!("{}", y), "0x00a9a3b3a78f094800ed05948e4308b7e20abdee2841f84f72b04a03b6f16099abcccf69aa8c68824abfd968e641c7d2");
+
+        let mut u2 = Fp2::zero(&BLS12_377_EXTENSION_2_FIELD);
+        u2.c0 = u.clone();
+        u2.c1 = u;
+        let (x, y) = mapping::fp2_to_g2(&u2).unwrap().into_xy();
+        assert_eq!(format!("{}", x.c0), "0x0111ffc39864eeb19caf744573fabd446826e7074ae4c89b5b50f5fc43519e68fbc46268e2255bffa3a6673d04236548");
+        assert_eq!(format!("{}", x.c1), "0x00b9abdee42ffd68f534d1af1fa182803f29ff1e6cca213b6dc3ab7f5839348ee4ff8b3190feab7b4fbac33616b09f47");
+        assert_eq!(format!("{}", y.c0), "0x00880a18a0c8903a4972e347e48f3e8b77440172ede3489db5c0a17a06abf048be5fdf52bfd65d0d6eb06d8d8b282a3b");
+        assert_eq!(format!("{}", y.c1), "0x019f3a9af484255e7e20508e5fdae872e9ca85aa13ff15104f23f384ccd859c31fb901895c65bb064052cb0eefbbeae1");
+    }
 }
@@ -6,6 +6,7 @@ use crate::extension_towers::fp6_as_3_over_2::*;
 use crate::extension_towers::fp12_as_2_over3_over_2::*;
 use crate::weierstrass::*;
 use crate::weierstrass::curve::*;
+use crate::weierstrass::glv::GlvParameters;
 use crate::pairings::bls12::*;
 use crate::pairings::TwistType;
 use crate::integers::MaxFieldUint;
@@ -69,6 +70,8 @@ pub const BLS12_381_MODULUS: U384Repr = U384Repr([0xb9feffffffffaaab,0x1eabfffeb
 const BLS12_381_R: U384Repr = U384Repr([0x760900000002fffd,0xebf4000bc40c0002,0x5f48985753c758ba,0x77ce585370525745,0x5c071a97a256ec6d,0x15f65ec3fa80e493]);
 const BLS12_381_R2: U384Repr = U384Repr([0xf4df1f341c341746,0x0a76e6a609d104f1,0x8de5476c4c95b6d5,0x67eb88a9939d83c0,0x9a793e85b519952d,0x11988fe592cae3aa]);
 const BLS12_381_MONT_INV: u64 = 0x89f3fffcfffcfffd;
+const BLS12_381_TWO_ADICITY: u64 = 1;
+const BLS12_381_TWO_ADIC_ROOT_OF_UNITY: U384Repr = U384Repr([0x43f5fffffffcaaae,0x32b7fff2ed47fffd,0x07e83a49a2e99d69,0xeca8f3318332bb7a,0xef148d1ea0f4c069,0x040ab3263eff0206]);
 
 pub const BLS12_381_FIELD: PrimeField<U384Repr> = PrimeField::<U384Repr> {
     mont_power: 384,
@@ -77,6 +80,8 @@ pub const BLS12_381_FIELD: PrimeField<U384Repr> = PrimeField::<U384Repr> {
     mont_r: BLS12_381_R,
     mont_r2: BLS12_381_R2,
     mont_inv: BLS12_381_MONT_INV,  
+    two_adicity: BLS12_381_TWO_ADICITY,
+    two_adic_root_of_unity: BLS12_381_TWO_ADIC_ROOT_OF_UNITY,
 };
 
 const BLS12_381_FP_NON_RESIDUE_REPR: U384Repr = U384Repr([0x43f5fffffffcaaae,0x32b7fff2ed47fffd,0x07e83a49a2e99d69,0xeca8f3318332bb7a,0xef148d1ea0f4c069,0x040ab3263eff0206]);
@@ -153,6 +158,26 @@ pub const BLS12_381_SUBGROUP_ORDER: [u64; 4] = [
     0x73eda753299d7d48
 ];
 
+const BLS12_381_SCALAR_FIELD_MODULUS: U256Repr = U256Repr([0xffffffff00000001,0x53bda402fffe5bfe,0x3339d80809a1d805,0x73eda753299d7d48]);
+const BLS12_381_SCALAR_FIELD_R: U256Repr = U256Repr([0x00000001fffffffe,0x5884b7fa00034802,0x998c4fefecbc4ff5,0x1824b159acc5056f]);
+const BLS12_381_SCALAR_FIELD_R2: U256Repr = U256Repr([0xc999e990f3f29c6d,0x2b6cedcb87925c23,0x05d314967254398f,0x0748d9d99f59ff11]);
+const BLS12_381_SCALAR_FIELD_MONT_INV: u64 = 0xfffffffeffffffff;
+const BLS12_381_SCALAR_FIELD_TWO_ADICITY: u64 = 32;
+// 7^((r - 1) / 2^32)
+const BLS12_381_SCALAR_FIELD_TWO_ADIC_ROOT_OF_UNITY: U256Repr = U256Repr([0xb9b58d8c5f0e466a,0x5b1b4c801819d7ec,0x0af53ae352a31e64,0x5bf3adda19e9b27b]);
+
+/// Field of the main subgroup order, base field of Jubjub and Bandersnatch
+pub const BLS12_381_SCALAR_FIELD: PrimeField<U256Repr> = PrimeField::<U256Repr> {
+    mont_power: 256,
+    modulus_bits: 255,
+    modulus: BLS12_381_SCALAR_FIELD_MODULUS,
+    mont_r: BLS12_381_SCALAR_FIELD_R,
+    mont_r2: BLS12_381_SCALAR_FIELD_R2,
+    mont_inv: BLS12_381_SCALAR_FIELD_MONT_INV,
+    two_adicity: BLS12_381_SCALAR_FIELD_TWO_ADICITY,
+    two_adic_root_of_unity: BLS12_381_SCALAR_FIELD_TWO_ADIC_ROOT_OF_UNITY,
+};
+
 const BLS12_381_X: [u64; 1] = [0xd201000000010000];
 const BLS12_381_X_IS_NEGATIVE: bool = true;
 
@@ -452,13 +477,38 @@ Extension2Over3Over2::<'static, U384Repr, PrimeField<U384Repr>> {
     frobenius_coeffs_are_calculated: true
 };   
 
+// endomorphism (x, y) -> (beta * x, y) on G1 and a short basis of the corresponding lattice for GLV multiplication
+const BLS12_381_G1_GLV_BETA: decl_fp!(U384Repr) = repr_into_fp!(
+    U384Repr([0xcd03c9e48671f071,0x5dab22461fcda5d2,0x587042afd3851b95,0x8eb60ebe01bacb9e,0x03f97d6e83d050d2,0x18f0206554638741]), 
+    U384Repr,
+    BLS12_381_FIELD
+);
+
+const BLS12_381_G1_GLV_A1: [u64; 1] = [0x0000000000000001];
+const BLS12_381_G1_GLV_B1: [u64; 2] = [0x0000000100000000, 0xac45a4010001a402];
+const BLS12_381_G1_GLV_A2: [u64; 2] = [0x00000000ffffffff, 0xac45a4010001a402];
+const BLS12_381_G1_GLV_B2: [u64; 1] = [0x0000000000000001];
+
+const BLS12_381_G1_GLV_PARAMETERS: GlvParameters<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>> = 
+    GlvParameters::<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>> {
+        beta: BLS12_381_G1_GLV_BETA,
+        basis: [
+            (&BLS12_381_G1_GLV_A1, false),
+            (&BLS12_381_G1_GLV_B1, false),
+            (&BLS12_381_G1_GLV_A2, false),
+            (&BLS12_381_G1_GLV_B2, true),
+        ],
+        cofactor_is_one: false
+    };
+
 pub const BLS12_381_G1_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>> = 
     WeierstrassCurve::<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>> {
         a: BLS12_381_FP_ZERO,
         b: BLS12_381_B_FOR_G1,
         curve_type: CurveType::AIsZero,
         subgroup_order_repr: &BLS12_381_SUBGROUP_ORDER,
-        params: &BLS12_381_G1_CURVE_PARAMETERS
+        params: &BLS12_381_G1_CURVE_PARAMETERS,
+        glv: Some(BLS12_381_G1_GLV_PARAMETERS)
     };   
 
 pub const BLS12_381_G2_CURVE: WeierstrassCurve<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>> = 
@@ -467,7 +517,8 @@ pub const BLS12_381_G2_CURVE: WeierstrassCurve<'static, CurveOverFp2Parameters<'
         b: BLS12_381_B_FOR_G2,
         curve_type: CurveType::AIsZero,
         subgroup_order_repr: &BLS12_381_SUBGROUP_ORDER,
-        params: &BLS12_381_G2_CURVE_PARAMETERS
+        params: &BLS12_381_G2_CURVE_PARAMETERS,
+        glv: None
     };   
 
 const BLS12_381_G1_GENERATOR_X: decl_fp!(U384Repr) = repr_into_fp!(
@@ -557,6 +608,91 @@ pub const BLS12_381_PAIRING_ENGINE: Bls12Instance<
 };
 
 
+// non-trivial cube root of unity in Fp, endomorphism (x, y) -> (beta * x, y) acts as [-x^2] on G1
+const BLS12_381_G1_ENDOMORPHISM_BETA: decl_fp!(U384Repr) = repr_into_fp!(
+    U384Repr([0x30f1361b798a64e8,0xf3b8ddab7ece5a2a,0x16a8ca3ac61577f7,0xc26a2ff874fd029b,0x3636b76660701c6e,0x051ba4ab241b6160]), 
+    U384Repr,
+    BLS12_381_FIELD
+);
+
+// 1/(1+u)^((p-1)/3)
+const BLS12_381_G2_PSI_COEFF_X: decl_fp2!(U384Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U384Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
+        U384Repr,
+        BLS12_381_FIELD
+    ), 
+    repr_into_fp!(
+        U384Repr([0x890dc9e4867545c3,0x2af322533285a5d5,0x50880866309b7e2c,0xa20d1b8c7e881024,0x14e4f04fe2db9068,0x14e56d3f1564853a]), 
+        U384Repr,
+        BLS12_381_FIELD
+    ),
+    U384Repr,
+    BLS12_381_EXTENSION_2_FIELD
+);
+
+// 1/(1+u)^((p-1)/2)
+const BLS12_381_G2_PSI_COEFF_Y: decl_fp2!(U384Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U384Repr([0x3e2f585da55c9ad1,0x4294213d86c18183,0x382844c88b623732,0x92ad2afd19103e18,0x1d794e4fac7cf0b9,0x0bd592fc7d825ec8]), 
+        U384Repr,
+        BLS12_381_FIELD
+    ), 
+    repr_into_fp!(
+        U384Repr([0x7bcfa7a25aa30fda,0xdc17dec12a927e7c,0x2f088dd86b4ebef1,0xd1ca2087da74d4a7,0x2da2596696cebc1d,0x0e2b7eedbbfd87d2]), 
+        U384Repr,
+        BLS12_381_FIELD
+    ),
+    U384Repr,
+    BLS12_381_EXTENSION_2_FIELD
+);
+
+/// Subgroup check for G1 point using the GLV endomorphism: P is in G1 iff phi(P) == [-x^2]P.
+/// Gives the same result as multiplication by the group order for any point on the curve
+pub fn check_correct_subgroup_fast_g1(
+    p: &CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>
+) -> bool {
+    if p.is_zero() {
+        return true;
+    }
+
+    let mut endo = p.clone();
+    endo.x.mul_assign(&BLS12_381_G1_ENDOMORPHISM_BETA);
+
+    // [x^2]P, sign of x doesn't matter here
+    let mut t = p.mul(&BLS12_381_X[..]);
+    t = t.mul(&BLS12_381_X[..]);
+    t.add_assign(&endo);
+
+    t.is_zero()
+}
+
+/// Subgroup check for G2 point using the untwist-Frobenius-twist endomorphism: P is in G2 iff psi(P) == [x]P.
+/// Gives the same result as multiplication by the group order for any point on the curve
+pub fn check_correct_subgroup_fast_g2(
+    p: &CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>
+) -> bool {
+    if p.is_zero() {
+        return true;
+    }
+
+    // Frobenius in Fp2 is a conjugation
+    let mut endo = p.clone();
+    endo.x.frobenius_map(1);
+    endo.x.mul_assign(&BLS12_381_G2_PSI_COEFF_X);
+    endo.y.frobenius_map(1);
+    endo.y.mul_assign(&BLS12_381_G2_PSI_COEFF_Y);
+    endo.z.frobenius_map(1);
+
+    let mut t = p.mul(&BLS12_381_X[..]);
+    if !BLS12_381_X_IS_NEGATIVE {
+        t.negate();
+    }
+    t.add_assign(&endo);
+
+    t.is_zero()
+}
+
 // isogeny for field-to-curve mapping
 
 pub const BLS12_381_G1_ISOGENY_A: decl_fp!(U384Repr) = repr_into_fp!(
@@ -577,7 +713,8 @@ pub const BLS12_381_G1_CURVE_ISOGENY: WeierstrassCurve<'static, CurveOverFpParam
         b: BLS12_381_G1_ISOGENY_B,
         curve_type: CurveType::Generic,
         subgroup_order_repr: &BLS12_381_SUBGROUP_ORDER,
-        params: &BLS12_381_G1_CURVE_PARAMETERS
+        params: &BLS12_381_G1_CURVE_PARAMETERS,
+        glv: None
     };  
 
 pub const BLS12_381_G1_SWU_Z: decl_fp!(U384Repr) = repr_into_fp!(
@@ -641,7 +778,8 @@ pub const BLS12_381_G2_CURVE_ISOGENY: WeierstrassCurve<'static, CurveOverFp2Para
         b: BLS12_381_G2_ISOGENY_B,
         curve_type: CurveType::AIsZero,
         subgroup_order_repr: &BLS12_381_SUBGROUP_ORDER,
-        params: &BLS12_381_G2_CURVE_PARAMETERS
+        params: &BLS12_381_G2_CURVE_PARAMETERS,
+        glv: None
     };   
 
 pub const BLS12_381_G2_MAPPING_H_EFF: [u64; 10] = [
@@ -675,6 +813,25 @@ pub mod mapping {
     pub fn fp_to_g1(
         el: &Fp<'static, U384Repr, PrimeField<U384Repr>>
     ) -> Result<CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
+    {
+        let point = map_to_curve_g1(el)?;
+
+        Ok(clear_cofactor_g1(&point))
+    }
+
+    pub fn fp2_to_g2(
+        el: &Fp2<'static, U384Repr, PrimeField<U384Repr>>
+    ) -> Result<CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
+    {
+        let point = map_to_curve_g2(el)?;
+
+        Ok(clear_cofactor_g2(&point))
+    }
+
+    // SWU mapping followed by the isogeny, result is on the curve but not in the main subgroup
+    fn map_to_curve_g1(
+        el: &Fp<'static, U384Repr, PrimeField<U384Repr>>
+    ) -> Result<CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
     {
         let (swu, iso) = &*crate::mapping::parameters::BLS12_G1_MAPPING_PARAMS;
         let (x_prime, y_prime) = simplified_swu_fp(
@@ -695,13 +852,20 @@ pub mod mapping {
             return Err(ApiError::InputError("mapped point is not on the curve".to_owned()));
         }
 
+        Ok(point)
+    }
+
+    fn clear_cofactor_g1(
+        point: &CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>
+    ) -> CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>
+    {
         let mut cofactor_cleared = point.mul(&BLS12_381_G1_MAPPING_H_EFF[..]);
         cofactor_cleared.normalize();
 
-        Ok(cofactor_cleared)
+        cofactor_cleared
     }
 
-    pub fn fp2_to_g2(
+    fn map_to_curve_g2(
         el: &Fp2<'static, U384Repr, PrimeField<U384Repr>>
     ) -> Result<CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
     {
@@ -724,10 +888,101 @@ pub mod mapping {
             return Err(ApiError::InputError("mapped point is not on the curve".to_owned()));
         }
 
+        Ok(point)
+    }
+
+    fn clear_cofactor_g2(
+        point: &CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>
+    ) -> CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>
+    {
         let mut cofactor_cleared = point.wnaf_mul_with_window_size(&BLS12_381_G2_MAPPING_H_EFF[..], 5);
         cofactor_cleared.normalize();
 
-        Ok(cofactor_cleared)
+        cofactor_cleared
+    }
+
+    // L parameter of both BLS12-381 suites
+    #[cfg(feature = "hash_to_curve")]
+    const HASH_TO_FIELD_SECURITY_BYTES: usize = 64;
+
+    #[cfg(feature = "hash_to_curve")]
+    fn hash_to_fp2(
+        msg: &[u8],
+        dst: &[u8],
+        count: usize
+    ) -> Result<Vec<Fp2<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
+    {
+        let elements = crate::mapping::hash_to_field::hash_to_fp_elements(
+            &BLS12_381_FIELD,
+            msg,
+            dst,
+            count * 2,
+            HASH_TO_FIELD_SECURITY_BYTES
+        )?;
+
+        let mut result = Vec::with_capacity(count);
+        for pair in elements.chunks(2) {
+            let mut el = BLS12_381_FP2_ZERO.clone();
+            el.c0 = pair[0].clone();
+            el.c1 = pair[1].clone();
+            result.push(el);
+        }
+
+        Ok(result)
+    }
+
+    /// hash_to_curve of the BLS12381G1_XMD:SHA-256_SSWU_RO_ suite from RFC 9380
+    #[cfg(feature = "hash_to_curve")]
+    pub fn hash_to_g1(
+        msg: &[u8],
+        dst: &[u8]
+    ) -> Result<CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
+    {
+        let u = crate::mapping::hash_to_field::hash_to_fp_elements(&BLS12_381_FIELD, msg, dst, 2, HASH_TO_FIELD_SECURITY_BYTES)?;
+        let mut point = map_to_curve_g1(&u[0])?;
+        point.add_assign(&map_to_curve_g1(&u[1])?);
+
+        Ok(clear_cofactor_g1(&point))
+    }
+
+    /// encode_to_curve of the BLS12381G1_XMD:SHA-256_SSWU_NU_ suite from RFC 9380.
+    /// Output is not uniformly distributed, use `hash_to_g1` if a random oracle is required
+    #[cfg(feature = "hash_to_curve")]
+    pub fn encode_to_g1(
+        msg: &[u8],
+        dst: &[u8]
+    ) -> Result<CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
+    {
+        let u = crate::mapping::hash_to_field::hash_to_fp_elements(&BLS12_381_FIELD, msg, dst, 1, HASH_TO_FIELD_SECURITY_BYTES)?;
+
+        fp_to_g1(&u[0])
+    }
+
+    /// hash_to_curve of the BLS12381G2_XMD:SHA-256_SSWU_RO_ suite from RFC 9380
+    #[cfg(feature = "hash_to_curve")]
+    pub fn hash_to_g2(
+        msg: &[u8],
+        dst: &[u8]
+    ) -> Result<CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
+    {
+        let u = hash_to_fp2(msg, dst, 2)?;
+        let mut point = map_to_curve_g2(&u[0])?;
+        point.add_assign(&map_to_curve_g2(&u[1])?);
+
+        Ok(clear_cofactor_g2(&point))
+    }
+
+    /// encode_to_curve of the BLS12381G2_XMD:SHA-256_SSWU_NU_ suite from RFC 9380.
+    /// Output is not uniformly distributed, use `hash_to_g2` if a random oracle is required
+    #[cfg(feature = "hash_to_curve")]
+    pub fn encode_to_g2(
+        msg: &[u8],
+        dst: &[u8]
+    ) -> Result<CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
+    {
+        let u = hash_to_fp2(msg, dst, 1)?;
+
+        fp2_to_g2(&u[0])
     }
 }
 
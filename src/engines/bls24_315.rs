@@ -0,0 +1,1072 @@
+use crate::field::*;
+use crate::fp::*;
+use crate::extension_towers::fp2::*;
+use crate::extension_towers::fp4_as_2_over_2::*;
+use crate::extension_towers::fp12_as_3_over_2_over_2::*;
+use crate::extension_towers::fp24_as_2_over_3_over_2_over_2::*;
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::pairings::bls24::*;
+use crate::pairings::TwistType;
+use crate::integers::MaxFieldUint;
+
+pub const BLS24_315_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0x6fe802ff40300001,0x421ee5da52bde502,0xdec1d01aa27a1ae0,0xd3f7498be97c5eaf,0x04c23a02b586d650,
+        0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0
+    ]
+);
+
+pub const BLS24_315_MODULUS: U320Repr = U320Repr([0x6fe802ff40300001,0x421ee5da52bde502,0xdec1d01aa27a1ae0,0xd3f7498be97c5eaf,0x04c23a02b586d650]);
+const BLS24_315_R: U320Repr = U320Repr([0xd4f76127b60fffcb,0x4f9a69ccdeaf967e,0xe1dfea7c5cb86f92,0x1dcdc608a9406596,0x03c9fd706b15a144]);
+const BLS24_315_R2: U320Repr = U320Repr([0x6b817891fe329c16,0x599ce86eec6e2c35,0xc338890f540d5ad6,0xcc160f6924c81f32,0x0215d8d4607a88d5]);
+const BLS24_315_MONT_INV: u64 = 0x702ff9ff402fffff;
+const BLS24_315_TWO_ADICITY: u64 = 20;
+// 13 is a quadratic non-residue, so 13^((p - 1) / 2^20) is a primitive 2^20-th root of unity
+const BLS24_315_TWO_ADIC_ROOT_OF_UNITY: U320Repr = U320Repr([0x9b5d1647037ca81a,0x12dd386125a33a28,0xd3e15f6e5bd15edc,0x91f8b99440a9df59,0x042c8c47696901ac]);
+
+pub const BLS24_315_FIELD: PrimeField<U320Repr> = PrimeField::<U320Repr> {
+    mont_power: 320,
+    modulus_bits: 315,
+    modulus: BLS24_315_MODULUS,
+    mont_r: BLS24_315_R,
+    mont_r2: BLS24_315_R2,
+    mont_inv: BLS24_315_MONT_INV,  
+    two_adicity: BLS24_315_TWO_ADICITY,
+    two_adic_root_of_unity: BLS24_315_TWO_ADIC_ROOT_OF_UNITY,
+};
+
+// Fp2 is Fp[u] / (u^2 - 13), 13 is neither a square nor a cube
+const BLS24_315_FP_NON_RESIDUE: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0x717fd10bbceffd45,0x75a264e0137fb258,0xc4ccc7465c989dab,0x3bca2ef9796975ca,0x01ab9a9a58d4d14d]), 
+    U320Repr,
+    BLS24_315_FIELD
+);
+
+pub const BLS24_315_FP_ZERO: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
+    U320Repr,
+    BLS24_315_FIELD
+);
+
+pub const BLS24_315_FP_ONE: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0xd4f76127b60fffcb,0x4f9a69ccdeaf967e,0xe1dfea7c5cb86f92,0x1dcdc608a9406596,0x03c9fd706b15a144]), 
+    U320Repr,
+    BLS24_315_FIELD
+);
+
+const BLS24_315_FP_MINUS_ONE: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0x9af0a1d78a200036,0xf2847c0d740e4e83,0xfce1e59e45c1ab4d,0xb6298383403bf918,0x00f83c924a71350c]), 
+    U320Repr,
+    BLS24_315_FIELD
+);
+
+pub const BLS24_315_EXTENSION_2_FIELD: Extension2<'static, U320Repr, PrimeField<U320Repr>> = 
+Extension2::<'static, U320Repr, PrimeField<U320Repr>> {
+    field: &BLS24_315_FIELD,
+    non_residue: BLS24_315_FP_NON_RESIDUE,
+    frobenius_coeffs_c1: [BLS24_315_FP_ONE, BLS24_315_FP_MINUS_ONE],
+    frobenius_coeffs_are_calculated: true
+};
+
+const BLS24_315_FP2_U: Fp2<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: BLS24_315_FP_ZERO,
+        c1: BLS24_315_FP_ONE,
+        extension_field: &BLS24_315_EXTENSION_2_FIELD
+    };
+
+// Fp4 is Fp2[v] / (v^2 - u), Frobenius coefficients are 13^((p^k - 1) / 4) = 13^(k * (p - 1) / 4)
+const BLS24_315_FP4_FROB_C1_1: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0xfae42bb398270bd0,0x1b8be1012ab8e38f,0x1cf98e018e23bb2f,0x901cd931ff164736,0x00dcaf44f7689727]), 
+    U320Repr,
+    BLS24_315_FIELD
+);
+
+const BLS24_315_FP4_FROB_C1_2: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0x9af0a1d78a200036,0xf2847c0d740e4e83,0xfce1e59e45c1ab4d,0xb6298383403bf918,0x00f83c924a71350c]), 
+    U320Repr,
+    BLS24_315_FIELD
+);
+
+const BLS24_315_FP4_FROB_C1_3: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0x7503d74ba808f431,0x269304d928050172,0xc1c8421914565fb1,0x43da7059ea661779,0x03e58abdbe1e3f29]), 
+    U320Repr,
+    BLS24_315_FIELD
+);
+
+pub const BLS24_315_EXTENSION_4_FIELD: Extension2Over2<'static, U320Repr, PrimeField<U320Repr>> = 
+Extension2Over2::<'static, U320Repr, PrimeField<U320Repr>> {
+    field: &BLS24_315_EXTENSION_2_FIELD,
+    non_residue: BLS24_315_FP2_U,
+    frobenius_coeffs_c1: [BLS24_315_FP_ONE, BLS24_315_FP4_FROB_C1_1, BLS24_315_FP4_FROB_C1_2, BLS24_315_FP4_FROB_C1_3],
+    frobenius_coeffs_are_calculated: true
+};
+
+const BLS24_315_FP4_ONE: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ONE,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+// Fp12 is Fp4[w] / (w^3 - v), Fp24 is Fp12[z] / (z^2 - w)
+const BLS24_315_FP4_NON_RESIDUE: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ONE,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+// Frobenius coefficients of Fp24 are v^((p^k - 1) / 6), ones of Fp12 are their squares and fourth powers
+const BLS24_315_FP24_FROB_C1_1: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x65d085e82d0bb9fa,0x242274a4b78a6efc,0x5b12d15185577258,0x7d5452ae392c613c,0x00ae5b91a42576ec]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP24_FROB_C1_2: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x21908ab21445494b,0x452f57f17fb2c12a,0xc38cb31938303c15,0x752bd6552457eefc,0x01615f503b2b5891]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP24_FROB_C1_3: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xe2400c1be4dbe41a,0xfb7ad046541af6eb,0xb5558e7766406a0e,0x10688ef2cc34d984,0x037a4ff5b43b9f3d]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP24_FROB_C1_4: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xe1be0c8b8f56a44e,0x302694e5149a6c85,0x1542fcd80e4c5954,0xefd29f4fe442aa2e,0x02f85a44730b7d56]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP24_FROB_C1_5: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x6a4015f469c8eb31,0xd76a8094821db5d4,0xbd54aad441fd3d4d,0xcd4ab750952bad94,0x03c93fb84d7e0cfa]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP24_FROB_C1_6: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xfae42bb398270bd0,0x1b8be1012ab8e38f,0x1cf98e018e23bb2f,0x901cd931ff164736,0x00dcaf44f7689727]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP24_FROB_C1_7: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x27927e7939ca2c5f,0x12897b796cd920a7,0x4a30b01b69d6af40,0xe9ef707337954968,0x0342efde597f6755]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP24_FROB_C1_8: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x7caeae631976a484,0x22ab10f288a8bb09,0x1224e276540e04a2,0xa5fc22d3247ea347,0x03f096d6bd7cb263]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP24_FROB_C1_9: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x046f900c3cbd3137,0xb3480befca9346d8,0x6241d982bca5caf5,0x4ff664a25bff4c58,0x031ae426a958960e]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP24_FROB_C1_10: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x493ba400c411c286,0x187b6ee9fdc40768,0x382eab02f86d99fa,0xeee84c68c43ab6e9,0x043d89f771c414e6]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP24_FROB_C1_11: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xb53a755c951e4846,0x592d910d6b7c0ebd,0x739cf1bea6106011,0xad7e2b0c54dcce93,0x048ad9eb5aca9e69]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C1_1: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x21908ab21445494b,0x452f57f17fb2c12a,0xc38cb31938303c15,0x752bd6552457eefc,0x01615f503b2b5891]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C1_2: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xe1be0c8b8f56a44e,0x302694e5149a6c85,0x1542fcd80e4c5954,0xefd29f4fe442aa2e,0x02f85a44730b7d56]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C1_3: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xfae42bb398270bd0,0x1b8be1012ab8e38f,0x1cf98e018e23bb2f,0x901cd931ff164736,0x00dcaf44f7689727]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C1_4: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x7caeae631976a484,0x22ab10f288a8bb09,0x1224e276540e04a2,0xa5fc22d3247ea347,0x03f096d6bd7cb263]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C1_5: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x493ba400c411c286,0x187b6ee9fdc40768,0x382eab02f86d99fa,0xeee84c68c43ab6e9,0x043d89f771c414e6]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C1_6: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x9af0a1d78a200036,0xf2847c0d740e4e83,0xfce1e59e45c1ab4d,0xb6298383403bf918,0x00f83c924a71350c]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C1_7: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x4e57784d2beab6b6,0xfcef8de8d30b23d8,0x1b351d016a49deca,0x5ecb7336c5246fb3,0x0360dab27a5b7dbf]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C1_8: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x8e29f673b0d95bb3,0x11f850f53e23787c,0xc97ed342942dc18c,0xe424aa3c0539b481,0x01c9dfbe427b58f9]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C1_9: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x7503d74ba808f431,0x269304d928050172,0xc1c8421914565fb1,0x43da7059ea661779,0x03e58abdbe1e3f29]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C1_10: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xf339549c26b95b7d,0x1f73d4e7ca1529f8,0xcc9ceda44e6c163e,0x2dfb26b8c4fdbb68,0x00d1a32bf80a23ed]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C1_11: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x26ac5efe7c1e3d7b,0x29a376f054f9dd9a,0xa6932517aa0c80e6,0xe50efd232541a7c6,0x0084b00b43c2c169]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C2_1: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xe1be0c8b8f56a44e,0x302694e5149a6c85,0x1542fcd80e4c5954,0xefd29f4fe442aa2e,0x02f85a44730b7d56]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C2_2: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x7caeae631976a484,0x22ab10f288a8bb09,0x1224e276540e04a2,0xa5fc22d3247ea347,0x03f096d6bd7cb263]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C2_3: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x9af0a1d78a200036,0xf2847c0d740e4e83,0xfce1e59e45c1ab4d,0xb6298383403bf918,0x00f83c924a71350c]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C2_4: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x8e29f673b0d95bb3,0x11f850f53e23787c,0xc97ed342942dc18c,0xe424aa3c0539b481,0x01c9dfbe427b58f9]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C2_5: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xf339549c26b95b7d,0x1f73d4e7ca1529f8,0xcc9ceda44e6c163e,0x2dfb26b8c4fdbb68,0x00d1a32bf80a23ed]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C2_6: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ONE,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C2_7: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xe1be0c8b8f56a44e,0x302694e5149a6c85,0x1542fcd80e4c5954,0xefd29f4fe442aa2e,0x02f85a44730b7d56]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C2_8: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x7caeae631976a484,0x22ab10f288a8bb09,0x1224e276540e04a2,0xa5fc22d3247ea347,0x03f096d6bd7cb263]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C2_9: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x9af0a1d78a200036,0xf2847c0d740e4e83,0xfce1e59e45c1ab4d,0xb6298383403bf918,0x00f83c924a71350c]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C2_10: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x8e29f673b0d95bb3,0x11f850f53e23787c,0xc97ed342942dc18c,0xe424aa3c0539b481,0x01c9dfbe427b58f9]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_FROB_C2_11: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xf339549c26b95b7d,0x1f73d4e7ca1529f8,0xcc9ceda44e6c163e,0x2dfb26b8c4fdbb68,0x00d1a32bf80a23ed]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+pub const BLS24_315_EXTENSION_12_FIELD: Extension3Over2Over2<'static, U320Repr, PrimeField<U320Repr>> = 
+Extension3Over2Over2::<'static, U320Repr, PrimeField<U320Repr>> {
+    non_residue: BLS24_315_FP4_NON_RESIDUE,
+    field: &BLS24_315_EXTENSION_4_FIELD,
+    frobenius_coeffs_c1: [
+        BLS24_315_FP4_ONE, BLS24_315_FP12_FROB_C1_1, BLS24_315_FP12_FROB_C1_2, 
+        BLS24_315_FP12_FROB_C1_3, BLS24_315_FP12_FROB_C1_4, BLS24_315_FP12_FROB_C1_5, 
+        BLS24_315_FP12_FROB_C1_6, BLS24_315_FP12_FROB_C1_7, BLS24_315_FP12_FROB_C1_8, 
+        BLS24_315_FP12_FROB_C1_9, BLS24_315_FP12_FROB_C1_10, BLS24_315_FP12_FROB_C1_11
+    ],
+    frobenius_coeffs_c2: [
+        BLS24_315_FP4_ONE, BLS24_315_FP12_FROB_C2_1, BLS24_315_FP12_FROB_C2_2, 
+        BLS24_315_FP12_FROB_C2_3, BLS24_315_FP12_FROB_C2_4, BLS24_315_FP12_FROB_C2_5, 
+        BLS24_315_FP12_FROB_C2_6, BLS24_315_FP12_FROB_C2_7, BLS24_315_FP12_FROB_C2_8, 
+        BLS24_315_FP12_FROB_C2_9, BLS24_315_FP12_FROB_C2_10, BLS24_315_FP12_FROB_C2_11
+    ],
+    frobenius_coeffs_are_calculated: true
+};
+
+const BLS24_315_FP4_ZERO: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_FP12_ZERO: Fp12<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp12::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: BLS24_315_FP4_ZERO,
+        c1: BLS24_315_FP4_ZERO,
+        c2: BLS24_315_FP4_ZERO,
+        extension_field: &BLS24_315_EXTENSION_12_FIELD
+    };
+
+pub const BLS24_315_EXTENSION_24_FIELD: Extension2Over3Over2Over2<'static, U320Repr, PrimeField<U320Repr>> = 
+Extension2Over3Over2Over2::<'static, U320Repr, PrimeField<U320Repr>> {
+    non_residue: BLS24_315_FP12_ZERO,
+    field: &BLS24_315_EXTENSION_12_FIELD,
+    frobenius_coeffs_c1: [
+        BLS24_315_FP4_ONE, BLS24_315_FP24_FROB_C1_1, BLS24_315_FP24_FROB_C1_2, 
+        BLS24_315_FP24_FROB_C1_3, BLS24_315_FP24_FROB_C1_4, BLS24_315_FP24_FROB_C1_5, 
+        BLS24_315_FP24_FROB_C1_6, BLS24_315_FP24_FROB_C1_7, BLS24_315_FP24_FROB_C1_8, 
+        BLS24_315_FP24_FROB_C1_9, BLS24_315_FP24_FROB_C1_10, BLS24_315_FP24_FROB_C1_11
+    ],
+    frobenius_coeffs_are_calculated: true
+};
+
+pub const BLS24_315_SUBGROUP_ORDER: [u64; 4] = [0x19d0c5fd00c00001, 0xc8c480ece644e364, 0x25fc7ec9cf927a98, 0x196deac24a9da12b];
+
+pub const BLS24_315_X: [u64; 1] = [0x00000000bfcfffff];
+pub const BLS24_315_X_IS_NEGATIVE: bool = true;
+
+// G2 is represented on the D twist y^2 = x^3 + 1 / v over Fp4
+const BLS24_315_B_FOR_G2: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: BLS24_315_FP_ZERO,
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: BLS24_315_FP_ZERO,
+            c1: repr_into_fp!(
+                U320Repr([0xcde092b3f08d89d5,0x38553725c12a12cf,0xee56fbee79819ed5,0x506e44dec78d1fd2,0x027cdf800d3fe579]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+pub const BLS24_315_G1_CURVE_PARAMETERS: CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>> = 
+    CurveOverFpParameters::<'static, U320Repr, PrimeField<U320Repr>> {
+        field: &BLS24_315_FIELD
+    };
+
+pub const BLS24_315_G2_CURVE_PARAMETERS: CurveOverFp4Parameters<'static, U320Repr, PrimeField<U320Repr>> = 
+    CurveOverFp4Parameters::<'static, U320Repr, PrimeField<U320Repr>> {
+        field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+pub const BLS24_315_G1_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>> {
+        a: BLS24_315_FP_ZERO,
+        b: BLS24_315_FP_ONE,
+        curve_type: CurveType::AIsZero,
+        subgroup_order_repr: &BLS24_315_SUBGROUP_ORDER,
+        params: &BLS24_315_G1_CURVE_PARAMETERS,
+        glv: None
+    };   
+
+pub const BLS24_315_G2_CURVE: WeierstrassCurve<'static, CurveOverFp4Parameters<'static, U320Repr, PrimeField<U320Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFp4Parameters<'static, U320Repr, PrimeField<U320Repr>>> {
+        a: BLS24_315_FP4_ZERO,
+        b: BLS24_315_B_FOR_G2,
+        curve_type: CurveType::AIsZero,
+        subgroup_order_repr: &BLS24_315_SUBGROUP_ORDER,
+        params: &BLS24_315_G2_CURVE_PARAMETERS,
+        glv: None
+    };   
+
+const BLS24_315_G1_GENERATOR_X: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0xfcb46f239578661f,0x824d99891c9040c4,0x25a4fa9e16584b1d,0x4f49d54553c4fe57,0x0464432a8c3af760]), 
+    U320Repr,
+    BLS24_315_FIELD
+);
+
+const BLS24_315_G1_GENERATOR_Y: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0x29ffc4693b507024,0xbb95ce224a6689bb,0xdd9cea038d95107c,0xeb878ea0b44c417d,0x03b691e6785f3c2c]), 
+    U320Repr,
+    BLS24_315_FIELD
+);
+
+const BLS24_315_G2_GENERATOR_X: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x6a0afcf2bb4b6783,0xfb34b8c224b93934,0xff19a183293a4750,0xa8fc410684e6dfe7,0x01966ee71c62c5e9]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: repr_into_fp!(
+                U320Repr([0x195bc732e6fef27d,0x5346ff33657833e7,0x7b57ea6f74f392e7,0xbd8e2df508ce9ae9,0x02a6d591349a2d8d]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x1957ae8545feeadb,0x043d3b9baa321ae8,0x0b64c79609a306f8,0xc2a30124139d49b9,0x04a9332f0b5d22ab]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: repr_into_fp!(
+                U320Repr([0x6b039fd9831f4e57,0xa8b7bac0f6344bf4,0xccac46d6f7c1660f,0x6561ee63cf97ef2f,0x0415431617b2eccb]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+const BLS24_315_G2_GENERATOR_Y: Fp4<'static, U320Repr, PrimeField<U320Repr>> = 
+    Fp4::<'static, U320Repr, PrimeField<U320Repr>> {
+        c0: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0x0275736a043969af,0xa8a2e53733643a3b,0x0d49e4bdb82c7623,0xf04e97c508392972,0x004786e9dfe19398]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: repr_into_fp!(
+                U320Repr([0x70a03aaa936cbd66,0xe1ae8a4ea7425575,0xdfe6e194de1e5c8c,0x4700a4c5607c44e7,0x03ebfc242b370268]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        c1: Fp2::<'static, U320Repr, PrimeField<U320Repr>> {
+            c0: repr_into_fp!(
+                U320Repr([0xbdfaa1d7c27bdcdd,0x1f9dd2a653b8fc14,0xf27e309c5cae5b50,0xba7d446ec1737365,0x0486cc87a18e9d23]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            c1: repr_into_fp!(
+                U320Repr([0x277f1f7cc76db6d3,0xee3d41ae7498b881,0xa3c17fe7380e4e9e,0xc0a6b6c8c31290bb,0x02ab34458abb6e42]), 
+                U320Repr,
+                BLS24_315_FIELD
+            ),
+            extension_field: &BLS24_315_EXTENSION_2_FIELD
+        },
+        extension_field: &BLS24_315_EXTENSION_4_FIELD
+    };
+
+pub const BLS24_315_G1_GENERATOR: CurvePoint<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>> = 
+    CurvePoint::<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>>
+    {
+        curve: &BLS24_315_G1_CURVE,
+        x: BLS24_315_G1_GENERATOR_X,
+        y: BLS24_315_G1_GENERATOR_Y,
+        z: BLS24_315_FP_ONE,
+    };
+
+pub const BLS24_315_G2_GENERATOR: CurvePoint<'static, CurveOverFp4Parameters<'static, U320Repr, PrimeField<U320Repr>>> = 
+    CurvePoint::<'static, CurveOverFp4Parameters<'static, U320Repr, PrimeField<U320Repr>>>
+    {
+        curve: &BLS24_315_G2_CURVE,
+        x: BLS24_315_G2_GENERATOR_X,
+        y: BLS24_315_G2_GENERATOR_Y,
+        z: BLS24_315_FP4_ONE,
+    };
+
+pub const BLS24_315_PAIRING_ENGINE: Bls24Instance<
+    'static, 
+    U320Repr, 
+    PrimeField<U320Repr>, 
+    CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>,
+    CurveOverFp4Parameters<'static, U320Repr, PrimeField<U320Repr>>
+> = Bls24Instance::<
+    'static, 
+    U320Repr, 
+    PrimeField<U320Repr>, 
+    CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>,
+    CurveOverFp4Parameters<'static, U320Repr, PrimeField<U320Repr>>
+> {
+    x: &BLS24_315_X,
+    x_is_negative: BLS24_315_X_IS_NEGATIVE,
+    twist_type: TwistType::D,
+    base_field: &BLS24_315_FIELD,
+    curve: &BLS24_315_G1_CURVE,
+    curve_twist: &BLS24_315_G2_CURVE,
+    fp2_extension: &BLS24_315_EXTENSION_2_FIELD,
+    fp4_extension: &BLS24_315_EXTENSION_4_FIELD,
+    fp12_extension: &BLS24_315_EXTENSION_12_FIELD,
+    fp24_extension: &BLS24_315_EXTENSION_24_FIELD,
+    prefer_naf: false,
+    x_naf: Vec::new()
+};
+
+#[cfg(test)]
+mod test {
+    use crate::traits::{FieldElement, ZeroAndOne};
+    use super::*;
+
+    #[test]
+    fn test_generators_are_on_curve() {
+        assert!(BLS24_315_G1_GENERATOR.is_on_curve());
+        assert!(BLS24_315_G2_GENERATOR.is_on_curve());
+        assert!(BLS24_315_G1_GENERATOR.check_correct_subgroup());
+        assert!(BLS24_315_G2_GENERATOR.check_correct_subgroup());
+    }
+
+    #[test]
+    fn test_frobenius_coeffs_match_runtime_calculation() {
+        let mut extension_4 = Extension2Over2::new(BLS24_315_FP2_U);
+        extension_4.calculate_frobenius_coeffs_optimized(&BLS24_315_MODULUS_UINT).unwrap();
+        assert!(extension_4.frobenius_coeffs_c1 == BLS24_315_EXTENSION_4_FIELD.frobenius_coeffs_c1);
+
+        let mut extension_12 = Extension3Over2Over2::new(BLS24_315_FP4_NON_RESIDUE);
+        extension_12.calculate_frobenius_coeffs_optimized(&BLS24_315_MODULUS_UINT).unwrap();
+        assert!(extension_12.frobenius_coeffs_c1 == BLS24_315_EXTENSION_12_FIELD.frobenius_coeffs_c1);
+        assert!(extension_12.frobenius_coeffs_c2 == BLS24_315_EXTENSION_12_FIELD.frobenius_coeffs_c2);
+
+        let mut extension_24 = Extension2Over3Over2Over2::new(BLS24_315_FP12_ZERO);
+        extension_24.calculate_frobenius_coeffs_optimized(&BLS24_315_MODULUS_UINT).unwrap();
+        assert!(extension_24.frobenius_coeffs_c1 == BLS24_315_EXTENSION_24_FIELD.frobenius_coeffs_c1);
+    }
+
+    #[test]
+    fn test_engine_bilinearity() {
+        use crate::weierstrass::Group;
+        use crate::pairings::PairingEngine;
+
+        let p = BLS24_315_G1_GENERATOR.clone();
+        let q = BLS24_315_G2_GENERATOR.clone();
+
+        let mut p2 = p.mul(vec![12345678]);
+        p2.normalize();
+
+        let mut q2 = q.mul(vec![12345678]);
+        q2.normalize();
+
+        let ans1 = BLS24_315_PAIRING_ENGINE.pair(&[p.clone()], &[q2]).unwrap();
+        let ans2 = BLS24_315_PAIRING_ENGINE.pair(&[p2], &[q.clone()]).unwrap();
+        let ans3 = BLS24_315_PAIRING_ENGINE.pair(&[p], &[q]).unwrap();
+
+        let one = Fp24::one(&BLS24_315_EXTENSION_24_FIELD);
+        assert!(ans3 != one);
+        assert!(ans3.pow(&BLS24_315_SUBGROUP_ORDER) == one);
+
+        let ans3 = ans3.pow(&vec![12345678]);
+
+        assert!(ans1 == ans2);
+        assert!(ans1 == ans3);
+    }
+
+    #[test]
+    fn test_pairing_against_reference() {
+        use crate::pairings::PairingEngine;
+
+        // final exponentiation results in a cube of the reduced optimal ate pairing
+        let ans = BLS24_315_PAIRING_ENGINE.pair(&[BLS24_315_G1_GENERATOR], &[BLS24_315_G2_GENERATOR]).unwrap();
+        assert_eq!(format!("{}", ans.c0.c0.c0.c0), "0x042850c25fcd5b71eccbaa72968e8af37f3d8c0e1241e97143797fc47409089d0bb9607b3d1a8c5b");
+    }
+
+    #[test]
+    fn test_pairing_of_negated_point_cancels() {
+        use crate::weierstrass::Group;
+        use crate::pairings::PairingEngine;
+
+        let mut p = BLS24_315_G1_GENERATOR.mul(vec![42]);
+        p.normalize();
+        let mut minus_p = p.clone();
+        minus_p.negate();
+        let q = BLS24_315_G2_GENERATOR.clone();
+
+        let ans = BLS24_315_PAIRING_ENGINE.pair(&[p, minus_p], &[q.clone(), q]).unwrap();
+        assert!(ans == Fp24::one(&BLS24_315_EXTENSION_24_FIELD));
+    }
+}
@@ -0,0 +1,571 @@
+use crate::field::*;
+use crate::fp::*;
+use crate::extension_towers::fp2::*;
+use crate::extension_towers::fp6_as_3_over_2::*;
+use crate::extension_towers::fp12_as_2_over3_over_2::*;
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::weierstrass::glv::GlvParameters;
+use crate::pairings::bn::*;
+use crate::pairings::TwistType;
+use crate::integers::MaxFieldUint;
+
+const REPR_ZERO: U256Repr = U256Repr([0,0,0,0]);
+
+pub const BN254_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0x3c208c16d87cfd47,0x97816a916871ca8d,0xb85045b68181585d,0x30644e72e131a029, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0
+    ]
+);
+
+pub const BN254_MODULUS: U256Repr = U256Repr([0x3c208c16d87cfd47,0x97816a916871ca8d,0xb85045b68181585d,0x30644e72e131a029]);
+const BN254_R: U256Repr = U256Repr([0xd35d438dc58f0d9d,0x0a78eb28f5c70b3d,0x666ea36f7879462c,0x0e0a77c19a07df2f]);
+const BN254_R2: U256Repr = U256Repr([0xf32cfc5b538afa89,0xb5e71911d44501fb,0x47ab1eff0a417ff6,0x06d89f71cab8351f]);
+const BN254_MONT_INV: u64 = 0x87d20782e4866389;
+const BN254_TWO_ADICITY: u64 = 1;
+const BN254_TWO_ADIC_ROOT_OF_UNITY: U256Repr = U256Repr([0x68c3488912edefaa,0x8d087f6872aabf4f,0x51e1a24709081231,0x2259d6b14729c0fa]);
+
+pub const BN254_FIELD: PrimeField<U256Repr> = PrimeField::<U256Repr> {
+    mont_power: 256,
+    modulus_bits: 254,
+    modulus: BN254_MODULUS,
+    mont_r: BN254_R,
+    mont_r2: BN254_R2,
+    mont_inv: BN254_MONT_INV,
+    two_adicity: BN254_TWO_ADICITY,
+    two_adic_root_of_unity: BN254_TWO_ADIC_ROOT_OF_UNITY,
+};
+
+const BN254_FP_NON_RESIDUE_REPR: U256Repr = U256Repr([0x68c3488912edefaa,0x8d087f6872aabf4f,0x51e1a24709081231,0x2259d6b14729c0fa]);
+
+const BN254_FP_NON_RESIDUE: decl_fp!(U256Repr) = repr_into_fp!(
+    BN254_FP_NON_RESIDUE_REPR, 
+    U256Repr,
+    BN254_FIELD
+);
+
+pub const BN254_FP_ZERO: decl_fp!(U256Repr) = repr_into_fp!(
+    REPR_ZERO, 
+    U256Repr,
+    BN254_FIELD
+);
+
+pub const BN254_FP_ONE: decl_fp!(U256Repr) = repr_into_fp!(
+    BN254_R, 
+    U256Repr,
+    BN254_FIELD
+);
+
+const BN254_EXTENSION_2_FROB_COEFF_0: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xd35d438dc58f0d9d,0x0a78eb28f5c70b3d,0x666ea36f7879462c,0x0e0a77c19a07df2f]), 
+    U256Repr,
+    BN254_FIELD
+);
+
+const BN254_EXTENSION_2_FROB_COEFF_1: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x68c3488912edefaa,0x8d087f6872aabf4f,0x51e1a24709081231,0x2259d6b14729c0fa]), 
+    U256Repr,
+    BN254_FIELD
+);
+
+pub const BN254_EXTENSION_2_FIELD: Extension2<'static, U256Repr, PrimeField<U256Repr>> = 
+    Extension2::<'static, U256Repr, PrimeField<U256Repr>> {
+        field: &BN254_FIELD,
+        non_residue: BN254_FP_NON_RESIDUE,
+        frobenius_coeffs_c1: [BN254_EXTENSION_2_FROB_COEFF_0, BN254_EXTENSION_2_FROB_COEFF_1],
+        frobenius_coeffs_are_calculated: true
+    };
+
+pub const BN254_FP2_ZERO: decl_fp2!(U256Repr) = repr_into_fp2!(
+    BN254_FP_ZERO, 
+    BN254_FP_ZERO,
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+pub const BN254_FP2_ONE: decl_fp2!(U256Repr) = repr_into_fp2!(
+    BN254_FP_ONE, 
+    BN254_FP_ZERO,
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+// non-residue is 9 + u
+const BN254_FP2_NON_RESIDUE: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0xf60647ce410d7ff7,0x2f3d6f4dd31bd011,0x2943337e3940c6d1,0x1d9598e8a7e39857]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0xd35d438dc58f0d9d,0x0a78eb28f5c70b3d,0x666ea36f7879462c,0x0e0a77c19a07df2f]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+pub const BN254_SUBGROUP_ORDER: [u64; 4] = [
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029
+];
+
+const BN254_SCALAR_FIELD_MODULUS: U256Repr = U256Repr([0x43e1f593f0000001,0x2833e84879b97091,0xb85045b68181585d,0x30644e72e131a029]);
+const BN254_SCALAR_FIELD_R: U256Repr = U256Repr([0xac96341c4ffffffb,0x36fc76959f60cd29,0x666ea36f7879462e,0x0e0a77c19a07df2f]);
+const BN254_SCALAR_FIELD_R2: U256Repr = U256Repr([0x1bb8e645ae216da7,0x53fe3ab1e35c59e3,0x8c49833d53bb8085,0x0216d0b17f4e44a5]);
+const BN254_SCALAR_FIELD_MONT_INV: u64 = 0xc2e1f593efffffff;
+const BN254_SCALAR_FIELD_TWO_ADICITY: u64 = 28;
+// 5^((r - 1) / 2^28)
+const BN254_SCALAR_FIELD_TWO_ADIC_ROOT_OF_UNITY: U256Repr = U256Repr([0x636e735580d13d9c,0xa22bf3742445ffd6,0x56452ac01eb203d8,0x1860ef942963f9e7]);
+
+/// Field of the main subgroup order, base field of Baby Jubjub
+pub const BN254_SCALAR_FIELD: PrimeField<U256Repr> = PrimeField::<U256Repr> {
+    mont_power: 256,
+    modulus_bits: 254,
+    modulus: BN254_SCALAR_FIELD_MODULUS,
+    mont_r: BN254_SCALAR_FIELD_R,
+    mont_r2: BN254_SCALAR_FIELD_R2,
+    mont_inv: BN254_SCALAR_FIELD_MONT_INV,
+    two_adicity: BN254_SCALAR_FIELD_TWO_ADICITY,
+    two_adic_root_of_unity: BN254_SCALAR_FIELD_TWO_ADIC_ROOT_OF_UNITY,
+};
+
+const BN254_U: [u64; 1] = [0x44e992b44a6909f1];
+const BN254_SIX_U_PLUS_2: [u64; 2] = [0x9d797039be763ba8, 0x0000000000000001];
+const BN254_U_IS_NEGATIVE: bool = false;
+
+const BN254_B_FOR_G1_REPR: U256Repr = U256Repr([0x7a17caa950ad28d7,0x1f6ac17ae15521b9,0x334bea4e696bd284,0x2a1f6744ce179d8e]);
+pub const BN254_B_FOR_G1: Fp<'static, U256Repr, PrimeField<U256Repr>> = 
+    Fp::<'static, U256Repr, PrimeField<U256Repr>> {
+        field: &BN254_FIELD,
+        repr: BN254_B_FOR_G1_REPR
+    };
+
+// b / (9 + u) for the D-type twist
+pub const BN254_B_FOR_G2: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0x3bf938e377b802a8,0x020b1b273633535d,0x26b7edf049755260,0x2514c6324384a86d]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x38e7ecccd1dcff67,0x65f0b37d93ce0d3e,0xd749d0dd22ac00aa,0x0141b9ce4a688d4d]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+pub const BN254_G1_CURVE_PARAMETERS: CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>> = 
+    CurveOverFpParameters::<'static, U256Repr, PrimeField<U256Repr>> {
+        field: &BN254_FIELD
+    };
+
+pub const BN254_G2_CURVE_PARAMETERS: CurveOverFp2Parameters<'static, U256Repr, PrimeField<U256Repr>> = 
+    CurveOverFp2Parameters::<'static, U256Repr, PrimeField<U256Repr>> {
+        field: &BN254_EXTENSION_2_FIELD
+    };
+
+const BN254_FP6_FROB_C1_0: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0xd35d438dc58f0d9d,0x0a78eb28f5c70b3d,0x666ea36f7879462c,0x0e0a77c19a07df2f]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP6_FROB_C1_1: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0xb5773b104563ab30,0x347f91c8a9aa6454,0x7a007127242e0991,0x1956bcd8118214ec]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x6e849f1ea0aa4757,0xaa1c7b6d89f89141,0xb6e713cdfae0ca3a,0x26694fbb4e82ebc3]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP6_FROB_C1_2: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0x3350c88e13e80b9c,0x7dce557cdb5e56b9,0x6001b4b8b615564a,0x2682e617020217e0]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP6_FROB_C1_3: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0xc9af22f716ad6bad,0xb311782a4aa662b2,0x19eeaf64e248c7f4,0x20273e77e3439f82]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0xacc02860f7ce93ac,0x3933d5817ba76b4c,0x69e6188b446c8467,0x0a46036d4417cc55]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP6_FROB_C1_4: decl_fp2!(U256Repr) = BN254_FP2_ZERO;
+
+const BN254_FP6_FROB_C1_5: decl_fp2!(U256Repr) = BN254_FP2_ZERO;
+
+const BN254_FP6_FROB_C2_0: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0xd35d438dc58f0d9d,0x0a78eb28f5c70b3d,0x666ea36f7879462c,0x0e0a77c19a07df2f]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP6_FROB_C2_1: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0x7361d77f843abe92,0xa5bb2bd3273411fb,0x9c941f314b3e2399,0x15df9cddbb9fd3ec]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x5dddfd154bd8c949,0x62cb29a5a4445b60,0x37bc870a0c7dd2b9,0x24830a9d3171f0fd]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP6_FROB_C2_2: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0x71930c11d782e155,0xa6bb947cffbe3323,0xaa303344d4741444,0x2c3b3f0d26594943]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP6_FROB_C2_3: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0x448a93a57b6762df,0xbfd62df528fdeadf,0xd858f5d00e9bd47a,0x06b03d4d3476ec58]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x2b19daf4bcc936d1,0xa1a54e7a56f4299f,0xb533eee05adeaef1,0x170c812b84dda0b2]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP6_FROB_C2_4: decl_fp2!(U256Repr) = BN254_FP2_ZERO;
+
+const BN254_FP6_FROB_C2_5: decl_fp2!(U256Repr) = BN254_FP2_ZERO;
+
+pub const BN254_EXTENSION_6_FIELD: Extension3Over2<'static, U256Repr, PrimeField<U256Repr>> = 
+    Extension3Over2::<'static, U256Repr, PrimeField<U256Repr>> {
+        non_residue: BN254_FP2_NON_RESIDUE,
+        field: &BN254_EXTENSION_2_FIELD,
+        frobenius_coeffs_c1: [BN254_FP6_FROB_C1_0, BN254_FP6_FROB_C1_1, BN254_FP6_FROB_C1_2, BN254_FP6_FROB_C1_3, BN254_FP6_FROB_C1_4, BN254_FP6_FROB_C1_5],
+        frobenius_coeffs_c2: [BN254_FP6_FROB_C2_0, BN254_FP6_FROB_C2_1, BN254_FP6_FROB_C2_2, BN254_FP6_FROB_C2_3, BN254_FP6_FROB_C2_4, BN254_FP6_FROB_C2_5],
+        frobenius_coeffs_are_calculated: true
+    };
+
+const BN254_FP12_FROB_C1_0: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0xd35d438dc58f0d9d,0x0a78eb28f5c70b3d,0x666ea36f7879462c,0x0e0a77c19a07df2f]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP12_FROB_C1_1: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0xaf9ba69633144907,0xca6b1d7387afb78a,0x11bded5ef08a2087,0x02f34d751a1f3a7c]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0xa222ae234c492d72,0xd00f02a4565de15b,0xdc2ff3a253dfc926,0x10a75716b3899551]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP12_FROB_C1_2: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0xca8d800500fa1bf2,0xf0c5d61468b39769,0x0e201271ad0d4418,0x04290f65bad856e6]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP12_FROB_C1_3: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0x365316184e46d97d,0x0af7129ed4c96d9f,0x659da72fca1009b5,0x08116d8983a20d23]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0xb1df4af7c39c1939,0x3d9f02878a73bf7f,0x9b2220928caf0ae0,0x26684515eff054a6]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP12_FROB_C1_4: decl_fp2!(U256Repr) = BN254_FP2_ZERO;
+
+const BN254_FP12_FROB_C1_5: decl_fp2!(U256Repr) = BN254_FP2_ZERO;
+
+const BN254_FP12_FROB_C1_6: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0x68c3488912edefaa,0x8d087f6872aabf4f,0x51e1a24709081231,0x2259d6b14729c0fa]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_FP12_FROB_C1_7: decl_fp2!(U256Repr) = BN254_FP2_ZERO;
+
+const BN254_FP12_FROB_C1_8: decl_fp2!(U256Repr) = BN254_FP2_ZERO;
+
+const BN254_FP12_FROB_C1_9: decl_fp2!(U256Repr) = BN254_FP2_ZERO;
+
+const BN254_FP12_FROB_C1_10: decl_fp2!(U256Repr) = BN254_FP2_ZERO;
+
+const BN254_FP12_FROB_C1_11: decl_fp2!(U256Repr) = BN254_FP2_ZERO;
+
+const BN254_FP6_ZERO: Fp6<'static, U256Repr, PrimeField<U256Repr>> = 
+    Fp6::<'static, U256Repr, PrimeField<U256Repr>> {
+        c0: BN254_FP2_ZERO,
+        c1: BN254_FP2_ZERO,
+        c2: BN254_FP2_ZERO,
+        extension_field: &BN254_EXTENSION_6_FIELD
+    };
+
+pub const BN254_EXTENSION_12_FIELD: Extension2Over3Over2<'static, U256Repr, PrimeField<U256Repr>> = 
+Extension2Over3Over2::<'static, U256Repr, PrimeField<U256Repr>> {
+    non_residue: BN254_FP6_ZERO,
+    field: &BN254_EXTENSION_6_FIELD,
+    frobenius_coeffs_c1: [
+            BN254_FP12_FROB_C1_0, BN254_FP12_FROB_C1_1,
+            BN254_FP12_FROB_C1_2, BN254_FP12_FROB_C1_3,
+            BN254_FP12_FROB_C1_4, BN254_FP12_FROB_C1_5,
+            BN254_FP12_FROB_C1_6, BN254_FP12_FROB_C1_7,
+            BN254_FP12_FROB_C1_8, BN254_FP12_FROB_C1_9,
+            BN254_FP12_FROB_C1_10, BN254_FP12_FROB_C1_11
+        ],
+    frobenius_coeffs_are_calculated: true
+};
+
+// endomorphism (x, y) -> (beta * x, y) on G1 and a short basis of the corresponding lattice for GLV multiplication
+const BN254_G1_GLV_BETA: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x71930c11d782e155,0xa6bb947cffbe3323,0xaa303344d4741444,0x2c3b3f0d26594943]), 
+    U256Repr,
+    BN254_FIELD
+);
+
+const BN254_G1_GLV_A1: [u64; 1] = [0x89d3256894d213e3];
+const BN254_G1_GLV_B1: [u64; 2] = [0x8211bbeb7d4f1128, 0x6f4d8248eeb859fc];
+const BN254_G1_GLV_A2: [u64; 2] = [0x0be4e1541221250b, 0x6f4d8248eeb859fd];
+const BN254_G1_GLV_B2: [u64; 1] = [0x89d3256894d213e3];
+
+// group of points has prime order, so GLV can be used for any point on the curve
+pub(crate) const BN254_G1_GLV_PARAMETERS: GlvParameters<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> = 
+    GlvParameters::<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> {
+        beta: BN254_G1_GLV_BETA,
+        basis: [
+            (&BN254_G1_GLV_A1, false),
+            (&BN254_G1_GLV_B1, true),
+            (&BN254_G1_GLV_A2, false),
+            (&BN254_G1_GLV_B2, false),
+        ],
+        cofactor_is_one: true
+    };
+
+pub const BN254_G1_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> {
+        a: BN254_FP_ZERO,
+        b: BN254_B_FOR_G1,
+        curve_type: CurveType::AIsZero,
+        subgroup_order_repr: &BN254_SUBGROUP_ORDER,
+        params: &BN254_G1_CURVE_PARAMETERS,
+        glv: Some(BN254_G1_GLV_PARAMETERS)
+    };
+
+pub const BN254_G2_CURVE: WeierstrassCurve<'static, CurveOverFp2Parameters<'static, U256Repr, PrimeField<U256Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFp2Parameters<'static, U256Repr, PrimeField<U256Repr>>> {
+        a: BN254_FP2_ZERO,
+        b: BN254_B_FOR_G2,
+        curve_type: CurveType::AIsZero,
+        subgroup_order_repr: &BN254_SUBGROUP_ORDER,
+        params: &BN254_G2_CURVE_PARAMETERS,
+        glv: None
+    };
+
+const BN254_G1_GENERATOR_X: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xd35d438dc58f0d9d,0x0a78eb28f5c70b3d,0x666ea36f7879462c,0x0e0a77c19a07df2f]), 
+    U256Repr,
+    BN254_FIELD
+);
+
+const BN254_G1_GENERATOR_Y: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xa6ba871b8b1e1b3a,0x14f1d651eb8e167b,0xccdd46def0f28c58,0x1c14ef83340fbe5e]), 
+    U256Repr,
+    BN254_FIELD
+);
+
+const BN254_G2_GENERATOR_X: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0x8e83b5d102bc2026,0xdceb1935497b0172,0xfbb8264797811adf,0x19573841af96503b]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0xafb4737da84c6140,0x6043dd5a5802d8c4,0x09e950fc52a02f86,0x14fef0833aea7b6b]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+const BN254_G2_GENERATOR_Y: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0x619dfa9d886be9f6,0xfe7fd297f59e9b78,0xff9e1a62231b7dfe,0x28fd7eebae9e4206]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0x64095b56c71856ee,0xdc57f922327d3cbb,0x55f935be33351076,0x0da4a0e693fd6482]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+pub const BN254_G1_GENERATOR: CurvePoint<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> = 
+    CurvePoint::<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> 
+    {
+        curve: &BN254_G1_CURVE,
+        x: BN254_G1_GENERATOR_X,
+        y: BN254_G1_GENERATOR_Y,
+        z: BN254_FP_ONE,
+    };
+
+pub const BN254_G2_GENERATOR: CurvePoint<'static, CurveOverFp2Parameters<'static, U256Repr, PrimeField<U256Repr>>> = 
+    CurvePoint::<'static, CurveOverFp2Parameters<'static, U256Repr, PrimeField<U256Repr>>>
+    {
+        curve: &BN254_G2_CURVE,
+        x: BN254_G2_GENERATOR_X,
+        y: BN254_G2_GENERATOR_Y,
+        z: BN254_FP2_ONE,
+    };
+
+// (9 + u)^((p - 1)/2)
+const BN254_NON_RESIDUE_IN_P_MINUS_ONE_OVER_2: decl_fp2!(U256Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U256Repr([0xe4bbdd0c2936b629,0xbb30f162e133bacb,0x31a9d1b6f9645366,0x253570bea500f8dd]), 
+        U256Repr,
+        BN254_FIELD
+    ), 
+    repr_into_fp!(
+        U256Repr([0xa1d77ce45ffe77c7,0x07affd117826d1db,0x6d16bd27bb7edc6b,0x2c87200285defecc]), 
+        U256Repr,
+        BN254_FIELD
+    ),
+    U256Repr,
+    BN254_EXTENSION_2_FIELD
+);
+
+pub const BN254_PAIRING_ENGINE: BnInstance<
+    'static, 
+    U256Repr, 
+    PrimeField<U256Repr>, 
+    CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>,
+    CurveOverFp2Parameters<'static, U256Repr, PrimeField<U256Repr>>
+> = BnInstance::<
+    'static, 
+    U256Repr, 
+    PrimeField<U256Repr>, 
+    CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>,
+    CurveOverFp2Parameters<'static, U256Repr, PrimeField<U256Repr>>
+> {
+    u: &BN254_U,
+    six_u_plus_2: &BN254_SIX_U_PLUS_2,
+    u_is_negative: BN254_U_IS_NEGATIVE,
+    twist_type: TwistType::D,
+    base_field: &BN254_FIELD,
+    curve: &BN254_G1_CURVE,
+    curve_twist: &BN254_G2_CURVE,
+    fp2_extension: &BN254_EXTENSION_2_FIELD,
+    fp6_extension: &BN254_EXTENSION_6_FIELD,
+    fp12_extension: &BN254_EXTENSION_12_FIELD,
+    non_residue_in_p_minus_one_over_2: BN254_NON_RESIDUE_IN_P_MINUS_ONE_OVER_2,
+    prefer_naf: false,
+    six_u_plus_2_naf: Vec::new()
+};
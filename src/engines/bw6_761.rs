@@ -0,0 +1,339 @@
+use crate::field::*;
+use crate::fp::*;
+use crate::extension_towers::fp3::*;
+use crate::extension_towers::fp6_as_2_over_3::*;
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::pairings::bw6::*;
+use crate::pairings::TwistType;
+use crate::integers::MaxFieldUint;
+
+pub const BW6_761_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0xf49d00000000008b,0xe6913e6870000082,0x160cf8aeeaf0a437,0x98a116c25667a8f8,
+        0x71dcd3dc73ebff2e,0x8689c8ed12f9fd90,0x03cebaff25b42304,0x707ba638e584e919,
+        0x528275ef8087be41,0xb926186a81d14688,0xd187c94004faff3e,0x0122e824fb83ce0a,
+        0x0, 0x0, 0x0, 0x0
+    ]
+);
+
+pub const BW6_761_MODULUS: U768Repr = U768Repr([0xf49d00000000008b,0xe6913e6870000082,0x160cf8aeeaf0a437,0x98a116c25667a8f8,0x71dcd3dc73ebff2e,0x8689c8ed12f9fd90,0x03cebaff25b42304,0x707ba638e584e919,0x528275ef8087be41,0xb926186a81d14688,0xd187c94004faff3e,0x0122e824fb83ce0a]);
+const BW6_761_R: U768Repr = U768Repr([0x0202ffffffff85d5,0x5a5826358fff8ce7,0x9e996e43827faade,0xda6aff320ee47df4,0xece9cb3e1d94b80b,0xc0e667a25248240b,0xa74da5bfdcad3905,0x2352e7fe462f2103,0x7b56588008b1c87c,0x45848a63e711022f,0xd7a81ebb9f65a9df,0x0051f77ef127e87d]);
+const BW6_761_R2: U768Repr = U768Repr([0xc686392d2d1fa659,0x7b14c9b2f79484ab,0x7fa1e825c1d2b459,0xd6ec28f848329d88,0x4afb427b73a1ed40,0x972c69400d5930ae,0x2c7a26bf8c995976,0xac52e458c6e57af9,0xac731bfa0c536dfe,0x121e5c630b103f50,0x8f1b0953b886cda4,0x00ad253c2da8d807]);
+const BW6_761_MONT_INV: u64 = 0x0a5593568fa798dd;
+const BW6_761_TWO_ADICITY: u64 = 1;
+// modulus is 3 mod 4, so the root of unity is -1
+const BW6_761_TWO_ADIC_ROOT_OF_UNITY: U768Repr = U768Repr([0xf29a000000007ab6,0x8c391832e000739b,0x77738a6b6870f959,0xbe36179047832b03,0x84f3089e56574722,0xc5a3614ac0b1d984,0x5c81153f4906e9fe,0x4d28be3a9f55c815,0xd72c1d6f77d5f5c5,0x73a18e069ac04458,0xf9dfaa846595555f,0x00d0f0a60a5be58c]);
+
+pub const BW6_761_FIELD: PrimeField<U768Repr> = PrimeField::<U768Repr> {
+    mont_power: 768,
+    modulus_bits: 761,
+    modulus: BW6_761_MODULUS,
+    mont_r: BW6_761_R,
+    mont_r2: BW6_761_R2,
+    mont_inv: BW6_761_MONT_INV,  
+    two_adicity: BW6_761_TWO_ADICITY,
+    two_adic_root_of_unity: BW6_761_TWO_ADIC_ROOT_OF_UNITY,
+};
+
+// Fp3 is Fp[u] / (u^3 + 4), -4 is neither a square nor a cube
+const BW6_761_FP_NON_RESIDUE: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xe12e00000001e9c2,0x63c1e3faa001cd69,0xb1b4384fcbe29cf6,0xc79630bc713d5a1d,0x30127ac071851e2d,0x0979f350dcd36af1,0x6a66defed8b361f2,0x53abac78b24d4e23,0xb7ab89dede485a92,0x5c3a0745675e8452,0x446f17918c5f5700,0x00fdf24e3267fa1e]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_FP_ZERO: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_FP_ONE: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x0202ffffffff85d5,0x5a5826358fff8ce7,0x9e996e43827faade,0xda6aff320ee47df4,0xece9cb3e1d94b80b,0xc0e667a25248240b,0xa74da5bfdcad3905,0x2352e7fe462f2103,0x7b56588008b1c87c,0x45848a63e711022f,0xd7a81ebb9f65a9df,0x0051f77ef127e87d]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C1_0: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x0202ffffffff85d5,0x5a5826358fff8ce7,0x9e996e43827faade,0xda6aff320ee47df4,0xece9cb3e1d94b80b,0xc0e667a25248240b,0xa74da5bfdcad3905,0x2352e7fe462f2103,0x7b56588008b1c87c,0x45848a63e711022f,0xd7a81ebb9f65a9df,0x0051f77ef127e87d]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C1_1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x7f96b51bd840c549,0xd59782096496171f,0x49b046fd9ce14bbc,0x4b6163bba7527a56,0xef6c92fb771d59f1,0x0425bedbac1dfdc7,0xd3ac39de759c0ffd,0x9f43ed0e063a81d0,0x5bd7d20b4f9a3ce2,0x0411f03c36cf5c3c,0x2d658fd49661c472,0x01100249ae760b93]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C1_2: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x67a04ae427bfb5f8,0x9d32d491eb6a5cff,0x43d03c1cb68051d4,0x0b75ca96f69859a5,0x0763497f5325ec60,0x48076b5c278dd94d,0x8ca3965ff91efd06,0x1e6077657ea02f5d,0xcdd6c153a8c37724,0x28b5b634e5c22ea4,0x9e01e3efd42e902c,0x00e3d6815769a804]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C2_0: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x0202ffffffff85d5,0x5a5826358fff8ce7,0x9e996e43827faade,0xda6aff320ee47df4,0xece9cb3e1d94b80b,0xc0e667a25248240b,0xa74da5bfdcad3905,0x2352e7fe462f2103,0x7b56588008b1c87c,0x45848a63e711022f,0xd7a81ebb9f65a9df,0x0051f77ef127e87d]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C2_1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x67a04ae427bfb5f8,0x9d32d491eb6a5cff,0x43d03c1cb68051d4,0x0b75ca96f69859a5,0x0763497f5325ec60,0x48076b5c278dd94d,0x8ca3965ff91efd06,0x1e6077657ea02f5d,0xcdd6c153a8c37724,0x28b5b634e5c22ea4,0x9e01e3efd42e902c,0x00e3d6815769a804]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C2_2: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x7f96b51bd840c549,0xd59782096496171f,0x49b046fd9ce14bbc,0x4b6163bba7527a56,0xef6c92fb771d59f1,0x0425bedbac1dfdc7,0xd3ac39de759c0ffd,0x9f43ed0e063a81d0,0x5bd7d20b4f9a3ce2,0x0411f03c36cf5c3c,0x2d658fd49661c472,0x01100249ae760b93]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_EXTENSION_3_FIELD: Extension3<'static, U768Repr, PrimeField<U768Repr>> = 
+Extension3::<'static, U768Repr, PrimeField<U768Repr>> {
+    field: &BW6_761_FIELD,
+    non_residue: BW6_761_FP_NON_RESIDUE,
+    frobenius_coeffs_c1: [BW6_761_FP3_FROB_C1_0, BW6_761_FP3_FROB_C1_1, BW6_761_FP3_FROB_C1_2],
+    frobenius_coeffs_c2: [BW6_761_FP3_FROB_C2_0, BW6_761_FP3_FROB_C2_1, BW6_761_FP3_FROB_C2_2],
+    frobenius_coeffs_are_calculated: true
+};
+
+const BW6_761_FP3_ZERO: Fp3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Fp3::<'static, U768Repr, PrimeField<U768Repr>> {
+        c0: BW6_761_FP_ZERO,
+        c1: BW6_761_FP_ZERO,
+        c2: BW6_761_FP_ZERO,
+        extension_field: &BW6_761_EXTENSION_3_FIELD
+    };
+
+// Fp6 is Fp3[v] / (v^2 - u), only the coefficients for powers 1 and 3 are used
+const BW6_761_FP6_FROB_C1_1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x8cfcb51bd8404a93,0x495e69d68495a383,0xd23cbc9234705263,0x8d2b4c2b5fcf4f52,0x6a798a5d20c612ce,0x3e825d90eb6c2443,0x772b249f2c9525fe,0x521b2ed366e4b9bb,0x84abb49bd7c4471d,0x907062359c0f17e3,0x3385e55030cc6f12,0x003f11a3a41a2606]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP6_FROB_C1_3: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xf29a000000007ab6,0x8c391832e000739b,0x77738a6b6870f959,0xbe36179047832b03,0x84f3089e56574722,0xc5a3614ac0b1d984,0x5c81153f4906e9fe,0x4d28be3a9f55c815,0xd72c1d6f77d5f5c5,0x73a18e069ac04458,0xf9dfaa846595555f,0x00d0f0a60a5be58c]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_EXTENSION_6_FIELD: Extension2Over3<'static, U768Repr, PrimeField<U768Repr>> = 
+Extension2Over3::<'static, U768Repr, PrimeField<U768Repr>> {
+    non_residue: BW6_761_FP3_ZERO,
+    field: &BW6_761_EXTENSION_3_FIELD,
+    frobenius_coeffs_c1: [
+            BW6_761_FP_ONE, BW6_761_FP6_FROB_C1_1,
+            BW6_761_FP_ZERO, BW6_761_FP6_FROB_C1_3,
+            BW6_761_FP_ZERO, BW6_761_FP_ZERO
+        ],
+    frobenius_coeffs_are_calculated: true
+};
+
+pub const BW6_761_SUBGROUP_ORDER: [u64; 6] = [0x8508c00000000001, 0x170b5d4430000000, 0x1ef3622fba094800, 0x1a22d9f300f5138f, 0xc63b05c06ca1493b, 0x01ae3a4617c510ea];
+
+// optimal ate pairing uses u + 1 and u^3 - u^2 - u for u = 0x8508c00000000001 that is x of BLS12-377
+pub const BW6_761_ATE_LOOP_1: [u64; 1] = [0x8508c00000000002];
+pub const BW6_761_ATE_LOOP_2: [u64; 3] = [0xffffffffffffffff, 0x8a442f991fffffff, 0x23ed1347970dec00];
+
+// w0 + w1 * p is (p^2 - p + 1) / r multiplied by (u^3 - u^2 + u + 2) / 3 that is coprime with r
+pub const BW6_761_EXP_W0: [u64; 9] = [0x06e03fffffffff76, 0x2910be647fffffad, 0x070971c27271b402, 0x804f698a42199df3, 0x66461ea6594c7675, 0x86c8717411b988e7, 0x1dcf0e1bf896396a, 0x9bec99c26287869f, 0x0818ed67060b87ee];
+pub const BW6_761_EXP_W1: [u64; 9] = [0xb45a00000000008b, 0xf8969f88c000006a, 0xc8d5444622a0f839, 0x0949285ab5e48447, 0x37fce459f7ee5ffe, 0x149f9cf6375d749b, 0x77c3310f69cfc035, 0x9bec99c26287869f, 0x0818ed67060b87ee];
+
+const BW6_761_B_FOR_G1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xf29a000000007ab6,0x8c391832e000739b,0x77738a6b6870f959,0xbe36179047832b03,0x84f3089e56574722,0xc5a3614ac0b1d984,0x5c81153f4906e9fe,0x4d28be3a9f55c815,0xd72c1d6f77d5f5c5,0x73a18e069ac04458,0xf9dfaa846595555f,0x00d0f0a60a5be58c]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+// G2 is represented on the M twist y^2 = x^3 + 4 over Fp
+const BW6_761_B_FOR_G2: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x136efffffffe16c9,0x82cf5a6dcffe3319,0x6458c05f1f0e0741,0xd10ae605e52a4eda,0x41ca591c0266e100,0x7d0fd59c3626929f,0x9967dc004d00c112,0x1ccff9c033379af5,0x9ad6ec10a23f63af,0x5cec11251a72c235,0x8d18b1ae789ba83e,0x0024f5d6c91bd3ec]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_CURVE_PARAMETERS: CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>> = 
+    CurveOverFpParameters::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &BW6_761_FIELD
+    };
+
+pub const BW6_761_G1_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> {
+        a: BW6_761_FP_ZERO,
+        b: BW6_761_B_FOR_G1,
+        curve_type: CurveType::AIsZero,
+        subgroup_order_repr: &BW6_761_SUBGROUP_ORDER,
+        params: &BW6_761_CURVE_PARAMETERS,
+        glv: None
+    };   
+
+pub const BW6_761_G2_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> {
+        a: BW6_761_FP_ZERO,
+        b: BW6_761_B_FOR_G2,
+        curve_type: CurveType::AIsZero,
+        subgroup_order_repr: &BW6_761_SUBGROUP_ORDER,
+        params: &BW6_761_CURVE_PARAMETERS,
+        glv: None
+    };   
+
+const BW6_761_G1_GENERATOR_X: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xd6e42d7614c2d770,0x4bb886eddbc3fc21,0x64648b044098b4d2,0x1a585c895a422985,0xf1a9ac17cf8685c9,0x352785830727aea5,0xddf8cb12306266fe,0x6913b4bfbc9e949a,0x3a4b78d67ba5f6ab,0x0f481c06a8d02a04,0x91d4e7365c43edac,0x00f4d17cd48beca5]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_G1_GENERATOR_Y: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x97e805c4bd16411f,0x870d844e1ee6dd08,0x1eba7a37cb9eab4d,0xd544c4df10b9889a,0x8fe37f21a33897be,0xe9bf99a43a0885d2,0xd7ee0c9e273de139,0xaa6a9ec7a38dd791,0x8f95d3fcf765da8e,0x42326e7db7357c99,0xe217e407e218695f,0x009d1eb23b7cf684]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_G2_GENERATOR_X: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x3d902a84cd9f4f78,0x864e451b8a9c05dd,0xc2b3c0d6646c5673,0x17a7682def1ecb9d,0xbe31a1e0fb768fe3,0x4df125e09b92d1a6,0x0943fce635b02ee9,0xffc8e7ad0605e780,0x8165c00a39341e95,0x8ccc2ae90a0f094f,0x73a8b8cc0ad09e0c,0x011027e203edd9f4]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_G2_GENERATOR_Y: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x9a159be4e773f67c,0x6b957244aa8f4e6b,0xa27b70c9c945a38c,0xacb6a09fda11d0ab,0x3abbdaa9bb6b1291,0xdbdf642af5694c36,0xb6360bb9560b369f,0xac0bd1e822b8d6da,0xfa355d17afe6945f,0x8d6a0fc1fbcad35e,0x72a63c7874409840,0x0114976e5b0db280]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_G1_GENERATOR: CurvePoint<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    CurvePoint::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>>
+    {
+        curve: &BW6_761_G1_CURVE,
+        x: BW6_761_G1_GENERATOR_X,
+        y: BW6_761_G1_GENERATOR_Y,
+        z: BW6_761_FP_ONE,
+    };
+
+pub const BW6_761_G2_GENERATOR: CurvePoint<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    CurvePoint::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>>
+    {
+        curve: &BW6_761_G2_CURVE,
+        x: BW6_761_G2_GENERATOR_X,
+        y: BW6_761_G2_GENERATOR_Y,
+        z: BW6_761_FP_ONE,
+    };
+
+pub const BW6_761_PAIRING_ENGINE: BW6Instance<
+    'static, 
+    U768Repr, 
+    PrimeField<U768Repr>, 
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>,
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>
+> = BW6Instance::<
+    'static, 
+    U768Repr, 
+    PrimeField<U768Repr>, 
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>,
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>
+> {
+    ate_loop_1: &BW6_761_ATE_LOOP_1,
+    ate_loop_1_is_negative: false,
+    ate_loop_2: &BW6_761_ATE_LOOP_2,
+    ate_loop_2_is_negative: false,
+    exp_w0: &BW6_761_EXP_W0,
+    exp_w1: &BW6_761_EXP_W1,
+    exp_w0_is_negative: false,
+    twist_type: TwistType::M,
+    base_field: &BW6_761_FIELD,
+    curve: &BW6_761_G1_CURVE,
+    curve_twist: &BW6_761_G2_CURVE,
+    fp3_extension: &BW6_761_EXTENSION_3_FIELD,
+    fp6_extension: &BW6_761_EXTENSION_6_FIELD,
+    ate_loop_1_naf: Vec::new(),
+    ate_loop_2_naf: Vec::new()
+};
+
+#[cfg(test)]
+mod test {
+    use crate::traits::FieldElement;
+    use super::*;
+
+    #[test]
+    fn test_generators_are_on_curve() {
+        assert!(BW6_761_G1_GENERATOR.is_on_curve());
+        assert!(BW6_761_G2_GENERATOR.is_on_curve());
+        assert!(BW6_761_G1_GENERATOR.check_correct_subgroup());
+        assert!(BW6_761_G2_GENERATOR.check_correct_subgroup());
+    }
+
+    #[test]
+    fn test_engine_bilinearity() {
+        use crate::weierstrass::Group;
+        use crate::pairings::PairingEngine;
+
+        let p = BW6_761_G1_GENERATOR.clone();
+        let q = BW6_761_G2_GENERATOR.clone();
+
+        let mut p2 = p.mul(vec![12345678]);
+        p2.normalize();
+
+        let mut q2 = q.mul(vec![12345678]);
+        q2.normalize();
+
+        let ans1 = BW6_761_PAIRING_ENGINE.pair(&[p.clone()], &[q2]).unwrap();
+        let ans2 = BW6_761_PAIRING_ENGINE.pair(&[p2], &[q.clone()]).unwrap();
+        let ans3 = BW6_761_PAIRING_ENGINE.pair(&[p], &[q]).unwrap();
+
+        let one = Fp6::one(&BW6_761_EXTENSION_6_FIELD);
+        assert!(ans3 != one);
+        assert!(ans3.pow(&BW6_761_SUBGROUP_ORDER) == one);
+
+        let ans3 = ans3.pow(&vec![12345678]);
+
+        assert!(ans1 == ans2);
+        assert!(ans1 == ans3);
+    }
+
+    #[test]
+    fn test_pairing_against_reference() {
+        use crate::pairings::PairingEngine;
+
+        // final exponentiation results in the reduced optimal ate pairing (f_{u + 1, Q}(P) * f_{u^3 - u^2 - u, Q}(P)^p)^((p^6 - 1) / r)
+        // raised to (w0 + w1 * p) / ((p^2 - p + 1) / r) = (u^3 - u^2 + u + 2) / 3, reference value is calculated
+        // with an independent implementation over Fp[v] / (v^6 + 4)
+        let ans = BW6_761_PAIRING_ENGINE.pair(&[BW6_761_G1_GENERATOR], &[BW6_761_G2_GENERATOR]).unwrap();
+        let expected = [
+            (&ans.c0, [
+                "0x00e66ba2878b43d3509c05f7332cb31b2bfddf7b0f07fb6753c5ce9930401bf8a16530ac22acda0e88ff371aa4f57e30827a38908452517c717e44f90c1463fef1bd722fb40b2b6c4cc7e3af32e5d223d62c17ff83f0fc740779d4167fe4705b",
+                "0x011400c7eaaee01df9c65660d25e48fab2e877ec5870d67309f93617c021af6bfcacff2d46eadc6784a5dbcb4d546507018970e4958b9227a9a3fc8331a50b6962e07faebbec53a0ac720d67e979c4c1903fb7326cc822bffd33a38f2decc8b4",
+                "0x00a43bf10f932ab4f1f19e07fda60a3c3d263478c6ec61b784ab02b01cb3ef169ec6e6d78f1171b72c9408ebed05ee48b2613080b39f339e8625dcff8097287548e0860c08909d1707025cede9b77b5e374ea3946bc210ceafbb9cd4b4889064",
+            ]),
+            (&ans.c1, [
+                "0x002ee49ee9b94fbf68175794438a81aba59dffd93128845cb661f872607f594ccfddcfe8b3cdcc6952b55cf7e83d008c870c90da23dd246d0460ea0309e915fca72f029ec5104f684060d5a4dd946bffeab47cdb2a90904b7c594e6e9249278d",
+                "0x005c3a585b573a23dd0c1f7ae3ee61053d03e384fadd33eabb633aec976573598e35a93121d019f4735e71d97fb6a62069a14c059ffec0fad020139b3114f2be966b913a091030a2918dc96ff68f8be81972c5605e1c6f0f3ac5cf61f637d440",
+                "0x00518c55cfffa3748923d49f4cff7eeeeae6bf5c9cbd0aa1fdfd1e854cfd6cbfe7f363cd2510e205de581c6260a81d8ab7acee91640588eb62767c8f37811abe7bec265864fcccbd61fd026dde1c323082a403e6e4e016a5ed8f79d2622d3348",
+            ]),
+        ];
+
+        for (el, coeffs) in expected.iter() {
+            assert_eq!(format!("{}", el.c0), coeffs[0]);
+            assert_eq!(format!("{}", el.c1), coeffs[1]);
+            assert_eq!(format!("{}", el.c2), coeffs[2]);
+        }
+    }
+
+    #[test]
+    fn test_pairing_of_negated_point_cancels() {
+        use crate::weierstrass::Group;
+        use crate::pairings::PairingEngine;
+
+        let mut p = BW6_761_G1_GENERATOR.mul(vec![42]);
+        p.normalize();
+        let mut minus_p = p.clone();
+        minus_p.negate();
+        let q = BW6_761_G2_GENERATOR.clone();
+
+        let ans = BW6_761_PAIRING_ENGINE.pair(&[p, minus_p], &[q.clone(), q]).unwrap();
+        assert!(ans == Fp6::one(&BW6_761_EXTENSION_6_FIELD));
+    }
+}
@@ -0,0 +1,161 @@
+//! Jubjub curve over the BLS12-381 scalar field, `-x^2 + y^2 = 1 - (10240/10241)*x^2*y^2`.
+//! Group order is 8 times a 252 bit prime. Since `a = -1` is a square and `d` is not, addition
+//! formulas are complete on the whole curve
+
+use crate::field::*;
+use crate::fp::*;
+use crate::edwards::curve::*;
+use crate::engines::bls12_381::BLS12_381_SCALAR_FIELD;
+
+const REPR_ONE: U256Repr = U256Repr([0x00000001fffffffe,0x5884b7fa00034802,0x998c4fefecbc4ff5,0x1824b159acc5056f]);
+
+pub const JUBJUB_SUBGROUP_ORDER: [u64; 4] = [
+    0xd0970e5ed6f72cb7,
+    0xa6682093ccc81082,
+    0x06673b0101343b00,
+    0x0e7db4ea6533afa9
+];
+
+pub const JUBJUB_COFACTOR: u64 = 8;
+
+// a = -1
+const JUBJUB_A: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xfffffffd00000003,0xfb38ec08fffb13fc,0x99ad88181ce5880f,0x5bc8f5f97cd877d8]),
+    U256Repr,
+    BLS12_381_SCALAR_FIELD
+);
+
+// d = -(10240/10241)
+const JUBJUB_D: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x2a522455b974f6b0,0xfc6cc9ef0d9acab3,0x7a08fb94c27628d1,0x57f8f6a8fe0e262e]),
+    U256Repr,
+    BLS12_381_SCALAR_FIELD
+);
+
+pub const JUBJUB_CURVE: EdwardsCurve<'static, U256Repr, PrimeField<U256Repr>> = 
+    EdwardsCurve::<'static, U256Repr, PrimeField<U256Repr>> {
+        a: JUBJUB_A,
+        d: JUBJUB_D,
+        subgroup_order_repr: &JUBJUB_SUBGROUP_ORDER,
+        field: &BLS12_381_SCALAR_FIELD
+    };
+
+const JUBJUB_GENERATOR_X: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x36d32c5896c2f1c5,0x56f8ca079b9bc5f6,0x98e8d1d8a8683c8d,0x015ad7c5e2e165cc]),
+    U256Repr,
+    BLS12_381_SCALAR_FIELD
+);
+
+const JUBJUB_GENERATOR_Y: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xfe0637f4ed09b09f,0x5ad237c95e4b2d2c,0xd943ede0fed9a448,0x5e2655b261c9d780]),
+    U256Repr,
+    BLS12_381_SCALAR_FIELD
+);
+
+const JUBJUB_GENERATOR_T: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x33660004481a4091,0x1a05eb64d68ff1f2,0x00384ae179e23e96,0x70ffecca7de90513]),
+    U256Repr,
+    BLS12_381_SCALAR_FIELD
+);
+
+pub const JUBJUB_GENERATOR: EdwardsPoint<'static, U256Repr, PrimeField<U256Repr>> = 
+    EdwardsPoint::<'static, U256Repr, PrimeField<U256Repr>> {
+        curve: &JUBJUB_CURVE,
+        x: JUBJUB_GENERATOR_X,
+        y: JUBJUB_GENERATOR_Y,
+        t: JUBJUB_GENERATOR_T,
+        z: repr_into_fp!(REPR_ONE, U256Repr, BLS12_381_SCALAR_FIELD),
+    };
+
+#[cfg(test)]
+mod test {
+    use crate::weierstrass::Group;
+    use crate::traits::{FieldElement, ZeroAndOne};
+    use crate::integers::MaxGroupSizeUint;
+    use super::*;
+
+    #[test]
+    fn test_scalar_field_constants() {
+        let field = crate::field::new_field::<U256Repr>("52435875175126190479447740508185965837690552500527637822603658699938581184513", 10).unwrap();
+        assert!(field.mont_r == BLS12_381_SCALAR_FIELD.mont_r);
+        assert!(field.mont_r2 == BLS12_381_SCALAR_FIELD.mont_r2);
+        assert!(field.mont_inv == BLS12_381_SCALAR_FIELD.mont_inv);
+        assert!(field.modulus_bits == BLS12_381_SCALAR_FIELD.modulus_bits);
+    }
+
+    #[test]
+    fn test_generator_is_on_curve() {
+        assert!(JUBJUB_GENERATOR.is_on_curve());
+        assert!(JUBJUB_GENERATOR.check_correct_subgroup());
+        assert!(!JUBJUB_GENERATOR.is_zero());
+    }
+
+    #[test]
+    fn test_point_of_order_two_is_not_in_subgroup() {
+        let mut minus_one = Fp::one(&BLS12_381_SCALAR_FIELD);
+        minus_one.negate();
+        let p = EdwardsPoint::point_from_xy(&JUBJUB_CURVE, Fp::zero(&BLS12_381_SCALAR_FIELD), minus_one);
+        assert!(p.is_on_curve());
+        assert!(!p.check_correct_subgroup());
+
+        let mut p2 = p.clone();
+        p2.double();
+        assert!(p2.is_zero());
+    }
+
+    #[test]
+    fn test_addition_and_doubling_agree() {
+        let g = JUBJUB_GENERATOR.clone();
+        let mut doubled = g.clone();
+        doubled.double();
+        let mut added = g.clone();
+        added.add_assign(&g);
+        assert!(doubled == added);
+        assert!(doubled.is_on_curve());
+
+        let mut three_g = doubled.clone();
+        three_g.add_assign_mixed(&g);
+        assert!(three_g == g.mul(&[3u64]));
+
+        let mut zero = three_g.clone();
+        zero.sub_assign(&g.mul(&[3u64]));
+        assert!(zero.is_zero());
+
+        let mut neg = g.clone();
+        neg.negate();
+        neg.add_assign(&g);
+        assert!(neg.is_zero());
+    }
+
+    #[test]
+    fn test_wnaf_mul_matches_mul() {
+        let scalar = [0x1234567890abcdef, 0xfedcba0987654321, 0x0123456789abcdef, 0x0e7db4ea6533afa9];
+        let p = JUBJUB_GENERATOR.mul(&scalar);
+        let q = JUBJUB_GENERATOR.wnaf_mul(&scalar[..]);
+        assert!(p == q);
+
+        let mut normalized = p.clone();
+        normalized.normalize();
+        assert!(normalized.is_normalized());
+        assert!(normalized.is_on_curve());
+        assert!(normalized == p);
+    }
+
+    #[test]
+    fn test_multiexp() {
+        let mut bases = vec![];
+        let mut scalars = vec![];
+        let mut expected = EdwardsPoint::zero(&JUBJUB_CURVE);
+        for i in 1..20u64 {
+            let mut base = JUBJUB_GENERATOR.mul(&[i * 1000 + 7]);
+            base.normalize();
+            let scalar = [i * 0x9e3779b97f4a7c15, i, 0, 1u64 << 60];
+            expected.add_assign(&base.mul(&scalar));
+            bases.push(base);
+            scalars.push(MaxGroupSizeUint::from(&scalar[..]));
+        }
+
+        let result = crate::multiexp::edwards_peppinger(&bases, scalars);
+        assert!(result == expected);
+    }
+}
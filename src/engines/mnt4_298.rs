@@ -0,0 +1,159 @@
+//! MNT4-298 curve of the MNT4/MNT6-298 cycle. Frobenius coefficients of the extension towers are
+//! calculated on first use, so the engine and the points are lazily initialized statics
+
+use once_cell::sync::Lazy;
+
+use crate::field::*;
+use crate::fp::*;
+use crate::traits::{FieldElement, ZeroAndOne};
+use crate::extension_towers::fp2::*;
+use crate::extension_towers::fp4_as_2_over_2::*;
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::pairings::mnt4::*;
+use crate::integers::MaxFieldUint;
+
+pub const MNT4_298_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0xc90cd65a71660001,0x41a9e35e51200e12,0xcaeec9635d1330ea,0xa266249da7b0548e,0x000003bcf7bcd473, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0
+    ]
+);
+
+pub const MNT4_298_SUBGROUP_ORDER: [u64; 5] = [
+    0xbb4334a400000001,0xfb494c07925d6ad3,0xcaeec9635cf44194,0xa266249da7b0548e,0x000003bcf7bcd473
+];
+
+const MNT4_298_FP_NON_RESIDUE: u64 = 17;
+const MNT4_298_A_FOR_G1: u64 = 2;
+const MNT4_298_B_FOR_G1: U320Repr = U320Repr([0x5d4b0ef528ec0fd5,0x7b980f4e9cd21a51,0xc3edd2a2070a085c,0x15585ea4d523234f,0x000003545a276394]);
+
+const MNT4_298_X: [u64; 3] = [0x0dc9a1b671660000,0x46609756bec2a33f,0x00000000001eef55];
+const MNT4_298_X_IS_NEGATIVE: bool = false;
+const MNT4_298_EXP_W0: [u64; 3] = [0x0dc9a1b671660001,0x46609756bec2a33f,0x00000000001eef55];
+const MNT4_298_EXP_W1: [u64; 1] = [1];
+const MNT4_298_EXP_W0_IS_NEGATIVE: bool = false;
+
+const MNT4_298_G1_GENERATOR_X: U320Repr = U320Repr([0xa216b5b9c8d2af46,0x60c4079492b948de,0xaee86aba8f73d690,0xba85213fe6ca3875,0x0000007a2caf82a1]);
+const MNT4_298_G1_GENERATOR_Y: U320Repr = U320Repr([0x9cba48e710a48ab2,0x6778c1afd96a71e2,0x89d0148dcc9862d3,0x82672f7f159fec2e,0x000002db619461cc]);
+
+const MNT4_298_G2_GENERATOR_X: [U320Repr; 2] = [
+    U320Repr([0xba7903df6c09a9a4,0x2cb14f01a931e72d,0x9001f205151e12a7,0x660571ff542f2ef8,0x00000371780491c5]),
+    U320Repr([0x968be32c0ae0a989,0x03302bb6c02c712c,0x697c851f002f5763,0xda165def838081af,0x0000004ba59a3f72]),
+];
+const MNT4_298_G2_GENERATOR_Y: [U320Repr; 2] = [
+    U320Repr([0x0d9522bca4e79f22,0xec98f0f610a5aafd,0xd31e5c4b3b2e0b60,0xaad868a1c47d6605,0x0000004b471f33ff]),
+    U320Repr([0x84fb61a3cbf0e0f1,0x3b5168ed8d75c7c4,0xcb7d982f78ec9cfc,0xa5031f3f81a5c100,0x00000355d05a1c69]),
+];
+
+pub static MNT4_298_FIELD: Lazy<PrimeField<U320Repr>> = Lazy::new(|| {
+    field_from_modulus(&MNT4_298_MODULUS_UINT).expect("MNT4-298 modulus is a valid field modulus")
+});
+
+pub static MNT4_298_EXTENSION_2_FIELD: Lazy<Extension2<'static, U320Repr, PrimeField<U320Repr>>> = Lazy::new(|| {
+    let non_residue = fp_from_u64(MNT4_298_FP_NON_RESIDUE);
+    let mut extension = Extension2::new(non_residue);
+    extension.calculate_frobenius_coeffs(&MNT4_298_MODULUS_UINT).expect("17 is a quadratic non-residue");
+
+    extension
+});
+
+pub static MNT4_298_EXTENSION_4_FIELD: Lazy<Extension2Over2<'static, U320Repr, PrimeField<U320Repr>>> = Lazy::new(|| {
+    // Fp4 is built as w^2 = u and multiplication by u is hardcoded in the tower, so the value is not used
+    let non_residue = Fp2::zero(&*MNT4_298_EXTENSION_2_FIELD);
+    let mut extension = Extension2Over2::new(non_residue);
+    extension.calculate_frobenius_coeffs_optimized(&MNT4_298_MODULUS_UINT).expect("Fp4 Frobenius coefficients must exist");
+
+    extension
+});
+
+pub static MNT4_298_G1_CURVE_PARAMETERS: Lazy<CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>> = Lazy::new(|| {
+    CurveOverFpParameters::new(&*MNT4_298_FIELD)
+});
+
+pub static MNT4_298_G2_CURVE_PARAMETERS: Lazy<CurveOverFp2Parameters<'static, U320Repr, PrimeField<U320Repr>>> = Lazy::new(|| {
+    CurveOverFp2Parameters::new(&*MNT4_298_EXTENSION_2_FIELD)
+});
+
+pub static MNT4_298_G1_CURVE: Lazy<WeierstrassCurve<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>>> = Lazy::new(|| {
+    let a = fp_from_u64(MNT4_298_A_FOR_G1);
+    let b = Fp::from_repr(&*MNT4_298_FIELD, MNT4_298_B_FOR_G1).expect("B is less than the modulus");
+
+    WeierstrassCurve::new(&MNT4_298_SUBGROUP_ORDER, a, b, &*MNT4_298_G1_CURVE_PARAMETERS).expect("B is not zero")
+});
+
+// A and B of the twist are A * u^2 and B * u^3
+pub static MNT4_298_G2_CURVE: Lazy<WeierstrassCurve<'static, CurveOverFp2Parameters<'static, U320Repr, PrimeField<U320Repr>>>> = Lazy::new(|| {
+    let twist = twist();
+    let mut twist_squared = twist.clone();
+    twist_squared.square();
+    let mut twist_cubed = twist_squared.clone();
+    twist_cubed.mul_assign(&twist);
+
+    let mut a = twist_squared;
+    a.mul_by_fp(&MNT4_298_G1_CURVE.a);
+    let mut b = twist_cubed;
+    b.mul_by_fp(&MNT4_298_G1_CURVE.b);
+
+    WeierstrassCurve::new(&MNT4_298_SUBGROUP_ORDER, a, b, &*MNT4_298_G2_CURVE_PARAMETERS).expect("B is not zero")
+});
+
+pub static MNT4_298_G1_GENERATOR: Lazy<CurvePoint<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>>> = Lazy::new(|| {
+    let x = Fp::from_repr(&*MNT4_298_FIELD, MNT4_298_G1_GENERATOR_X).expect("coordinate is less than the modulus");
+    let y = Fp::from_repr(&*MNT4_298_FIELD, MNT4_298_G1_GENERATOR_Y).expect("coordinate is less than the modulus");
+
+    CurvePoint::point_from_xy(&*MNT4_298_G1_CURVE, x, y)
+});
+
+pub static MNT4_298_G2_GENERATOR: Lazy<CurvePoint<'static, CurveOverFp2Parameters<'static, U320Repr, PrimeField<U320Repr>>>> = Lazy::new(|| {
+    let x = fp2_from_reprs(&MNT4_298_G2_GENERATOR_X);
+    let y = fp2_from_reprs(&MNT4_298_G2_GENERATOR_Y);
+
+    CurvePoint::point_from_xy(&*MNT4_298_G2_CURVE, x, y)
+});
+
+pub static MNT4_298_PAIRING_ENGINE: Lazy<MNT4Instance<
+    'static,
+    U320Repr,
+    PrimeField<U320Repr>,
+    CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>,
+    CurveOverFp2Parameters<'static, U320Repr, PrimeField<U320Repr>>
+>> = Lazy::new(|| {
+    let params = MNT4InstanceParams {
+        x: &MNT4_298_X,
+        x_is_negative: MNT4_298_X_IS_NEGATIVE,
+        exp_w0: &MNT4_298_EXP_W0,
+        exp_w1: &MNT4_298_EXP_W1,
+        exp_w0_is_negative: MNT4_298_EXP_W0_IS_NEGATIVE,
+        base_field: &*MNT4_298_FIELD,
+        curve: &*MNT4_298_G1_CURVE,
+        curve_twist: &*MNT4_298_G2_CURVE,
+        twist: twist(),
+        fp2_extension: &*MNT4_298_EXTENSION_2_FIELD,
+        fp4_extension: &*MNT4_298_EXTENSION_4_FIELD,
+        force_no_naf: true
+    };
+
+    MNT4Instance::from_params(params)
+});
+
+fn fp_from_u64(value: u64) -> Fp<'static, U320Repr, PrimeField<U320Repr>> {
+    Fp::from_repr(&*MNT4_298_FIELD, U320Repr::from(value)).expect("small constant is less than the modulus")
+}
+
+fn fp2_from_reprs(reprs: &[U320Repr; 2]) -> Fp2<'static, U320Repr, PrimeField<U320Repr>> {
+    let mut el = Fp2::zero(&*MNT4_298_EXTENSION_2_FIELD);
+    el.c0 = Fp::from_repr(&*MNT4_298_FIELD, reprs[0]).expect("coordinate is less than the modulus");
+    el.c1 = Fp::from_repr(&*MNT4_298_FIELD, reprs[1]).expect("coordinate is less than the modulus");
+
+    el
+}
+
+// u, the generator of Fp2 over Fp
+fn twist() -> Fp2<'static, U320Repr, PrimeField<U320Repr>> {
+    let mut twist = Fp2::zero(&*MNT4_298_EXTENSION_2_FIELD);
+    twist.c1 = Fp::one(&*MNT4_298_FIELD);
+
+    twist
+}
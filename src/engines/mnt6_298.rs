@@ -0,0 +1,176 @@
+//! MNT6-298 curve of the MNT4/MNT6-298 cycle. Frobenius coefficients of the extension towers are
+//! calculated on first use, so the engine and the points are lazily initialized statics
+
+use once_cell::sync::Lazy;
+
+use crate::field::*;
+use crate::fp::*;
+use crate::traits::{FieldElement, ZeroAndOne};
+use crate::extension_towers::fp3::*;
+use crate::extension_towers::fp6_as_2_over_3::*;
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::pairings::mnt6::*;
+use crate::integers::MaxFieldUint;
+
+pub const MNT6_298_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0xbb4334a400000001,0xfb494c07925d6ad3,0xcaeec9635cf44194,0xa266249da7b0548e,0x000003bcf7bcd473, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0
+    ]
+);
+
+pub const MNT6_298_SUBGROUP_ORDER: [u64; 5] = [
+    0xc90cd65a71660001,0x41a9e35e51200e12,0xcaeec9635d1330ea,0xa266249da7b0548e,0x000003bcf7bcd473
+];
+
+const MNT6_298_FP_NON_RESIDUE: u64 = 5;
+const MNT6_298_A_FOR_G1: u64 = 11;
+const MNT6_298_B_FOR_G1: U320Repr = U320Repr([0xdba59463d0c65282,0x20b1a2d263fde47d,0x3d6c24e683fc09b4,0xdd042e957b71c44d,0x000000d68c7b1dc5]);
+
+const MNT6_298_X: [u64; 3] = [0x0dc9a1b671660000,0x46609756bec2a33f,0x00000000001eef55];
+const MNT6_298_X_IS_NEGATIVE: bool = true;
+const MNT6_298_EXP_W0: [u64; 3] = [0x0dc9a1b671660000,0x46609756bec2a33f,0x00000000001eef55];
+const MNT6_298_EXP_W1: [u64; 1] = [1];
+const MNT6_298_EXP_W0_IS_NEGATIVE: bool = true;
+
+const MNT6_298_G1_GENERATOR_X: U320Repr = U320Repr([0xadebc01abbc0447d,0xe0b4c671392584bd,0x61ed56f9bad79b57,0x2c69d1d90471b2ba,0x000002a4feee24fd]);
+const MNT6_298_G1_GENERATOR_Y: U320Repr = U320Repr([0x8a333d73d91d3028,0x5d71e9c75e1b9720,0xfd69cbfcbff07fc2,0xdb2f82f4e037bf7a,0x0000032986c245f6]);
+
+const MNT6_298_G2_GENERATOR_X: [U320Repr; 3] = [
+    U320Repr([0x44ad76257e4c6813,0xada7404b743ad2e6,0x02cbaa723cd60035,0x56ce532bccb3b449,0x0000034f7320a12b]),
+    U320Repr([0xa9b15cac5a0c80fa,0x40fec84f1b2890ab,0xb45f681952e01093,0x52eec50e61a70ab5,0x000000cf41620baa]),
+    U320Repr([0x01258391bd4917ce,0xf31b056ac767e2cb,0xb48007ca3c4e105c,0xe326433cccb8032f,0x0000011f99170e10]),
+];
+const MNT6_298_G2_GENERATOR_Y: [U320Repr; 3] = [
+    U320Repr([0x7a582c1b60fecc91,0x8309487c0b83e171,0x5e07ebd38b363ec4,0xc64d62ad05c79c41,0x000003a65968f03c]),
+    U320Repr([0x2faa5b2c37685c6e,0x2dcb0c7117cc7440,0x451ab3accaea5db8,0xdb1506c1a24cefc2,0x000000ca5e8427e5]),
+    U320Repr([0x7baf782f5c60e7f7,0xbb715f647c2e55a2,0x9a1b3e197277d83a,0x02c9a4ef94130762,0x000000f75d2dd883]),
+];
+
+pub static MNT6_298_FIELD: Lazy<PrimeField<U320Repr>> = Lazy::new(|| {
+    field_from_modulus(&MNT6_298_MODULUS_UINT).expect("MNT6-298 modulus is a valid field modulus")
+});
+
+pub static MNT6_298_EXTENSION_3_FIELD: Lazy<Extension3<'static, U320Repr, PrimeField<U320Repr>>> = Lazy::new(|| {
+    let non_residue = fp_from_u64(MNT6_298_FP_NON_RESIDUE);
+    let mut extension = Extension3::new(non_residue);
+    extension.calculate_frobenius_coeffs_optimized(&MNT6_298_MODULUS_UINT).expect("5 is a cubic non-residue");
+
+    extension
+});
+
+pub static MNT6_298_EXTENSION_6_FIELD: Lazy<Extension2Over3<'static, U320Repr, PrimeField<U320Repr>>> = Lazy::new(|| {
+    // Fp6 is built as w^2 = u and multiplication by u is hardcoded in the tower, so the value is not used
+    let mut non_residue = Fp3::zero(&*MNT6_298_EXTENSION_3_FIELD);
+    non_residue.c0 = fp_from_u64(MNT6_298_FP_NON_RESIDUE);
+    let mut extension = Extension2Over3::new(non_residue);
+    extension.calculate_frobenius_coeffs_optimized(&MNT6_298_MODULUS_UINT).expect("Fp6 Frobenius coefficients must exist");
+
+    extension
+});
+
+pub static MNT6_298_G1_CURVE_PARAMETERS: Lazy<CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>> = Lazy::new(|| {
+    CurveOverFpParameters::new(&*MNT6_298_FIELD)
+});
+
+pub static MNT6_298_G2_CURVE_PARAMETERS: Lazy<CurveOverFp3Parameters<'static, U320Repr, PrimeField<U320Repr>>> = Lazy::new(|| {
+    CurveOverFp3Parameters::new(&*MNT6_298_EXTENSION_3_FIELD)
+});
+
+pub static MNT6_298_G1_CURVE: Lazy<WeierstrassCurve<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>>> = Lazy::new(|| {
+    let a = fp_from_u64(MNT6_298_A_FOR_G1);
+    let b = Fp::from_repr(&*MNT6_298_FIELD, MNT6_298_B_FOR_G1).expect("B is less than the modulus");
+
+    WeierstrassCurve::new(&MNT6_298_SUBGROUP_ORDER, a, b, &*MNT6_298_G1_CURVE_PARAMETERS).expect("B is not zero")
+});
+
+// A and B of the twist are A * u^2 and B * u^3
+pub static MNT6_298_G2_CURVE: Lazy<WeierstrassCurve<'static, CurveOverFp3Parameters<'static, U320Repr, PrimeField<U320Repr>>>> = Lazy::new(|| {
+    let twist = twist();
+    let mut twist_squared = twist.clone();
+    twist_squared.square();
+    let mut twist_cubed = twist_squared.clone();
+    twist_cubed.mul_assign(&twist);
+
+    let mut a = twist_squared;
+    a.mul_by_fp(&MNT6_298_G1_CURVE.a);
+    let mut b = twist_cubed;
+    b.mul_by_fp(&MNT6_298_G1_CURVE.b);
+
+    WeierstrassCurve::new(&MNT6_298_SUBGROUP_ORDER, a, b, &*MNT6_298_G2_CURVE_PARAMETERS).expect("B is not zero")
+});
+
+pub static MNT6_298_G1_GENERATOR: Lazy<CurvePoint<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>>> = Lazy::new(|| {
+    let x = Fp::from_repr(&*MNT6_298_FIELD, MNT6_298_G1_GENERATOR_X).expect("coordinate is less than the modulus");
+    let y = Fp::from_repr(&*MNT6_298_FIELD, MNT6_298_G1_GENERATOR_Y).expect("coordinate is less than the modulus");
+
+    CurvePoint::point_from_xy(&*MNT6_298_G1_CURVE, x, y)
+});
+
+pub static MNT6_298_G2_GENERATOR: Lazy<CurvePoint<'static, CurveOverFp3Parameters<'static, U320Repr, PrimeField<U320Repr>>>> = Lazy::new(|| {
+    let x = fp3_from_reprs(&MNT6_298_G2_GENERATOR_X);
+    let y = fp3_from_reprs(&MNT6_298_G2_GENERATOR_Y);
+
+    CurvePoint::point_from_xy(&*MNT6_298_G2_CURVE, x, y)
+});
+
+pub static MNT6_298_PAIRING_ENGINE: Lazy<MNT6Instance<
+    'static,
+    U320Repr,
+    PrimeField<U320Repr>,
+    CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>,
+    CurveOverFp3Parameters<'static, U320Repr, PrimeField<U320Repr>>
+>> = Lazy::new(|| {
+    let params = MNT6InstanceParams {
+        x: &MNT6_298_X,
+        x_is_negative: MNT6_298_X_IS_NEGATIVE,
+        exp_w0: &MNT6_298_EXP_W0,
+        exp_w1: &MNT6_298_EXP_W1,
+        exp_w0_is_negative: MNT6_298_EXP_W0_IS_NEGATIVE,
+        base_field: &*MNT6_298_FIELD,
+        curve: &*MNT6_298_G1_CURVE,
+        curve_twist: &*MNT6_298_G2_CURVE,
+        twist: twist(),
+        fp3_extension: &*MNT6_298_EXTENSION_3_FIELD,
+        fp6_extension: &*MNT6_298_EXTENSION_6_FIELD,
+        force_no_naf: false
+    };
+
+    MNT6Instance::from_params(params)
+});
+
+fn fp_from_u64(value: u64) -> Fp<'static, U320Repr, PrimeField<U320Repr>> {
+    Fp::from_repr(&*MNT6_298_FIELD, U320Repr::from(value)).expect("small constant is less than the modulus")
+}
+
+fn fp3_from_reprs(reprs: &[U320Repr; 3]) -> Fp3<'static, U320Repr, PrimeField<U320Repr>> {
+    let mut el = Fp3::zero(&*MNT6_298_EXTENSION_3_FIELD);
+    el.c0 = Fp::from_repr(&*MNT6_298_FIELD, reprs[0]).expect("coordinate is less than the modulus");
+    el.c1 = Fp::from_repr(&*MNT6_298_FIELD, reprs[1]).expect("coordinate is less than the modulus");
+    el.c2 = Fp::from_repr(&*MNT6_298_FIELD, reprs[2]).expect("coordinate is less than the modulus");
+
+    el
+}
+
+// u, the generator of Fp3 over Fp
+fn twist() -> Fp3<'static, U320Repr, PrimeField<U320Repr>> {
+    let mut twist = Fp3::zero(&*MNT6_298_EXTENSION_3_FIELD);
+    twist.c1 = Fp::one(&*MNT6_298_FIELD);
+
+    twist
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pairings::PairingEngine;
+
+    #[test]
+    fn test_pairing_of_generators() {
+        // same curve and points as in the MNT6 pairing engine test
+        let result = MNT6_298_PAIRING_ENGINE.pair(&[MNT6_298_G1_GENERATOR.clone()], &[MNT6_298_G2_GENERATOR.clone()]).unwrap();
+        assert!(format!("{}", result.c0.c0) == "0x0000014ac12149eebffe74a1c75a7225deb91ca243c49eef01392080ff519ab6209431f81b50ec03");
+    }
+}
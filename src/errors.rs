@@ -1,6 +1,30 @@
 use std::error::Error;
 use std::fmt;
 
+/// Stable numeric codes of failure kinds. Values are never reused or changed, new kinds get new
+/// values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ErrorCode {
+    Other = 1,
+    InvalidLength = 2,
+    NonCanonicalFieldElement = 3,
+    PointNotOnCurve = 4,
+    PointNotInSubgroup = 5,
+    NonResidueCheckFailed = 6,
+    LimitExceeded = 7,
+    InvalidEncoding = 8,
+    BufferTooSmall = 9,
+    NullPointer = 10,
+    Panic = 11,
+}
+
+impl ErrorCode {
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ApiError {
     Overflow,
@@ -10,8 +34,77 @@ pub enum ApiError {
     UnknownParameter(String),
     OutputError(String),
     MissingValue,
+    /// Failure of a kind with a stable code. `remaining` is the length of the input starting from
+    /// the position of failure, use `ApiError::offset` to get the position itself
+    Coded {
+        code: ErrorCode,
+        remaining: Option<usize>,
+        description: String,
+    },
 }
 
+impl ApiError {
+    pub(crate) fn coded<S: Into<String>>(code: ErrorCode, description: S) -> Self {
+        ApiError::Coded {
+            code,
+            remaining: None,
+            description: description.into(),
+        }
+    }
+
+    /// Error at the start of `encoding`, which must be a tail of the input
+    pub(crate) fn coded_at<S: Into<String>>(code: ErrorCode, description: S, encoding: &[u8]) -> Self {
+        ApiError::Coded {
+            code,
+            remaining: Some(encoding.len()),
+            description: description.into(),
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ApiError::Coded { code, .. } => *code,
+            _ => ErrorCode::Other,
+        }
+    }
+
+    /// Byte offset of the failure in `input`, that must be the full input of the failed call
+    pub fn offset(&self, input: &[u8]) -> Option<usize> {
+        match self {
+            ApiError::Coded { remaining: Some(remaining), .. } if *remaining <= input.len() => {
+                Some(input.len() - *remaining)
+            },
+            _ => None
+        }
+    }
+
+    /// Packs the code and the offset into a value returned by C interfaces. The lowest byte is the
+    /// code, upper three bytes are the offset plus one, or zero if offset is unknown or doesn't fit
+    pub fn packed_code(&self, input: &[u8]) -> u32 {
+        let offset = match self.offset(input) {
+            Some(offset) if offset < MAX_PACKED_OFFSET => (offset as u32) + 1,
+            _ => 0,
+        };
+
+        (offset << 8) | (self.code().as_u8() as u32)
+    }
+
+    /// Adjusts the position of failure for the input that was extended by `padding` bytes
+    /// before parsing. Failures inside the padding lose the position
+    pub(crate) fn without_padding(self, padding: usize) -> Self {
+        match self {
+            ApiError::Coded { code, remaining, description } => ApiError::Coded {
+                code,
+                remaining: remaining.and_then(|r| r.checked_sub(padding)).filter(|r| *r != 0),
+                description,
+            },
+            other => other,
+        }
+    }
+}
+
+const MAX_PACKED_OFFSET: usize = (1 << 24) - 1;
+
 impl Error for ApiError {
     fn description(&self) -> &str {
         match *self {
@@ -22,6 +115,8 @@ impl Error for ApiError {
             ApiError::UnknownParameter(_) => "parameter has value out of bounds",
             ApiError::OutputError(_) => "error outputing results",
             ApiError::MissingValue => "missing value",
+            ApiError::Coded { code: ErrorCode::LimitExceeded, .. } => "parameter has value out of bounds",
+            ApiError::Coded { .. } => "invalid input parameters",
         }
     }
 }
@@ -36,6 +131,8 @@ impl fmt::Display for ApiError {
             ApiError::UnknownParameter(descr) => write!(f, "parameter has value out of bounds, {}", descr),
             ApiError::OutputError(descr) => write!(f, "error outputing results, {}", descr),
             ApiError::MissingValue => write!(f, "missing value"),
+            ApiError::Coded { code: ErrorCode::LimitExceeded, description, .. } => write!(f, "parameter has value out of bounds, {}", description),
+            ApiError::Coded { description, .. } => write!(f, "invalid input parameters, {}", description),
         }
     }
-}
\ No newline at end of file
+}
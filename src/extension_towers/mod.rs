@@ -4,6 +4,8 @@ pub mod fp4_as_2_over_2;
 pub mod fp6_as_2_over_3;
 pub mod fp6_as_3_over_2;
 pub mod fp12_as_2_over3_over_2;
+pub mod fp12_as_3_over_2_over_2;
+pub mod fp24_as_2_over_3_over_2_over_2;
 
 use crate::fp::Fp;
 use crate::field::{SizedPrimeField};
@@ -120,6 +122,49 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp6Fp12FrobeniusBaseElem
     }
 }
 
+pub(crate) struct Fp12Fp24FrobeniusBaseElements<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> >{
+    pub(crate) non_residue_in_q_powers_minus_one_by_six: [fp4_as_2_over_2::Fp4<'a, E, F>; 12],
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp12Fp24FrobeniusBaseElements<'a, E, F> {
+    pub(crate) fn construct(modulus: &MaxFieldUint, non_residue: &fp4_as_2_over_2::Fp4<'a, E, F>) -> Result<Self, ()> {
+        // we perform an explicit division, so we do not check that
+        // modulus == 1 mod 6 due to expensive division
+
+        let one = MaxFieldUint::from(1u64);
+        let six = MaxFieldUint::from(6u64);
+
+        // NON_RESIDUE**((q - 1) / 6)
+        let f_1 = {
+            let power = *modulus - one;
+            let (power, rem) = power.div_mod(six);
+            if !rem.is_zero() {
+                if !crate::features::in_gas_metering() {
+                    return Err(());
+                }
+            }
+
+            non_residue.pow(power.as_ref())
+        };
+
+        // (q^(k+1) - 1) / 6 = q * (q^k - 1) / 6 + (q - 1) / 6, so
+        // NON_RESIDUE**((q^(k+1) - 1) / 6) = (NON_RESIDUE**((q^k - 1) / 6)).frobenius(1) * f_1
+        let mut powers = [fp4_as_2_over_2::Fp4::one(non_residue.extension_field); 12];
+        for k in 1..12 {
+            let mut f_k = powers[k-1].clone();
+            f_k.frobenius_map(1);
+            f_k.mul_assign(&f_1);
+            powers[k] = f_k;
+        }
+
+        let result = Fp12Fp24FrobeniusBaseElements::<'a, E, F> {
+            non_residue_in_q_powers_minus_one_by_six: powers,
+        };
+
+        Ok(result)
+    }
+}
+
 pub(crate) fn is_non_nth_root<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>>
 (
     element: & Fp<'a, FE, F>,
@@ -191,6 +236,39 @@ pub(crate) fn is_non_nth_root_fp2<'a, FE: ElementRepr, F: SizedPrimeField<Repr =
     }
 }
 
+/// Element of Fp4 is an n-th power if and only if its norm down to Fp is,
+/// so the check is performed for the norm
+pub(crate) fn is_non_nth_root_fp4<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>>
+(
+    element: & self::fp4_as_2_over_2::Fp4<'a, FE, F>,
+    modulus: &MaxFieldUint,
+    n: u64
+) -> bool {
+    use crate::traits::FieldExtension;
+
+    if element.is_zero() {
+        return false;
+    }
+
+    // N(c0 + c1*v) = c0^2 - u*c1^2 in Fp2
+    let mut norm_in_fp2 = element.c0.clone();
+    norm_in_fp2.square();
+    let mut t = element.c1.clone();
+    t.square();
+    element.extension_field.multiply_by_non_residue(&mut t);
+    norm_in_fp2.sub_assign(&t);
+
+    // N(c0 + c1*u) = c0^2 - beta*c1^2 in Fp
+    let mut norm = norm_in_fp2.c0.clone();
+    norm.square();
+    let mut t = norm_in_fp2.c1.clone();
+    t.square();
+    norm_in_fp2.extension_field.multiply_by_non_residue(&mut t);
+    norm.sub_assign(&t);
+
+    is_non_nth_root(&norm, modulus, n)
+}
+
 pub(crate) fn is_one_mod_two
 (
     modulus: &MaxFieldUint,
@@ -122,6 +122,8 @@ pub trait SizedPrimeField: Sized + Send + Sync + std::fmt::Debug + 'static + Cop
     fn mont_r2(&self) -> &Self::Repr;
     fn mont_inv(&self) -> u64;
     fn is_valid_repr(&self, repr: &Self::Repr) -> bool;
+    fn two_adicity(&self) -> u64;
+    fn two_adic_root_of_unity(&self) -> &Self::Repr;
 }
 
 #[derive(Debug)]
@@ -131,7 +133,11 @@ pub struct PrimeField<E: ElementRepr> {
     pub modulus: E,
     pub mont_r: E,
     pub mont_r2: E,
-    pub mont_inv: u64
+    pub mont_inv: u64,
+    // largest s such that 2^s divides modulus - 1
+    pub two_adicity: u64,
+    // primitive 2^s-th root of unity in Montgomery form for square roots, zero if it's not precomputed
+    pub two_adic_root_of_unity: E,
 }
 
 impl<E: ElementRepr> Clone for PrimeField<E> {
@@ -142,7 +148,9 @@ impl<E: ElementRepr> Clone for PrimeField<E> {
             modulus: self.modulus,
             mont_r: self.mont_r,
             mont_r2: self.mont_r2,
-            mont_inv: self.mont_inv
+            mont_inv: self.mont_inv,
+            two_adicity: self.two_adicity,
+            two_adic_root_of_unity: self.two_adic_root_of_unity,
         }
     }
 }
@@ -174,6 +182,25 @@ impl<E: ElementRepr> SizedPrimeField for PrimeField<E> {
     fn is_valid_repr(&self, repr: &Self::Repr) -> bool {
         repr < &self.modulus
     }
+
+    #[inline(always)]
+    fn two_adicity(&self) -> u64 { self.two_adicity }
+
+    #[inline(always)]
+    fn two_adic_root_of_unity(&self) -> &Self::Repr { &self.two_adic_root_of_unity }
+}
+
+impl<E: ElementRepr> PrimeField<E> {
+    /// Searches for a root of unity required by square roots for moduli that are 1 mod 4,
+    /// so it's not recalculated on every call. Moduli that are 3 mod 4 don't need it
+    pub(crate) fn precompute_square_root_parameters(&mut self) {
+        if self.two_adic_root_of_unity.is_zero() {
+            let root = crate::square_root::find_two_adic_root_of_unity(&*self).map(|el| el.repr);
+            if let Some(root) = root {
+                self.two_adic_root_of_unity = root;
+            }
+        }
+    }
 }
 
 pub(crate) fn calculate_num_limbs(bitlength: usize) -> Result<usize, ()> {
@@ -189,7 +216,17 @@ pub(crate) fn calculate_num_limbs(bitlength: usize) -> Result<usize, ()> {
     Ok(num_limbs)
 }
 
+/// Field with all the parameters precomputed, including ones needed for square roots
 pub fn field_from_modulus<R: ElementRepr>(modulus: &MaxFieldUint) -> Result<PrimeField<R>, ()> {
+    let mut field = field_from_modulus_without_square_roots(modulus)?;
+    field.precompute_square_root_parameters();
+
+    Ok(field)
+}
+
+/// Same as `field_from_modulus`, but skips the search for a root of unity that costs far more
+/// than parsing a field. Used by the ABI operations that never take square roots
+pub(crate) fn field_from_modulus_without_square_roots<R: ElementRepr>(modulus: &MaxFieldUint) -> Result<PrimeField<R>, ()> {
     if modulus.low_u64() & 1 == 0 {
         // modulus is even
         return Err(());
@@ -245,6 +282,27 @@ pub fn field_from_modulus<R: ElementRepr>(modulus: &MaxFieldUint) -> Result<Prim
         *r2_el = r2_ref[i];
     }
 
+    // modulus is odd, so modulus - 1 differs only in the lowest bit
+    let mut modulus_minus_one = modulus_repr;
+    modulus_minus_one.as_mut()[0] &= !1u64;
+
+    let mut two_adicity = 0u64;
+    for &limb in modulus_minus_one.as_ref().iter() {
+        if limb == 0 {
+            two_adicity += 64;
+        } else {
+            two_adicity += limb.trailing_zeros() as u64;
+            break;
+        }
+    }
+
+    // for moduli that are 3 mod 4 the root of unity is -1
+    let mut two_adic_root_of_unity = R::default();
+    if two_adicity == 1 {
+        two_adic_root_of_unity = modulus_repr;
+        two_adic_root_of_unity.sub_noborrow(&r_repr);
+    }
+
     let concrete = PrimeField {
         mont_power: (num_limbs*64) as u64,
         modulus_bits: bitlength as u64,
@@ -252,6 +310,8 @@ pub fn field_from_modulus<R: ElementRepr>(modulus: &MaxFieldUint) -> Result<Prim
         mont_r: r_repr,
         mont_r2: r2_repr,
         mont_inv: inv,  
+        two_adicity,
+        two_adic_root_of_unity,
     };
 
     Ok(concrete)
@@ -6,6 +6,7 @@ use once_cell::sync::Lazy;
 use serde_json;
 
 use super::parsers::*;
+use crate::public_interface::constants::{NUM_LIMBS_MIN, NUM_LIMBS_MAX};
 
 pub(crate) trait ArithmeticAdditionParams {
     fn params(&self) -> &HashMap<usize, u64>;
@@ -26,6 +27,12 @@ pub(crate) struct G1G2AdditionParams {
     lookup_parameters: HashMap<usize, u64>
 }
 
+impl G1G2AdditionParams {
+    pub(crate) fn validate(&self) -> Result<(), ApiError> {
+        validate_limbs_lookup(&self.lookup_parameters, "price")
+    }
+}
+
 impl ArithmeticAdditionParams for G1G2AdditionParams {
     fn params(&self) -> &HashMap<usize, u64> {
         &self.lookup_parameters
@@ -43,6 +50,15 @@ pub(crate) struct G1G2MultiplicationParams {
     per_limb: HashMap<usize, u64>
 }
 
+impl G1G2MultiplicationParams {
+    pub(crate) fn validate(&self) -> Result<(), ApiError> {
+        validate_limbs_lookup(&self.base, "base")?;
+        validate_limbs_lookup(&self.per_limb, "per_limb")?;
+
+        Ok(())
+    }
+}
+
 impl ArithmeticMultiplicationParams for G1G2MultiplicationParams {
     fn params(&self) -> (&HashMap<usize, u64>, &HashMap<usize, u64>) {
         (&self.base, &self.per_limb)
@@ -69,6 +85,24 @@ pub(crate) struct G1G2MultiexpParams {
     discounts: HashMap<usize, u64>
 }
 
+impl G1G2MultiexpParams {
+    pub(crate) fn validate(&self) -> Result<(), ApiError> {
+        if self.discount_multiplier == 0 {
+            return Err(ApiError::InputError("Multiexp discount multiplier can not be zero".to_owned()));
+        }
+        if self.max_pairs == 0 {
+            return Err(ApiError::InputError("Multiexp discounts table should cover at least one pair".to_owned()));
+        }
+        for num_pairs in 1..=self.max_pairs {
+            if !self.discounts.contains_key(&num_pairs) {
+                return Err(ApiError::InputError(format!("Multiexp discounts table has no value for {} pairs", num_pairs)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl ArithmeticMultiexpParams for G1G2MultiexpParams {
     fn params(&self) -> (u64, (usize, u64), &HashMap<usize, u64>) {
         (self.discount_multiplier, (self.max_pairs, self.max_discount), &self.discounts)
@@ -111,6 +145,17 @@ pub(crate) static MULTIEXP_PARAMS_INSTANCE: Lazy<G1G2MultiexpParams> = Lazy::new
     serde_json::from_str(MULTIEXP_PARAMS_JSON).expect("must deserialize parameters")
 });
 
+/// Checks that lookup table over number of modulus limbs covers every supported limb count
+pub(crate) fn validate_limbs_lookup(lookup: &HashMap<usize, u64>, name: &str) -> Result<(), ApiError> {
+    for limbs in NUM_LIMBS_MIN..=NUM_LIMBS_MAX {
+        if !lookup.contains_key(&limbs) {
+            return Err(ApiError::InputError(format!("Lookup table `{}` has no value for {} limbs", name, limbs)));
+        }
+    }
+
+    Ok(())
+}
+
 pub(crate) fn meter_addition<P: ArithmeticAdditionParams>(modulus_limbs: usize, parameters: &P) -> Result<u64, ApiError> {
     let found = *parameters.params().get(&modulus_limbs).ok_or(ApiError::MissingValue)?;
 
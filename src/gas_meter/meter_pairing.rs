@@ -6,6 +6,7 @@ use serde_json;
 use std::collections::HashMap;
 
 use super::parsers::*;
+use super::schedule::GasSchedule;
 
 use crate::public_interface::decode_utils::*;
 use crate::public_interface::sane_limits::*;
@@ -15,6 +16,8 @@ pub(crate) const MNT6_MAX_MODULUS_POWER: usize = 6;
 pub(crate) const BN_MAX_MODULUS_POWER: usize = 6;
 pub(crate) const BLS12_MAX_MODULUS_POWER: usize = 6;
 
+const BLS24_OVER_BLS12_COST_FACTOR: u64 = 5;
+
 #[derive(Clone, Deserialize, Debug)]
 pub(crate) struct MntPairingParams {
     #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints")]
@@ -63,6 +66,93 @@ pub(crate) struct BnPairingParams {
     final_exp: Vec<(u64, Vec<(usize, usize)>)>
 }
 
+impl MntPairingParams {
+    pub(crate) fn validate(&self, max_power: usize) -> Result<(), ApiError> {
+        // ate loop bits and hamming weight, powers of modulus limbs
+        // final exponentiation w0 and w1 bits and hamming weights, powers of modulus limbs
+        validate_pairing_model(
+            &self.one_off, 
+            self.multiplier, 
+            (&self.miller_features, &self.miller, &[1, 1, max_power]), 
+            (&self.final_exp_features, &self.final_exp, &[1, 1, 1, 1, max_power])
+        )
+    }
+}
+
+impl Bls12PairingParams {
+    pub(crate) fn validate(&self, max_power: usize) -> Result<(), ApiError> {
+        // x bits and hamming weight, powers of modulus limbs
+        validate_pairing_model(
+            &self.one_off, 
+            self.multiplier, 
+            (&self.miller_features, &self.miller, &[1, 1, max_power]), 
+            (&self.final_exp_features, &self.final_exp, &[1, 1, max_power])
+        )
+    }
+}
+
+impl BnPairingParams {
+    pub(crate) fn validate(&self, max_power: usize) -> Result<(), ApiError> {
+        // 6u+2 (for Miller loop) or u (for final exponentiation) bits and hamming weight, powers of modulus limbs
+        validate_pairing_model(
+            &self.one_off, 
+            self.multiplier, 
+            (&self.miller_features, &self.miller, &[1, 1, max_power]), 
+            (&self.final_exp_features, &self.final_exp, &[1, 1, max_power])
+        )
+    }
+}
+
+/// Checks that the sparse polynomial model is evaluatable by `eval_model` for 
+/// variables with a given number of available powers
+fn validate_polynomial_model(
+    features: &[(String, u64)],
+    coeffs_variables_and_powers: &[(u64, Vec<(usize, usize)>)],
+    variable_powers: &[usize],
+    name: &str
+) -> Result<(), ApiError> {
+    if features.len() != variable_powers.len() {
+        return Err(ApiError::InputError(format!("Model `{}` should have {} features, got {}", name, variable_powers.len(), features.len())));
+    }
+    if coeffs_variables_and_powers.len() == 0 {
+        return Err(ApiError::InputError(format!("Model `{}` is empty", name)));
+    }
+    let mut max_var_id = 0usize;
+    for (_, var_and_power) in coeffs_variables_and_powers.iter() {
+        for (variable, power) in var_and_power.iter() {
+            let max_power = *variable_powers.get(*variable).ok_or(ApiError::InputError(format!("Model `{}` uses unknown variable {}", name, variable)))?;
+            if *power == 0 || *power > max_power {
+                return Err(ApiError::InputError(format!("Model `{}` uses variable {} in power {}, while maximum is {}", name, variable, power, max_power)));
+            }
+            if max_var_id < *variable {
+                max_var_id = *variable;
+            }
+        }
+    }
+
+    if max_var_id + 1 != variable_powers.len() {
+        return Err(ApiError::InputError(format!("Model `{}` should use all {} variables", name, variable_powers.len())));
+    }
+
+    Ok(())
+}
+
+fn validate_pairing_model(
+    one_off: &HashMap<usize, u64>,
+    multiplier: u64,
+    (miller_features, miller, miller_variable_powers): (&[(String, u64)], &[(u64, Vec<(usize, usize)>)], &[usize]),
+    (final_exp_features, final_exp, final_exp_variable_powers): (&[(String, u64)], &[(u64, Vec<(usize, usize)>)], &[usize]),
+) -> Result<(), ApiError> {
+    super::meter_arith::validate_limbs_lookup(one_off, "one_off")?;
+    if multiplier == 0 {
+        return Err(ApiError::InputError("Pairing model multiplier can not be zero".to_owned()));
+    }
+    validate_polynomial_model(miller_features, miller, miller_variable_powers, "miller")?;
+    validate_polynomial_model(final_exp_features, final_exp, final_exp_variable_powers, "final_exp")?;
+
+    Ok(())
+}
+
 static MNT4_PARAMS_JSON: &'static str = include_str!("mnt4_model.json");
 static MNT6_PARAMS_JSON: &'static str = include_str!("mnt6_model.json");
 static BLS12_PARAMS_JSON: &'static str = include_str!("bls12_model.json");
@@ -84,7 +174,15 @@ pub(crate) static BN_PARAMS_INSTANCE: Lazy<BnPairingParams> = Lazy::new(|| {
     serde_json::from_str(BN_PARAMS_JSON).expect("must deserialize parameters")
 });
 
-pub(crate) fn meter_mnt_pairing(input: &[u8], params: &MntPairingParams, max_power: usize, ext_degree: usize) -> Result<u64, ApiError> {
+pub(crate) fn meter_mnt_pairing(input: &[u8], schedule: &GasSchedule, max_power: usize, ext_degree: usize) -> Result<u64, ApiError> {
+    let params = match ext_degree {
+        2 => &schedule.mnt4,
+        3 => &schedule.mnt6,
+        _ => {
+            return Err(ApiError::InputError("Invalid extension degree for MNT4/6 pairing cost calculation".to_owned()));
+        }
+    };
+
     let (
         modulus, 
         order_len, 
@@ -111,17 +209,17 @@ pub(crate) fn meter_mnt_pairing(input: &[u8], params: &MntPairingParams, max_pow
         max_power
     )?;
 
-    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G1_MULTIPLICATION_PARAMS_INSTANCE, false)?;
+    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g1_multiplication, false)?;
     let g1_subgroup_checks_cost = g1_subgroup_check_cost_per_point.checked_mul(num_g1_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
 
     estimate = estimate.checked_add(g1_subgroup_checks_cost).ok_or(ApiError::Overflow)?;
 
     let g2_subgroup_check_cost_per_point = match ext_degree {
         2 => {
-            super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE, false)?
+            super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g2_ext_2_multiplication, false)?
         },
         3 => {
-            super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G2_EXT_3_MULTIPLICATION_PARAMS_INSTANCE, false)?
+            super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g2_ext_3_multiplication, false)?
         },
         _ => {
             return Err(ApiError::InputError("Invalid extension degree for MNT4/6 pairing cost calculation".to_owned()));
@@ -135,6 +233,47 @@ pub(crate) fn meter_mnt_pairing(input: &[u8], params: &MntPairingParams, max_pow
     Ok(estimate)
 }
 
+/// BW6 pairing is priced with the MNT6 model: both have Fp6 as the target group and the same final
+/// exponentiation shape. Two Miller loops are priced as one loop over the sum of their lengths, and G2
+/// is defined over the base field, so its subgroup checks are priced as the ones in G1
+pub(crate) fn meter_bw6_pairing(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
+    let params = &schedule.mnt6;
+
+    let (
+        modulus, 
+        order_len, 
+        num_pairs, 
+        (ate_loop_1_bits, ate_loop_1_hamming), 
+        (ate_loop_2_bits, ate_loop_2_hamming), 
+        (exp_w0_bits, exp_w0_hamming),
+        (exp_w1_bits, exp_w1_hamming),
+        (num_g1_subgroup_checks, num_g2_subgroup_checks),
+        _
+    ) = parse_bw6_pairing_parameters(&input)?;
+
+    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+    let order_limbs = num_units_for_group_order_length(order_len)?;
+
+    let mut estimate = calculate_mnt_pairing_cost(
+        modulus_limbs,
+        order_limbs,
+        num_pairs,
+        (ate_loop_1_bits + ate_loop_2_bits, ate_loop_1_hamming + ate_loop_2_hamming), 
+        (exp_w0_bits, exp_w0_hamming),
+        (exp_w1_bits, exp_w1_hamming),
+        params,
+        MNT6_MAX_MODULUS_POWER
+    )?;
+
+    let subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g1_multiplication, false)?;
+    let num_subgroup_checks = (num_g1_subgroup_checks as u64).checked_add(num_g2_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
+    let subgroup_checks_cost = subgroup_check_cost_per_point.checked_mul(num_subgroup_checks).ok_or(ApiError::Overflow)?;
+
+    estimate = estimate.checked_add(subgroup_checks_cost).ok_or(ApiError::Overflow)?;
+
+    Ok(estimate)
+}
+
 fn calculate_mnt_pairing_cost(
     modulus_limbs: usize,
     _order_limbs: usize,
@@ -197,7 +336,9 @@ fn calculate_mnt_pairing_cost(
     Ok(result)
 }
 
-pub(crate) fn meter_bls12_pairing(input: &[u8], params: &Bls12PairingParams, max_power: usize) -> Result<u64, ApiError> {
+pub(crate) fn meter_bls12_pairing(input: &[u8], schedule: &GasSchedule, max_power: usize) -> Result<u64, ApiError> {
+    let params = &schedule.bls12;
+
     let (
         modulus, 
         order_len, 
@@ -228,12 +369,12 @@ pub(crate) fn meter_bls12_pairing(input: &[u8], params: &Bls12PairingParams, max
         max_power
     )?;
 
-    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G1_MULTIPLICATION_PARAMS_INSTANCE, false)?;
+    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g1_multiplication, false)?;
     let g1_subgroup_check_cost = g1_subgroup_check_cost_per_point.checked_mul(num_g1_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
 
     estimate = estimate.checked_add(g1_subgroup_check_cost).ok_or(ApiError::Overflow)?;
 
-    let g2_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE, false)?;
+    let g2_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g2_ext_2_multiplication, false)?;
     let g2_subgroup_check_cost = g2_subgroup_check_cost_per_point.checked_mul(num_g2_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
 
     estimate = estimate.checked_add(g2_subgroup_check_cost).ok_or(ApiError::Overflow)?;
@@ -242,7 +383,9 @@ pub(crate) fn meter_bls12_pairing(input: &[u8], params: &Bls12PairingParams, max
 }
 
 
-pub(crate) fn meter_bn_pairing(input: &[u8], params: &BnPairingParams, max_power: usize) -> Result<u64, ApiError> {
+pub(crate) fn meter_bn_pairing(input: &[u8], schedule: &GasSchedule, max_power: usize) -> Result<u64, ApiError> {
+    let params = &schedule.bn;
+
     let (
         modulus, 
         order_len, 
@@ -293,12 +436,57 @@ pub(crate) fn meter_bn_pairing(input: &[u8], params: &BnPairingParams, max_power
         max_power
     )?;
 
-    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G1_MULTIPLICATION_PARAMS_INSTANCE, false)?;
+    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g1_multiplication, false)?;
+    let g1_subgroup_check_cost = g1_subgroup_check_cost_per_point.checked_mul(num_g1_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
+
+    estimate = estimate.checked_add(g1_subgroup_check_cost).ok_or(ApiError::Overflow)?;
+
+    let g2_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g2_ext_2_multiplication, false)?;
+    let g2_subgroup_check_cost = g2_subgroup_check_cost_per_point.checked_mul(num_g2_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
+
+    estimate = estimate.checked_add(g2_subgroup_check_cost).ok_or(ApiError::Overflow)?;
+
+    Ok(estimate)
+}
+
+/// BLS24 pairing is priced with the BLS12 model scaled by a constant factor: Fp24 and Fp4 arithmetic 
+/// costs three times as much as Fp12 and Fp2 one, and the hard part of final exponentiation has about twice 
+/// as many exponentiations by x, that gives six at most. For 4 pairs BLS24-315 runs 2.45-2.55 times longer
+/// than BLS12-381 (`bench_bls24_315_pairing_4_through_the_api` vs `bench_bls12_381_pairing_4_through_the_api`)
+/// and a factor of five makes it 2.8 times more expensive, so it overestimates by 10-15%. G2 subgroup checks
+/// are priced as ones over Fp2 with a factor of three
+pub(crate) fn meter_bls24_pairing(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
+    let params = &schedule.bls12;
+
+    let (
+        modulus, 
+        order_len, 
+        num_pairs, 
+        (x_bits, x_hamming),
+        (num_g1_subgroup_checks, num_g2_subgroup_checks),
+        _
+    ) = parse_bls24_pairing_parameters(&input)?;
+
+    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+    let order_limbs = num_units_for_group_order_length(order_len)?;
+
+    let mut estimate = calculate_bls12_pairing_cost(
+        modulus_limbs,
+        order_limbs,
+        num_pairs,
+        (x_bits, x_hamming),
+        params,
+        BLS12_MAX_MODULUS_POWER
+    )?;
+    estimate = estimate.checked_mul(BLS24_OVER_BLS12_COST_FACTOR).ok_or(ApiError::Overflow)?;
+
+    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g1_multiplication, false)?;
     let g1_subgroup_check_cost = g1_subgroup_check_cost_per_point.checked_mul(num_g1_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
 
     estimate = estimate.checked_add(g1_subgroup_check_cost).ok_or(ApiError::Overflow)?;
 
-    let g2_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE, false)?;
+    let g2_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g2_ext_2_multiplication, false)?;
+    let g2_subgroup_check_cost_per_point = g2_subgroup_check_cost_per_point.checked_mul(3).ok_or(ApiError::Overflow)?;
     let g2_subgroup_check_cost = g2_subgroup_check_cost_per_point.checked_mul(num_g2_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
 
     estimate = estimate.checked_add(g2_subgroup_check_cost).ok_or(ApiError::Overflow)?;
@@ -1,6 +1,7 @@
 mod parsers;
 mod meter_arith;
 mod meter_pairing;
+mod schedule;
 
 extern crate serde;
 extern crate serde_json;
@@ -12,45 +13,59 @@ use crate::public_interface::constants::*;
 use self::parsers::*;
 use crate::public_interface::OperationType;
 
+pub use self::schedule::{GasSchedule, GasModel};
+use self::schedule::BUILTIN_GAS_SCHEDULE;
+
 pub struct GasMeter;
 
 // This is pure rust API
 pub fn meter_operation(operation: OperationType, input: &[u8]) -> Result<u64, ApiError> {
+    meter_operation_with_schedule(operation, input, &*BUILTIN_GAS_SCHEDULE)
+}
+
+// Same as `meter_operation`, but uses user-supplied gas schedule
+pub fn meter_operation_with_schedule(operation: OperationType, input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
     match operation {
         OperationType::G1ADD => {
-            meter_addition_g1(&input)
+            meter_addition_g1(&input, schedule)
         },
         OperationType::G1MUL => {
-            meter_multiplication_g1(&input)
+            meter_multiplication_g1(&input, schedule)
         },
         OperationType::G1MULTIEXP => {
-            meter_multiexp_g1(&input)
+            meter_multiexp_g1(&input, schedule)
         },
         OperationType::G2ADD => {
-            meter_addition_g2(&input)
+            meter_addition_g2(&input, schedule)
         },
         OperationType::G2MUL => {
-            meter_multiplication_g2(&input)
+            meter_multiplication_g2(&input, schedule)
         },
         OperationType::G2MULTIEXP => {
-            meter_multiexp_g2(&input)
+            meter_multiexp_g2(&input, schedule)
         },
         OperationType::MNT4PAIR => {
-            meter_mnt4(&input)
+            meter_mnt4(&input, schedule)
         },
         OperationType::MNT6PAIR => {
-            meter_mnt6(&input)
+            meter_mnt6(&input, schedule)
+        },
+        OperationType::BW6PAIR => {
+            meter_bw6(&input, schedule)
+        },
+        OperationType::BLS24PAIR => {
+            meter_bls24(&input, schedule)
         },
         OperationType::BLS12PAIR => {
-            meter_bls12(&input)
+            meter_bls12(&input, schedule)
         },
         OperationType::BNPAIR => {
-            meter_bn(&input)
+            meter_bn(&input, schedule)
         } 
     }
 }
 
-fn meter_addition_g1(input: &[u8]) -> Result<u64, ApiError> {
+fn meter_addition_g1(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
 
     let (modulus, modulus_len, _, rest) = parse_g1_curve_parameters(&input)?;
     if rest.len() != modulus_len * 4 {
@@ -58,12 +73,12 @@ fn meter_addition_g1(input: &[u8]) -> Result<u64, ApiError> {
     }
     let modulus_limbs = num_limbs_for_modulus(&modulus)?;
 
-    let params = &*meter_arith::G1_ADDITION_PARAMS_INSTANCE;
+    let params = &schedule.g1_addition;
 
     meter_arith::meter_addition(modulus_limbs, params)
 }
 
-fn meter_addition_g2(input: &[u8]) -> Result<u64, ApiError> {
+fn meter_addition_g2(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
 
     let (modulus, modulus_len, _, ext_degree, rest) = parse_g2_curve_parameters(&input)?;
     if rest.len() != modulus_len * 4 * (ext_degree as usize) {
@@ -72,9 +87,9 @@ fn meter_addition_g2(input: &[u8]) -> Result<u64, ApiError> {
     let modulus_limbs = num_limbs_for_modulus(&modulus)?;
 
     let params = if ext_degree == EXTENSION_DEGREE_2 {
-        &*meter_arith::G2_EXT_2_ADDITION_PARAMS_INSTANCE
+        &schedule.g2_ext_2_addition
     } else if ext_degree == EXTENSION_DEGREE_3 {
-        &*meter_arith::G2_EXT_3_ADDITION_PARAMS_INSTANCE
+        &schedule.g2_ext_3_addition
     } else {
         unreachable!();
     };
@@ -83,7 +98,7 @@ fn meter_addition_g2(input: &[u8]) -> Result<u64, ApiError> {
 }
 
 
-fn meter_multiplication_g1(input: &[u8]) -> Result<u64, ApiError> {
+fn meter_multiplication_g1(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
     let (modulus, modulus_len, order_len, rest) = parse_g1_curve_parameters(&input)?;
     if rest.len() != modulus_len * 2 + order_len {
         return Err(ApiError::InputError("Input is either too short or contains garbage for g1 multiplication metering".to_owned()));
@@ -92,12 +107,12 @@ fn meter_multiplication_g1(input: &[u8]) -> Result<u64, ApiError> {
     // let order_limbs = num_units_for_group_order(&order)?;
     let order_limbs = num_units_for_group_order_length(order_len)?;
 
-    let params = &*meter_arith::G1_MULTIPLICATION_PARAMS_INSTANCE;
+    let params = &schedule.g1_multiplication;
 
     meter_arith::meter_multiplication(modulus_limbs, order_limbs, params, true)
 }
 
-fn meter_multiplication_g2(input: &[u8]) -> Result<u64, ApiError> {
+fn meter_multiplication_g2(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
     let (modulus, modulus_len, order_len, ext_degree, rest) = parse_g2_curve_parameters(&input)?;
     if rest.len() != modulus_len * 2 * (ext_degree as usize) + order_len {
         return Err(ApiError::InputError("Input is either too short or contains garbage for g2 multiplication metering".to_owned()));
@@ -108,9 +123,9 @@ fn meter_multiplication_g2(input: &[u8]) -> Result<u64, ApiError> {
     let order_limbs = num_units_for_group_order_length(order_len)?;
 
     let params = if ext_degree == EXTENSION_DEGREE_2 {
-        &*meter_arith::G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE
+        &schedule.g2_ext_2_multiplication
     } else if ext_degree == EXTENSION_DEGREE_3 {
-        &*meter_arith::G2_EXT_3_MULTIPLICATION_PARAMS_INSTANCE
+        &schedule.g2_ext_3_multiplication
     } else {
         unreachable!();
     };
@@ -118,7 +133,7 @@ fn meter_multiplication_g2(input: &[u8]) -> Result<u64, ApiError> {
     meter_arith::meter_multiplication(modulus_limbs, order_limbs, params, true)
 }
 
-fn meter_multiexp_g1(input: &[u8]) -> Result<u64, ApiError> {
+fn meter_multiexp_g1(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
     let (modulus, modulus_len, order_len, rest) = parse_g1_curve_parameters(&input)?;
     let modulus_limbs = num_limbs_for_modulus(&modulus)?;
     // let order_limbs = num_units_for_group_order(&order)?;
@@ -135,13 +150,13 @@ fn meter_multiexp_g1(input: &[u8]) -> Result<u64, ApiError> {
         return Err(ApiError::InputError("Input is either too short or contains garbage for g1 multiexp metering".to_owned()));
     }
 
-    let params = &*meter_arith::G1_MULTIPLICATION_PARAMS_INSTANCE;
-    let discounts = &*meter_arith::MULTIEXP_PARAMS_INSTANCE;
+    let params = &schedule.g1_multiplication;
+    let discounts = &schedule.multiexp_discounts;
 
     meter_arith::meter_multiexp(modulus_limbs, order_limbs, num_pairs, params, discounts)
 }
 
-fn meter_multiexp_g2(input: &[u8]) -> Result<u64, ApiError> {
+fn meter_multiexp_g2(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
     let (modulus, modulus_len, order_len, ext_degree, rest) = parse_g2_curve_parameters(&input)?;
 
     let modulus_limbs = num_limbs_for_modulus(&modulus)?;
@@ -149,9 +164,9 @@ fn meter_multiexp_g2(input: &[u8]) -> Result<u64, ApiError> {
     let order_limbs = num_units_for_group_order_length(order_len)?;
 
     let params = if ext_degree == EXTENSION_DEGREE_2 {
-        &*meter_arith::G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE
+        &schedule.g2_ext_2_multiplication
     } else if ext_degree == EXTENSION_DEGREE_3 {
-        &*meter_arith::G2_EXT_3_MULTIPLICATION_PARAMS_INSTANCE
+        &schedule.g2_ext_3_multiplication
     } else {
         unreachable!();
     };
@@ -167,75 +182,93 @@ fn meter_multiexp_g2(input: &[u8]) -> Result<u64, ApiError> {
         return Err(ApiError::InputError("Input is either too short or contains garbage for g2 multiexp metering".to_owned()));
     }
 
-    let discounts = &*meter_arith::MULTIEXP_PARAMS_INSTANCE;
+    let discounts = &schedule.multiexp_discounts;
 
     meter_arith::meter_multiexp(modulus_limbs, order_limbs, num_pairs, params, discounts)
 }
 
-fn meter_bls12(input: &[u8]) -> Result<u64, ApiError> {
-    self::meter_pairing::meter_bls12_pairing(input, &*self::meter_pairing::BLS12_PARAMS_INSTANCE, self::meter_pairing::BLS12_MAX_MODULUS_POWER)
+fn meter_bls12(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
+    self::meter_pairing::meter_bls12_pairing(input, schedule, self::meter_pairing::BLS12_MAX_MODULUS_POWER)
 }
 
-fn meter_bn(input: &[u8]) -> Result<u64, ApiError> {
-    self::meter_pairing::meter_bn_pairing(input, &*self::meter_pairing::BN_PARAMS_INSTANCE, self::meter_pairing::BN_MAX_MODULUS_POWER)
+fn meter_bn(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
+    self::meter_pairing::meter_bn_pairing(input, schedule, self::meter_pairing::BN_MAX_MODULUS_POWER)
 }
 
-fn meter_mnt4(input: &[u8]) -> Result<u64, ApiError> {
+fn meter_mnt4(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
     self::meter_pairing::meter_mnt_pairing(
         input, 
-        &*self::meter_pairing::MNT4_PARAMS_INSTANCE, 
+        schedule, 
         self::meter_pairing::MNT4_MAX_MODULUS_POWER,
         2
     )
 }
 
-fn meter_mnt6(input: &[u8]) -> Result<u64, ApiError> {
+fn meter_mnt6(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
     self::meter_pairing::meter_mnt_pairing(
         input, 
-        &*self::meter_pairing::MNT6_PARAMS_INSTANCE, 
+        schedule, 
         self::meter_pairing::MNT6_MAX_MODULUS_POWER,
         3
     )
 }
 
+fn meter_bw6(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
+    self::meter_pairing::meter_bw6_pairing(input, schedule)
+}
+
+fn meter_bls24(input: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
+    self::meter_pairing::meter_bls24_pairing(input, schedule)
+}
+
 impl GasMeter {
     pub fn meter(bytes: &[u8]) -> Result<u64, ApiError> {
+        Self::meter_with_schedule(bytes, &*BUILTIN_GAS_SCHEDULE)
+    }
+
+    pub fn meter_with_schedule(bytes: &[u8], schedule: &GasSchedule) -> Result<u64, ApiError> {
         let (op_type, rest) = split(bytes, OPERATION_ENCODING_LENGTH , "Input should be longer than operation type encoding")?;
         let operation = op_type[0];
         let result = match operation {
             OPERATION_G1_ADD => {
-                meter_addition_g1(&rest)
+                meter_addition_g1(&rest, schedule)
             },
             OPERATION_G2_ADD => {
-                meter_addition_g2(&rest)
+                meter_addition_g2(&rest, schedule)
             },
             OPERATION_G1_MUL => {
-                meter_multiplication_g1(&rest)
+                meter_multiplication_g1(&rest, schedule)
             },
             OPERATION_G2_MUL => {
-                meter_multiplication_g2(&rest)
+                meter_multiplication_g2(&rest, schedule)
             }
             OPERATION_G1_MULTIEXP => {
-                meter_multiexp_g1(&rest)
+                meter_multiexp_g1(&rest, schedule)
             },
             OPERATION_G2_MULTIEXP => {
-                meter_multiexp_g2(&rest)
+                meter_multiexp_g2(&rest, schedule)
             },
             OPERATION_PAIRING => {
                 let (curve_type, rest) = split(rest, CURVE_TYPE_LENGTH, "Input should be longer than curve type encoding")?;
 
                 match curve_type[0] {
                     BLS12 => {
-                        meter_bls12(&rest)
+                        meter_bls12(&rest, schedule)
                     },
                     BN => {
-                        meter_bn(&rest)
+                        meter_bn(&rest, schedule)
                     },
                     MNT4 => {
-                        meter_mnt4(&rest)
+                        meter_mnt4(&rest, schedule)
                     },
                     MNT6 => {
-                        meter_mnt6(&rest)
+                        meter_mnt6(&rest, schedule)
+                    },
+                    BW6 => {
+                        meter_bw6(&rest, schedule)
+                    },
+                    BLS24 => {
+                        meter_bls24(&rest, schedule)
                     },
                     _ => {
                         return Err(ApiError::InputError("Unknown curve type".to_owned()));
@@ -190,6 +190,115 @@ pub(crate) fn parse_mnt_pairing_parameters<'a>(bytes: &'a [u8], ext_degree: usiz
     )
 }
 
+/// Same as `parse_mnt_pairing_parameters`, but for BW6 curves: twist type follows the non-residue,
+/// there are two Miller loop parameters and G2 points are encoded over the base field
+pub(crate) fn parse_bw6_pairing_parameters<'a>(bytes: &'a [u8]) -> Result<(
+    MaxFieldUint, 
+    usize,
+    usize,
+    (u64, u64),
+    (u64, u64),
+    (u64, u64),
+    (u64, u64),
+    (usize, usize),
+    &'a [u8]), ApiError> 
+{
+    use crate::public_interface::sane_limits::*;
+
+    let ((modulus, modulus_len), rest) = get_base_field_params(&bytes)?;
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get A parameter")?;
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get B parameter")?;
+
+    let (order_len, _, rest) = parse_group_order_from_encoding(rest)?;
+
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get non-residue")?;
+
+    let (_, rest) = decode_twist_type(rest)?;
+
+    let mut rest = rest;
+    let mut ate_loops = [(0u64, 0u64); 2];
+    for ate_loop in ate_loops.iter_mut() {
+        let (x, r) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
+        if x.is_zero() {
+            return Err(ApiError::InputError("Ate pairing loop count parameters can not be zero".to_owned()));
+        }
+
+        let ate_loop_bits = x.bits();
+        let ate_loop_hamming = calculate_hamming_weight(&x.as_ref());
+
+        if ate_loop_hamming > MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING {
+            return Err(ApiError::InputError("Ate pairing loop has too large hamming weight".to_owned()));
+        }
+
+        let (_, r) = decode_sign_is_negative(r)?;
+        rest = r;
+
+        *ate_loop = (ate_loop_bits as u64, ate_loop_hamming as u64);
+    }
+
+    let (exp_w0, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W0_BIT_LENGTH)?;
+    if exp_w0.is_zero() {
+        return Err(ApiError::InputError("Final exp w0 loop count parameters can not be zero".to_owned()));
+    }
+    let exp_w0_bits = exp_w0.bits();
+    let exp_w0_hamming = calculate_hamming_weight(&exp_w0.as_ref());
+
+    let (exp_w1, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W1_BIT_LENGTH)?;
+    if exp_w1.is_zero() {
+        return Err(ApiError::InputError("Final exp w1 loop count parameters can not be zero".to_owned()));
+    }
+    let exp_w1_bits = exp_w1.bits();
+    let exp_w1_hamming = calculate_hamming_weight(&exp_w1.as_ref());
+
+    let (_, rest) = decode_sign_is_negative(rest)?;
+
+    let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
+    let num_pairs = num_pairs_encoding[0] as usize;
+
+    if num_pairs == 0 {
+        return Err(ApiError::InputError("Zero pairs encoded".to_owned()));
+    }
+
+    let mut num_g1_subgroup_checks = 0;
+    let mut num_g2_subgroup_checks = 0;
+
+    let mut global_rest = rest;
+
+    for _ in 0..num_pairs {
+        let (check_g1, rest) = decode_boolean(&global_rest)?;
+        let (_, rest) = split(rest, modulus_len*2, "input is not long enough to get G1 point encoding")?;
+        let (check_g2, rest) = decode_boolean(&rest)?;
+        let (_, rest) = split(rest, modulus_len*2, "input is not long enough to get G2 point encoding")?;
+        global_rest = rest;
+
+        if check_g1 {
+            num_g1_subgroup_checks += 1;
+        }
+
+        if check_g2 {
+            num_g2_subgroup_checks += 1;
+        }
+    }
+
+    if global_rest.len() != 0 {
+        return Err(ApiError::InputError("Input has garbage at the end for BW6 pairing".to_owned()));
+    }
+
+    Ok(
+        (
+            modulus,
+            order_len,
+            num_pairs,
+            ate_loops[0],
+            ate_loops[1],
+            (exp_w0_bits as u64, exp_w0_hamming as u64),
+            (exp_w1_bits as u64, exp_w1_hamming as u64),
+            (num_g1_subgroup_checks, num_g2_subgroup_checks),
+            rest
+        )
+    )
+}
+
 pub(crate) fn parse_bls12_bn_pairing_parameters<'a>(bytes: &'a [u8], max_x_bit_limit: usize) -> Result<(
     MaxFieldUint, 
     usize,
@@ -279,6 +388,87 @@ pub(crate) fn parse_bls12_bn_pairing_parameters<'a>(bytes: &'a [u8], max_x_bit_l
     )
 }
 
+/// Same as `parse_bls12_bn_pairing_parameters`, but for BLS24 curves: Fp12(24) non-residue is an element
+/// of Fp4 and G2 points are encoded over Fp4
+pub(crate) fn parse_bls24_pairing_parameters<'a>(bytes: &'a [u8]) -> Result<(
+    MaxFieldUint, 
+    usize,
+    usize,
+    (u64, u64),
+    (usize, usize),
+    &'a [u8]), ApiError> 
+{
+    use crate::public_interface::sane_limits::*;
+
+    let ((modulus, modulus_len), rest) = get_base_field_params(&bytes)?;
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get A parameter")?;
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get B parameter")?;
+
+    let (order_len, _, rest) = parse_group_order_from_encoding(rest)?;
+    
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get Fp2 non-residue")?;
+    let (_, rest) = split(rest, modulus_len*4, "Input is not long enough to get Fp12/Fp24 non-residue")?;
+
+    let (_, rest) = decode_twist_type(rest)?;
+
+    let (x, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_BLS24_X_BIT_LENGTH)?;
+    if x.is_zero() {
+        return Err(ApiError::InputError("Ate pairing loop count parameters can not be zero".to_owned()));
+    }
+
+    let x_bits = x.bits();
+    let x_hamming = calculate_hamming_weight(&x.as_ref());
+
+    if x_hamming > MAX_BLS24_X_HAMMING {
+        return Err(ApiError::InputError(format!("Hamming weight for scalar is too large, file {}, line {}", file!(), line!())));
+    }
+
+    let (_, rest) = decode_sign_is_negative(rest)?;
+
+    let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
+    let num_pairs = num_pairs_encoding[0] as usize;
+
+    if num_pairs == 0 {
+        return Err(ApiError::InputError("Zero pairs encoded".to_owned()));
+    }
+
+    let mut num_g1_subgroup_checks = 0;
+    let mut num_g2_subgroup_checks = 0;
+
+    let mut global_rest = rest;
+
+    for _ in 0..num_pairs {
+        let (check_g1, rest) = decode_boolean(&global_rest)?;
+        let (_, rest) = split(rest, modulus_len*2, "input is not long enough to get G1 point encoding")?;
+        let (check_g2, rest) = decode_boolean(&rest)?;
+        let (_, rest) = split(rest, modulus_len*8, "input is not long enough to get G2 point encoding")?;
+        global_rest = rest;
+
+        if check_g1 {
+            num_g1_subgroup_checks += 1;
+        }
+
+        if check_g2 {
+            num_g2_subgroup_checks += 1;
+        }
+    }
+
+    if global_rest.len() != 0 {
+        return Err(ApiError::InputError("Input has garbage at the end for BLS24 pairing".to_owned()));
+    }
+
+    Ok(
+        (
+            modulus,
+            order_len,
+            num_pairs,
+            (x_bits as u64, x_hamming as u64),
+            (num_g1_subgroup_checks, num_g2_subgroup_checks),
+            global_rest
+        )
+    )
+}
+
 use serde::{Deserializer};
 use std::collections::HashMap;
 
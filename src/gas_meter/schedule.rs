@@ -0,0 +1,291 @@
+use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
+use serde_json;
+
+use crate::errors::ApiError;
+
+use super::meter_arith::*;
+use super::meter_pairing::*;
+
+/// Individual models that together form a gas schedule. Every model is
+/// encoded in JSON in the same format as the corresponding file in `src/gas_meter`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GasModel {
+    G1Addition,
+    G2AdditionExt2,
+    G2AdditionExt3,
+    G1Multiplication,
+    G2MultiplicationExt2,
+    G2MultiplicationExt3,
+    MultiexpDiscounts,
+    Bls12Pairing,
+    BnPairing,
+    Mnt4Pairing,
+    Mnt6Pairing,
+}
+
+impl GasModel {
+    pub fn all() -> [GasModel; 11] {
+        [
+            GasModel::G1Addition,
+            GasModel::G2AdditionExt2,
+            GasModel::G2AdditionExt3,
+            GasModel::G1Multiplication,
+            GasModel::G2MultiplicationExt2,
+            GasModel::G2MultiplicationExt3,
+            GasModel::MultiexpDiscounts,
+            GasModel::Bls12Pairing,
+            GasModel::BnPairing,
+            GasModel::Mnt4Pairing,
+            GasModel::Mnt6Pairing,
+        ]
+    }
+
+    /// Name of the file that holds the built-in model
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            GasModel::G1Addition => "g1_addition.json",
+            GasModel::G2AdditionExt2 => "g2_addition_ext2.json",
+            GasModel::G2AdditionExt3 => "g2_addition_ext3.json",
+            GasModel::G1Multiplication => "g1_multiplication.json",
+            GasModel::G2MultiplicationExt2 => "g2_multiplication_ext2.json",
+            GasModel::G2MultiplicationExt3 => "g2_multiplication_ext3.json",
+            GasModel::MultiexpDiscounts => "multiexp_discounts.json",
+            GasModel::Bls12Pairing => "bls12_model.json",
+            GasModel::BnPairing => "bn_model.json",
+            GasModel::Mnt4Pairing => "mnt4_model.json",
+            GasModel::Mnt6Pairing => "mnt6_model.json",
+        }
+    }
+}
+
+/// Full set of models used for gas metering. `GasSchedule::default()` is the
+/// built-in schedule used by `meter_operation`, any model can be replaced by
+/// a user-supplied one that is validated against the expected model shape
+#[derive(Clone, Debug)]
+pub struct GasSchedule {
+    pub(crate) g1_addition: G1G2AdditionParams,
+    pub(crate) g2_ext_2_addition: G1G2AdditionParams,
+    pub(crate) g2_ext_3_addition: G1G2AdditionParams,
+    pub(crate) g1_multiplication: G1G2MultiplicationParams,
+    pub(crate) g2_ext_2_multiplication: G1G2MultiplicationParams,
+    pub(crate) g2_ext_3_multiplication: G1G2MultiplicationParams,
+    pub(crate) multiexp_discounts: G1G2MultiexpParams,
+    pub(crate) bls12: Bls12PairingParams,
+    pub(crate) bn: BnPairingParams,
+    pub(crate) mnt4: MntPairingParams,
+    pub(crate) mnt6: MntPairingParams,
+}
+
+pub(crate) static BUILTIN_GAS_SCHEDULE: Lazy<GasSchedule> = Lazy::new(|| {
+    GasSchedule {
+        g1_addition: G1_ADDITION_PARAMS_INSTANCE.clone(),
+        g2_ext_2_addition: G2_EXT_2_ADDITION_PARAMS_INSTANCE.clone(),
+        g2_ext_3_addition: G2_EXT_3_ADDITION_PARAMS_INSTANCE.clone(),
+        g1_multiplication: G1_MULTIPLICATION_PARAMS_INSTANCE.clone(),
+        g2_ext_2_multiplication: G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE.clone(),
+        g2_ext_3_multiplication: G2_EXT_3_MULTIPLICATION_PARAMS_INSTANCE.clone(),
+        multiexp_discounts: MULTIEXP_PARAMS_INSTANCE.clone(),
+        bls12: BLS12_PARAMS_INSTANCE.clone(),
+        bn: BN_PARAMS_INSTANCE.clone(),
+        mnt4: MNT4_PARAMS_INSTANCE.clone(),
+        mnt6: MNT6_PARAMS_INSTANCE.clone(),
+    }
+});
+
+fn parse_model<T: DeserializeOwned>(model: GasModel, json: &str) -> Result<T, ApiError> {
+    serde_json::from_str(json).map_err(|e| {
+        ApiError::InputError(format!("Failed to parse gas model {}: {}", model.file_name(), e))
+    })
+}
+
+fn annotate(model: GasModel, result: Result<(), ApiError>) -> Result<(), ApiError> {
+    result.map_err(|e| {
+        match e {
+            ApiError::InputError(descr) => ApiError::InputError(format!("Invalid gas model {}: {}", model.file_name(), descr)),
+            e => e
+        }
+    })
+}
+
+impl Default for GasSchedule {
+    fn default() -> Self {
+        BUILTIN_GAS_SCHEDULE.clone()
+    }
+}
+
+impl GasSchedule {
+    /// Creates a schedule where every model is taken from the `models` list. All models
+    /// must be present exactly once
+    pub fn from_json_models(models: &[(GasModel, &str)]) -> Result<Self, ApiError> {
+        for model in GasModel::all().iter() {
+            let count = models.iter().filter(|(m, _)| m == model).count();
+            if count != 1 {
+                return Err(ApiError::InputError(format!("Gas model {} should be given exactly once, got {} times", model.file_name(), count)));
+            }
+        }
+
+        let mut schedule = Self::default();
+        for (model, json) in models.iter() {
+            schedule.set_model_from_json(*model, json)?;
+        }
+
+        Ok(schedule)
+    }
+
+    /// Replaces one model in this schedule. Schedule is unchanged if the model can not be parsed or is invalid
+    pub fn set_model_from_json(&mut self, model: GasModel, json: &str) -> Result<(), ApiError> {
+        match model {
+            GasModel::G1Addition => {
+                let params: G1G2AdditionParams = parse_model(model, json)?;
+                annotate(model, params.validate())?;
+                self.g1_addition = params;
+            },
+            GasModel::G2AdditionExt2 => {
+                let params: G1G2AdditionParams = parse_model(model, json)?;
+                annotate(model, params.validate())?;
+                self.g2_ext_2_addition = params;
+            },
+            GasModel::G2AdditionExt3 => {
+                let params: G1G2AdditionParams = parse_model(model, json)?;
+                annotate(model, params.validate())?;
+                self.g2_ext_3_addition = params;
+            },
+            GasModel::G1Multiplication => {
+                let params: G1G2MultiplicationParams = parse_model(model, json)?;
+                annotate(model, params.validate())?;
+                self.g1_multiplication = params;
+            },
+            GasModel::G2MultiplicationExt2 => {
+                let params: G1G2MultiplicationParams = parse_model(model, json)?;
+                annotate(model, params.validate())?;
+                self.g2_ext_2_multiplication = params;
+            },
+            GasModel::G2MultiplicationExt3 => {
+                let params: G1G2MultiplicationParams = parse_model(model, json)?;
+                annotate(model, params.validate())?;
+                self.g2_ext_3_multiplication = params;
+            },
+            GasModel::MultiexpDiscounts => {
+                let params: G1G2MultiexpParams = parse_model(model, json)?;
+                annotate(model, params.validate())?;
+                self.multiexp_discounts = params;
+            },
+            GasModel::Bls12Pairing => {
+                let params: Bls12PairingParams = parse_model(model, json)?;
+                annotate(model, params.validate(BLS12_MAX_MODULUS_POWER))?;
+                self.bls12 = params;
+            },
+            GasModel::BnPairing => {
+                let params: BnPairingParams = parse_model(model, json)?;
+                annotate(model, params.validate(BN_MAX_MODULUS_POWER))?;
+                self.bn = params;
+            },
+            GasModel::Mnt4Pairing => {
+                let params: MntPairingParams = parse_model(model, json)?;
+                annotate(model, params.validate(MNT4_MAX_MODULUS_POWER))?;
+                self.mnt4 = params;
+            },
+            GasModel::Mnt6Pairing => {
+                let params: MntPairingParams = parse_model(model, json)?;
+                annotate(model, params.validate(MNT6_MAX_MODULUS_POWER))?;
+                self.mnt6 = params;
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Builder-style version of `set_model_from_json`
+    pub fn with_model_from_json(mut self, model: GasModel, json: &str) -> Result<Self, ApiError> {
+        self.set_model_from_json(model, json)?;
+
+        Ok(self)
+    }
+
+    /// Loads every model that is present in the directory under its canonical
+    /// file name (see `GasModel::file_name`), missing ones are taken from the built-in schedule
+    pub fn from_directory<P: AsRef<std::path::Path>>(path: P) -> Result<Self, ApiError> {
+        let mut schedule = Self::default();
+        for model in GasModel::all().iter() {
+            let file_path = path.as_ref().join(model.file_name());
+            if !file_path.exists() {
+                continue;
+            }
+            let json = std::fs::read_to_string(&file_path).map_err(|e| {
+                ApiError::InputError(format!("Failed to read gas model {}: {}", file_path.display(), e))
+            })?;
+            schedule.set_model_from_json(*model, &json)?;
+        }
+
+        Ok(schedule)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_builtin_schedule_is_valid() {
+        let schedule = GasSchedule::default();
+
+        schedule.g1_addition.validate().unwrap();
+        schedule.g2_ext_2_addition.validate().unwrap();
+        schedule.g2_ext_3_addition.validate().unwrap();
+        schedule.g1_multiplication.validate().unwrap();
+        schedule.g2_ext_2_multiplication.validate().unwrap();
+        schedule.g2_ext_3_multiplication.validate().unwrap();
+        schedule.multiexp_discounts.validate().unwrap();
+        schedule.bls12.validate(BLS12_MAX_MODULUS_POWER).unwrap();
+        schedule.bn.validate(BN_MAX_MODULUS_POWER).unwrap();
+        schedule.mnt4.validate(MNT4_MAX_MODULUS_POWER).unwrap();
+        schedule.mnt6.validate(MNT6_MAX_MODULUS_POWER).unwrap();
+    }
+
+    #[test]
+    fn test_load_all_models() {
+        let models: Vec<(GasModel, &str)> = vec![
+            (GasModel::G1Addition, include_str!("g1_addition.json")),
+            (GasModel::G2AdditionExt2, include_str!("g2_addition_ext2.json")),
+            (GasModel::G2AdditionExt3, include_str!("g2_addition_ext3.json")),
+            (GasModel::G1Multiplication, include_str!("g1_multiplication.json")),
+            (GasModel::G2MultiplicationExt2, include_str!("g2_multiplication_ext2.json")),
+            (GasModel::G2MultiplicationExt3, include_str!("g2_multiplication_ext3.json")),
+            (GasModel::MultiexpDiscounts, include_str!("multiexp_discounts.json")),
+            (GasModel::Bls12Pairing, include_str!("bls12_model.json")),
+            (GasModel::BnPairing, include_str!("bn_model.json")),
+            (GasModel::Mnt4Pairing, include_str!("mnt4_model.json")),
+            (GasModel::Mnt6Pairing, include_str!("mnt6_model.json")),
+        ];
+
+        let _ = GasSchedule::from_json_models(&models).unwrap();
+        assert!(GasSchedule::from_json_models(&models[1..]).is_err());
+    }
+
+    #[test]
+    fn test_reject_invalid_models() {
+        let mut schedule = GasSchedule::default();
+
+        // missing limbs
+        let missing_limbs = r#"{"price": [[4, 390], [5, 480]]}"#;
+        assert!(schedule.set_model_from_json(GasModel::G1Addition, missing_limbs).is_err());
+
+        // not a JSON at all
+        assert!(schedule.set_model_from_json(GasModel::G1Multiplication, "garbage").is_err());
+
+        // zero multiplier
+        let zero_multiplier = r#"{"discounts": [[1, 1200]], "max_pairs": 1, "max_discount": 174, "discount_multiplier": 0}"#;
+        assert!(schedule.set_model_from_json(GasModel::MultiexpDiscounts, zero_multiplier).is_err());
+
+        // power of the modulus limbs is too large for BLS12 model
+        let bls12 = include_str!("bls12_model.json").replace("[[0, 1], [2, 1]]", "[[0, 1], [2, 7]]");
+        assert!(schedule.set_model_from_json(GasModel::Bls12Pairing, &bls12).is_err());
+
+        // valid model with modified values
+        let cheap_addition = r#"{"price": [[4, 1], [5, 1], [6, 1], [7, 1], [8, 1], [9, 1], [10, 1], [11, 1], [12, 1], [13, 1], [14, 1], [15, 1], [16, 1]]}"#;
+        schedule.set_model_from_json(GasModel::G1Addition, cheap_addition).unwrap();
+        assert_eq!(meter_addition(6, &schedule.g1_addition).unwrap(), 1);
+        assert_eq!(meter_addition(6, &GasSchedule::default().g1_addition).unwrap(), 600);
+    }
+}
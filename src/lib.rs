@@ -13,23 +13,30 @@ mod representation;
 mod field;
 mod fp;
 mod weierstrass;
+#[cfg(feature = "edwards")]
+mod edwards;
 mod mont_inverse;
 mod multiexp;
 mod extension_towers;
-mod pairings;
+pub mod pairings;
 mod sliding_window_exp;
 mod errors;
 mod integers;
 mod features;
 mod wnaf;
 mod square_root;
-mod engines;
+pub mod engines;
 
 #[cfg(feature = "mappings")]
 mod mapping;
 
 pub mod public_interface;
 
+pub mod typed_api;
+
+#[cfg(feature = "bls_signatures")]
+pub mod bls_signatures;
+
 #[cfg(feature = "gas_metering")]
 pub mod gas_meter;
 
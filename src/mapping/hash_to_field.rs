@@ -0,0 +1,152 @@
+use crate::fp::*;
+use crate::representation::*;
+use crate::field::*;
+use crate::traits::*;
+use crate::errors::ApiError;
+
+use sha2::{Sha256, Digest};
+
+const SHA256_OUTPUT_BYTES: usize = 32;
+const SHA256_BLOCK_BYTES: usize = 64;
+const MAX_DST_BYTES: usize = 255;
+
+/// expand_message_xmd from RFC 9380 (section 5.3.1) instantiated with SHA-256
+pub(crate) fn expand_message_xmd_sha256(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize
+) -> Result<Vec<u8>, ApiError> {
+    let ell = (len_in_bytes + SHA256_OUTPUT_BYTES - 1) / SHA256_OUTPUT_BYTES;
+    if ell > 255 || len_in_bytes > 65535 {
+        return Err(ApiError::InputError("requested too many bytes from expand_message_xmd".to_owned()));
+    }
+
+    // long tags are hashed down as described in section 5.3.3
+    let oversized_dst;
+    let dst = if dst.len() > MAX_DST_BYTES {
+        oversized_dst = Sha256::new().chain(b"H2C-OVERSIZE-DST-").chain(dst).finalize();
+
+        &oversized_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = Sha256::new()
+        .chain(&[0u8; SHA256_BLOCK_BYTES][..])
+        .chain(msg)
+        .chain(&[(len_in_bytes >> 8) as u8, len_in_bytes as u8, 0u8])
+        .chain(dst)
+        .chain(&dst_len)
+        .finalize();
+
+    let mut b_i = Sha256::new()
+        .chain(&b_0)
+        .chain(&[1u8])
+        .chain(dst)
+        .chain(&dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_BYTES);
+    uniform_bytes.extend_from_slice(&b_i);
+
+    for i in 2..=ell {
+        let mut xored = [0u8; SHA256_OUTPUT_BYTES];
+        for ((x, a), b) in xored.iter_mut().zip(b_0.iter()).zip(b_i.iter()) {
+            *x = a ^ b;
+        }
+
+        b_i = Sha256::new()
+            .chain(&xored)
+            .chain(&[i as u8])
+            .chain(dst)
+            .chain(&dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+
+    Ok(uniform_bytes)
+}
+
+// interprets bytes as a big endian integer of any length and reduces it modulo the field characteristic
+fn fp_from_be_bytes_reduced<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(
+    field: &'a F,
+    bytes: &[u8]
+) -> Result<Fp<'a, E, F>, ApiError> {
+    let mut shift_repr = E::default();
+    shift_repr.as_mut()[1] = 1;
+    let shift = Fp::from_repr(field, shift_repr).map_err(|_| {
+        ApiError::UnknownParameter("field is too small for hashing".to_owned())
+    })?;
+
+    let mut result = Fp::zero(field);
+    let (top, rest) = bytes.split_at(bytes.len() % 8);
+    for chunk in std::iter::once(top).chain(rest.chunks(8)) {
+        let mut limb = 0u64;
+        for &b in chunk.iter() {
+            limb = (limb << 8) | (b as u64);
+        }
+        let limb = Fp::from_repr(field, E::from(limb)).map_err(|_| {
+            ApiError::UnknownParameter("field is too small for hashing".to_owned())
+        })?;
+
+        result.mul_assign(&shift);
+        result.add_assign(&limb);
+    }
+
+    Ok(result)
+}
+
+/// hash_to_field from RFC 9380 (section 5.2) with expand_message_xmd and SHA-256. Returns `num_elements`
+/// elements of the prime field, so for an extension of degree m every m consecutive elements
+/// are coefficients of one element. `security_bytes` is the parameter L of the suite
+pub(crate) fn hash_to_fp_elements<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(
+    field: &'a F,
+    msg: &[u8],
+    dst: &[u8],
+    num_elements: usize,
+    security_bytes: usize
+) -> Result<Vec<Fp<'a, E, F>>, ApiError> {
+    let uniform_bytes = expand_message_xmd_sha256(msg, dst, num_elements * security_bytes)?;
+
+    let mut elements = Vec::with_capacity(num_elements);
+    for chunk in uniform_bytes.chunks(security_bytes) {
+        elements.push(fp_from_be_bytes_reduced(field, chunk)?);
+    }
+
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXPANDER_DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    #[test]
+    fn test_expand_message_xmd_sha256_vectors() {
+        let vectors = [
+            ("", 0x20, "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+            ("abc", 0x20, "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+            ("abc", 0x80, "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40"),
+        ];
+
+        for (msg, len, expected) in vectors.iter() {
+            let uniform_bytes = expand_message_xmd_sha256(msg.as_bytes(), EXPANDER_DST, *len).unwrap();
+            assert_eq!(hex::encode(&uniform_bytes), *expected);
+        }
+    }
+
+    #[test]
+    fn test_expand_message_xmd_sha256_long_dst() {
+        let mut dst = b"QUUX-V01-CS02-with-expander-SHA256-128-long-DST-".to_vec();
+        dst.resize(256, b'1');
+
+        let uniform_bytes = expand_message_xmd_sha256(b"abc", &dst, 0x20).unwrap();
+        assert_eq!(hex::encode(&uniform_bytes), "52dbf4f36cf560fca57dedec2ad924ee9c266341d8f3d6afe5171733b16bbb12");
+
+        assert!(expand_message_xmd_sha256(b"abc", EXPANDER_DST, 256 * 32).is_err());
+    }
+}
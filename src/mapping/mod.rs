@@ -5,9 +5,13 @@ use crate::traits::*;
 use crate::extension_towers::fp2::Fp2;
 
 pub mod simple_swu;
+pub mod svdw;
 pub mod isogeny;
 pub mod parameters;
 
+#[cfg(feature = "hash_to_curve")]
+pub mod hash_to_field;
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum Sign {
     Zero,
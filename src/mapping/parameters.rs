@@ -6,6 +6,7 @@ use self::once_cell::sync::Lazy;
 
 use super::isogeny::*;
 use super::simple_swu::*;
+use super::svdw::*;
 use crate::weierstrass::*;
 
 pub static BLS12_G1_MAPPING_PARAMS: Lazy<
@@ -24,4 +25,12 @@ pub static BLS12_G2_MAPPING_PARAMS: Lazy<
         )
         > = Lazy::new(|| {
             super::constants::calculate_bls12_381_g2_mapping_params(&crate::engines::bls12_381::BLS12_381_EXTENSION_2_FIELD)
-});
\ No newline at end of file
+});
+
+pub static BLS12_377_G1_SVDW_PARAMS: Lazy<SvdwParameters<CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>> = Lazy::new(|| {
+    calculate_svdw_params(&crate::engines::bls12_377::BLS12_377_G1_CURVE).expect("SvdW constants must exist for BLS12-377 G1")
+});
+
+pub static BLS12_377_G2_SVDW_PARAMS: Lazy<SvdwParameters<CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>> = Lazy::new(|| {
+    calculate_svdw_params(&crate::engines::bls12_377::BLS12_377_G2_CURVE).expect("SvdW constants must exist for BLS12-377 G2")
+});
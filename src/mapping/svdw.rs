@@ -0,0 +1,351 @@
+use crate::fp::*;
+use crate::representation::*;
+use crate::field::*;
+use crate::traits::*;
+use crate::extension_towers::fp2::Fp2;
+use super::*;
+
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::square_root::*;
+
+/// Constants of the Shallue-van de Woestijne mapping (section 6.6.1 of the hash-to-curve draft).
+/// Unlike the simplified SWU it works for any curve, so it doesn't need an isogeny when A * B == 0
+#[derive(Clone)]
+pub struct SvdwParameters<C: CurveParameters> {
+    pub z: C::BaseFieldElement,
+    // g(Z)
+    pub c1: C::BaseFieldElement,
+    // -Z / 2
+    pub c2: C::BaseFieldElement,
+    // sqrt(-g(Z) * (3 * Z^2 + 4 * A)), sgn0(c3) == 0
+    pub c3: C::BaseFieldElement,
+    // -4 * g(Z) / (3 * Z^2 + 4 * A)
+    pub c4: C::BaseFieldElement,
+}
+
+/// Square roots and signs in the fields the mapping is used over
+pub(crate) trait MappingField: Sized {
+    fn is_square(&self) -> bool;
+    fn square_root(&self) -> Option<Self>;
+    fn sign(&self) -> Sign;
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>> MappingField for Fp<'a, E, F> {
+    fn is_square(&self) -> bool {
+        legendre_symbol_fp(self) != LegendreSymbol::QuadraticNonResidue
+    }
+
+    fn square_root(&self) -> Option<Self> {
+        sqrt(self)
+    }
+
+    fn sign(&self) -> Sign {
+        sign_of_fp(self)
+    }
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>> MappingField for Fp2<'a, E, F> {
+    fn is_square(&self) -> bool {
+        legendre_symbol_fp2(self) != LegendreSymbol::QuadraticNonResidue
+    }
+
+    fn square_root(&self) -> Option<Self> {
+        sqrt_ext2(self)
+    }
+
+    fn sign(&self) -> Sign {
+        sign_of_fp2(self)
+    }
+}
+
+// our SignZero is equal to SignPlus
+fn sgn0<T: MappingField>(el: &T) -> bool {
+    el.sign() == Sign::SignMinus
+}
+
+// g(x) = x^3 + A * x + B
+fn curve_rhs<C: CurveParameters>(x: &C::BaseFieldElement, curve: &WeierstrassCurve<C>) -> C::BaseFieldElement {
+    let mut gx = x.clone();
+    gx.square();
+    gx.add_assign(&curve.a);
+    gx.mul_assign(&x);
+    gx.add_assign(&curve.b);
+
+    gx
+}
+
+const MAX_Z_SEARCH: u64 = 1000;
+
+/// Finds Z as in `find_z_svdw` of the draft (Z = 1, -1, 2, -2, ...) and calculates the constants
+pub(crate) fn calculate_svdw_params<C: CurveParameters>(curve: &WeierstrassCurve<C>) -> Option<SvdwParameters<C>>
+    where C::BaseFieldElement: MappingField
+{
+    let one = C::BaseFieldElement::one(curve.params.params());
+    let mut two = one.clone();
+    two.double();
+    let two_inv = two.inverse()?;
+
+    let mut candidate = C::BaseFieldElement::zero(curve.params.params());
+    for _ in 0..MAX_Z_SEARCH {
+        candidate.add_assign(&one);
+        let mut minus_candidate = candidate.clone();
+        minus_candidate.negate();
+
+        for z in [candidate.clone(), minus_candidate].iter() {
+            let gz = curve_rhs(z, curve);
+            if gz.is_zero() {
+                continue;
+            }
+
+            // 3 * Z^2 + 4 * A
+            let mut t = z.clone();
+            t.square();
+            let mut t3 = t.clone();
+            t3.double();
+            t3.add_assign(&t);
+            let mut four_a = curve.a.clone();
+            four_a.double();
+            four_a.double();
+            t3.add_assign(&four_a);
+            if t3.is_zero() {
+                continue;
+            }
+
+            // -(3 * Z^2 + 4 * A) / (4 * g(Z)) must be a non-zero square
+            let mut four_gz = gz.clone();
+            four_gz.double();
+            four_gz.double();
+            let mut h = t3.clone();
+            h.negate();
+            h.mul_assign(&four_gz.inverse()?);
+            if !h.is_square() {
+                continue;
+            }
+
+            let mut c2 = z.clone();
+            c2.negate();
+            c2.mul_assign(&two_inv);
+
+            if !gz.is_square() && !curve_rhs(&c2, curve).is_square() {
+                continue;
+            }
+
+            let mut c3_squared = gz.clone();
+            c3_squared.negate();
+            c3_squared.mul_assign(&t3);
+            let mut c3 = c3_squared.square_root()?;
+            if sgn0(&c3) {
+                c3.negate();
+            }
+
+            let mut c4 = four_gz;
+            c4.negate();
+            c4.mul_assign(&t3.inverse()?);
+
+            return Some(SvdwParameters {
+                z: z.clone(),
+                c1: gz,
+                c2,
+                c3,
+                c4,
+            });
+        }
+    }
+
+    None
+}
+
+pub(crate) fn svdw<C: CurveParameters>(
+    u: &C::BaseFieldElement,
+    params: &SvdwParameters<C>,
+    curve: &WeierstrassCurve<C>
+) -> (C::BaseFieldElement, C::BaseFieldElement)
+    where C::BaseFieldElement: MappingField
+{
+    let one = C::BaseFieldElement::one(curve.params.params());
+
+    // we do NOT use constant time operations here
+
+    // 1.  tv1 = u^2 * c1
+    let mut tv1 = u.clone();
+    tv1.square();
+    tv1.mul_assign(&params.c1);
+
+    // 2.  tv2 = 1 + tv1
+    let mut tv2 = one.clone();
+    tv2.add_assign(&tv1);
+
+    // 3.  tv1 = 1 - tv1
+    let mut t = one.clone();
+    t.sub_assign(&tv1);
+    let tv1 = t;
+
+    // 4.  tv3 = tv1 * tv2
+    let mut tv3 = tv1.clone();
+    tv3.mul_assign(&tv2);
+
+    // 5.  tv3 = inv0(tv3)
+    let tv3 = tv3.inverse().unwrap_or(C::BaseFieldElement::zero(curve.params.params()));
+
+    // 6.  tv4 = u * tv1
+    // 7.  tv4 = tv4 * tv3
+    // 8.  tv4 = tv4 * c3
+    let mut tv4 = u.clone();
+    tv4.mul_assign(&tv1);
+    tv4.mul_assign(&tv3);
+    tv4.mul_assign(&params.c3);
+
+    // 9.   x1 = c2 - tv4
+    let mut x1 = params.c2.clone();
+    x1.sub_assign(&tv4);
+
+    // 10-13. gx1 = x1^3 + A * x1 + B
+    // 14.  e1 = is_square(gx1)
+    let e1 = curve_rhs(&x1, curve).is_square();
+
+    // 15.  x2 = c2 + tv4
+    let mut x2 = params.c2.clone();
+    x2.add_assign(&tv4);
+
+    // 16-19. gx2 = x2^3 + A * x2 + B
+    // 20.  e2 = is_square(gx2) AND NOT e1
+    let e2 = !e1 && curve_rhs(&x2, curve).is_square();
+
+    // 21.  x3 = tv2^2
+    // 22.  x3 = x3 * tv3
+    // 23.  x3 = x3^2
+    // 24.  x3 = x3 * c4
+    // 25.  x3 = x3 + Z
+    let mut x3 = tv2;
+    x3.square();
+    x3.mul_assign(&tv3);
+    x3.square();
+    x3.mul_assign(&params.c4);
+    x3.add_assign(&params.z);
+
+    // 26.   x = CMOV(x3, x1, e1)
+    // 27.   x = CMOV(x, x2, e2)
+    let x = if e1 {
+        x1
+    } else if e2 {
+        x2
+    } else {
+        x3
+    };
+
+    // 28-30. gx = x^3 + A * x + B
+    let gx = curve_rhs(&x, curve);
+
+    // 31.   y = sqrt(gx)
+    let mut y = gx.square_root().expect("gx is a square");
+
+    // 32.  e3 = sgn0(u) == sgn0(y)
+    // 33.   y = CMOV(-y, y, e3)
+    if sgn0(u) != sgn0(&y) {
+        y.negate();
+    }
+
+    // 34. return (x, y)
+    (x, y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::constants::*;
+    use crate::engines::bls12_377::*;
+
+    // reference values are calculated with an independent implementation of section 6.6.1 of RFC 9380
+
+    #[test]
+    fn test_bls12_377_g1_constants() {
+        let params = calculate_svdw_params(&BLS12_377_G1_CURVE).unwrap();
+
+        assert_eq!(format!("{}", params.z), "0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001");
+        assert_eq!(format!("{}", params.c1), "0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002");
+        assert_eq!(format!("{}", params.c2), "0x00d71d230be28875631d82e03650a49d8d116cf9807a89c78f79b117dd04a4000b85aea2180000004284600000000000");
+        assert_eq!(format!("{}", params.c3), "0x010bcfca51912bfd7769051f3d2834f53a453922e3c750460d1bc80ceb2b55712857a312e3391c495d93a4b77468919a");
+        assert_eq!(format!("{}", params.c4), "0x011ed1840fd8b5f1d97cae80486b862766c1e6a200a3625f69f796ca7c0630000f5ce8d82000000058b07ffffffffffe");
+    }
+
+    #[test]
+    fn test_bls12_377_g2_constants() {
+        let params = calculate_svdw_params(&BLS12_377_G2_CURVE).unwrap();
+
+        let expected = [
+            (&params.z, "0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002", "0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+            (&params.c1, "0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008", "0x010222f6db0fd6f343bd03737460c589dc7b4f91cd5fd889129207b63c6bf8000dd39e5c1ccccccd1c9ed9999999999a"),
+            (&params.c2, "0x01ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508c00000000000", "0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+            (&params.c3, "0x012503a00dd389a25f71f2b866fb20de53cd99e5fdfe632d34dfccd6be0d24f6e881371aea5cacb7855a66489e0def98", "0x015c7eccd36cc4d5829323ff23b71e5fab3460209ed0fb84da6267e8c03460eb52915e35b969aa60d835ae66d30c38ca"),
+            (&params.c4, "0x011ed1840fd8b5f1d97cae80486b862766c1e6a200a3625f69f796ca7c0630000f5ce8d82000000058b07ffffffffffe", "0x00395d1a6991bdfd2b7f5619a815813b148d2e20668713acaecb1e287f347000031294f80666666678234ccccccccccd"),
+        ];
+
+        for (el, c0, c1) in expected.iter() {
+            assert_eq!(format!("{}", el.c0), *c0);
+            assert_eq!(format!("{}", el.c1), *c1);
+        }
+    }
+
+    #[test]
+    fn test_bls12_377_g1_map_outputs() {
+        let params = calculate_svdw_params(&BLS12_377_G1_CURVE).unwrap();
+
+        // u = 0 is the exceptional case, u = 1 and u = -1 only differ in the sign of y
+        let vectors = [
+            ("0", "0x00d71d230be28875631d82e03650a49d8d116cf9807a89c78f79b117dd04a4000b85aea2180000004284600000000000", "0x019b9fd1f1e2b7bcbf3035269db46a2c50b2dba37442aa9dcf7d57f36aa82a33dca8ee157a6362a2081a6b7f053f2b92"),
+            ("1", "0x01ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508bfffffffffea", "0x0156e7a46294f6d1814ad1169d66fa2c36eb933577908fb13ec375aa1a720ba584f128f68da0e18fed3f91e8215f016b"),
+            ("2a", "0x008db4844272390142a516f31136a3343477cc75016b83620981838e4999eea4f30aa7b2ab05a1a65d99337525dc149c", "0x00ba7a323ed759a5960bb8174261914e79f55838b71b92b5ca9f871f357a7770875338e967e03910e20816f4feac9046"),
+            ("1ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508c00000000000", "0x01ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508bfffffffffea", "0x005752a1b5301a1944f034a9cf3a4f0ee33746bd896483dde02fec859f973c5a921a344da25f1e7097c92e17dea0fe96"),
+        ];
+
+        for (u, x, y) in vectors.iter() {
+            let u = str_radix_into_field(u, 16, &BLS12_377_FIELD);
+            let (mapped_x, mapped_y) = svdw(&u, &params, &BLS12_377_G1_CURVE);
+            assert_eq!(format!("{}", mapped_x), *x);
+            assert_eq!(format!("{}", mapped_y), *y);
+        }
+    }
+
+    #[test]
+    fn test_bls12_377_g2_map_outputs() {
+        let params = calculate_svdw_params(&BLS12_377_G2_CURVE).unwrap();
+
+        let vectors = [
+            (("0", "0"), [
+                "0x011ed1840fd8b5f1d97cae80486b862766c1e6a200a3625f69f796ca7c0630000f5ce8d82000000058b0800000000000",
+                "0x00395d1a6991bdfd2b7f5619a815813b148d2e20668713acaecb1e287f347000031294f80666666678234ccccccccccd",
+                "0x0151b42ac821c955e33df8fd26161c5fd212981597e6c257bdb654fb745ebb54c27b839a146446fb96bda78fe67fe7a0",
+                "0x009f08fc86ab860e599adb9933455b71e14dc998473f70a652d64662b3896567b0120ac704847a4e2224af7c533ae646",
+            ]),
+            (("1", "0"), [
+                "0x0128133a6b432661e5574369a9e506869415c77798f7ff47b4f1d052811550ff8f5086b4daabfeb8471fea83efd1d661",
+                "0x0035e73cd3339357fc19d6d39860a5042e62588aa7fd2c3747478f90ce73fa0daef99966b5975ed19dc39b13f39186fd",
+                "0x0125d392e9550292fb766c309d4c66b2ed0ddd197266cfa842b51b5736eca84cb189d210a12db6f9e9cc8b7e077fa129",
+                "0x0142403942ba47f4b5d896be8c36636ef4a5ac561cd8137adffd2c6b1d3ade476cfb135a6c67c5c37c1fe70586ab8b66",
+            ]),
+            (("2a", "2a"), [
+                "0x00576117f0fa94a0c8b90bee27aee57500fdea12442a32fce1b4867dd0fb52c54219a7d097bc46753ece398f70e10065",
+                "0x0173d89b1bdd4be3e05532bd87cdf7f074f6b9fc82dd769b310175edf090bb74b2a1a758c155e3db9b0023a5f2df8bba",
+                "0x01984c81e7fbacd992eea57f3672b7d066bedc0cdaa0d5db177bac3c9003db09b1ccc69a0544685ae689bedd162a9250",
+                "0x00dafe17162cec5812fb0ef3386a129cbabf83e687ea57a184d0a59a8fb01f9e69a0cb39ed6eed7947ce6dae8c458a97",
+            ]),
+            // sign of u is taken from c1 when c0 is zero
+            (("0", "1ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508c00000000000"), [
+                "0x008b745e86cf5989b26f61bb93fcf6eb929bea1181c3c0042362b31317ec24655a5b92fbaf81f694a17c45249d623586",
+                "0x00c69fc29e9527749881f78b5d2bd85373c320bf0a41db7aba6bcb9e14f02e64ddcf89bbd73a38b368ef0fe1faf5bf50",
+                "0x007e675a7d0804314102eb1c5a04ae9a19d2f1b22df459cb14033296699e7e8c3b91fc25793985fe1cce6748a8ac6170",
+                "0x0134f506560ac05a2ced5882cab161782a452dfda2071bbd756618d90f432b9e61c4a8069c78ed28e62749ba80bbdb33",
+            ]),
+        ];
+
+        for ((u_c0, u_c1), expected) in vectors.iter() {
+            let u = str_radix_into_ext2(u_c0, u_c1, 16, &BLS12_377_EXTENSION_2_FIELD);
+            let (mapped_x, mapped_y) = svdw(&u, &params, &BLS12_377_G2_CURVE);
+            assert_eq!(format!("{}", mapped_x.c0), expected[0]);
+            assert_eq!(format!("{}", mapped_x.c1), expected[1]);
+            assert_eq!(format!("{}", mapped_y.c0), expected[2]);
+            assert_eq!(format!("{}", mapped_y.c1), expected[3]);
+        }
+    }
+}
@@ -1,12 +1,38 @@
 use crate::weierstrass::Group;
 use crate::weierstrass::curve::CurvePoint;
 use crate::weierstrass::CurveParameters;
+#[cfg(feature = "edwards")]
+use crate::edwards::curve::EdwardsPoint;
+#[cfg(feature = "edwards")]
+use crate::field::SizedPrimeField;
+#[cfg(feature = "edwards")]
+use crate::representation::ElementRepr;
+use crate::representation::num_bits;
 use crate::integers::MaxGroupSizeUint;
 
 pub(crate) fn peppinger<'a, C: CurveParameters>
-    (bases: &[CurvePoint<'a, C>], mut scalars: Vec<MaxGroupSizeUint>) -> CurvePoint<'a, C>
+    (bases: &[CurvePoint<'a, C>], scalars: Vec<MaxGroupSizeUint>) -> CurvePoint<'a, C>
+{
+    let order_bits = num_bits(&bases[0].curve.subgroup_order_repr);
+    let zero_point = CurvePoint::zero(bases[0].curve);
+
+    peppinger_impl(bases, scalars, zero_point, order_bits)
+}
+
+/// Bases are expected to be normalized
+#[cfg(feature = "edwards")]
+pub(crate) fn edwards_peppinger<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>>
+    (bases: &[EdwardsPoint<'a, FE, F>], scalars: Vec<MaxGroupSizeUint>) -> EdwardsPoint<'a, FE, F>
+{
+    let order_bits = num_bits(&bases[0].curve.subgroup_order_repr);
+    let zero_point = EdwardsPoint::zero(bases[0].curve);
+
+    peppinger_impl(bases, scalars, zero_point, order_bits)
+}
+
+fn peppinger_impl<G: Group>
+    (bases: &[G], mut scalars: Vec<MaxGroupSizeUint>, zero_point: G, order_bits: u32) -> G
 {
-    use crate::representation::*;
     debug_assert!(bases.len() == scalars.len());
 
     let c = if bases.len() < 32 {
@@ -17,8 +43,10 @@ pub(crate) fn peppinger<'a, C: CurveParameters>
 
     let mask = (1u64 << c) - 1u64;
     let mut cur = 0;
-    let num_bits = num_bits(&bases[0].curve.subgroup_order_repr);
-    let zero_point = CurvePoint::zero(bases[0].curve);
+    // scalars are not reduced, so they may be longer than the group order
+    let num_bits = scalars.iter()
+        .map(|s| num_bits(s.as_ref()))
+        .fold(order_bits, std::cmp::max);
 
     let mut windows = Vec::with_capacity((num_bits / c + 1) as usize);
     let mut buckets = Vec::with_capacity((1 << c) - 1);
@@ -63,4 +91,28 @@ pub(crate) fn peppinger<'a, C: CurveParameters>
     }
 
     acc
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::engines::bls12_377::BLS12_377_G1_GENERATOR;
+
+    #[test]
+    fn test_scalars_longer_than_group_order() {
+        // BLS12-377 group order has 253 bits, encoded scalars have 256
+        let g = BLS12_377_G1_GENERATOR.clone();
+        let mut h = g.mul(&[7u64]);
+        h.normalize();
+        let bases = vec![g, h];
+        let scalars = vec![
+            MaxGroupSizeUint::from_big_endian(&[0xff; 32]),
+            MaxGroupSizeUint::from_big_endian(&[0x80; 32]),
+        ];
+
+        let mut expected = bases[0].mul(&scalars[0]);
+        expected.add_assign(&bases[1].mul(&scalars[1]));
+
+        assert!(peppinger(&bases, scalars).into_xy() == expected.into_xy());
+    }
+}
@@ -0,0 +1,231 @@
+use crate::weierstrass::{Group, CurveParameters};
+use crate::weierstrass::curve::CurvePoint;
+use crate::errors::{ApiError, ErrorCode};
+use byteorder::{ByteOrder, LittleEndian};
+use super::PairingEngine;
+
+/// Pairing equation e(P_0, Q_0) * ... * e(P_n, Q_n) == 1
+pub struct PairingEquation<'a, CB: CurveParameters, CTW: CurveParameters> {
+    pub g1_points: Vec<CurvePoint<'a, CB>>,
+    pub g2_points: Vec<CurvePoint<'a, CTW>>,
+}
+
+impl<'a, CB: CurveParameters, CTW: CurveParameters> Clone for PairingEquation<'a, CB, CTW> {
+    fn clone(&self) -> Self {
+        Self {
+            g1_points: self.g1_points.clone(),
+            g2_points: self.g2_points.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BatchVerificationResult {
+    Valid,
+    // indexes of equations that do not hold
+    Invalid(Vec<usize>)
+}
+
+/// Checks many pairing equations at once. Every equation is raised to an independent random non-zero
+/// 128-bit power taken from the OS random number generator, and the product of all of them is compared
+/// with one, so only one multi-Miller loop and one final exponentiation are needed if all equations hold.
+/// Otherwise the set of equations is bisected to find ones that do not hold. Any check over a set with
+/// a failing equation passes by chance with probability of about 2^-128, valid equations are never
+/// reported. G2 points are expected to be in the correct subgroup
+pub(crate) fn batch_verify<'a, CB, CTW, E>(
+    engine: &E,
+    equations: &[PairingEquation<'a, CB, CTW>],
+    one: &E::PairingResult
+) -> Result<BatchVerificationResult, ApiError>
+    where CB: CurveParameters,
+        CTW: CurveParameters,
+        E: PairingEngine<G1 = CurvePoint<'a, CB>, G2 = CurvePoint<'a, CTW>>
+{
+    if equations.len() == 0 {
+        return Err(ApiError::InputError("No pairing equations to verify".to_owned()));
+    }
+
+    let mut scaled_equations = Vec::with_capacity(equations.len());
+
+    for eq in equations.iter() {
+        if eq.g1_points.len() != eq.g2_points.len() {
+            return Err(ApiError::InputError("Pairing equation has different number of G1 and G2 points".to_owned()));
+        }
+
+        let c = random_coefficient()?;
+
+        let mut g1_points = Vec::with_capacity(eq.g1_points.len());
+        for p in eq.g1_points.iter() {
+            let mut p = p.mul(&c);
+            p.normalize();
+            g1_points.push(p);
+        }
+
+        scaled_equations.push(PairingEquation {
+            g1_points,
+            g2_points: eq.g2_points.clone(),
+        });
+    }
+
+    let all_indexes: Vec<usize> = (0..equations.len()).collect();
+
+    if check_combined(engine, &scaled_equations, &all_indexes, one)? {
+        return Ok(BatchVerificationResult::Valid);
+    }
+
+    let mut invalid = vec![];
+    bisect(engine, &scaled_equations, &all_indexes, one, &mut invalid)?;
+
+    Ok(BatchVerificationResult::Invalid(invalid))
+}
+
+// coefficients must be unpredictable for whoever has produced the equations
+fn random_coefficient() -> Result<[u64; 2], ApiError> {
+    let mut bytes = [0u8; 16];
+    loop {
+        getrandom::getrandom(&mut bytes).map_err(|e| {
+            ApiError::coded(ErrorCode::Other, format!("Failed to get random coefficient: {}", e))
+        })?;
+
+        let c = [LittleEndian::read_u64(&bytes[0..8]), LittleEndian::read_u64(&bytes[8..16])];
+        if c[0] != 0 || c[1] != 0 {
+            return Ok(c);
+        }
+    }
+}
+
+fn check_combined<'a, CB, CTW, E>(
+    engine: &E,
+    equations: &[PairingEquation<'a, CB, CTW>],
+    indexes: &[usize],
+    one: &E::PairingResult
+) -> Result<bool, ApiError>
+    where CB: CurveParameters,
+        CTW: CurveParameters,
+        E: PairingEngine<G1 = CurvePoint<'a, CB>, G2 = CurvePoint<'a, CTW>>
+{
+    let mut g1_points = vec![];
+    let mut g2_points = vec![];
+
+    for &i in indexes.iter() {
+        for (p, q) in equations[i].g1_points.iter().zip(equations[i].g2_points.iter()) {
+            if !p.is_zero() && !q.is_zero() {
+                g1_points.push(p.clone());
+                g2_points.push(q.clone());
+            }
+        }
+    }
+
+    if g1_points.len() == 0 {
+        return Ok(true);
+    }
+
+    let result = engine.pair(&g1_points, &g2_points).ok_or_else(|| {
+        ApiError::UnknownParameter("Pairing engine returned no value".to_owned())
+    })?;
+
+    Ok(&result == one)
+}
+
+// combined check for a given set of indexes is known to fail
+fn bisect<'a, CB, CTW, E>(
+    engine: &E,
+    scaled_equations: &[PairingEquation<'a, CB, CTW>],
+    indexes: &[usize],
+    one: &E::PairingResult,
+    invalid: &mut Vec<usize>
+) -> Result<(), ApiError>
+    where CB: CurveParameters,
+        CTW: CurveParameters,
+        E: PairingEngine<G1 = CurvePoint<'a, CB>, G2 = CurvePoint<'a, CTW>>
+{
+    if indexes.len() == 1 {
+        invalid.push(indexes[0]);
+
+        return Ok(());
+    }
+
+    let (left, right) = indexes.split_at(indexes.len() / 2);
+
+    let left_is_valid = check_combined(engine, scaled_equations, left, one)?;
+    if !left_is_valid {
+        bisect(engine, scaled_equations, left, one, invalid)?;
+    }
+
+    // if the left half holds then the right one must fail, so don't check it again
+    if left_is_valid || !check_combined(engine, scaled_equations, right, one)? {
+        bisect(engine, scaled_equations, right, one, invalid)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::engines::bls12_381::*;
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    type CB = crate::weierstrass::CurveOverFpParameters<'static, crate::field::U384Repr, crate::field::PrimeField<crate::field::U384Repr>>;
+    type CTW = crate::weierstrass::CurveOverFp2Parameters<'static, crate::field::U384Repr, crate::field::PrimeField<crate::field::U384Repr>>;
+
+    // e([a]P, [b]Q) * e(-[a * b]P, Q) == 1
+    fn make_equation<R: Rng>(rng: &mut R, valid: bool) -> PairingEquation<'static, CB, CTW> {
+        let a: u64 = rng.gen();
+        let b: u64 = rng.gen();
+        let ab = (a as u128) * (b as u128);
+        let mut ab = [ab as u64, (ab >> 64) as u64];
+        if !valid {
+            ab[0] = ab[0].wrapping_add(1);
+        }
+
+        let mut p0 = BLS12_381_G1_GENERATOR.mul(&[a]);
+        p0.normalize();
+        let mut q0 = BLS12_381_G2_GENERATOR.mul(&[b]);
+        q0.normalize();
+        let mut p1 = BLS12_381_G1_GENERATOR.mul(&ab);
+        p1.negate();
+        p1.normalize();
+
+        PairingEquation {
+            g1_points: vec![p0, p1],
+            g2_points: vec![q0, BLS12_381_G2_GENERATOR.clone()],
+        }
+    }
+
+    #[test]
+    fn test_bls12_381_batch_verification() {
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+        let mut equations = vec![];
+        for _ in 0..8 {
+            equations.push(make_equation(&mut rng, true));
+        }
+
+        let engine = &BLS12_381_PAIRING_ENGINE;
+
+        let result = engine.batch_verify(&equations).unwrap();
+        assert_eq!(result, BatchVerificationResult::Valid);
+
+        equations[2] = make_equation(&mut rng, false);
+        equations[7] = make_equation(&mut rng, false);
+
+        let result = engine.batch_verify(&equations).unwrap();
+        assert_eq!(result, BatchVerificationResult::Invalid(vec![2, 7]));
+
+        let result = engine.batch_verify(&equations[2..3]).unwrap();
+        assert_eq!(result, BatchVerificationResult::Invalid(vec![0]));
+
+        assert!(engine.batch_verify(&equations[0..0]).is_err());
+        equations[0].g2_points.pop();
+        assert!(engine.batch_verify(&equations).is_err());
+    }
+
+    #[test]
+    fn test_random_coefficients_are_distinct() {
+        let a = random_coefficient().unwrap();
+        let b = random_coefficient().unwrap();
+        assert!(a != b);
+    }
+}
@@ -10,12 +10,32 @@ use crate::extension_towers::fp12_as_2_over3_over_2::{Fp12, Extension2Over3Over2
 use crate::extension_towers::fp6_as_3_over_2::{Extension3Over2};
 use crate::pairings::PairingEngine;
 use crate::pairings::TwistType;
-use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf};
-
-pub(crate) struct PreparedTwistPoint<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+#[cfg(feature = "batch_verification")]
+use crate::pairings::batch::{PairingEquation, BatchVerificationResult, batch_verify};
+#[cfg(feature = "batch_verification")]
+use crate::errors::ApiError;
+use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf, normalized_points};
+
+/// Line coefficients of the Miller loop for a fixed G2 point, can be reused between pairing calls
+pub struct PreparedTwistPoint<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
     pub(crate) ell_coeffs: Vec<(Fp2<'a, FE, F>, Fp2<'a, FE, F>, Fp2<'a, FE, F>)>
 }
 
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for PreparedTwistPoint<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            ell_coeffs: self.ell_coeffs.clone(),
+        }
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> PreparedTwistPoint<'a, FE, F> {
+    // point at infinity has no coefficients
+    pub fn is_zero(&self) -> bool {
+        self.ell_coeffs.len() == 0
+    }
+}
+
 #[derive(Clone)]
 pub struct Bls12InstanceParams<
 'a, 
@@ -102,6 +122,14 @@ impl<
             x_naf: naf
         }
     }
+
+    /// See `crate::pairings::batch::batch_verify`
+    #[cfg(feature = "batch_verification")]
+    pub fn batch_verify(&self, equations: &[PairingEquation<'a, CB, CTW>]) -> Result<BatchVerificationResult, ApiError> {
+        let one = Fp12::one(self.fp12_extension);
+
+        batch_verify(self, equations, &one)
+    }
 }
 
 impl<
@@ -347,31 +375,53 @@ impl<
         }
     }
 
+    fn two_inv(&self) -> Fp<'a, FE, F> {
+        let mut two_inv = Fp::one(self.base_field);
+        two_inv.double();
+
+        two_inv.inverse().expect("inverse of 2 is guaranteed to exist")
+    }
+
+    fn prepare_twist_point(&self, twist_point: & CurvePoint<'a, CTW>, two_inv: &Fp<'a, FE, F>) -> PreparedTwistPoint<'a, FE, F> {
+        if self.prefer_naf {
+            debug_assert!(self.x_naf.len() > 0);
+            self.prepare_naf(twist_point, two_inv)
+        } else {
+            self.prepare(twist_point, two_inv)
+        }
+    }
+
+    fn miller_loop_prepared<'b, I>(&self, i: I) -> Fp12<'a, FE, F>
+    where 'a: 'b,
+        I: IntoIterator<
+            Item = &'b (&'b CurvePoint<'a, CB>, 
+                &'b PreparedTwistPoint<'a, FE, F>)
+        >
+    {
+        if self.prefer_naf {
+            self.miller_loop_naf(i)
+        } else {
+            self.miller_loop_binary(i)
+        }
+    }
+
     fn miller_loop_naf<'b, I>(&self, i: I) -> Fp12<'a, FE, F>
     where 'a: 'b,
         I: IntoIterator<
             Item = &'b (&'b CurvePoint<'a, CB>, 
-                &'b CurvePoint<'a, CTW>)
+                &'b PreparedTwistPoint<'a, FE, F>)
         >
     {
         let mut g1_references = vec![];
         let mut prepared_coeffs = vec![];
 
-        let mut two_inv = Fp::one(self.base_field);
-        two_inv.double();
-        let two_inv = two_inv.inverse().expect("inverse of 2 is guaranteed to exist");
-
         for (p, q) in i.into_iter() {
             if !p.is_zero() && !q.is_zero() {
-                let coeffs = self.prepare_naf(&q.clone(), &two_inv);
-                let ell_coeffs = coeffs.ell_coeffs;
-                prepared_coeffs.push(ell_coeffs);
+                prepared_coeffs.push(q.ell_coeffs.iter());
                 g1_references.push(p);
             }
         }
 
-        let mut prepared_coeffs: Vec<_> = prepared_coeffs.into_iter().map(|el| el.into_iter()).collect();
-
         let mut f = Fp12::one(self.fp12_extension);
 
         let mut it = self.x_naf.iter().rev();
@@ -402,31 +452,23 @@ impl<
         f
     }
 
-    fn miller_loop<'b, I>(&self, i: I) -> Fp12<'a, FE, F>
+    fn miller_loop_binary<'b, I>(&self, i: I) -> Fp12<'a, FE, F>
     where 'a: 'b,
         I: IntoIterator<
             Item = &'b (&'b CurvePoint<'a, CB>, 
-                &'b CurvePoint<'a, CTW>)
+                &'b PreparedTwistPoint<'a, FE, F>)
         >
     {
         let mut g1_references = vec![];
         let mut prepared_coeffs = vec![];
 
-        let mut two_inv = Fp::one(self.base_field);
-        two_inv.double();
-        let two_inv = two_inv.inverse().expect("inverse of 2 is guaranteed to exist");
-
         for (p, q) in i.into_iter() {
             if !p.is_zero() && !q.is_zero() {
-                let coeffs = self.prepare(&q.clone(), &two_inv);
-                let ell_coeffs = coeffs.ell_coeffs;
-                prepared_coeffs.push(ell_coeffs);
+                prepared_coeffs.push(q.ell_coeffs.iter());
                 g1_references.push(p);
             }
         }
 
-        let mut prepared_coeffs: Vec<_> = prepared_coeffs.into_iter().map(|el| el.into_iter()).collect();
-
         let mut f = Fp12::one(self.fp12_extension);
 
         for i in MsbBitIterator::new(&self.x).skip(1) {
@@ -449,6 +491,74 @@ impl<
 
         f
     }
+}
+
+
+impl<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    > PairingEngine for Bls12Instance<'a, FE, F, CB, CTW> {
+    type PairingResult = Fp12<'a, FE, F>;
+    type G1 = CurvePoint<'a, CB>;
+    type G2 = CurvePoint<'a, CTW>;
+
+    type PreparedG2 = PreparedTwistPoint<'a, FE, F>;
+
+    fn pair<'b>
+        (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>]) -> Option<Self::PairingResult> {
+            if points.len() != twists.len() {
+                return None;
+            }
+
+            if !crate::features::in_gas_metering() {
+                if points.len() == 0 || twists.len() == 0 {
+                    return None;
+                }
+            }
+
+            let two_inv = self.two_inv();
+
+            let points = normalized_points(points);
+
+            let mut g1_references = Vec::with_capacity(points.len());
+            let mut prepared = Vec::with_capacity(points.len());
+            for (p, q) in points.iter().zip(twists.iter()) {
+                if !p.is_zero() && !q.is_zero() {
+                    g1_references.push(p);
+                    prepared.push(self.prepare_twist_point(q, &two_inv));
+                }
+            }
+
+            if g1_references.len() == 0 {
+                return Some(Fp12::one(self.fp12_extension));
+            }
+
+            let pairs: Vec<_> = g1_references.into_iter().zip(prepared.iter()).collect();
+
+            let loop_result = self.miller_loop_prepared(&pairs[..]);
+
+            self.final_exponentiation(&loop_result)
+        }   
+
+    fn prepare_g2(&self, twist: &CurvePoint<'a, CTW>) -> Option<Self::PreparedG2> {
+        let mut twist = twist.clone();
+        twist.normalize();
+
+        Some(self.prepare_twist_point(&twist, &self.two_inv()))
+    }
+
+    fn miller_loop<'b>(&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [Self::PreparedG2]) -> Option<Self::PairingResult> {
+        if points.len() != twists.len() {
+            return None;
+        }
+
+        let pairs: Vec<_> = points.iter().zip(twists.iter()).collect();
+
+        Some(self.miller_loop_prepared(&pairs[..]))
+    }
 
     fn final_exponentiation(&self, f: &Fp12<'a, FE, F>) -> Option<Fp12<'a, FE, F>> {
         // Computing the final exponentation following
@@ -532,56 +642,6 @@ impl<
     }
 }
 
-
-impl<
-    'a, 
-        FE: ElementRepr, 
-        F: SizedPrimeField<Repr = FE>, 
-        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
-        CTW: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
-    > PairingEngine for Bls12Instance<'a, FE, F, CB, CTW> {
-    type PairingResult = Fp12<'a, FE, F>;
-    type G1 = CurvePoint<'a, CB>;
-    type G2 = CurvePoint<'a, CTW>;
-
-    fn pair<'b>
-        (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>]) -> Option<Self::PairingResult> {
-            if points.len() != twists.len() {
-                return None;
-            }
-
-            if !crate::features::in_gas_metering() {
-                if points.len() == 0 || twists.len() == 0 {
-                    return None;
-                }
-            }
-            
-            let mut pairs = Vec::with_capacity(points.len());
-            for (p, q) in points.iter().zip(twists.iter()) {
-                if !p.is_zero() && !q.is_zero() {
-                    pairs.push((p, q));
-                }
-            }
-
-            if pairs.len() == 0 {
-                return Some(Fp12::one(self.fp12_extension));
-            }
-
-            let loop_result = if self.prefer_naf {
-                let loop_result = self.miller_loop_naf(&pairs[..]);
-                debug_assert!(self.x_naf.len() > 0);
-
-                loop_result
-            } else {
-                let loop_result = self.miller_loop(&pairs[..]);
-
-                loop_result
-            };
-
-            self.final_exponentiation(&loop_result)
-        }   
-}
-
 #[cfg(test)]
 mod tests {
     use num_bigint::BigUint;
@@ -993,4 +1053,43 @@ mod tests {
 
         assert!(format!("{}",pairing_result.c0.c0.c0) == "0x00b718ff624a95f189bfb44bcd6d6556226837c1f74d1afbf4bea573b71c17d3a243cae41d966e2164aad0991fd790cc");
     }
+
+    #[test]
+    fn test_bls12_381_split_miller_loop() {
+        use crate::engines::bls12_381::*;
+        use crate::weierstrass::Group;
+
+        let engine = &BLS12_381_PAIRING_ENGINE;
+
+        let mut p = BLS12_381_G1_GENERATOR.mul(&[12345678]);
+        p.normalize();
+        let mut q = BLS12_381_G2_GENERATOR.mul(&[87654321]);
+        q.normalize();
+
+        let expected = engine.pair(&[p.clone(), BLS12_381_G1_GENERATOR], &[BLS12_381_G2_GENERATOR, q.clone()]).unwrap();
+
+        let prepared_generator = engine.prepare_g2(&BLS12_381_G2_GENERATOR).unwrap();
+        let prepared_q = engine.prepare_g2(&q).unwrap();
+        assert!(!prepared_q.is_zero());
+
+        // accumulate Miller loop outputs over separate calls and finalize once
+        let mut f = engine.miller_loop(&[p.clone()], &[prepared_generator]).unwrap();
+        f.mul_assign(&engine.miller_loop(&[BLS12_381_G1_GENERATOR], &[prepared_q]).unwrap());
+        let result = engine.final_exponentiation(&f).unwrap();
+
+        assert!(result == expected);
+
+        // G1 points that are not normalized give the same result
+        let p_projective = BLS12_381_G1_GENERATOR.mul(&[12345678]);
+        assert!(!p_projective.is_normalized());
+        let expected = engine.pair(&[p.clone()], &[q.clone()]).unwrap();
+        assert!(engine.pair(&[p_projective.clone()], &[q.clone()]).unwrap() == expected);
+        let prepared_q = engine.prepare_g2(&q).unwrap();
+        assert!(engine.pair_prepared(&[p_projective], &[prepared_q]).unwrap() == expected);
+
+        let prepared_zero = engine.prepare_g2(&CurvePoint::zero(&BLS12_381_G2_CURVE)).unwrap();
+        assert!(prepared_zero.is_zero());
+        let f = engine.miller_loop(&[BLS12_381_G1_GENERATOR], &[prepared_zero]).unwrap();
+        assert!(engine.final_exponentiation(&f).unwrap() == Fp12::one(&BLS12_381_EXTENSION_12_FIELD));
+    }
 }
\ No newline at end of file
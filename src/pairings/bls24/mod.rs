@@ -0,0 +1,649 @@
+use crate::field::SizedPrimeField;
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::traits::{FieldElement, MsbBitIterator, ZeroAndOne};
+use crate::weierstrass::Group;
+use crate::weierstrass::{CurveParameters};
+use crate::weierstrass::curve::{WeierstrassCurve, CurvePoint};
+use crate::extension_towers::fp2::{Extension2};
+use crate::extension_towers::fp4_as_2_over_2::{Fp4, Extension2Over2};
+use crate::extension_towers::fp12_as_3_over_2_over_2::{Extension3Over2Over2};
+use crate::extension_towers::fp24_as_2_over_3_over_2_over_2::{Fp24, Extension2Over3Over2Over2};
+use crate::pairings::PairingEngine;
+use crate::pairings::TwistType;
+#[cfg(feature = "batch_verification")]
+use crate::pairings::batch::{PairingEquation, BatchVerificationResult, batch_verify};
+#[cfg(feature = "batch_verification")]
+use crate::errors::ApiError;
+use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf, normalized_points};
+
+/// Line coefficients of the Miller loop for a fixed G2 point, can be reused between pairing calls
+pub struct PreparedTwistPoint<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+    pub(crate) ell_coeffs: Vec<(Fp4<'a, FE, F>, Fp4<'a, FE, F>, Fp4<'a, FE, F>)>
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for PreparedTwistPoint<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            ell_coeffs: self.ell_coeffs.clone(),
+        }
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> PreparedTwistPoint<'a, FE, F> {
+    // point at infinity has no coefficients
+    pub fn is_zero(&self) -> bool {
+        self.ell_coeffs.len() == 0
+    }
+}
+
+#[derive(Clone)]
+pub struct Bls24InstanceParams<
+'a, 
+    FE: ElementRepr, 
+    F: SizedPrimeField<Repr = FE>, 
+    CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+    CTW: CurveParameters<BaseFieldElement = Fp4<'a, FE, F>>
+> {
+    pub x: &'a [u64],
+    pub x_is_negative: bool,
+    pub twist_type: TwistType,
+    pub base_field: &'a F,
+    pub curve: &'a WeierstrassCurve<'a, CB>,
+    pub curve_twist: &'a WeierstrassCurve<'a, CTW>,
+    pub fp2_extension: &'a Extension2<'a, FE, F>,
+    pub fp4_extension: &'a Extension2Over2<'a, FE, F>,
+    pub fp12_extension: &'a Extension3Over2Over2<'a, FE, F>,
+    pub fp24_extension: &'a Extension2Over3Over2Over2<'a, FE, F>,
+    pub force_no_naf: bool
+}
+
+#[derive(Clone)]
+pub struct Bls24Instance<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp4<'a, FE, F>>
+    > {
+    pub x: &'a [u64],
+    pub x_is_negative: bool,
+    pub twist_type: TwistType,
+    pub base_field: &'a F,
+    pub curve: &'a WeierstrassCurve<'a, CB>,
+    pub curve_twist: &'a WeierstrassCurve<'a, CTW>,
+    pub fp2_extension: &'a Extension2<'a, FE, F>,
+    pub fp4_extension: &'a Extension2Over2<'a, FE, F>,
+    pub fp12_extension: &'a Extension3Over2Over2<'a, FE, F>,
+    pub fp24_extension: &'a Extension2Over3Over2Over2<'a, FE, F>,
+    pub prefer_naf: bool,
+    pub x_naf: Vec<i8>
+}
+
+impl<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp4<'a, FE, F>>
+    > Bls24Instance<'a, FE, F, CB, CTW> 
+{
+    pub fn from_params(params: Bls24InstanceParams::<'a, FE, F, CB, CTW>) -> Self {
+        let (prefer_naf, naf) = if params.force_no_naf {
+            (false, vec![])
+        } else {
+            let naf_vec = into_ternary_wnaf(&params.x);
+            let original_bits = calculate_bits(&params.x);
+            let original_hamming = calculate_hamming_weight(&params.x);
+            let naf_hamming = calculate_naf_hamming_weight(&naf_vec);
+            let naf_length = naf_vec.len() as u32;
+
+            let naf_is_beneficial = if naf_length + naf_hamming < original_bits + original_hamming {
+                true
+            } else {
+                false
+            };
+
+            if naf_is_beneficial {
+                (true, naf_vec)
+            } else {
+                (false, vec![])
+            }
+        };
+
+        Self {
+            x: params.x,
+            x_is_negative: params.x_is_negative,
+            twist_type: params.twist_type,
+            base_field: params.base_field,
+            curve: params.curve,
+            curve_twist: params.curve_twist,
+            fp2_extension: params.fp2_extension,
+            fp4_extension: params.fp4_extension,
+            fp12_extension: params.fp12_extension,
+            fp24_extension: params.fp24_extension,
+            prefer_naf: prefer_naf,
+            x_naf: naf
+        }
+    }
+
+    /// See `crate::pairings::batch::batch_verify`
+    #[cfg(feature = "batch_verification")]
+    pub fn batch_verify(&self, equations: &[PairingEquation<'a, CB, CTW>]) -> Result<BatchVerificationResult, ApiError> {
+        let one = Fp24::one(self.fp24_extension);
+
+        batch_verify(self, equations, &one)
+    }
+}
+
+impl<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp4<'a, FE, F>>
+    > Bls24Instance<'a, FE, F, CB, CTW> {
+    fn ell(
+        &self,
+        f: &mut Fp24<'a, FE, F>,
+        coeffs: &(Fp4<'a, FE, F>, Fp4<'a, FE, F>, Fp4<'a, FE, F>),
+        p: & CurvePoint<'a, CB>,
+    ) {
+        debug_assert!(p.is_normalized());
+        let mut c0 = coeffs.0.clone();
+        let mut c1 = coeffs.1.clone();
+        let mut c2 = coeffs.2.clone();
+
+        match self.twist_type {
+            TwistType::M => {
+                c2.mul_by_fp(&p.y);
+                c1.mul_by_fp(&p.x);
+                f.mul_by_014(&c0, &c1, &c2);
+            },
+            TwistType::D => {
+                c0.mul_by_fp(&p.y);
+                c1.mul_by_fp(&p.x);
+                f.mul_by_034(&c0, &c1, &c2);
+            },
+        }
+    }
+
+    fn exp_by_x(&self, f: &mut Fp24<'a, FE, F>) {
+        *f = f.cyclotomic_exp(&self.x);
+        if self.x_is_negative {
+            f.conjugate();
+        }
+    }
+
+    fn doubling_step(
+        &self,
+        r: &mut CurvePoint<'a, CTW>,
+        two_inv: &Fp<'a, FE, F>,
+    ) -> (Fp4<'a, FE, F>, Fp4<'a, FE, F>, Fp4<'a, FE, F>) {
+        // Use adapted formulas from ZEXE instead
+        let mut a = r.x.clone();
+        a.mul_assign(&r.y);
+        a.mul_by_fp(two_inv);
+        let mut b = r.y.clone();
+        b.square();
+        let mut c = r.z.clone();
+        c.square();
+
+        let mut e = self.curve_twist.b.clone();
+        let mut t0 = c.clone();
+        t0.double();
+        t0.add_assign(&c);
+
+        e.mul_assign(&t0);
+
+        let mut f = e.clone();
+        f.double();
+        f.add_assign(&e);
+
+        let mut g = b.clone();
+        g.add_assign(&f);
+        g.mul_by_fp(two_inv);
+
+        let mut h = r.y.clone();
+        h.add_assign(&r.z);
+        h.square();
+
+        let mut t1 = b.clone();
+        t1.add_assign(&c);
+
+        h.sub_assign(&t1);
+
+        let mut i = e.clone();
+        i.sub_assign(&b);
+
+        let mut j = r.x.clone();
+        j.square();
+
+        let mut e_square = e.clone();
+        e_square.square();
+
+        r.x = b.clone();
+        r.x.sub_assign(&f);
+        r.x.mul_assign(&a);
+
+        let mut e_square_by_3 = e_square.clone();
+        e_square_by_3.double();
+        e_square_by_3.add_assign(&e_square);
+
+        r.y = g;
+        r.y.square();
+        r.y.sub_assign(&e_square_by_3);
+
+        r.z = b.clone();
+        r.z.mul_assign(&h);
+
+        let mut j_by_three = j.clone();
+        j_by_three.double();
+        j_by_three.add_assign(&j);
+        h.negate();
+
+        match self.twist_type {
+            TwistType::M => {
+                (i, j_by_three, h)
+            },
+            TwistType::D => {
+                (h, j_by_three, i)
+            },
+        }
+    }
+
+    fn addition_step(
+        &self,
+        r: &mut CurvePoint<'a, CTW>,
+        q: & CurvePoint<'a, CTW>,
+    ) -> (Fp4<'a, FE, F>, Fp4<'a, FE, F>, Fp4<'a, FE, F>) {
+        debug_assert!(q.is_normalized());
+        // use adapted zexe formulas too instead of ones from pairing crate
+        let mut theta = q.y.clone();
+        theta.mul_assign(&r.z);
+        theta.negate();
+        theta.add_assign(&r.y);
+
+        let mut lambda = q.x.clone();
+        lambda.mul_assign(&r.z);
+        lambda.negate();
+        lambda.add_assign(&r.x);
+
+        let mut c = theta.clone();
+        c.square();
+        let mut d = lambda.clone();
+        d.square();
+        let mut e = lambda.clone();
+        e.mul_assign(&d);
+        let mut f = r.z.clone();
+        f.mul_assign(&c);
+        let mut g = r.x.clone();
+        g.mul_assign(&d);
+
+        let mut h = g.clone();
+        h.double();
+        h.negate();
+        h.add_assign(&e);
+        h.add_assign(&f);
+        
+
+        r.x = lambda.clone();
+        r.x.mul_assign(&h);
+
+        let mut t0 = g.clone();
+        t0.sub_assign(&h);
+        t0.mul_assign(&theta);
+
+        r.y.mul_assign(&e);
+        r.y.negate();
+        r.y.add_assign(&t0);
+
+        r.z.mul_assign(&e);
+
+        let mut t1 = lambda.clone();
+        t1.mul_assign(&q.y);
+        
+        let mut j = theta.clone();
+        j.mul_assign(&q.x);
+        j.sub_assign(&t1);
+
+        theta.negate();
+        match self.twist_type {
+            TwistType::M => (j, theta, lambda),
+            TwistType::D => (lambda, theta, j),
+        }
+    }
+
+    fn prepare(&self, twist_point: & CurvePoint<'a, CTW>, two_inv: &Fp<'a, FE, F>) -> PreparedTwistPoint<'a, FE, F> {
+        debug_assert!(twist_point.is_normalized());
+
+        if twist_point.is_zero() {
+            return PreparedTwistPoint {
+                ell_coeffs: vec![],
+            };
+        }
+
+        let mut ell_coeffs = Vec::with_capacity(self.x.len() * 64 * 2);
+        let mut r = CurvePoint::<CTW>::point_from_xy(&self.curve_twist, twist_point.x.clone(), twist_point.y.clone());
+
+        for i in MsbBitIterator::new(&self.x).skip(1) {
+            ell_coeffs.push(self.doubling_step(&mut r, &two_inv));
+
+            if i {
+                ell_coeffs.push(self.addition_step(&mut r, &twist_point));
+            }
+        }
+
+        PreparedTwistPoint {
+            ell_coeffs,
+        }
+    }
+
+    fn prepare_naf(&self, twist_point: & CurvePoint<'a, CTW>, two_inv: &Fp<'a, FE, F>) -> PreparedTwistPoint<'a, FE, F> {
+        debug_assert!(twist_point.is_normalized());
+
+        if twist_point.is_zero() {
+            return PreparedTwistPoint {
+                ell_coeffs: vec![],
+            };
+        }
+
+        let mut ell_coeffs = Vec::with_capacity(self.x.len() * 64 * 2);
+
+        let mut twist_point_negated = twist_point.clone();
+        twist_point_negated.negate();
+
+        let mut r = CurvePoint::<CTW>::point_from_xy(&self.curve_twist, twist_point.x.clone(), twist_point.y.clone());
+
+        let mut it = self.x_naf.iter().rev();
+        
+        {
+            let first = it.next().expect("naf has enough coefficients");
+            assert_eq!(*first, 1);
+        }
+
+        for &i in it {
+            ell_coeffs.push(self.doubling_step(&mut r, &two_inv));
+            
+            if i != 0 {
+                if i > 0 {
+                    ell_coeffs.push(self.addition_step(&mut r, &twist_point));
+                } else {
+                    ell_coeffs.push(self.addition_step(&mut r, &twist_point_negated));
+                }
+            }
+        }
+
+        PreparedTwistPoint {
+            ell_coeffs,
+        }
+    }
+
+    fn two_inv(&self) -> Fp<'a, FE, F> {
+        let mut two_inv = Fp::one(self.base_field);
+        two_inv.double();
+
+        two_inv.inverse().expect("inverse of 2 is guaranteed to exist")
+    }
+
+    fn prepare_twist_point(&self, twist_point: & CurvePoint<'a, CTW>, two_inv: &Fp<'a, FE, F>) -> PreparedTwistPoint<'a, FE, F> {
+        if self.prefer_naf {
+            debug_assert!(self.x_naf.len() > 0);
+            self.prepare_naf(twist_point, two_inv)
+        } else {
+            self.prepare(twist_point, two_inv)
+        }
+    }
+
+    fn miller_loop_prepared<'b, I>(&self, i: I) -> Fp24<'a, FE, F>
+    where 'a: 'b,
+        I: IntoIterator<
+            Item = &'b (&'b CurvePoint<'a, CB>, 
+                &'b PreparedTwistPoint<'a, FE, F>)
+        >
+    {
+        if self.prefer_naf {
+            self.miller_loop_naf(i)
+        } else {
+            self.miller_loop_binary(i)
+        }
+    }
+
+    fn miller_loop_naf<'b, I>(&self, i: I) -> Fp24<'a, FE, F>
+    where 'a: 'b,
+        I: IntoIterator<
+            Item = &'b (&'b CurvePoint<'a, CB>, 
+                &'b PreparedTwistPoint<'a, FE, F>)
+        >
+    {
+        let mut g1_references = vec![];
+        let mut prepared_coeffs = vec![];
+
+        for (p, q) in i.into_iter() {
+            if !p.is_zero() && !q.is_zero() {
+                prepared_coeffs.push(q.ell_coeffs.iter());
+                g1_references.push(p);
+            }
+        }
+
+        let mut f = Fp24::one(self.fp24_extension);
+
+        let mut it = self.x_naf.iter().rev();
+        
+        {
+            let first = it.next().expect("naf has enough coefficients");
+            assert_eq!(*first, 1);
+        }
+
+        for &i in it {
+            f.square();
+
+            for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                self.ell(&mut f, &coeffs.next().expect("next miller loop element for doubling step"), p);
+            }
+
+            if i != 0 {
+                for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                    self.ell(&mut f, &coeffs.next().expect("next miller loop element for addition step"), p);
+                }
+            }
+        }
+
+        if self.x_is_negative {
+            f.conjugate();
+        }
+
+        f
+    }
+
+    fn miller_loop_binary<'b, I>(&self, i: I) -> Fp24<'a, FE, F>
+    where 'a: 'b,
+        I: IntoIterator<
+            Item = &'b (&'b CurvePoint<'a, CB>, 
+                &'b PreparedTwistPoint<'a, FE, F>)
+        >
+    {
+        let mut g1_references = vec![];
+        let mut prepared_coeffs = vec![];
+
+        for (p, q) in i.into_iter() {
+            if !p.is_zero() && !q.is_zero() {
+                prepared_coeffs.push(q.ell_coeffs.iter());
+                g1_references.push(p);
+            }
+        }
+
+        let mut f = Fp24::one(self.fp24_extension);
+
+        for i in MsbBitIterator::new(&self.x).skip(1) {
+            f.square();
+
+            for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                self.ell(&mut f, &coeffs.next().expect("next miller loop element for doubling step"), p);
+            }
+
+            if i {
+                for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                    self.ell(&mut f, &coeffs.next().expect("next miller loop element for addition step"), p);
+                }
+            }
+        }
+
+        if self.x_is_negative {
+            f.conjugate();
+        }
+
+        f
+    }
+}
+
+
+impl<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp4<'a, FE, F>>
+    > PairingEngine for Bls24Instance<'a, FE, F, CB, CTW> {
+    type PairingResult = Fp24<'a, FE, F>;
+    type G1 = CurvePoint<'a, CB>;
+    type G2 = CurvePoint<'a, CTW>;
+
+    type PreparedG2 = PreparedTwistPoint<'a, FE, F>;
+
+    fn pair<'b>
+        (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>]) -> Option<Self::PairingResult> {
+            if points.len() != twists.len() {
+                return None;
+            }
+
+            if !crate::features::in_gas_metering() {
+                if points.len() == 0 || twists.len() == 0 {
+                    return None;
+                }
+            }
+
+            let two_inv = self.two_inv();
+
+            let points = normalized_points(points);
+
+            let mut g1_references = Vec::with_capacity(points.len());
+            let mut prepared = Vec::with_capacity(points.len());
+            for (p, q) in points.iter().zip(twists.iter()) {
+                if !p.is_zero() && !q.is_zero() {
+                    g1_references.push(p);
+                    prepared.push(self.prepare_twist_point(q, &two_inv));
+                }
+            }
+
+            if g1_references.len() == 0 {
+                return Some(Fp24::one(self.fp24_extension));
+            }
+
+            let pairs: Vec<_> = g1_references.into_iter().zip(prepared.iter()).collect();
+
+            let loop_result = self.miller_loop_prepared(&pairs[..]);
+
+            self.final_exponentiation(&loop_result)
+        }   
+
+    fn prepare_g2(&self, twist: &CurvePoint<'a, CTW>) -> Option<Self::PreparedG2> {
+        let mut twist = twist.clone();
+        twist.normalize();
+
+        Some(self.prepare_twist_point(&twist, &self.two_inv()))
+    }
+
+    fn miller_loop<'b>(&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [Self::PreparedG2]) -> Option<Self::PairingResult> {
+        if points.len() != twists.len() {
+            return None;
+        }
+
+        let pairs: Vec<_> = points.iter().zip(twists.iter()).collect();
+
+        Some(self.miller_loop_prepared(&pairs[..]))
+    }
+
+    fn final_exponentiation(&self, f: &Fp24<'a, FE, F>) -> Option<Fp24<'a, FE, F>> {
+        // easy part is f^((p^12 - 1)(p^4 + 1)), hard part uses the decomposition
+        // 3 * (p^8 - p^4 + 1) / r = l_0 + l_1 * p + ... + l_7 * p^7 with
+        // l_7 = (x - 1)^2, l_6 = x * l_7, l_5 = x * l_6, l_4 = x * l_5,
+        // l_3 = x * l_4 - l_7, l_2 = x * l_3, l_1 = x * l_2, l_0 = x * l_1 + 3
+        // that results in a (non-degenerate) cube of the reduced pairing
+
+        // f1 = f^(p^12)
+        let mut f1 = f.clone();
+        f1.conjugate();
+
+        match f.inverse() {
+            Some(mut f2) => {
+                // r = f^(p^12 - 1)
+                let mut r = f1.clone();
+                r.mul_assign(&f2);
+
+                // f2 = f^(p^12 - 1)
+                f2 = r.clone();
+                // r = f^((p^12 - 1)(p^4))
+                r.frobenius_map(4);
+
+                // r = f^((p^12 - 1)(p^4 + 1))
+                r.mul_assign(&f2);
+
+                // Hard part of the final exponentation is below
+
+                let mut r_inv = r.clone();
+                r_inv.conjugate();
+
+                // y7 = r^(x - 1)
+                let mut y7 = r.clone();
+                self.exp_by_x(&mut y7);
+                y7.mul_assign(&r_inv);
+
+                // y7 = r^((x - 1)^2)
+                let mut y7_inv = y7.clone();
+                y7_inv.conjugate();
+                self.exp_by_x(&mut y7);
+                y7.mul_assign(&y7_inv);
+
+                let mut y6 = y7.clone();
+                self.exp_by_x(&mut y6);
+
+                let mut y5 = y6.clone();
+                self.exp_by_x(&mut y5);
+
+                let mut y4 = y5.clone();
+                self.exp_by_x(&mut y4);
+
+                let mut y3 = y4.clone();
+                self.exp_by_x(&mut y3);
+                y7_inv = y7.clone();
+                y7_inv.conjugate();
+                y3.mul_assign(&y7_inv);
+
+                let mut y2 = y3.clone();
+                self.exp_by_x(&mut y2);
+
+                let mut y1 = y2.clone();
+                self.exp_by_x(&mut y1);
+
+                // y0 = r^(x * l_1 + 3)
+                let mut y0 = y1.clone();
+                self.exp_by_x(&mut y0);
+                let mut r_cubed = r.clone();
+                r_cubed.cyclotomic_square();
+                r_cubed.mul_assign(&r);
+                y0.mul_assign(&r_cubed);
+
+                // Horner scheme over Frobenius powers
+                let mut result = y7;
+                for y in [y6, y5, y4, y3, y2, y1, y0].iter() {
+                    result.frobenius_map(1);
+                    result.mul_assign(y);
+                }
+
+                Some(result)
+            },
+            None => None,
+        }
+    }
+}
@@ -10,12 +10,32 @@ use crate::extension_towers::fp12_as_2_over3_over_2::{Fp12, Extension2Over3Over2
 use crate::extension_towers::fp6_as_3_over_2::{Extension3Over2};
 use crate::pairings::{PairingEngine};
 use crate::pairings::TwistType;
-use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf};
-
-pub(crate) struct PreparedTwistPoint<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+#[cfg(feature = "batch_verification")]
+use crate::pairings::batch::{PairingEquation, BatchVerificationResult, batch_verify};
+#[cfg(feature = "batch_verification")]
+use crate::errors::ApiError;
+use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf, normalized_points};
+
+/// Line coefficients of the Miller loop for a fixed G2 point, can be reused between pairing calls
+pub struct PreparedTwistPoint<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
     pub(crate) ell_coeffs: Vec<(Fp2<'a, FE, F>, Fp2<'a, FE, F>, Fp2<'a, FE, F>)>
 }
 
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for PreparedTwistPoint<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            ell_coeffs: self.ell_coeffs.clone(),
+        }
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> PreparedTwistPoint<'a, FE, F> {
+    // point at infinity has no coefficients
+    pub fn is_zero(&self) -> bool {
+        self.ell_coeffs.len() == 0
+    }
+}
+
 #[derive(Clone)]
 pub struct BnInstanceParams<
     'a, 
@@ -108,6 +128,14 @@ impl<
             six_u_plus_2_naf: naf
         }
     }
+
+    /// See `crate::pairings::batch::batch_verify`
+    #[cfg(feature = "batch_verification")]
+    pub fn batch_verify(&self, equations: &[PairingEquation<'a, CB, CTW>]) -> Result<BatchVerificationResult, ApiError> {
+        let one = Fp12::one(self.fp12_extension);
+
+        batch_verify(self, equations, &one)
+    }
 }
 
 impl<
@@ -443,31 +471,53 @@ impl<
         }
     }
 
-    fn miller_loop<'b, I>(&self, i: I) -> Fp12<'a, FE, F>
+    fn two_inv(&self) -> Fp<'a, FE, F> {
+        let mut two_inv = Fp::one(self.base_field);
+        two_inv.double();
+
+        two_inv.inverse().expect("inverse of 2 is guaranteed to exist")
+    }
+
+    fn prepare_twist_point(&self, twist_point: & CurvePoint<'a, CTW>, two_inv: &Fp<'a, FE, F>) -> PreparedTwistPoint<'a, FE, F> {
+        if self.prefer_naf {
+            debug_assert!(self.six_u_plus_2_naf.len() > 0);
+            self.prepare_naf(twist_point, two_inv)
+        } else {
+            self.prepare(twist_point, two_inv)
+        }
+    }
+
+    fn miller_loop_prepared<'b, I>(&self, i: I) -> Fp12<'a, FE, F>
+    where 'a: 'b,
+        I: IntoIterator<
+            Item = &'b (&'b CurvePoint<'a, CB>, 
+                &'b PreparedTwistPoint<'a, FE, F>)
+        >
+    {
+        if self.prefer_naf {
+            self.miller_loop_naf(i)
+        } else {
+            self.miller_loop_binary(i)
+        }
+    }
+
+    fn miller_loop_binary<'b, I>(&self, i: I) -> Fp12<'a, FE, F>
     where 'a: 'b,
         I: IntoIterator<
             Item = &'b (&'b CurvePoint<'a, CB>, 
-                &'b CurvePoint<'a, CTW>)
+                &'b PreparedTwistPoint<'a, FE, F>)
         >
     {
         let mut g1_references = vec![];
         let mut prepared_coeffs = vec![];
 
-        let mut two_inv = Fp::one(self.base_field);
-        two_inv.double();
-        let two_inv = two_inv.inverse().expect("inverse of 2 is guaranteed to exist");
-
         for (p, q) in i.into_iter() {
             if !p.is_zero() && !q.is_zero() {
-                let coeffs = self.prepare(&q.clone(), &two_inv);
-                let ell_coeffs = coeffs.ell_coeffs;
-                prepared_coeffs.push(ell_coeffs);
+                prepared_coeffs.push(q.ell_coeffs.iter());
                 g1_references.push(p);
             }
         }
 
-        let mut prepared_coeffs: Vec<_> = prepared_coeffs.into_iter().map(|el| el.into_iter()).collect();
-
         let mut f = Fp12::one(&self.fp12_extension);
 
         for i in MsbBitIterator::new(&self.six_u_plus_2).skip(1) {    
@@ -509,27 +559,19 @@ impl<
     where 'a: 'b,
         I: IntoIterator<
             Item = &'b (&'b CurvePoint<'a, CB>, 
-                &'b CurvePoint<'a, CTW>)
+                &'b PreparedTwistPoint<'a, FE, F>)
         >
     {
         let mut g1_references = vec![];
         let mut prepared_coeffs = vec![];
 
-        let mut two_inv = Fp::one(self.base_field);
-        two_inv.double();
-        let two_inv = two_inv.inverse().expect("inverse of 2 is guaranteed to exist");
-
         for (p, q) in i.into_iter() {
             if !p.is_zero() && !q.is_zero() {
-                let coeffs = self.prepare_naf(&q.clone(), &two_inv);
-                let ell_coeffs = coeffs.ell_coeffs;
-                prepared_coeffs.push(ell_coeffs);
+                prepared_coeffs.push(q.ell_coeffs.iter());
                 g1_references.push(p);
             }
         }
 
-        let mut prepared_coeffs: Vec<_> = prepared_coeffs.into_iter().map(|el| el.into_iter()).collect();
-
         let mut f = Fp12::one(self.fp12_extension);
 
         let mut it = self.six_u_plus_2_naf.iter().rev();
@@ -569,6 +611,74 @@ impl<
 
         f
     }
+}
+
+
+impl<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    > PairingEngine for BnInstance<'a, FE, F, CB, CTW> {
+    type PairingResult = Fp12<'a, FE, F>;
+    type G1 = CurvePoint<'a, CB>;
+    type G2 = CurvePoint<'a, CTW>;
+
+    type PreparedG2 = PreparedTwistPoint<'a, FE, F>;
+
+    fn pair<'b>
+        (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>]) -> Option<Self::PairingResult> {
+            if points.len() != twists.len() {
+                return None;
+            }
+
+            if !crate::features::in_gas_metering() {
+                if points.len() == 0 || twists.len() == 0 {
+                    return None;
+                }
+            }
+
+            let two_inv = self.two_inv();
+
+            let points = normalized_points(points);
+
+            let mut g1_references = Vec::with_capacity(points.len());
+            let mut prepared = Vec::with_capacity(points.len());
+            for (p, q) in points.iter().zip(twists.iter()) {
+                if !p.is_zero() && !q.is_zero() {
+                    g1_references.push(p);
+                    prepared.push(self.prepare_twist_point(q, &two_inv));
+                }
+            }
+
+            if g1_references.len() == 0 {
+                return Some(Fp12::one(self.fp12_extension));
+            }
+
+            let pairs: Vec<_> = g1_references.into_iter().zip(prepared.iter()).collect();
+
+            let loop_result = self.miller_loop_prepared(&pairs[..]);
+
+            self.final_exponentiation(&loop_result)
+        }   
+
+    fn prepare_g2(&self, twist: &CurvePoint<'a, CTW>) -> Option<Self::PreparedG2> {
+        let mut twist = twist.clone();
+        twist.normalize();
+
+        Some(self.prepare_twist_point(&twist, &self.two_inv()))
+    }
+
+    fn miller_loop<'b>(&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [Self::PreparedG2]) -> Option<Self::PairingResult> {
+        if points.len() != twists.len() {
+            return None;
+        }
+
+        let pairs: Vec<_> = points.iter().zip(twists.iter()).collect();
+
+        Some(self.miller_loop_prepared(&pairs[..]))
+    }
 
     fn final_exponentiation(&self, f: &Fp12<'a, FE, F>) -> Option<Fp12<'a, FE, F>> {
         // use Zexe and pairing crate fused
@@ -667,56 +777,6 @@ impl<
     }
 }
 
-
-impl<
-    'a, 
-        FE: ElementRepr, 
-        F: SizedPrimeField<Repr = FE>, 
-        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
-        CTW: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
-    > PairingEngine for BnInstance<'a, FE, F, CB, CTW> {
-    type PairingResult = Fp12<'a, FE, F>;
-    type G1 = CurvePoint<'a, CB>;
-    type G2 = CurvePoint<'a, CTW>;
-
-    fn pair<'b>
-        (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>]) -> Option<Self::PairingResult> {
-            if points.len() != twists.len() {
-                return None;
-            }
-
-            if !crate::features::in_gas_metering() {
-                if points.len() == 0 || twists.len() == 0 {
-                    return None;
-                }
-            }
-            
-            let mut pairs = Vec::with_capacity(points.len());
-            for (p, q) in points.iter().zip(twists.iter()) {
-                if !p.is_zero() && !q.is_zero() {
-                    pairs.push((p, q));
-                }
-            }
-
-            if pairs.len() == 0 {
-                return Some(Fp12::one(self.fp12_extension));
-            }
-
-            let loop_result = if self.prefer_naf {
-                let loop_result = self.miller_loop_naf(&pairs[..]);
-                debug_assert!(self.six_u_plus_2_naf.len() > 0);
-
-                loop_result
-            } else {
-                let loop_result = self.miller_loop(&pairs[..]);
-
-                loop_result
-            };
-
-            self.final_exponentiation(&loop_result)
-        }   
-}
-
 #[cfg(test)]
 mod tests {
     use num_bigint::BigUint;
@@ -990,6 +1050,25 @@ mod tests {
 
         // let pairing_result = engine.pair(&[p.clone()], &[q.clone()]).unwrap();
 
+        // same prepared point is reused for different G1 points
+        let prepared_q = engine.prepare_g2(&q).unwrap();
+        let prepared_q2 = engine.prepare_g2(&q2).unwrap();
+        assert!(engine.pair_prepared(&[p.clone()], &[prepared_q.clone()]).unwrap() == engine.pair(&[p.clone()], &[q.clone()]).unwrap());
+        assert!(engine.pair_prepared(&[p2.clone()], &[prepared_q.clone()]).unwrap() == engine.pair(&[p2.clone()], &[q.clone()]).unwrap());
+
+        // G1 points that are not normalized give the same result
+        let p3 = p.mul(vec![87654321]);
+        assert!(!p3.is_normalized());
+        let mut p3_normalized = p3.clone();
+        p3_normalized.normalize();
+        let expected = engine.pair(&[p3_normalized], &[q.clone()]).unwrap();
+        assert!(engine.pair(&[p3.clone()], &[q.clone()]).unwrap() == expected);
+        assert!(engine.pair_prepared(&[p3], &[prepared_q.clone()]).unwrap() == expected);
+        assert!(
+            engine.pair_prepared(&[p.clone(), p2.clone()], &[prepared_q2, prepared_q]).unwrap() == 
+            engine.pair(&[p.clone(), p2.clone()], &[q2.clone(), q.clone()]).unwrap()
+        );
+
         let ans1 = engine.pair(&[p.clone()], &[q2]).unwrap();
         let ans2 = engine.pair(&[p2], &[q.clone()]).unwrap();
         let ans3 = engine.pair(&[p], &[q]).unwrap();
@@ -0,0 +1,679 @@
+use crate::field::SizedPrimeField;
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::traits::{FieldElement, MsbBitIterator, ZeroAndOne};
+use crate::weierstrass::Group;
+use crate::weierstrass::{CurveParameters};
+use crate::weierstrass::curve::{WeierstrassCurve, CurvePoint};
+use crate::extension_towers::fp3::{Extension3};
+use crate::extension_towers::fp6_as_2_over_3::{Fp6, Extension2Over3};
+use crate::pairings::PairingEngine;
+use crate::pairings::TwistType;
+#[cfg(feature = "batch_verification")]
+use crate::pairings::batch::{PairingEquation, BatchVerificationResult, batch_verify};
+#[cfg(feature = "batch_verification")]
+use crate::errors::ApiError;
+use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf, normalized_points};
+
+/// Line coefficients of both Miller loops for a fixed G2 point, can be reused between pairing calls
+pub struct PreparedTwistPoint<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+    pub(crate) ell_coeffs_1: Vec<(Fp<'a, FE, F>, Fp<'a, FE, F>, Fp<'a, FE, F>)>,
+    pub(crate) ell_coeffs_2: Vec<(Fp<'a, FE, F>, Fp<'a, FE, F>, Fp<'a, FE, F>)>
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for PreparedTwistPoint<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            ell_coeffs_1: self.ell_coeffs_1.clone(),
+            ell_coeffs_2: self.ell_coeffs_2.clone(),
+        }
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> PreparedTwistPoint<'a, FE, F> {
+    // point at infinity has no coefficients
+    pub fn is_zero(&self) -> bool {
+        self.ell_coeffs_1.len() == 0 && self.ell_coeffs_2.len() == 0
+    }
+}
+
+/// Parameters of the BW6 curve. Optimal ate pairing is f_{ate_loop_1, Q}(P) * f_{ate_loop_2, Q}(P)^p,
+/// hard part of the final exponentiation is w0 + w1 * p that is a multiple of (p^2 - p + 1) / r
+#[derive(Clone)]
+pub struct BW6InstanceParams<
+    'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    > {
+    pub ate_loop_1: &'a [u64],
+    pub ate_loop_1_is_negative: bool,
+    pub ate_loop_2: &'a [u64],
+    pub ate_loop_2_is_negative: bool,
+    pub exp_w0: &'a [u64],
+    pub exp_w1: &'a [u64],
+    pub exp_w0_is_negative: bool,
+    pub twist_type: TwistType,
+    pub base_field: &'a F,
+    pub curve: &'a WeierstrassCurve<'a, CB>,
+    pub curve_twist: &'a WeierstrassCurve<'a, CTW>,
+    pub fp3_extension: &'a Extension3<'a, FE, F>,
+    pub fp6_extension: &'a Extension2Over3<'a, FE, F>,
+    pub force_no_naf: bool
+}
+
+#[derive(Clone)]
+pub struct BW6Instance<
+    'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    > {
+    pub ate_loop_1: &'a [u64],
+    pub ate_loop_1_is_negative: bool,
+    pub ate_loop_2: &'a [u64],
+    pub ate_loop_2_is_negative: bool,
+    pub exp_w0: &'a [u64],
+    pub exp_w1: &'a [u64],
+    pub exp_w0_is_negative: bool,
+    pub twist_type: TwistType,
+    pub base_field: &'a F,
+    pub curve: &'a WeierstrassCurve<'a, CB>,
+    pub curve_twist: &'a WeierstrassCurve<'a, CTW>,
+    pub fp3_extension: &'a Extension3<'a, FE, F>,
+    pub fp6_extension: &'a Extension2Over3<'a, FE, F>,
+    // empty NAF means that the binary representation of the loop count is used
+    pub ate_loop_1_naf: Vec<i8>,
+    pub ate_loop_2_naf: Vec<i8>
+}
+
+fn naf_if_beneficial(x: &[u64]) -> Vec<i8> {
+    let naf_vec = into_ternary_wnaf(x);
+    let original_bits = calculate_bits(x);
+    let original_hamming = calculate_hamming_weight(x);
+    let naf_hamming = calculate_naf_hamming_weight(&naf_vec);
+    let naf_length = naf_vec.len() as u32;
+
+    if naf_length + naf_hamming < original_bits + original_hamming {
+        naf_vec
+    } else {
+        vec![]
+    }
+}
+
+// digits of the loop count from the most significant one, except the leading one
+fn loop_digits(x: &[u64], naf: &[i8]) -> Vec<i8> {
+    if naf.len() == 0 {
+        MsbBitIterator::new(x).skip(1).map(|bit| bit as i8).collect()
+    } else {
+        let mut it = naf.iter().rev();
+        let first = it.next().expect("naf has enough coefficients");
+        assert_eq!(*first, 1);
+
+        it.cloned().collect()
+    }
+}
+
+impl<
+    'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    > BW6Instance<'a, FE, F, CB, CTW>
+{
+    pub fn from_params(params: BW6InstanceParams::<'a, FE, F, CB, CTW>) -> Self {
+        let (naf_1, naf_2) = if params.force_no_naf {
+            (vec![], vec![])
+        } else {
+            (naf_if_beneficial(&params.ate_loop_1), naf_if_beneficial(&params.ate_loop_2))
+        };
+
+        Self {
+            ate_loop_1: params.ate_loop_1,
+            ate_loop_1_is_negative: params.ate_loop_1_is_negative,
+            ate_loop_2: params.ate_loop_2,
+            ate_loop_2_is_negative: params.ate_loop_2_is_negative,
+            exp_w0: params.exp_w0,
+            exp_w1: params.exp_w1,
+            exp_w0_is_negative: params.exp_w0_is_negative,
+            twist_type: params.twist_type,
+            base_field: params.base_field,
+            curve: params.curve,
+            curve_twist: params.curve_twist,
+            fp3_extension: params.fp3_extension,
+            fp6_extension: params.fp6_extension,
+            ate_loop_1_naf: naf_1,
+            ate_loop_2_naf: naf_2
+        }
+    }
+
+    /// See `crate::pairings::batch::batch_verify`
+    #[cfg(feature = "batch_verification")]
+    pub fn batch_verify(&self, equations: &[PairingEquation<'a, CB, CTW>]) -> Result<BatchVerificationResult, ApiError> {
+        let one = Fp6::one(self.fp6_extension);
+
+        batch_verify(self, equations, &one)
+    }
+}
+
+impl<
+    'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    > BW6Instance<'a, FE, F, CB, CTW> {
+    fn ell(
+        &self,
+        f: &mut Fp6<'a, FE, F>,
+        coeffs: &(Fp<'a, FE, F>, Fp<'a, FE, F>, Fp<'a, FE, F>),
+        p: & CurvePoint<'a, CB>,
+    ) {
+        debug_assert!(p.is_normalized());
+        let mut c0 = coeffs.0.clone();
+        let mut c1 = coeffs.1.clone();
+        let mut c2 = coeffs.2.clone();
+
+        // Fp6 is Fp3[v] / (v^2 - u) with Fp3 = Fp[u] / (u^3 - non_residue), so the line
+        // has the same sparse structure as for BLS12: 0, 1, 4 coefficients in the basis
+        // (1, u, u^2, v, uv, u^2v) for M twist and 0, 3, 4 for D twist
+        let mut line = Fp6::zero(self.fp6_extension);
+        match self.twist_type {
+            TwistType::M => {
+                c2.mul_assign(&p.y);
+                c1.mul_assign(&p.x);
+                line.c0.c0 = c0;
+                line.c0.c1 = c1;
+                line.c1.c1 = c2;
+            },
+            TwistType::D => {
+                c0.mul_assign(&p.y);
+                c1.mul_assign(&p.x);
+                line.c0.c0 = c0;
+                line.c1.c0 = c1;
+                line.c1.c1 = c2;
+            },
+        }
+
+        f.mul_assign(&line);
+    }
+
+    fn doubling_step(
+        &self,
+        r: &mut CurvePoint<'a, CTW>,
+        two_inv: &Fp<'a, FE, F>,
+    ) -> (Fp<'a, FE, F>, Fp<'a, FE, F>, Fp<'a, FE, F>) {
+        // same formulas as for BLS12, but the twist is defined over the base field
+        let mut a = r.x.clone();
+        a.mul_assign(&r.y);
+        a.mul_assign(two_inv);
+        let mut b = r.y.clone();
+        b.square();
+        let mut c = r.z.clone();
+        c.square();
+
+        let mut e = self.curve_twist.b.clone();
+        let mut t0 = c.clone();
+        t0.double();
+        t0.add_assign(&c);
+
+        e.mul_assign(&t0);
+
+        let mut f = e.clone();
+        f.double();
+        f.add_assign(&e);
+
+        let mut g = b.clone();
+        g.add_assign(&f);
+        g.mul_assign(two_inv);
+
+        let mut h = r.y.clone();
+        h.add_assign(&r.z);
+        h.square();
+
+        let mut t1 = b.clone();
+        t1.add_assign(&c);
+
+        h.sub_assign(&t1);
+
+        let mut i = e.clone();
+        i.sub_assign(&b);
+
+        let mut j = r.x.clone();
+        j.square();
+
+        let mut e_square = e.clone();
+        e_square.square();
+
+        r.x = b.clone();
+        r.x.sub_assign(&f);
+        r.x.mul_assign(&a);
+
+        let mut e_square_by_3 = e_square.clone();
+        e_square_by_3.double();
+        e_square_by_3.add_assign(&e_square);
+
+        r.y = g;
+        r.y.square();
+        r.y.sub_assign(&e_square_by_3);
+
+        r.z = b.clone();
+        r.z.mul_assign(&h);
+
+        let mut j_by_three = j.clone();
+        j_by_three.double();
+        j_by_three.add_assign(&j);
+        h.negate();
+
+        match self.twist_type {
+            TwistType::M => {
+                (i, j_by_three, h)
+            },
+            TwistType::D => {
+                (h, j_by_three, i)
+            },
+        }
+    }
+
+    fn addition_step(
+        &self,
+        r: &mut CurvePoint<'a, CTW>,
+        q: & CurvePoint<'a, CTW>,
+    ) -> (Fp<'a, FE, F>, Fp<'a, FE, F>, Fp<'a, FE, F>) {
+        debug_assert!(q.is_normalized());
+        let mut theta = q.y.clone();
+        theta.mul_assign(&r.z);
+        theta.negate();
+        theta.add_assign(&r.y);
+
+        let mut lambda = q.x.clone();
+        lambda.mul_assign(&r.z);
+        lambda.negate();
+        lambda.add_assign(&r.x);
+
+        let mut c = theta.clone();
+        c.square();
+        let mut d = lambda.clone();
+        d.square();
+        let mut e = lambda.clone();
+        e.mul_assign(&d);
+        let mut f = r.z.clone();
+        f.mul_assign(&c);
+        let mut g = r.x.clone();
+        g.mul_assign(&d);
+
+        let mut h = g.clone();
+        h.double();
+        h.negate();
+        h.add_assign(&e);
+        h.add_assign(&f);
+
+        r.x = lambda.clone();
+        r.x.mul_assign(&h);
+
+        let mut t0 = g.clone();
+        t0.sub_assign(&h);
+        t0.mul_assign(&theta);
+
+        r.y.mul_assign(&e);
+        r.y.negate();
+        r.y.add_assign(&t0);
+
+        r.z.mul_assign(&e);
+
+        let mut t1 = lambda.clone();
+        t1.mul_assign(&q.y);
+
+        let mut j = theta.clone();
+        j.mul_assign(&q.x);
+        j.sub_assign(&t1);
+
+        theta.negate();
+        match self.twist_type {
+            TwistType::M => (j, theta, lambda),
+            TwistType::D => (lambda, theta, j),
+        }
+    }
+
+    fn prepare_for_loop(
+        &self,
+        twist_point: & CurvePoint<'a, CTW>,
+        twist_point_negated: & CurvePoint<'a, CTW>,
+        digits: &[i8],
+        two_inv: &Fp<'a, FE, F>
+    ) -> Vec<(Fp<'a, FE, F>, Fp<'a, FE, F>, Fp<'a, FE, F>)> {
+        let mut ell_coeffs = Vec::with_capacity(digits.len() * 2);
+        let mut r = CurvePoint::<CTW>::point_from_xy(&self.curve_twist, twist_point.x.clone(), twist_point.y.clone());
+
+        for &i in digits.iter() {
+            ell_coeffs.push(self.doubling_step(&mut r, &two_inv));
+
+            if i != 0 {
+                if i > 0 {
+                    ell_coeffs.push(self.addition_step(&mut r, &twist_point));
+                } else {
+                    ell_coeffs.push(self.addition_step(&mut r, &twist_point_negated));
+                }
+            }
+        }
+
+        ell_coeffs
+    }
+
+    fn prepare_twist_point(&self, twist_point: & CurvePoint<'a, CTW>, two_inv: &Fp<'a, FE, F>) -> PreparedTwistPoint<'a, FE, F> {
+        debug_assert!(twist_point.is_normalized());
+
+        if twist_point.is_zero() {
+            return PreparedTwistPoint {
+                ell_coeffs_1: vec![],
+                ell_coeffs_2: vec![],
+            };
+        }
+
+        let mut twist_point_negated = twist_point.clone();
+        twist_point_negated.negate();
+
+        let digits_1 = loop_digits(&self.ate_loop_1, &self.ate_loop_1_naf);
+        let digits_2 = loop_digits(&self.ate_loop_2, &self.ate_loop_2_naf);
+
+        PreparedTwistPoint {
+            ell_coeffs_1: self.prepare_for_loop(twist_point, &twist_point_negated, &digits_1, two_inv),
+            ell_coeffs_2: self.prepare_for_loop(twist_point, &twist_point_negated, &digits_2, two_inv),
+        }
+    }
+
+    fn two_inv(&self) -> Fp<'a, FE, F> {
+        let mut two_inv = Fp::one(self.base_field);
+        two_inv.double();
+
+        two_inv.inverse().expect("inverse of 2 is guaranteed to exist")
+    }
+
+    fn miller_loop_for_count<'b>(
+        &self,
+        g1_references: &[&'b CurvePoint<'a, CB>],
+        prepared_coeffs: &mut [std::slice::Iter<'b, (Fp<'a, FE, F>, Fp<'a, FE, F>, Fp<'a, FE, F>)>],
+        digits: &[i8]
+    ) -> Fp6<'a, FE, F> {
+        let mut f = Fp6::one(self.fp6_extension);
+
+        for &i in digits.iter() {
+            f.square();
+
+            for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                self.ell(&mut f, &coeffs.next().expect("next miller loop element for doubling step"), p);
+            }
+
+            if i != 0 {
+                for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                    self.ell(&mut f, &coeffs.next().expect("next miller loop element for addition step"), p);
+                }
+            }
+        }
+
+        f
+    }
+
+    fn miller_loop_prepared<'b, I>(&self, i: I) -> Fp6<'a, FE, F>
+    where 'a: 'b,
+        I: IntoIterator<
+            Item = &'b (&'b CurvePoint<'a, CB>,
+                &'b PreparedTwistPoint<'a, FE, F>)
+        >
+    {
+        let mut g1_references = vec![];
+        let mut prepared_coeffs_1 = vec![];
+        let mut prepared_coeffs_2 = vec![];
+
+        for (p, q) in i.into_iter() {
+            if !p.is_zero() && !q.is_zero() {
+                prepared_coeffs_1.push(q.ell_coeffs_1.iter());
+                prepared_coeffs_2.push(q.ell_coeffs_2.iter());
+                g1_references.push(*p);
+            }
+        }
+
+        // f_{ate_loop_1, Q}(P)
+        let digits_1 = loop_digits(&self.ate_loop_1, &self.ate_loop_1_naf);
+        let mut f_1 = self.miller_loop_for_count(&g1_references, &mut prepared_coeffs_1, &digits_1);
+        if self.ate_loop_1_is_negative {
+            f_1.conjugate();
+        }
+
+        // f_{ate_loop_2, Q}(P)^p
+        let digits_2 = loop_digits(&self.ate_loop_2, &self.ate_loop_2_naf);
+        let mut f_2 = self.miller_loop_for_count(&g1_references, &mut prepared_coeffs_2, &digits_2);
+        if self.ate_loop_2_is_negative {
+            f_2.conjugate();
+        }
+        f_2.frobenius_map(1);
+
+        f_1.mul_assign(&f_2);
+
+        f_1
+    }
+
+    fn final_exponentiation_part_one(&self, elt: &Fp6<'a, FE, F>, elt_inv: &Fp6<'a, FE, F>) -> Fp6<'a, FE, F> {
+        // (q^3-1)*(q+1)
+
+        // elt_q3 = elt^(q^3)
+        let mut elt_q3 = elt.clone();
+        elt_q3.frobenius_map(3);
+        // elt_q3_over_elt = elt^(q^3-1)
+        let mut elt_q3_over_elt = elt_q3;
+        elt_q3_over_elt.mul_assign(&elt_inv);
+        // alpha = elt^((q^3-1) * q)
+        let mut alpha = elt_q3_over_elt.clone();
+        alpha.frobenius_map(1);
+        // beta = elt^((q^3-1)*(q+1)
+        alpha.mul_assign(&elt_q3_over_elt);
+
+        alpha
+    }
+
+    fn final_exponentiation_part_two(&self, elt: &Fp6<'a, FE, F>) -> Fp6<'a, FE, F> {
+        // elt^w0 * (elt^q)^w1 is computed with a shared chain of squarings. Element is
+        // in the cyclotomic subgroup at this point, so the inverse is a conjugate
+        let mut elt_w0 = elt.clone();
+        if self.exp_w0_is_negative {
+            elt_w0.conjugate();
+        }
+        let mut elt_w0_inv = elt_w0.clone();
+        elt_w0_inv.conjugate();
+
+        let mut elt_q = elt.clone();
+        elt_q.frobenius_map(1);
+        let mut elt_q_inv = elt_q.clone();
+        elt_q_inv.conjugate();
+
+        let mut sum = elt_w0.clone();
+        sum.mul_assign(&elt_q);
+        let mut sum_inv = sum.clone();
+        sum_inv.conjugate();
+
+        let mut diff = elt_w0.clone();
+        diff.mul_assign(&elt_q_inv);
+        let mut diff_inv = diff.clone();
+        diff_inv.conjugate();
+
+        let naf_w0 = into_ternary_wnaf(&self.exp_w0);
+        let naf_w1 = into_ternary_wnaf(&self.exp_w1);
+        let length = std::cmp::max(naf_w0.len(), naf_w1.len());
+
+        let mut res = Fp6::one(self.fp6_extension);
+        let mut found_nonzero = false;
+
+        for i in (0..length).rev() {
+            if found_nonzero {
+                res.square();
+            }
+
+            let d0 = naf_w0.get(i).cloned().unwrap_or(0);
+            let d1 = naf_w1.get(i).cloned().unwrap_or(0);
+
+            let multiplier = match (d0, d1) {
+                (0, 0) => continue,
+                (1, 0) => &elt_w0,
+                (-1, 0) => &elt_w0_inv,
+                (0, 1) => &elt_q,
+                (0, -1) => &elt_q_inv,
+                (1, 1) => &sum,
+                (-1, -1) => &sum_inv,
+                (1, -1) => &diff,
+                (-1, 1) => &diff_inv,
+                _ => unreachable!("NAF digits are -1, 0 or 1")
+            };
+
+            found_nonzero = true;
+            res.mul_assign(multiplier);
+        }
+
+        res
+    }
+}
+
+impl<
+    'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    > PairingEngine for BW6Instance<'a, FE, F, CB, CTW> {
+    type PairingResult = Fp6<'a, FE, F>;
+    type G1 = CurvePoint<'a, CB>;
+    type G2 = CurvePoint<'a, CTW>;
+
+    type PreparedG2 = PreparedTwistPoint<'a, FE, F>;
+
+    fn pair<'b>
+        (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>]) -> Option<Self::PairingResult> {
+            if points.len() != twists.len() {
+                return None;
+            }
+
+            if !crate::features::in_gas_metering() {
+                if points.len() == 0 || twists.len() == 0 {
+                    return None;
+                }
+            }
+
+            let two_inv = self.two_inv();
+
+            let points = normalized_points(points);
+
+            let mut g1_references = Vec::with_capacity(points.len());
+            let mut prepared = Vec::with_capacity(points.len());
+            for (p, q) in points.iter().zip(twists.iter()) {
+                if !p.is_zero() && !q.is_zero() {
+                    g1_references.push(p);
+                    prepared.push(self.prepare_twist_point(q, &two_inv));
+                }
+            }
+
+            if g1_references.len() == 0 {
+                return Some(Fp6::one(self.fp6_extension));
+            }
+
+            let pairs: Vec<_> = g1_references.into_iter().zip(prepared.iter()).collect();
+
+            let loop_result = self.miller_loop_prepared(&pairs[..]);
+
+            self.final_exponentiation(&loop_result)
+        }
+
+    fn prepare_g2(&self, twist: &CurvePoint<'a, CTW>) -> Option<Self::PreparedG2> {
+        let mut twist = twist.clone();
+        twist.normalize();
+
+        Some(self.prepare_twist_point(&twist, &self.two_inv()))
+    }
+
+    fn miller_loop<'b>(&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [Self::PreparedG2]) -> Option<Self::PairingResult> {
+        if points.len() != twists.len() {
+            return None;
+        }
+
+        let pairs: Vec<_> = points.iter().zip(twists.iter()).collect();
+
+        Some(self.miller_loop_prepared(&pairs[..]))
+    }
+
+    fn final_exponentiation(&self, f: &Fp6<'a, FE, F>) -> Option<Fp6<'a, FE, F>> {
+        let value_inv = f.inverse()?;
+        let value_to_first_chunk = self.final_exponentiation_part_one(f, &value_inv);
+
+        Some(self.final_exponentiation_part_two(&value_to_first_chunk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traits::FieldElement;
+    use crate::extension_towers::fp6_as_2_over_3::{Fp6};
+    use crate::weierstrass::Group;
+    use crate::pairings::{PairingEngine, TwistType};
+    use crate::engines::bw6_761::*;
+    use super::{BW6Instance, BW6InstanceParams};
+
+    #[test]
+    fn test_bw6_761_naf_and_binary_loops_agree() {
+        let engine = BW6Instance::from_params(BW6InstanceParams {
+            ate_loop_1: &BW6_761_ATE_LOOP_1,
+            ate_loop_1_is_negative: false,
+            ate_loop_2: &BW6_761_ATE_LOOP_2,
+            ate_loop_2_is_negative: false,
+            exp_w0: &BW6_761_EXP_W0,
+            exp_w1: &BW6_761_EXP_W1,
+            exp_w0_is_negative: false,
+            twist_type: TwistType::M,
+            base_field: &BW6_761_FIELD,
+            curve: &BW6_761_G1_CURVE,
+            curve_twist: &BW6_761_G2_CURVE,
+            fp3_extension: &BW6_761_EXTENSION_3_FIELD,
+            fp6_extension: &BW6_761_EXTENSION_6_FIELD,
+            force_no_naf: false
+        });
+        // second loop count is dense in binary
+        assert!(engine.ate_loop_2_naf.len() > 0);
+
+        let mut p = BW6_761_G1_GENERATOR.mul(&[12345u64]);
+        p.normalize();
+        let mut q = BW6_761_G2_GENERATOR.mul(&[6789u64]);
+        q.normalize();
+
+        let naf_result = engine.pair(&[p.clone()], &[q.clone()]).unwrap();
+        let binary_result = BW6_761_PAIRING_ENGINE.pair(&[p], &[q]).unwrap();
+
+        assert!(naf_result == binary_result);
+        assert!(naf_result != Fp6::one(&BW6_761_EXTENSION_6_FIELD));
+    }
+
+    #[test]
+    fn test_bw6_761_prepared_miller_loops_multiply() {
+        let engine = &BW6_761_PAIRING_ENGINE;
+
+        let mut p0 = BW6_761_G1_GENERATOR.mul(&[3u64]);
+        p0.normalize();
+        let mut q0 = BW6_761_G2_GENERATOR.mul(&[5u64]);
+        q0.normalize();
+        let mut p1 = BW6_761_G1_GENERATOR.mul(&[7u64]);
+        p1.normalize();
+        let mut q1 = BW6_761_G2_GENERATOR.mul(&[11u64]);
+        q1.normalize();
+
+        let prepared_0 = engine.prepare_g2(&q0).unwrap();
+        let prepared_1 = engine.prepare_g2(&q1).unwrap();
+
+        let mut f = engine.miller_loop(&[p0.clone()], &[prepared_0]).unwrap();
+        let f1 = engine.miller_loop(&[p1.clone()], &[prepared_1]).unwrap();
+        f.mul_assign(&f1);
+        let split = engine.final_exponentiation(&f).unwrap();
+
+        let joint = engine.pair(&[p0, p1], &[q0, q1]).unwrap();
+
+        assert!(split == joint);
+    }
+}
@@ -7,7 +7,7 @@ use crate::weierstrass::curve::{WeierstrassCurve, CurvePoint};
 use crate::extension_towers::fp2::{Fp2, Extension2};
 use crate::extension_towers::fp4_as_2_over_2::{Fp4, Extension2Over2};
 use crate::pairings::PairingEngine;
-use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf};
+use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf, normalized_points};
 use crate::weierstrass::Group;
 
 #[derive(Clone)]
@@ -111,7 +111,8 @@ struct PrecomputedG1<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
     pub(crate) y_by_twist: Fp2<'a, FE, F>,
 }
 
-struct PrecomputedG2<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+/// Miller loop coefficients for a fixed G2 point, can be reused between pairing calls
+pub struct PrecomputedG2<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
     pub(crate) x: Fp2<'a, FE, F>,
     pub(crate) y: Fp2<'a, FE, F>,
     pub(crate) x_over_twist: Fp2<'a, FE, F>,
@@ -120,18 +121,58 @@ struct PrecomputedG2<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
     pub(crate) addition_coefficients: Vec<AteAdditionCoefficients<'a, FE, F>>,
 }
 
-struct AteDoubleCoefficients<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for PrecomputedG2<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            x: self.x.clone(),
+            y: self.y.clone(),
+            x_over_twist: self.x_over_twist.clone(),
+            y_over_twist: self.y_over_twist.clone(),
+            double_coefficients: self.double_coefficients.clone(),
+            addition_coefficients: self.addition_coefficients.clone(),
+        }
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> PrecomputedG2<'a, FE, F> {
+    // point at infinity has no coefficients
+    pub fn is_zero(&self) -> bool {
+        self.double_coefficients.len() == 0
+    }
+}
+
+pub(crate) struct AteDoubleCoefficients<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
     pub(crate) c_h:  Fp2<'a, FE, F>,
     pub(crate) c_4c: Fp2<'a, FE, F>,
     pub(crate) c_j:  Fp2<'a, FE, F>,
     pub(crate) c_l:  Fp2<'a, FE, F>,
 }
 
-struct AteAdditionCoefficients<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for AteDoubleCoefficients<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            c_h: self.c_h.clone(),
+            c_4c: self.c_4c.clone(),
+            c_j: self.c_j.clone(),
+            c_l: self.c_l.clone(),
+        }
+    }
+}
+
+pub(crate) struct AteAdditionCoefficients<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
     pub(crate) c_l1: Fp2<'a, FE, F>,
     pub(crate) c_rz: Fp2<'a, FE, F>,
 }
 
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for AteAdditionCoefficients<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            c_l1: self.c_l1.clone(),
+            c_rz: self.c_rz.clone(),
+        }
+    }
+}
+
 struct ExtendedCoordinates<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
     pub(crate) x: Fp2<'a, FE, F>,
     pub(crate) y: Fp2<'a, FE, F>,
@@ -146,16 +187,41 @@ impl<
         CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
         CTW: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
     > MNT4Instance<'a, FE, F, CB, CTW> {
-    fn miller_loop<'b, I>(&self, i: I) -> Result< Fp4<'a, FE, F>, () >
+    fn prepare_twist_point(&self, twist_point: &CurvePoint<'a, CTW>, twist_inv: &Fp2<'a, FE, F>) -> Result<PrecomputedG2<'a, FE, F>, ()> {
+        if self.prefer_naf {
+            debug_assert!(self.x_naf.len() > 0);
+            self.precompute_g2_naf(twist_point, twist_inv)
+        } else {
+            self.precompute_g2(twist_point, twist_inv)
+        }
+    }
+
+    fn miller_loop_prepared<'b, I>(&self, i: I) -> Result<Fp4<'a, FE, F>, ()>
+    where 'a: 'b,
+        I: IntoIterator<
+            Item = &'b (&'b CurvePoint<'a, CB>, 
+                &'b PrecomputedG2<'a, FE, F>)
+        >
+    {
+        if self.prefer_naf {
+            self.miller_loop_naf(i)
+        } else {
+            self.miller_loop_binary(i)
+        }
+    }
+
+    fn miller_loop_binary<'b, I>(&self, i: I) -> Result< Fp4<'a, FE, F>, () >
     where 'a: 'b,
         I: IntoIterator<
             Item = &'b (&'b CurvePoint<'a, CB>, 
-                &'b CurvePoint<'a, CTW>)
+                &'b PrecomputedG2<'a, FE, F>)
         >
     {
         let mut f = Fp4::one(self.fp4_extension);
         for (p, q) in i.into_iter() {
-            f.mul_assign(&self.ate_pairing_loop(p, q)?);
+            if !p.is_zero() && !q.is_zero() {
+                f.mul_assign(&self.ate_pairing_loop(p, q)?);
+            }
         }
 
         Ok(f)
@@ -165,12 +231,14 @@ impl<
     where 'a: 'b,
         I: IntoIterator<
             Item = &'b (&'b CurvePoint<'a, CB>, 
-                &'b CurvePoint<'a, CTW>)
+                &'b PrecomputedG2<'a, FE, F>)
         >
     {
         let mut f = Fp4::one(self.fp4_extension);
         for (p, q) in i.into_iter() {
-            f.mul_assign(&self.ate_pairing_loop_naf(p, q)?);
+            if !p.is_zero() && !q.is_zero() {
+                f.mul_assign(&self.ate_pairing_loop_naf(p, q)?);
+            }
         }
 
         Ok(f)
@@ -351,7 +419,7 @@ impl<
     }
 
     fn precompute_g2(&self, g2_point: &CurvePoint<'a, CTW>, twist_inv: &Fp2<'a, FE, F>) -> Result<PrecomputedG2<'a, FE, F>, ()> {
-        // not asserting normalization, it will be asserted in the loop
+        debug_assert!(g2_point.is_normalized());
         // precompute addition and doubling coefficients
         let mut x_over_twist = g2_point.x.clone();
         x_over_twist.mul_assign(&twist_inv);
@@ -368,6 +436,10 @@ impl<
             addition_coefficients: vec![],
         };
 
+        if g2_point.is_zero() {
+            return Ok(g2_p);
+        }
+
         let mut r = ExtendedCoordinates {
             x: g2_point.x.clone(),
             y: g2_point.y.clone(),
@@ -411,7 +483,7 @@ impl<
     }
 
     fn precompute_g2_naf(&self, g2_point: &CurvePoint<'a, CTW>, twist_inv: &Fp2<'a, FE, F>) -> Result<PrecomputedG2<'a, FE, F>, ()> {
-        // not asserting normalization, it will be asserted in the loop
+        debug_assert!(g2_point.is_normalized());
         // precompute addition and doubling coefficients
         let mut x_over_twist = g2_point.x.clone();
         x_over_twist.mul_assign(&twist_inv);
@@ -428,6 +500,10 @@ impl<
             addition_coefficients: vec![],
         };
 
+        if g2_point.is_zero() {
+            return Ok(g2_p);
+        }
+
         let mut r = ExtendedCoordinates {
             x: g2_point.x.clone(),
             y: g2_point.y.clone(),
@@ -488,15 +564,11 @@ impl<
     fn ate_pairing_loop(
         &self, 
         point: &CurvePoint<'a, CB>, 
-        twist_point: &CurvePoint<'a, CTW> 
+        q: &PrecomputedG2<'a, FE, F>
     ) -> Result<Fp4<'a, FE, F>, ()> {
         debug_assert!(point.is_normalized());
-        debug_assert!(twist_point.is_normalized());
-
-        let twist_inv = self.twist.inverse().ok_or(())?;
 
         let p = self.precompute_g1(&point);
-        let q = self.precompute_g2(&twist_point, &twist_inv)?;
         let mut l1_coeff = Fp2::zero(&self.fp2_extension);
         l1_coeff.c0 = p.x.clone();
         l1_coeff.sub_assign(&q.x_over_twist);
@@ -582,15 +654,11 @@ impl<
     fn ate_pairing_loop_naf(
         &self, 
         point: &CurvePoint<'a, CB>, 
-        twist_point: &CurvePoint<'a, CTW> 
+        q: &PrecomputedG2<'a, FE, F>
     ) -> Result<Fp4<'a, FE, F>, ()> {
         debug_assert!(point.is_normalized());
-        debug_assert!(twist_point.is_normalized());
-
-        let twist_inv = self.twist.inverse().ok_or(())?;
 
         let p = self.precompute_g1(&point);
-        let q = self.precompute_g2_naf(&twist_point, &twist_inv)?;
 
         let mut l1_coeff = Fp2::zero(&self.fp2_extension);
         l1_coeff.c0 = p.x.clone();
@@ -681,18 +749,6 @@ impl<
         Ok(f)
     }
 
-    fn final_exponentiation(&self, f: &Fp4<'a, FE, F>) -> Option<Fp4<'a, FE, F>> {
-        let value_inv = f.inverse();
-        if value_inv.is_none() {
-            return None;
-        }
-        let value_inv = value_inv.expect("is some");
-        let value_to_first_chunk = self.final_exponentiation_part_one(f, &value_inv);
-        let value_inv_to_first_chunk = self.final_exponentiation_part_one(&value_inv, f);
-        
-        Some(self.final_exponentiation_part_two(&value_to_first_chunk, &value_inv_to_first_chunk))
-    }
-
     fn final_exponentiation_part_one(&self, elt: &Fp4<'a, FE, F>, elt_inv: &Fp4<'a, FE, F>) -> Fp4<'a, FE, F> {
         /* (q^2-1) */
 
@@ -734,6 +790,8 @@ impl<
     type G1 = CurvePoint<'a, CB>;
     type G2 = CurvePoint<'a, CTW>;
 
+    type PreparedG2 = PrecomputedG2<'a, FE, F>;
+
     fn pair<'b>
         (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>]) -> Option<Self::PairingResult> {
             if points.len() != twists.len() {
@@ -746,6 +804,8 @@ impl<
                 }
             }
 
+            let points = normalized_points(points);
+
             let mut pairs = Vec::with_capacity(points.len());
             for (p, q) in points.iter().zip(twists.iter()) {
                 if !p.is_zero() && !q.is_zero() {
@@ -757,20 +817,50 @@ impl<
                 return Some(Fp4::one(self.fp4_extension));
             }
 
-            let loop_result = if self.prefer_naf {
-                self.miller_loop_naf(&pairs[..])
-            } else {
-                self.miller_loop(&pairs[..])
-            };  
+            let twist_inv = self.twist.inverse()?;
 
-            if loop_result.is_err() {
-                return None;
+            let mut prepared = Vec::with_capacity(pairs.len());
+            for (_, q) in pairs.iter() {
+                prepared.push(self.prepare_twist_point(q, &twist_inv).ok()?);
             }
 
-            let loop_result = loop_result.expect("is some");
+            let pairs: Vec<_> = pairs.into_iter().map(|(p, _)| p).zip(prepared.iter()).collect();
+
+            let loop_result = self.miller_loop_prepared(&pairs[..]).ok()?;
 
             self.final_exponentiation(&loop_result)
         }   
+
+    fn prepare_g2(&self, twist: &CurvePoint<'a, CTW>) -> Option<Self::PreparedG2> {
+        let mut twist = twist.clone();
+        twist.normalize();
+
+        let twist_inv = self.twist.inverse()?;
+
+        self.prepare_twist_point(&twist, &twist_inv).ok()
+    }
+
+    fn miller_loop<'b>(&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [Self::PreparedG2]) -> Option<Self::PairingResult> {
+        if points.len() != twists.len() {
+            return None;
+        }
+
+        let pairs: Vec<_> = points.iter().zip(twists.iter()).collect();
+
+        self.miller_loop_prepared(&pairs[..]).ok()
+    }
+
+    fn final_exponentiation(&self, f: &Fp4<'a, FE, F>) -> Option<Fp4<'a, FE, F>> {
+        let value_inv = f.inverse();
+        if value_inv.is_none() {
+            return None;
+        }
+        let value_inv = value_inv.expect("is some");
+        let value_to_first_chunk = self.final_exponentiation_part_one(f, &value_inv);
+        let value_inv_to_first_chunk = self.final_exponentiation_part_one(&value_inv, f);
+        
+        Some(self.final_exponentiation_part_two(&value_to_first_chunk, &value_inv_to_first_chunk))
+    }
 }
 
 #[cfg(test)]
@@ -895,6 +985,12 @@ mod tests {
 
         let _pairing_result = engine.pair(&[p.clone()], &[q.clone()]).unwrap();
 
+        let prepared_q = engine.prepare_g2(&q).unwrap();
+        let mut f = engine.miller_loop(&[p.clone()], &[prepared_q.clone()]).unwrap();
+        f.mul_assign(&engine.miller_loop(&[p2.clone()], &[prepared_q]).unwrap());
+        let split_result = engine.final_exponentiation(&f).unwrap();
+        assert!(split_result == engine.pair(&[p.clone(), p2.clone()], &[q.clone(), q.clone()]).unwrap());
+
         let ans1 = engine.pair(&[p.clone()], &[q2]).unwrap();
         let ans2 = engine.pair(&[p2], &[q.clone()]).unwrap();
         let ans3 = engine.pair(&[p], &[q]).unwrap();
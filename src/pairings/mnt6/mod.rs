@@ -7,7 +7,7 @@ use crate::weierstrass::curve::{WeierstrassCurve, CurvePoint};
 use crate::extension_towers::fp3::{Fp3, Extension3};
 use crate::extension_towers::fp6_as_2_over_3::{Fp6, Extension2Over3};
 use crate::pairings::PairingEngine;
-use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf};
+use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf, normalized_points};
 use crate::weierstrass::Group;
 
 #[derive(Clone)]
@@ -112,25 +112,66 @@ struct PrecomputedG1<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
     pub y_by_twist: Fp3<'a, FE, F>,
 }
 
-struct PrecomputedG2<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
-    pub x: Fp3<'a, FE, F>,
-    pub y: Fp3<'a, FE, F>,
-    pub x_over_twist: Fp3<'a, FE, F>,
-    pub y_over_twist: Fp3<'a, FE, F>,
-    pub double_coefficients: Vec<AteDoubleCoefficients<'a, FE, F>>,
-    pub addition_coefficients: Vec<AteAdditionCoefficients<'a, FE, F>>,
+/// Miller loop coefficients for a fixed G2 point, can be reused between pairing calls
+pub struct PrecomputedG2<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+    pub(crate) x: Fp3<'a, FE, F>,
+    pub(crate) y: Fp3<'a, FE, F>,
+    pub(crate) x_over_twist: Fp3<'a, FE, F>,
+    pub(crate) y_over_twist: Fp3<'a, FE, F>,
+    pub(crate) double_coefficients: Vec<AteDoubleCoefficients<'a, FE, F>>,
+    pub(crate) addition_coefficients: Vec<AteAdditionCoefficients<'a, FE, F>>,
 }
 
-struct AteDoubleCoefficients<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
-    pub c_h:  Fp3<'a, FE, F>,
-    pub c_4c: Fp3<'a, FE, F>,
-    pub c_j:  Fp3<'a, FE, F>,
-    pub c_l:  Fp3<'a, FE, F>,
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for PrecomputedG2<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            x: self.x.clone(),
+            y: self.y.clone(),
+            x_over_twist: self.x_over_twist.clone(),
+            y_over_twist: self.y_over_twist.clone(),
+            double_coefficients: self.double_coefficients.clone(),
+            addition_coefficients: self.addition_coefficients.clone(),
+        }
+    }
 }
 
-struct AteAdditionCoefficients<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
-    pub c_l1: Fp3<'a, FE, F>,
-    pub c_rz: Fp3<'a, FE, F>,
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> PrecomputedG2<'a, FE, F> {
+    // point at infinity has no coefficients
+    pub fn is_zero(&self) -> bool {
+        self.double_coefficients.len() == 0
+    }
+}
+
+pub(crate) struct AteDoubleCoefficients<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+    pub(crate) c_h:  Fp3<'a, FE, F>,
+    pub(crate) c_4c: Fp3<'a, FE, F>,
+    pub(crate) c_j:  Fp3<'a, FE, F>,
+    pub(crate) c_l:  Fp3<'a, FE, F>,
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for AteDoubleCoefficients<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            c_h: self.c_h.clone(),
+            c_4c: self.c_4c.clone(),
+            c_j: self.c_j.clone(),
+            c_l: self.c_l.clone(),
+        }
+    }
+}
+
+pub(crate) struct AteAdditionCoefficients<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+    pub(crate) c_l1: Fp3<'a, FE, F>,
+    pub(crate) c_rz: Fp3<'a, FE, F>,
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for AteAdditionCoefficients<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            c_l1: self.c_l1.clone(),
+            c_rz: self.c_rz.clone(),
+        }
+    }
 }
 
 struct ExtendedCoordinates<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
@@ -147,16 +188,18 @@ impl<
         CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
         CTW: CurveParameters<BaseFieldElement = Fp3<'a, FE, F>>
     > MNT6Instance<'a, FE, F, CB, CTW> {
-    fn miller_loop<'b, I>(&self, i: I) -> Result<Fp6<'a, FE, F>, ()>
+    fn miller_loop_prepared<'b, I>(&self, i: I) -> Result<Fp6<'a, FE, F>, ()>
     where 'a: 'b,
         I: IntoIterator<
             Item = &'b (&'b CurvePoint<'a, CB>, 
-                &'b CurvePoint<'a, CTW>)
+                &'b PrecomputedG2<'a, FE, F>)
         >
     {
         let mut f = Fp6::one(self.fp6_extension);
         for (p, q) in i.into_iter() {
-            f.mul_assign(&self.ate_pairing_loop(p, q)?);
+            if !p.is_zero() && !q.is_zero() {
+                f.mul_assign(&self.ate_pairing_loop(p, q)?);
+            }
         }
 
         Ok(f)
@@ -338,7 +381,7 @@ impl<
 
 
     fn precompute_g2(&self, g2_point: &CurvePoint<'a, CTW>, twist_inv: &Fp3<'a, FE, F>) -> Result<PrecomputedG2<'a, FE, F>, ()> {
-        // not asserting normalization, it will be asserted in the loop
+        debug_assert!(g2_point.is_normalized());
         // precompute addition and doubling coefficients
         let mut x_over_twist = g2_point.x.clone();
         x_over_twist.mul_assign(&twist_inv);
@@ -355,6 +398,10 @@ impl<
             addition_coefficients: vec![],
         };
 
+        if g2_point.is_zero() {
+            return Ok(g2_p);
+        }
+
         let mut r = ExtendedCoordinates {
             x: g2_point.x.clone(),
             y: g2_point.y.clone(),
@@ -400,15 +447,11 @@ impl<
     fn ate_pairing_loop(
         &self, 
         point: &CurvePoint<'a, CB>, 
-        twist_point: &CurvePoint<'a, CTW> 
+        q: &PrecomputedG2<'a, FE, F>
     ) -> Result<Fp6<'a, FE, F>, ()> {
         debug_assert!(point.is_normalized());
-        debug_assert!(twist_point.is_normalized());
-
-        let twist_inv = self.twist.inverse().ok_or(())?;
 
         let p = self.precompute_g1(&point);
-        let q = self.precompute_g2(&twist_point, &twist_inv)?;
         let mut l1_coeff = Fp3::zero(&self.fp3_extension);
         l1_coeff.c0 = p.x.clone();
         l1_coeff.sub_assign(&q.x_over_twist);
@@ -491,18 +534,6 @@ impl<
         Ok(f)
     }
 
-    fn final_exponentiation(&self, f: &Fp6<'a, FE, F>) -> Option<Fp6<'a, FE, F>> {
-        let value_inv = f.inverse();
-        if value_inv.is_none() {
-            return None;
-        }
-        let value_inv = value_inv.expect("is some");
-        let value_to_first_chunk = self.final_exponentiation_part_one(f, &value_inv);
-        let value_inv_to_first_chunk = self.final_exponentiation_part_one(&value_inv, f);
-        
-        Some(self.final_exponentiation_part_two(&value_to_first_chunk, &value_inv_to_first_chunk))
-    }
-
     fn final_exponentiation_part_one(&self, elt: &Fp6<'a, FE, F>, elt_inv: &Fp6<'a, FE, F>) -> Fp6<'a, FE, F> {
         // (q^3-1)*(q+1)
 
@@ -549,6 +580,8 @@ impl<
     type G1 = CurvePoint<'a, CB>;
     type G2 = CurvePoint<'a, CTW>;
 
+    type PreparedG2 = PrecomputedG2<'a, FE, F>;
+
     fn pair<'b>
         (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>]) -> Option<Self::PairingResult> {
             if points.len() != twists.len() {
@@ -560,7 +593,9 @@ impl<
                     return None;
                 }
             }
-            
+
+            let points = normalized_points(points);
+
             let mut pairs = Vec::with_capacity(points.len());
             for (p, q) in points.iter().zip(twists.iter()) {
                 if !p.is_zero() && !q.is_zero() {
@@ -572,22 +607,50 @@ impl<
                 return Some(Fp6::one(self.fp6_extension));
             }
 
-            // let loop_result = if self.prefer_naf {
-            //     self.miller_loop_naf(&pairs[..])
-            // } else {
-            //     self.miller_loop(&pairs[..])
-            // };  
+            let twist_inv = self.twist.inverse()?;
 
-            let loop_result = self.miller_loop(&pairs[..]);
-
-            if loop_result.is_err() {
-                return None;
+            let mut prepared = Vec::with_capacity(pairs.len());
+            for (_, q) in pairs.iter() {
+                prepared.push(self.precompute_g2(q, &twist_inv).ok()?);
             }
 
-            let loop_result = loop_result.expect("is some");
+            let pairs: Vec<_> = pairs.into_iter().map(|(p, _)| p).zip(prepared.iter()).collect();
+
+            let loop_result = self.miller_loop_prepared(&pairs[..]).ok()?;
 
             self.final_exponentiation(&loop_result)
         }   
+
+    fn prepare_g2(&self, twist: &CurvePoint<'a, CTW>) -> Option<Self::PreparedG2> {
+        let mut twist = twist.clone();
+        twist.normalize();
+
+        let twist_inv = self.twist.inverse()?;
+
+        self.precompute_g2(&twist, &twist_inv).ok()
+    }
+
+    fn miller_loop<'b>(&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [Self::PreparedG2]) -> Option<Self::PairingResult> {
+        if points.len() != twists.len() {
+            return None;
+        }
+
+        let pairs: Vec<_> = points.iter().zip(twists.iter()).collect();
+
+        self.miller_loop_prepared(&pairs[..]).ok()
+    }
+
+    fn final_exponentiation(&self, f: &Fp6<'a, FE, F>) -> Option<Fp6<'a, FE, F>> {
+        let value_inv = f.inverse();
+        if value_inv.is_none() {
+            return None;
+        }
+        let value_inv = value_inv.expect("is some");
+        let value_to_first_chunk = self.final_exponentiation_part_one(f, &value_inv);
+        let value_inv_to_first_chunk = self.final_exponentiation_part_one(&value_inv, f);
+        
+        Some(self.final_exponentiation_part_two(&value_to_first_chunk, &value_inv_to_first_chunk))
+    }
 }
 
 #[cfg(test)]
@@ -1008,6 +1071,14 @@ mod tests {
         let mut q2 = q.mul(vec![12345678]);
         q2.normalize();
 
+        let prepared_q = engine.prepare_g2(&q).unwrap();
+        let prepared_q2 = engine.prepare_g2(&q2).unwrap();
+        assert!(engine.pair_prepared(&[p.clone()], &[prepared_q.clone()]).unwrap() == engine.pair(&[p.clone()], &[q.clone()]).unwrap());
+        assert!(
+            engine.pair_prepared(&[p.clone(), p2.clone()], &[prepared_q2, prepared_q]).unwrap() == 
+            engine.pair(&[p.clone(), p2.clone()], &[q2.clone(), q.clone()]).unwrap()
+        );
+
         let ans1 = engine.pair(&[p.clone()], &[q2]).unwrap();
         let ans2 = engine.pair(&[p2], &[q.clone()]).unwrap();
         let ans3 = engine.pair(&[p], &[q]).unwrap();
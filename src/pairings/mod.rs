@@ -1,11 +1,16 @@
 
-use crate::traits::{FieldElement};
-use crate::weierstrass::Group;
+// traits of the associated types, so callers outside of the crate can multiply Miller loop outputs and points
+pub use crate::traits::FieldElement;
+pub use crate::weierstrass::Group;
 
 pub mod bls12;
 pub mod bn;
 pub mod mnt6;
 pub mod mnt4;
+pub mod bw6;
+pub mod bls24;
+#[cfg(feature = "batch_verification")]
+pub mod batch;
 
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub enum TwistType {
@@ -18,7 +23,47 @@ pub trait PairingEngine: Sized + Clone + Send + Sync {
     type G1: Group;
     type G2: Group;
 
+    type PreparedG2: Clone;
+
     fn pair<'b> (&self, points: &'b [Self::G1], twists: &'b [Self::G2]) -> Option<Self::PairingResult>;
+
+    /// Precomputes Miller loop coefficients for a G2 point, result is specific for this engine instance
+    fn prepare_g2(&self, twist: &Self::G2) -> Option<Self::PreparedG2>;
+
+    /// Multi-Miller loop without the final exponentiation. G1 points must be normalized. Outputs of 
+    /// separate calls can be multiplied together and finalized once
+    fn miller_loop<'b> (&self, points: &'b [Self::G1], twists: &'b [Self::PreparedG2]) -> Option<Self::PairingResult>;
+
+    fn final_exponentiation(&self, f: &Self::PairingResult) -> Option<Self::PairingResult>;
+
+    /// Same as `pair`, but G2 points are prepared in advance by `prepare_g2` of the same engine,
+    /// so precomputation for fixed G2 points can be cached and reused between calls
+    fn pair_prepared<'b> (&self, points: &'b [Self::G1], twists: &'b [Self::PreparedG2]) -> Option<Self::PairingResult> {
+        if points.len() != twists.len() {
+            return None;
+        }
+
+        if !crate::features::in_gas_metering() {
+            if points.len() == 0 || twists.len() == 0 {
+                return None;
+            }
+        }
+
+        let points = normalized_points(points);
+        let loop_result = self.miller_loop(&points, twists)?;
+
+        self.final_exponentiation(&loop_result)
+    }
+}
+
+// Miller loops use affine coordinates of G1 points, so inputs of `pair` and `pair_prepared` are normalized first
+pub(crate) fn normalized_points<G: Group>(points: &[G]) -> Vec<G> {
+    points.iter().map(|p| {
+        let mut p = p.clone();
+        p.normalize();
+
+        p
+    }).collect()
 }
 
 pub(crate) fn calculate_hamming_weight(representation: &[u64]) -> u32 {
@@ -0,0 +1,714 @@
+//! Encoder of the EIP-1962 byte ABI described in `documentation/ABI.md`.
+//!
+//! `AbiEncoder` has a typed builder for every `OperationType`. `AbiOperation::encode` produces the
+//! input of `perform_operation` and `AbiOperation::encode_with_operation` the input of `API::run`
+//! that is additionally prefixed with the operation (and the curve type for pairings).
+//!
+//! All the integers are big endian. Leading zeroes are stripped from the modulus, the group order
+//! and loop parameters (those are encoded densely with a length byte), field elements and scalars
+//! are padded to the modulus and group order byte length. Values are not validated beyond what is
+//! necessary to lay them out, so the decoder is the one to reject e.g. points that are not on curve.
+
+use crate::errors::ApiError;
+
+use super::constants::*;
+use super::unified_api::OperationType;
+use super::curve_builder::{PairingFamily, PairingParameters, TwistType};
+
+pub(crate) fn strip_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+
+    &bytes[start..]
+}
+
+fn encode_length(len: usize) -> Result<u8, ApiError> {
+    if len > u8::max_value() as usize {
+        return Err(ApiError::InputError("Encoded value is too long".to_owned()));
+    }
+
+    Ok(len as u8)
+}
+
+// length byte followed by the dense big endian encoding
+pub(crate) fn encode_with_length(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    let bytes = strip_leading_zeros(bytes);
+    let mut result = vec![encode_length(bytes.len())?];
+    result.extend_from_slice(bytes);
+
+    Ok(result)
+}
+
+pub(crate) fn encode_fixed_len(bytes: &[u8], len: usize) -> Result<Vec<u8>, ApiError> {
+    let bytes = strip_leading_zeros(bytes);
+    if bytes.len() > len {
+        return Err(ApiError::InputError(format!("Encoded value is longer than {} bytes", len)));
+    }
+    let mut result = vec![0u8; len - bytes.len()];
+    result.extend_from_slice(bytes);
+
+    Ok(result)
+}
+
+fn encode_extension_element(coeffs: &[Vec<u8>], degree: usize, modulus_len: usize) -> Result<Vec<u8>, ApiError> {
+    if coeffs.len() != degree {
+        return Err(ApiError::InputError(format!("Extension field element has {} coefficients, expected {}", coeffs.len(), degree)));
+    }
+    let mut result = Vec::with_capacity(degree * modulus_len);
+    for c in coeffs.iter() {
+        result.extend(encode_fixed_len(c, modulus_len)?);
+    }
+
+    Ok(result)
+}
+
+fn encode_sign(is_negative: bool) -> u8 {
+    if is_negative { SIGN_MINUS } else { SIGN_PLUS }
+}
+
+fn encode_boolean(value: bool) -> u8 {
+    if value { BOOLEAN_TRUE } else { BOOLEAN_FALSE }
+}
+
+fn encode_twist_type(twist_type: TwistType) -> u8 {
+    match twist_type {
+        TwistType::M => TWIST_TYPE_M,
+        TwistType::D => TWIST_TYPE_D,
+    }
+}
+
+/// Encoded input of a single operation
+pub trait AbiOperation {
+    fn operation(&self) -> OperationType;
+
+    /// Input of `perform_operation` for `self.operation()`
+    fn encode(&self) -> Result<Vec<u8>, ApiError>;
+
+    /// Input of `API::run`
+    fn encode_with_operation(&self) -> Result<Vec<u8>, ApiError> {
+        let mut encoding = match self.operation() {
+            OperationType::G1ADD => vec![OPERATION_G1_ADD],
+            OperationType::G1MUL => vec![OPERATION_G1_MUL],
+            OperationType::G1MULTIEXP => vec![OPERATION_G1_MULTIEXP],
+            OperationType::G2ADD => vec![OPERATION_G2_ADD],
+            OperationType::G2MUL => vec![OPERATION_G2_MUL],
+            OperationType::G2MULTIEXP => vec![OPERATION_G2_MULTIEXP],
+            OperationType::BLS12PAIR => vec![OPERATION_PAIRING, BLS12],
+            OperationType::BNPAIR => vec![OPERATION_PAIRING, BN],
+            OperationType::MNT4PAIR => vec![OPERATION_PAIRING, MNT4],
+            OperationType::MNT6PAIR => vec![OPERATION_PAIRING, MNT6],
+            OperationType::BW6PAIR => vec![OPERATION_PAIRING, BW6],
+            OperationType::BLS24PAIR => vec![OPERATION_PAIRING, BLS24],
+        };
+        encoding.extend(self.encode()?);
+
+        Ok(encoding)
+    }
+}
+
+/// Curve description and point encoding of a group, implemented for `G1Curve` and `G2Curve`
+pub trait CurveEncoding: Clone {
+    type Point: Clone;
+
+    const ADD: OperationType;
+    const MUL: OperationType;
+    const MULTIEXP: OperationType;
+
+    /// Common prefix of all the operations in the group
+    fn encode_prefix(&self) -> Result<Vec<u8>, ApiError>;
+    fn encode_point(&self, point: &Self::Point) -> Result<Vec<u8>, ApiError>;
+    fn encode_scalar(&self, scalar: &[u8]) -> Result<Vec<u8>, ApiError>;
+}
+
+/// Curve over the base field, `a` and `b` are the coefficients of the short Weierstrass form
+#[derive(Clone, Debug)]
+pub struct G1Curve {
+    pub modulus: Vec<u8>,
+    pub a: Vec<u8>,
+    pub b: Vec<u8>,
+    pub order: Vec<u8>,
+}
+
+impl G1Curve {
+    pub fn new(modulus: &[u8], a: &[u8], b: &[u8], order: &[u8]) -> Self {
+        Self {
+            modulus: modulus.to_vec(),
+            a: a.to_vec(),
+            b: b.to_vec(),
+            order: order.to_vec(),
+        }
+    }
+
+    /// Byte length of an encoded base field element
+    pub fn modulus_len(&self) -> usize {
+        strip_leading_zeros(&self.modulus).len()
+    }
+
+    /// Byte length of an encoded scalar
+    pub fn order_len(&self) -> usize {
+        strip_leading_zeros(&self.order).len()
+    }
+}
+
+/// Affine point over the base field, the point at infinity is encoded as (0, 0)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G1Point {
+    pub x: Vec<u8>,
+    pub y: Vec<u8>,
+}
+
+impl G1Point {
+    pub fn new(x: &[u8], y: &[u8]) -> Self {
+        Self {
+            x: x.to_vec(),
+            y: y.to_vec(),
+        }
+    }
+
+    pub fn infinity() -> Self {
+        Self::new(&[], &[])
+    }
+}
+
+impl CurveEncoding for G1Curve {
+    type Point = G1Point;
+
+    const ADD: OperationType = OperationType::G1ADD;
+    const MUL: OperationType = OperationType::G1MUL;
+    const MULTIEXP: OperationType = OperationType::G1MULTIEXP;
+
+    fn encode_prefix(&self) -> Result<Vec<u8>, ApiError> {
+        let mut encoding = encode_with_length(&self.modulus)?;
+        let modulus_len = self.modulus_len();
+        encoding.extend(encode_fixed_len(&self.a, modulus_len)?);
+        encoding.extend(encode_fixed_len(&self.b, modulus_len)?);
+        encoding.extend(encode_with_length(&self.order)?);
+
+        Ok(encoding)
+    }
+
+    fn encode_point(&self, point: &G1Point) -> Result<Vec<u8>, ApiError> {
+        let modulus_len = self.modulus_len();
+        let mut encoding = encode_fixed_len(&point.x, modulus_len)?;
+        encoding.extend(encode_fixed_len(&point.y, modulus_len)?);
+
+        Ok(encoding)
+    }
+
+    fn encode_scalar(&self, scalar: &[u8]) -> Result<Vec<u8>, ApiError> {
+        encode_fixed_len(scalar, self.order_len())
+    }
+}
+
+/// Curve over Fp2 or Fp3 built with `fp_non_residue`. Coefficients `a` and `b` are given as
+/// (c0, c1) or (c0, c1, c2), their number defines the extension degree
+#[derive(Clone, Debug)]
+pub struct G2Curve {
+    pub modulus: Vec<u8>,
+    pub fp_non_residue: Vec<u8>,
+    pub a: Vec<Vec<u8>>,
+    pub b: Vec<Vec<u8>>,
+    pub order: Vec<u8>,
+}
+
+impl G2Curve {
+    pub fn new(modulus: &[u8], fp_non_residue: &[u8], a: &[&[u8]], b: &[&[u8]], order: &[u8]) -> Self {
+        Self {
+            modulus: modulus.to_vec(),
+            fp_non_residue: fp_non_residue.to_vec(),
+            a: a.iter().map(|c| c.to_vec()).collect(),
+            b: b.iter().map(|c| c.to_vec()).collect(),
+            order: order.to_vec(),
+        }
+    }
+
+    pub fn extension_degree(&self) -> usize {
+        self.a.len()
+    }
+
+    pub fn modulus_len(&self) -> usize {
+        strip_leading_zeros(&self.modulus).len()
+    }
+
+    pub fn order_len(&self) -> usize {
+        strip_leading_zeros(&self.order).len()
+    }
+}
+
+/// Affine point over Fp2 or Fp3, coordinates are given coefficient by coefficient. The point at
+/// infinity is encoded as all zeroes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G2Point {
+    pub x: Vec<Vec<u8>>,
+    pub y: Vec<Vec<u8>>,
+}
+
+impl G2Point {
+    pub fn new(x: &[&[u8]], y: &[&[u8]]) -> Self {
+        Self {
+            x: x.iter().map(|c| c.to_vec()).collect(),
+            y: y.iter().map(|c| c.to_vec()).collect(),
+        }
+    }
+
+    pub fn infinity() -> Self {
+        Self::new(&[], &[])
+    }
+
+    fn encode(&self, modulus_len: usize, extension_degree: usize) -> Result<Vec<u8>, ApiError> {
+        if self.x.is_empty() && self.y.is_empty() {
+            return Ok(vec![0u8; 2 * extension_degree * modulus_len]);
+        }
+        let mut encoding = encode_extension_element(&self.x, extension_degree, modulus_len)?;
+        encoding.extend(encode_extension_element(&self.y, extension_degree, modulus_len)?);
+
+        Ok(encoding)
+    }
+}
+
+impl CurveEncoding for G2Curve {
+    type Point = G2Point;
+
+    const ADD: OperationType = OperationType::G2ADD;
+    const MUL: OperationType = OperationType::G2MUL;
+    const MULTIEXP: OperationType = OperationType::G2MULTIEXP;
+
+    fn encode_prefix(&self) -> Result<Vec<u8>, ApiError> {
+        let extension_degree = self.extension_degree();
+        let degree_encoding = match extension_degree {
+            2 => EXTENSION_DEGREE_2,
+            3 => EXTENSION_DEGREE_3,
+            _ => {
+                return Err(ApiError::InputError(format!("Unsupported extension degree {}", extension_degree)));
+            }
+        };
+        let modulus_len = self.modulus_len();
+        let mut encoding = encode_with_length(&self.modulus)?;
+        encoding.push(degree_encoding);
+        encoding.extend(encode_fixed_len(&self.fp_non_residue, modulus_len)?);
+        encoding.extend(encode_extension_element(&self.a, extension_degree, modulus_len)?);
+        encoding.extend(encode_extension_element(&self.b, extension_degree, modulus_len)?);
+        encoding.extend(encode_with_length(&self.order)?);
+
+        Ok(encoding)
+    }
+
+    fn encode_point(&self, point: &G2Point) -> Result<Vec<u8>, ApiError> {
+        point.encode(self.modulus_len(), self.extension_degree())
+    }
+
+    fn encode_scalar(&self, scalar: &[u8]) -> Result<Vec<u8>, ApiError> {
+        encode_fixed_len(scalar, self.order_len())
+    }
+}
+
+/// Parameters of MNT4 and MNT6 curves as in the pairing ABI. `fp_non_residue` builds Fp2 for MNT4
+/// and Fp3 for MNT6 curves
+#[derive(Clone, Debug)]
+pub struct MntPairingParameters {
+    pub fp_non_residue: Vec<u8>,
+    pub x: Vec<u8>,
+    pub x_is_negative: bool,
+    pub exp_w0: Vec<u8>,
+    pub exp_w1: Vec<u8>,
+    pub exp_w0_is_negative: bool,
+}
+
+/// Parameters of BW6 curves as in the pairing ABI. `fp_non_residue` builds Fp3, the optimal ate pairing
+/// is a product of Miller loops for `ate_loop_1` and `ate_loop_2`
+#[derive(Clone, Debug)]
+pub struct Bw6PairingParameters {
+    pub fp_non_residue: Vec<u8>,
+    pub twist_type: TwistType,
+    pub ate_loop_1: Vec<u8>,
+    pub ate_loop_1_is_negative: bool,
+    pub ate_loop_2: Vec<u8>,
+    pub ate_loop_2_is_negative: bool,
+    pub exp_w0: Vec<u8>,
+    pub exp_w1: Vec<u8>,
+    pub exp_w0_is_negative: bool,
+}
+
+/// Parameters of BLS24 curves as in the pairing ABI. `fp_non_residue` builds Fp2, Fp4 is Fp2[v] / (v^2 - u)
+/// and `fp4_non_residue` given as (c0, c1, c2, c3) builds Fp12 and Fp24 over it
+#[derive(Clone, Debug)]
+pub struct Bls24PairingParameters {
+    pub fp_non_residue: Vec<u8>,
+    pub fp4_non_residue: Vec<Vec<u8>>,
+    pub twist_type: TwistType,
+    pub x: Vec<u8>,
+    pub x_is_negative: bool,
+}
+
+/// Entry point for the typed builders
+pub struct AbiEncoder;
+
+impl AbiEncoder {
+    pub fn g1_add(curve: &G1Curve, p0: &G1Point, p1: &G1Point) -> AddBuilder<G1Curve> {
+        AddBuilder::new(curve, p0, p1)
+    }
+
+    pub fn g1_mul(curve: &G1Curve, point: &G1Point, scalar: &[u8]) -> MulBuilder<G1Curve> {
+        MulBuilder::new(curve, point, scalar)
+    }
+
+    /// Pairs of (point, scalar) are added by `MultiexpBuilder::with_pair`
+    pub fn g1_multiexp(curve: &G1Curve) -> MultiexpBuilder<G1Curve> {
+        MultiexpBuilder::new(curve)
+    }
+
+    pub fn g2_add(curve: &G2Curve, p0: &G2Point, p1: &G2Point) -> AddBuilder<G2Curve> {
+        AddBuilder::new(curve, p0, p1)
+    }
+
+    pub fn g2_mul(curve: &G2Curve, point: &G2Point, scalar: &[u8]) -> MulBuilder<G2Curve> {
+        MulBuilder::new(curve, point, scalar)
+    }
+
+    pub fn g2_multiexp(curve: &G2Curve) -> MultiexpBuilder<G2Curve> {
+        MultiexpBuilder::new(curve)
+    }
+
+    /// BLS12 or BN pairing depending on `params.family`
+    pub fn bls12_bn_pairing(curve: &G1Curve, params: &PairingParameters) -> Bls12BnPairingBuilder {
+        Bls12BnPairingBuilder {
+            curve: curve.clone(),
+            params: params.clone(),
+            pairs: vec![],
+        }
+    }
+
+    pub fn mnt4_pairing(curve: &G1Curve, params: &MntPairingParameters) -> MntPairingBuilder {
+        MntPairingBuilder::new(OperationType::MNT4PAIR, curve, params)
+    }
+
+    pub fn mnt6_pairing(curve: &G1Curve, params: &MntPairingParameters) -> MntPairingBuilder {
+        MntPairingBuilder::new(OperationType::MNT6PAIR, curve, params)
+    }
+
+    pub fn bw6_pairing(curve: &G1Curve, params: &Bw6PairingParameters) -> Bw6PairingBuilder {
+        Bw6PairingBuilder {
+            curve: curve.clone(),
+            params: params.clone(),
+            pairs: vec![],
+        }
+    }
+
+    pub fn bls24_pairing(curve: &G1Curve, params: &Bls24PairingParameters) -> Bls24PairingBuilder {
+        Bls24PairingBuilder {
+            curve: curve.clone(),
+            params: params.clone(),
+            pairs: vec![],
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AddBuilder<C: CurveEncoding> {
+    curve: C,
+    p0: C::Point,
+    p1: C::Point,
+}
+
+impl<C: CurveEncoding> AddBuilder<C> {
+    fn new(curve: &C, p0: &C::Point, p1: &C::Point) -> Self {
+        Self {
+            curve: curve.clone(),
+            p0: p0.clone(),
+            p1: p1.clone(),
+        }
+    }
+}
+
+impl<C: CurveEncoding> AbiOperation for AddBuilder<C> {
+    fn operation(&self) -> OperationType {
+        C::ADD
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, ApiError> {
+        let mut encoding = self.curve.encode_prefix()?;
+        encoding.extend(self.curve.encode_point(&self.p0)?);
+        encoding.extend(self.curve.encode_point(&self.p1)?);
+
+        Ok(encoding)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MulBuilder<C: CurveEncoding> {
+    curve: C,
+    point: C::Point,
+    scalar: Vec<u8>,
+}
+
+impl<C: CurveEncoding> MulBuilder<C> {
+    fn new(curve: &C, point: &C::Point, scalar: &[u8]) -> Self {
+        Self {
+            curve: curve.clone(),
+            point: point.clone(),
+            scalar: scalar.to_vec(),
+        }
+    }
+}
+
+impl<C: CurveEncoding> AbiOperation for MulBuilder<C> {
+    fn operation(&self) -> OperationType {
+        C::MUL
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, ApiError> {
+        let mut encoding = self.curve.encode_prefix()?;
+        encoding.extend(self.curve.encode_point(&self.point)?);
+        encoding.extend(self.curve.encode_scalar(&self.scalar)?);
+
+        Ok(encoding)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MultiexpBuilder<C: CurveEncoding> {
+    curve: C,
+    pairs: Vec<(C::Point, Vec<u8>)>,
+}
+
+impl<C: CurveEncoding> MultiexpBuilder<C> {
+    fn new(curve: &C) -> Self {
+        Self {
+            curve: curve.clone(),
+            pairs: vec![],
+        }
+    }
+
+    pub fn with_pair(mut self, point: &C::Point, scalar: &[u8]) -> Self {
+        self.pairs.push((point.clone(), scalar.to_vec()));
+
+        self
+    }
+}
+
+impl<C: CurveEncoding> AbiOperation for MultiexpBuilder<C> {
+    fn operation(&self) -> OperationType {
+        C::MULTIEXP
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, ApiError> {
+        let mut encoding = self.curve.encode_prefix()?;
+        encoding.push(encode_length(self.pairs.len())?);
+        for (point, scalar) in self.pairs.iter() {
+            encoding.extend(self.curve.encode_point(point)?);
+            encoding.extend(self.curve.encode_scalar(scalar)?);
+        }
+
+        Ok(encoding)
+    }
+}
+
+// pair of points with flags of subgroup checks
+#[derive(Clone, Debug)]
+struct PairingPair {
+    g1: G1Point,
+    check_g1: bool,
+    g2: G2Point,
+    check_g2: bool,
+}
+
+fn encode_pairs(pairs: &[PairingPair], modulus_len: usize, extension_degree: usize) -> Result<Vec<u8>, ApiError> {
+    let mut encoding = vec![encode_length(pairs.len())?];
+    for pair in pairs.iter() {
+        encoding.push(encode_boolean(pair.check_g1));
+        encoding.extend(encode_fixed_len(&pair.g1.x, modulus_len)?);
+        encoding.extend(encode_fixed_len(&pair.g1.y, modulus_len)?);
+        encoding.push(encode_boolean(pair.check_g2));
+        encoding.extend(pair.g2.encode(modulus_len, extension_degree)?);
+    }
+
+    Ok(encoding)
+}
+
+// pairing ABI input of BLS12 and BN curves without the pairs
+pub(crate) fn encode_bls12_bn_prefix(curve: &G1Curve, params: &PairingParameters) -> Result<Vec<u8>, ApiError> {
+    let modulus_len = curve.modulus_len();
+    let mut encoding = curve.encode_prefix()?;
+    encoding.extend(encode_fixed_len(&params.fp_non_residue, modulus_len)?);
+    encoding.extend(encode_fixed_len(&params.fp2_non_residue.0, modulus_len)?);
+    encoding.extend(encode_fixed_len(&params.fp2_non_residue.1, modulus_len)?);
+    encoding.push(encode_twist_type(params.twist_type));
+    encoding.extend(encode_with_length(&params.x)?);
+    encoding.push(encode_sign(params.x_is_negative));
+
+    Ok(encoding)
+}
+
+#[derive(Clone, Debug)]
+pub struct Bls12BnPairingBuilder {
+    curve: G1Curve,
+    params: PairingParameters,
+    pairs: Vec<PairingPair>,
+}
+
+impl Bls12BnPairingBuilder {
+    /// Adds a pair of points that are checked to be in the main subgroup
+    pub fn with_pair(self, g1: &G1Point, g2: &G2Point) -> Self {
+        self.with_pair_and_checks(g1, true, g2, true)
+    }
+
+    pub fn with_pair_and_checks(mut self, g1: &G1Point, check_g1: bool, g2: &G2Point, check_g2: bool) -> Self {
+        self.pairs.push(PairingPair { g1: g1.clone(), check_g1, g2: g2.clone(), check_g2 });
+
+        self
+    }
+}
+
+impl AbiOperation for Bls12BnPairingBuilder {
+    fn operation(&self) -> OperationType {
+        match self.params.family {
+            PairingFamily::Bls12 => OperationType::BLS12PAIR,
+            PairingFamily::Bn => OperationType::BNPAIR,
+        }
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, ApiError> {
+        let mut encoding = encode_bls12_bn_prefix(&self.curve, &self.params)?;
+        encoding.extend(encode_pairs(&self.pairs, self.curve.modulus_len(), 2)?);
+
+        Ok(encoding)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MntPairingBuilder {
+    operation: OperationType,
+    curve: G1Curve,
+    params: MntPairingParameters,
+    pairs: Vec<PairingPair>,
+}
+
+impl MntPairingBuilder {
+    fn new(operation: OperationType, curve: &G1Curve, params: &MntPairingParameters) -> Self {
+        Self {
+            operation,
+            curve: curve.clone(),
+            params: params.clone(),
+            pairs: vec![],
+        }
+    }
+
+    /// Adds a pair of points that are checked to be in the main subgroup. G2 points are in Fp2
+    /// for MNT4 and in Fp3 for MNT6 curves
+    pub fn with_pair(self, g1: &G1Point, g2: &G2Point) -> Self {
+        self.with_pair_and_checks(g1, true, g2, true)
+    }
+
+    pub fn with_pair_and_checks(mut self, g1: &G1Point, check_g1: bool, g2: &G2Point, check_g2: bool) -> Self {
+        self.pairs.push(PairingPair { g1: g1.clone(), check_g1, g2: g2.clone(), check_g2 });
+
+        self
+    }
+}
+
+impl AbiOperation for MntPairingBuilder {
+    fn operation(&self) -> OperationType {
+        self.operation
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, ApiError> {
+        let modulus_len = self.curve.modulus_len();
+        let extension_degree = if self.operation == OperationType::MNT4PAIR { 2 } else { 3 };
+
+        let mut encoding = self.curve.encode_prefix()?;
+        encoding.extend(encode_fixed_len(&self.params.fp_non_residue, modulus_len)?);
+        encoding.extend(encode_with_length(&self.params.x)?);
+        encoding.push(encode_sign(self.params.x_is_negative));
+        encoding.extend(encode_with_length(&self.params.exp_w0)?);
+        encoding.extend(encode_with_length(&self.params.exp_w1)?);
+        encoding.push(encode_sign(self.params.exp_w0_is_negative));
+        encoding.extend(encode_pairs(&self.pairs, modulus_len, extension_degree)?);
+
+        Ok(encoding)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Bw6PairingBuilder {
+    curve: G1Curve,
+    params: Bw6PairingParameters,
+    pairs: Vec<PairingPair>,
+}
+
+impl Bw6PairingBuilder {
+    /// Adds a pair of points that are checked to be in the main subgroup. G2 points are on the twist
+    /// over the base field, so are encoded as G1 ones
+    pub fn with_pair(self, g1: &G1Point, g2: &G1Point) -> Self {
+        self.with_pair_and_checks(g1, true, g2, true)
+    }
+
+    pub fn with_pair_and_checks(mut self, g1: &G1Point, check_g1: bool, g2: &G1Point, check_g2: bool) -> Self {
+        let g2 = G2Point::new(&[&g2.x], &[&g2.y]);
+        self.pairs.push(PairingPair { g1: g1.clone(), check_g1, g2, check_g2 });
+
+        self
+    }
+}
+
+impl AbiOperation for Bw6PairingBuilder {
+    fn operation(&self) -> OperationType {
+        OperationType::BW6PAIR
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, ApiError> {
+        let modulus_len = self.curve.modulus_len();
+
+        let mut encoding = self.curve.encode_prefix()?;
+        encoding.extend(encode_fixed_len(&self.params.fp_non_residue, modulus_len)?);
+        encoding.push(encode_twist_type(self.params.twist_type));
+        encoding.extend(encode_with_length(&self.params.ate_loop_1)?);
+        encoding.push(encode_sign(self.params.ate_loop_1_is_negative));
+        encoding.extend(encode_with_length(&self.params.ate_loop_2)?);
+        encoding.push(encode_sign(self.params.ate_loop_2_is_negative));
+        encoding.extend(encode_with_length(&self.params.exp_w0)?);
+        encoding.extend(encode_with_length(&self.params.exp_w1)?);
+        encoding.push(encode_sign(self.params.exp_w0_is_negative));
+        encoding.extend(encode_pairs(&self.pairs, modulus_len, 1)?);
+
+        Ok(encoding)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Bls24PairingBuilder {
+    curve: G1Curve,
+    params: Bls24PairingParameters,
+    pairs: Vec<PairingPair>,
+}
+
+impl Bls24PairingBuilder {
+    /// Adds a pair of points that are checked to be in the main subgroup. G2 points are over Fp4
+    pub fn with_pair(self, g1: &G1Point, g2: &G2Point) -> Self {
+        self.with_pair_and_checks(g1, true, g2, true)
+    }
+
+    pub fn with_pair_and_checks(mut self, g1: &G1Point, check_g1: bool, g2: &G2Point, check_g2: bool) -> Self {
+        self.pairs.push(PairingPair { g1: g1.clone(), check_g1, g2: g2.clone(), check_g2 });
+
+        self
+    }
+}
+
+impl AbiOperation for Bls24PairingBuilder {
+    fn operation(&self) -> OperationType {
+        OperationType::BLS24PAIR
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, ApiError> {
+        let modulus_len = self.curve.modulus_len();
+
+        let mut encoding = self.curve.encode_prefix()?;
+        encoding.extend(encode_fixed_len(&self.params.fp_non_residue, modulus_len)?);
+        encoding.extend(encode_extension_element(&self.params.fp4_non_residue, 4, modulus_len)?);
+        encoding.push(encode_twist_type(self.params.twist_type));
+        encoding.extend(encode_with_length(&self.params.x)?);
+        encoding.push(encode_sign(self.params.x_is_negative));
+        encoding.extend(encode_pairs(&self.pairs, modulus_len, 4)?);
+
+        Ok(encoding)
+    }
+}
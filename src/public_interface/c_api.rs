@@ -1,6 +1,6 @@
 use super::unified_api::{OperationType, PREALLOCATE_FOR_ERROR_BYTES, PREALLOCATE_FOR_RESULT_BYTES, perform_operation};
 
-// this is C interface
+// this is C interface, returns zero on success or `ApiError::packed_code` of the failure
 #[no_mangle]
 pub extern "C" fn c_perform_operation(
     op: ::std::os::raw::c_char,
@@ -66,7 +66,7 @@ pub extern "C" fn c_perform_operation(
                 unsafe { *char_len = 0u32 };
             }
 
-            return 1u32;
+            return error.packed_code(input);
         }
     }
 } 
@@ -124,7 +124,61 @@ pub extern "C" fn c_meter_operation(
                 unsafe { *char_len = 0u32 };
             }
 
-            return 1u32;
+            return error.packed_code(input);
         }
     }
-} 
\ No newline at end of file
+} 
+
+/// Bounds-checked version of `c_perform_operation`. Capacities of the output and error buffers
+/// are passed explicitly and nothing is written past them. If the output buffer is too small
+/// `o_len` is set to the required length and `ERROR_CODE_BUFFER_TOO_SMALL` is returned. Error
+/// description is truncated to `err_capacity`, `err_len` is set to its full length. Returns zero
+/// on success or `ApiError::packed_code` of the failure
+#[no_mangle]
+pub unsafe extern "C" fn c_perform_operation_v2(
+    op: u8,
+    i: *const u8,
+    i_len: u32,
+    o: *mut u8,
+    o_capacity: u32,
+    o_len: *mut u32,
+    err: *mut u8,
+    err_capacity: u32,
+    err_len: *mut u32) -> u32
+{
+    use crate::errors::{ApiError, ErrorCode};
+    use super::ffi_utils::perform_with_buffers;
+
+    perform_with_buffers(i, i_len, o, o_capacity, o_len, err, err_capacity, err_len, |input| {
+        let operation = OperationType::from_u8(op).ok_or_else(|| {
+            ApiError::coded(ErrorCode::InvalidEncoding, "Unknown operation type")
+        })?;
+
+        perform_operation(operation, input)
+    })
+}
+
+/// Bounds-checked version of `c_meter_operation`, see `c_perform_operation_v2`
+#[cfg(feature = "gas_metering")]
+#[no_mangle]
+pub unsafe extern "C" fn c_meter_operation_v2(
+    op: u8,
+    i: *const u8,
+    i_len: u32,
+    o: *mut u64,
+    err: *mut u8,
+    err_capacity: u32,
+    err_len: *mut u32) -> u32
+{
+    use crate::gas_meter::meter_operation;
+    use crate::errors::{ApiError, ErrorCode};
+    use super::ffi_utils::meter_with_buffers;
+
+    meter_with_buffers(i, i_len, o, err, err_capacity, err_len, |input| {
+        let operation = OperationType::from_u8(op).ok_or_else(|| {
+            ApiError::coded(ErrorCode::InvalidEncoding, "Unknown operation type")
+        })?;
+
+        meter_operation(operation, input)
+    })
+}
@@ -5,6 +5,8 @@ pub const BLS12: u8 = 0x01;
 pub const BN: u8 = 0x02;
 pub const MNT4: u8 = 0x03;
 pub const MNT6: u8 = 0x04;
+pub const BW6: u8 = 0x05;
+pub const BLS24: u8 = 0x06;
 
 pub const TWIST_TYPE_LENGTH: usize = 1;
 pub const TWIST_TYPE_M: u8 = 0x01;
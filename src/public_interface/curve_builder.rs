@@ -0,0 +1,549 @@
+//! Runtime construction of arbitrary EIP-1962 curves.
+//!
+//! `CurveBuilder` takes curve parameters as big endian integers, validates them by the same code
+//! that handles the byte ABI and precomputes everything that doesn't depend on the points
+//! (Montgomery constants, Frobenius coefficients, parameters of the twist). Resulting `RuntimeCurve`
+//! is specialized for the number of modulus limbs once, so every operation only decodes points.
+//!
+//! Points and scalars use the same encodings as the byte ABI: every base field element is encoded
+//! big endian with the modulus byte length, Fp2 elements as c0 || c1, scalars big endian with
+//! the group order byte length.
+
+use crate::weierstrass::{Group, CurveOverFpParameters, CurveOverFp2Parameters};
+use crate::weierstrass::curve::{CurvePoint, WeierstrassCurve};
+use crate::pairings::PairingEngine;
+use crate::pairings::bls12::{Bls12Instance, Bls12InstanceParams};
+use crate::pairings::bn::{BnInstance, BnInstanceParams};
+use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+use crate::representation::ElementRepr;
+use crate::traits::ZeroAndOne;
+use crate::multiexp::peppinger;
+use crate::field::PrimeField;
+use crate::integers::*;
+
+use super::decode_g1::*;
+use super::decode_g2::*;
+use super::decode_fp::*;
+use super::decode_utils::*;
+use super::constants::*;
+use super::prepared_curve::{PreparedCurve, Tower, fp2_from_reprs};
+use super::abi_encoder::{G1Curve, CurveEncoding, encode_bls12_bn_prefix};
+
+use crate::errors::{ApiError, ErrorCode};
+
+pub use crate::pairings::TwistType;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PairingFamily {
+    Bls12,
+    Bn,
+}
+
+/// Parameters of a pairing friendly curve, same as in the pairing ABI. `x` is the curve parameter,
+/// for BN curves it's usually called `u`
+#[derive(Clone, Debug)]
+pub struct PairingParameters {
+    pub family: PairingFamily,
+    pub fp_non_residue: Vec<u8>,
+    pub fp2_non_residue: (Vec<u8>, Vec<u8>),
+    pub twist_type: TwistType,
+    pub x: Vec<u8>,
+    pub x_is_negative: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct CurveBuilder {
+    modulus: Vec<u8>,
+    a: Vec<u8>,
+    b: Vec<u8>,
+    order: Vec<u8>,
+    pairing: Option<PairingParameters>,
+}
+
+/// Decodes a hex string with an optional `0x` prefix into big endian bytes
+pub fn decode_hex(encoding: &str) -> Result<Vec<u8>, ApiError> {
+    let encoding = encoding.trim_start_matches("0x");
+    let digits: Result<Vec<u8>, ApiError> = encoding.chars().map(|c| {
+        c.to_digit(16).map(|d| d as u8).ok_or_else(|| {
+            ApiError::InputError(format!("Invalid hex character {}", c))
+        })
+    }).collect();
+    let mut digits = digits?;
+    if digits.len() % 2 == 1 {
+        digits.insert(0, 0u8);
+    }
+
+    Ok(digits.chunks(2).map(|pair| (pair[0] << 4) | pair[1]).collect())
+}
+
+impl CurveBuilder {
+    /// All parameters are big endian integers
+    pub fn new(modulus: &[u8], a: &[u8], b: &[u8], order: &[u8]) -> Self {
+        Self {
+            modulus: modulus.to_vec(),
+            a: a.to_vec(),
+            b: b.to_vec(),
+            order: order.to_vec(),
+            pairing: None,
+        }
+    }
+
+    pub fn from_hex(modulus: &str, a: &str, b: &str, order: &str) -> Result<Self, ApiError> {
+        Ok(Self::new(&decode_hex(modulus)?, &decode_hex(a)?, &decode_hex(b)?, &decode_hex(order)?))
+    }
+
+    /// Makes a curve that also supports G2 operations and pairings
+    pub fn with_pairing(mut self, params: PairingParameters) -> Self {
+        self.pairing = Some(params);
+
+        self
+    }
+
+    pub fn build(&self) -> Result<RuntimeCurve, ApiError> {
+        use crate::field::*;
+
+        let g1_prefix = self.encode_g1_prefix()?;
+        let (modulus_len, modulus, _) = parse_modulus_and_length(&g1_prefix)?;
+        let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+        if modulus_limbs < NUM_LIMBS_MIN || modulus_limbs > NUM_LIMBS_MAX {
+            return Err(ApiError::InputError(format!("Unsupported number of modulus limbs {}", modulus_limbs)));
+        }
+        let (order_len, _, _) = parse_group_order_from_encoding(&g1_prefix[(1 + modulus_len * 3)..])?;
+
+        let ops: Result<Box<dyn CurveOps>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PreparedCurve, self, prepare);
+
+        Ok(RuntimeCurve {
+            ops: ops?,
+            field_byte_len: modulus_len,
+            scalar_byte_len: order_len,
+        })
+    }
+
+    fn to_g1_curve(&self) -> G1Curve {
+        G1Curve::new(&self.modulus, &self.a, &self.b, &self.order)
+    }
+
+    // modulus, A, B and group order as in the G1 ABI
+    fn encode_g1_prefix(&self) -> Result<Vec<u8>, ApiError> {
+        self.to_g1_curve().encode_prefix()
+    }
+
+    // pairing ABI input without the curve type and the pairs
+    fn encode_pairing_prefix(&self, params: &PairingParameters) -> Result<Vec<u8>, ApiError> {
+        encode_bls12_bn_prefix(&self.to_g1_curve(), params)
+    }
+}
+
+/// Curve with precomputed parameters. G2 operations and pairings are available only if the
+/// curve was built with pairing parameters
+pub struct RuntimeCurve {
+    ops: Box<dyn CurveOps>,
+    field_byte_len: usize,
+    scalar_byte_len: usize,
+}
+
+impl RuntimeCurve {
+    /// Byte length of an encoded base field element
+    pub fn field_byte_len(&self) -> usize {
+        self.field_byte_len
+    }
+
+    /// Byte length of an encoded scalar
+    pub fn scalar_byte_len(&self) -> usize {
+        self.scalar_byte_len
+    }
+
+    pub fn supports_pairing(&self) -> bool {
+        self.ops.supports_pairing()
+    }
+
+    pub fn g1_add(&self, p0: &[u8], p1: &[u8]) -> Result<Vec<u8>, ApiError> {
+        self.ops.g1_add(p0, p1)
+    }
+
+    pub fn g1_mul(&self, point: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ApiError> {
+        self.ops.g1_mul(point, scalar)
+    }
+
+    /// Takes pairs of (point, scalar)
+    pub fn g1_multiexp(&self, pairs: &[(&[u8], &[u8])]) -> Result<Vec<u8>, ApiError> {
+        self.ops.g1_multiexp(pairs)
+    }
+
+    pub fn g2_add(&self, p0: &[u8], p1: &[u8]) -> Result<Vec<u8>, ApiError> {
+        self.ops.g2_add(p0, p1)
+    }
+
+    pub fn g2_mul(&self, point: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ApiError> {
+        self.ops.g2_mul(point, scalar)
+    }
+
+    /// Takes pairs of (point, scalar)
+    pub fn g2_multiexp(&self, pairs: &[(&[u8], &[u8])]) -> Result<Vec<u8>, ApiError> {
+        self.ops.g2_multiexp(pairs)
+    }
+
+    /// Returns the product of pairings of (G1, G2) pairs as an encoded Fp12 element, same as
+    /// `PairingApi::pair_raw`. Points must be on curve and in the main subgroup
+    pub fn pairing(&self, pairs: &[(&[u8], &[u8])]) -> Result<Vec<u8>, ApiError> {
+        self.ops.pairing(pairs)
+    }
+
+    /// Checks that the product of pairings is equal to one
+    pub fn pairing_check(&self, pairs: &[(&[u8], &[u8])]) -> Result<bool, ApiError> {
+        self.ops.pairing_check(pairs)
+    }
+}
+
+trait CurveOps: Send + Sync {
+    fn supports_pairing(&self) -> bool;
+    fn g1_add(&self, p0: &[u8], p1: &[u8]) -> Result<Vec<u8>, ApiError>;
+    fn g1_mul(&self, point: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ApiError>;
+    fn g1_multiexp(&self, pairs: &[(&[u8], &[u8])]) -> Result<Vec<u8>, ApiError>;
+    fn g2_add(&self, p0: &[u8], p1: &[u8]) -> Result<Vec<u8>, ApiError>;
+    fn g2_mul(&self, point: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ApiError>;
+    fn g2_multiexp(&self, pairs: &[(&[u8], &[u8])]) -> Result<Vec<u8>, ApiError>;
+    fn pairing(&self, pairs: &[(&[u8], &[u8])]) -> Result<Vec<u8>, ApiError>;
+    fn pairing_check(&self, pairs: &[(&[u8], &[u8])]) -> Result<bool, ApiError>;
+}
+
+impl<FE: ElementRepr> PreparedCurve<FE> {
+    fn prepare(builder: &CurveBuilder) -> Result<Box<dyn CurveOps>, ApiError> {
+        let encoding = match builder.pairing.as_ref() {
+            Some(params) => builder.encode_pairing_prefix(params)?,
+            None => builder.encode_g1_prefix()?,
+        };
+        let (curve, rest) = match builder.pairing.as_ref() {
+            Some(params) => Self::parse_pairing(params.family, &encoding)?,
+            None => Self::parse_g1(&encoding)?,
+        };
+        debug_assert!(rest.is_empty());
+
+        Ok(Box::new(curve))
+    }
+
+    fn decode_g1<'a>(
+        &self, 
+        encoding: &[u8], 
+        curve: &'a WeierstrassCurve<'a, CurveOverFpParameters<'a, FE, PrimeField<FE>>>
+    ) -> Result<CurvePoint<'a, CurveOverFpParameters<'a, FE, PrimeField<FE>>>, ApiError> {
+        if encoding.len() != 2 * self.modulus_len {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid length of G1 point encoding", encoding));
+        }
+        let (point, _) = decode_g1_point_from_xy(encoding, self.modulus_len, curve)?;
+        if !point.is_on_curve() {
+            return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G1 point is not on curve", encoding));
+        }
+
+        Ok(point)
+    }
+
+    fn decode_g2<'a>(
+        &self, 
+        encoding: &[u8], 
+        curve: &'a WeierstrassCurve<'a, CurveOverFp2Parameters<'a, FE, PrimeField<FE>>>
+    ) -> Result<CurvePoint<'a, CurveOverFp2Parameters<'a, FE, PrimeField<FE>>>, ApiError> {
+        if encoding.len() != 4 * self.modulus_len {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid length of G2 point encoding", encoding));
+        }
+        let (point, _) = decode_g2_point_from_xy_in_fp2(encoding, self.modulus_len, curve)?;
+        if !point.is_on_curve() {
+            return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G2 point is not on curve", encoding));
+        }
+
+        Ok(point)
+    }
+
+    fn decode_scalar(&self, encoding: &[u8]) -> Result<MaxGroupSizeUint, ApiError> {
+        if encoding.len() != self.order_len {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid length of scalar encoding", encoding));
+        }
+        let (scalar, _) = decode_scalar_representation(encoding, self.order_len)?;
+
+        Ok(scalar)
+    }
+
+    fn pair<'a>(&self, tower: &Tower<'a, FE>, pairs: &[(&[u8], &[u8])]) -> Result<Fp12<'a, FE, PrimeField<FE>>, ApiError> {
+        let mut g1_points = Vec::with_capacity(pairs.len());
+        let mut g2_points = Vec::with_capacity(pairs.len());
+        for (g1_encoding, g2_encoding) in pairs.iter() {
+            let g1 = self.decode_g1(g1_encoding, tower.g1_curve)?;
+            let g2 = self.decode_g2(g2_encoding, tower.g2_curve)?;
+            if !g1.check_correct_subgroup() || !g2.check_correct_subgroup() {
+                return Err(ApiError::coded(ErrorCode::PointNotInSubgroup, "G1 or G2 point is not in the expected subgroup"));
+            }
+            if !g1.is_zero() && !g2.is_zero() {
+                g1_points.push(g1);
+                g2_points.push(g2);
+            }
+        }
+
+        if g1_points.is_empty() {
+            return Ok(Fp12::one(tower.fp12_extension));
+        }
+
+        let params = tower.params;
+        let result = match params.family {
+            PairingFamily::Bls12 => {
+                let engine = Bls12Instance::from_params(Bls12InstanceParams {
+                    x: params.x.as_ref(),
+                    x_is_negative: params.x_is_negative,
+                    twist_type: params.twist_type,
+                    base_field: tower.base_field,
+                    curve: tower.g1_curve,
+                    curve_twist: tower.g2_curve,
+                    fp2_extension: tower.fp2_extension,
+                    fp6_extension: tower.fp6_extension,
+                    fp12_extension: tower.fp12_extension,
+                    force_no_naf: true
+                });
+
+                engine.pair(&g1_points, &g2_points)
+            },
+            PairingFamily::Bn => {
+                let engine = BnInstance::from_params(BnInstanceParams {
+                    u: params.x.as_ref(),
+                    six_u_plus_2: params.six_u_plus_2.as_ref(),
+                    u_is_negative: params.x_is_negative,
+                    twist_type: params.twist_type,
+                    base_field: tower.base_field,
+                    curve: tower.g1_curve,
+                    curve_twist: tower.g2_curve,
+                    fp2_extension: tower.fp2_extension,
+                    fp6_extension: tower.fp6_extension,
+                    fp12_extension: tower.fp12_extension,
+                    non_residue_in_p_minus_one_over_2: fp2_from_reprs(&params.non_residue_in_p_minus_one_over_2, tower.fp2_extension),
+                    force_no_naf: true
+                });
+
+                engine.pair(&g1_points, &g2_points)
+            }
+        };
+
+        result.ok_or_else(|| ApiError::UnknownParameter("Pairing engine returned no value".to_owned()))
+    }
+}
+
+fn check_multiexp_pairs(pairs: &[(&[u8], &[u8])]) -> Result<(), ApiError> {
+    if pairs.is_empty() {
+        return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+    }
+
+    Ok(())
+}
+
+impl<FE: ElementRepr> CurveOps for PreparedCurve<FE> {
+    fn supports_pairing(&self) -> bool {
+        self.pairing.is_some()
+    }
+
+    fn g1_add(&self, p0: &[u8], p1: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let fp_params = CurveOverFpParameters::new(&self.base_field);
+        let curve = self.g1_curve(&fp_params)?;
+        let mut p0 = self.decode_g1(p0, &curve)?;
+        let p1 = self.decode_g1(p1, &curve)?;
+        p0.add_assign(&p1);
+
+        serialize_g1_point(self.modulus_len, &p0)
+    }
+
+    fn g1_mul(&self, point: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let fp_params = CurveOverFpParameters::new(&self.base_field);
+        let curve = self.g1_curve(&fp_params)?;
+        let point = self.decode_g1(point, &curve)?;
+        let scalar = self.decode_scalar(scalar)?;
+
+        serialize_g1_point(self.modulus_len, &point.mul(&scalar))
+    }
+
+    fn g1_multiexp(&self, pairs: &[(&[u8], &[u8])]) -> Result<Vec<u8>, ApiError> {
+        check_multiexp_pairs(pairs)?;
+        let fp_params = CurveOverFpParameters::new(&self.base_field);
+        let curve = self.g1_curve(&fp_params)?;
+        let mut bases = Vec::with_capacity(pairs.len());
+        let mut scalars = Vec::with_capacity(pairs.len());
+        for (point, scalar) in pairs.iter() {
+            bases.push(self.decode_g1(point, &curve)?);
+            scalars.push(self.decode_scalar(scalar)?);
+        }
+
+        serialize_g1_point(self.modulus_len, &peppinger(&bases, scalars))
+    }
+
+    fn g2_add(&self, p0: &[u8], p1: &[u8]) -> Result<Vec<u8>, ApiError> {
+        self.with_tower(|tower| {
+            let mut p0 = self.decode_g2(p0, tower.g2_curve)?;
+            let p1 = self.decode_g2(p1, tower.g2_curve)?;
+            p0.add_assign(&p1);
+
+            serialize_g2_point_in_fp2(self.modulus_len, &p0)
+        })
+    }
+
+    fn g2_mul(&self, point: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ApiError> {
+        self.with_tower(|tower| {
+            let point = self.decode_g2(point, tower.g2_curve)?;
+            let scalar = self.decode_scalar(scalar)?;
+
+            serialize_g2_point_in_fp2(self.modulus_len, &point.mul(&scalar))
+        })
+    }
+
+    fn g2_multiexp(&self, pairs: &[(&[u8], &[u8])]) -> Result<Vec<u8>, ApiError> {
+        check_multiexp_pairs(pairs)?;
+        self.with_tower(|tower| {
+            let mut bases = Vec::with_capacity(pairs.len());
+            let mut scalars = Vec::with_capacity(pairs.len());
+            for (point, scalar) in pairs.iter() {
+                bases.push(self.decode_g2(point, tower.g2_curve)?);
+                scalars.push(self.decode_scalar(scalar)?);
+            }
+
+            serialize_g2_point_in_fp2(self.modulus_len, &peppinger(&bases, scalars))
+        })
+    }
+
+    fn pairing(&self, pairs: &[(&[u8], &[u8])]) -> Result<Vec<u8>, ApiError> {
+        self.with_tower(|tower| {
+            let result = self.pair(tower, pairs)?;
+
+            serialize_fp12_fixed_len(self.modulus_len, &result)
+        })
+    }
+
+    fn pairing_check(&self, pairs: &[(&[u8], &[u8])]) -> Result<bool, ApiError> {
+        self.with_tower(|tower| {
+            let result = self.pair(tower, pairs)?;
+
+            Ok(result == Fp12::one(tower.fp12_extension))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::engines::{bn254, bls12_381};
+    use crate::pairings::PairingEngine;
+
+    const BN254_MODULUS: &str = "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+    const BN254_ORDER: &str = "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+    const BLS12_381_MODULUS: &str = "0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+    const BLS12_381_ORDER: &str = "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+
+    fn scalar(value: u8) -> Vec<u8> {
+        let mut encoding = vec![0u8; 32];
+        encoding[31] = value;
+
+        encoding
+    }
+
+    fn bn254_builder() -> CurveBuilder {
+        let modulus = decode_hex(BN254_MODULUS).unwrap();
+        let mut minus_one = modulus.clone();
+        *minus_one.last_mut().unwrap() -= 1;
+
+        CurveBuilder::from_hex(BN254_MODULUS, "0x00", "0x03", BN254_ORDER).unwrap()
+            .with_pairing(PairingParameters {
+                family: PairingFamily::Bn,
+                fp_non_residue: minus_one,
+                fp2_non_residue: (vec![9], vec![1]),
+                twist_type: TwistType::D,
+                x: decode_hex("0x44e992b44a6909f1").unwrap(),
+                x_is_negative: false,
+            })
+    }
+
+    fn bls12_381_builder() -> CurveBuilder {
+        let modulus = decode_hex(BLS12_381_MODULUS).unwrap();
+        let mut minus_one = modulus.clone();
+        *minus_one.last_mut().unwrap() -= 1;
+
+        CurveBuilder::from_hex(BLS12_381_MODULUS, "0x00", "0x04", BLS12_381_ORDER).unwrap()
+            .with_pairing(PairingParameters {
+                family: PairingFamily::Bls12,
+                fp_non_residue: minus_one,
+                fp2_non_residue: (vec![1], vec![1]),
+                twist_type: TwistType::M,
+                x: decode_hex("0xd201000000010000").unwrap(),
+                x_is_negative: true,
+            })
+    }
+
+    #[test]
+    fn test_bn254_runtime_curve() {
+        let curve = bn254_builder().build().unwrap();
+        assert!(curve.supports_pairing());
+        assert_eq!(curve.field_byte_len(), 32);
+        assert_eq!(curve.scalar_byte_len(), 32);
+
+        let g1 = serialize_g1_point(32, &bn254::BN254_G1_GENERATOR).unwrap();
+        let g2 = serialize_g2_point_in_fp2(32, &bn254::BN254_G2_GENERATOR).unwrap();
+
+        let expected_g1 = serialize_g1_point(32, &bn254::BN254_G1_GENERATOR.mul(&[7u64])).unwrap();
+        let expected_g2 = serialize_g2_point_in_fp2(32, &bn254::BN254_G2_GENERATOR.mul(&[7u64])).unwrap();
+        assert_eq!(curve.g1_mul(&g1, &scalar(7)).unwrap(), expected_g1);
+        assert_eq!(curve.g2_mul(&g2, &scalar(7)).unwrap(), expected_g2);
+
+        let double = curve.g1_add(&g1, &g1).unwrap();
+        assert_eq!(curve.g1_mul(&g1, &scalar(2)).unwrap(), double);
+        let multiexp = curve.g1_multiexp(&[(&g1, &scalar(3)), (&double, &scalar(1))]).unwrap();
+        assert_eq!(multiexp, curve.g1_mul(&g1, &scalar(5)).unwrap());
+
+        let expected = bn254::BN254_PAIRING_ENGINE.pair(&[bn254::BN254_G1_GENERATOR], &[bn254::BN254_G2_GENERATOR]).unwrap();
+        assert_eq!(curve.pairing(&[(&g1, &g2)]).unwrap(), serialize_fp12_fixed_len(32, &expected).unwrap());
+
+        let mut minus_g1 = bn254::BN254_G1_GENERATOR;
+        minus_g1.negate();
+        let minus_g1 = serialize_g1_point(32, &minus_g1).unwrap();
+        assert!(curve.pairing_check(&[(&g1, &g2), (&minus_g1, &g2)]).unwrap());
+        assert!(!curve.pairing_check(&[(&g1, &g2)]).unwrap());
+    }
+
+    #[test]
+    fn test_bls12_381_runtime_curve() {
+        let curve = bls12_381_builder().build().unwrap();
+
+        let g1 = serialize_g1_point(48, &bls12_381::BLS12_381_G1_GENERATOR).unwrap();
+        let g2 = serialize_g2_point_in_fp2(48, &bls12_381::BLS12_381_G2_GENERATOR).unwrap();
+
+        let expected = bls12_381::BLS12_381_PAIRING_ENGINE.pair(&[bls12_381::BLS12_381_G1_GENERATOR], &[bls12_381::BLS12_381_G2_GENERATOR]).unwrap();
+        assert_eq!(curve.pairing(&[(&g1, &g2)]).unwrap(), serialize_fp12_fixed_len(48, &expected).unwrap());
+
+        let g2_double = curve.g2_add(&g2, &g2).unwrap();
+        assert_eq!(curve.g2_multiexp(&[(&g2, &scalar(2))]).unwrap(), g2_double);
+
+        // not on curve
+        let mut bad = g1.clone();
+        bad[95] ^= 1;
+        assert!(curve.g1_add(&g1, &bad).is_err());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let g1_only = CurveBuilder::from_hex(BN254_MODULUS, "0x00", "0x03", BN254_ORDER).unwrap().build().unwrap();
+        assert!(!g1_only.supports_pairing());
+        assert!(g1_only.pairing_check(&[]).is_err());
+
+        // even modulus
+        assert!(CurveBuilder::from_hex("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46", "0x00", "0x03", BN254_ORDER).unwrap().build().is_err());
+        // B is zero
+        assert!(CurveBuilder::from_hex(BN254_MODULUS, "0x00", "0x00", BN254_ORDER).unwrap().build().is_err());
+        // B is not less than the modulus
+        assert!(CurveBuilder::from_hex(BN254_MODULUS, "0x00", BN254_MODULUS, BN254_ORDER).unwrap().build().is_err());
+        assert!(CurveBuilder::new(&decode_hex(BN254_MODULUS).unwrap(), &[], &[1u8; 33], &[7]).build().is_err());
+
+        // Fp2 non-residue is a square
+        let mut builder = bn254_builder();
+        builder.pairing.as_mut().unwrap().fp_non_residue = vec![4];
+        assert!(builder.build().is_err());
+
+        // A must be zero for BLS12
+        let mut builder = bls12_381_builder();
+        builder.a = vec![1];
+        assert!(builder.build().is_err());
+
+        assert!(decode_hex("0xzz").is_err());
+        assert_eq!(decode_hex("0x123").unwrap(), vec![0x01, 0x23]);
+    }
+}
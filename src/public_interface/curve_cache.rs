@@ -0,0 +1,276 @@
+//! Optional cache of parsed curve parameters.
+//!
+//! Every EIP-1962 call carries the full curve description, and parsing it (Montgomery constants,
+//! non-residue checks, Frobenius coefficients) can take a noticeable part of a call. With the
+//! `curve_cache` feature parsed parameters are kept in a small LRU cache keyed by the exact bytes
+//! of the curve description, so repeated calls over the same curve skip this setup.
+//!
+//! Only successfully parsed curves are stored and the cached value is exactly what parsing would
+//! produce, so outputs and errors never depend on whether the cache was hit. In fuzzing and gas
+//! metering modes the cache is bypassed entirely to keep every call deterministic.
+
+use std::sync::Arc;
+
+use crate::representation::ElementRepr;
+
+use super::prepared_curve::PreparedCurve;
+use super::curve_builder::PairingFamily;
+
+use crate::errors::ApiError;
+
+/// Number of curves kept in the cache unless changed by `set_curve_cache_capacity`
+pub const DEFAULT_CURVE_CACHE_CAPACITY: usize = 16;
+
+/// Parses modulus, A, B and group order as encoded in the G1 ABI
+pub(crate) fn parse_g1_cached<FE: ElementRepr>(bytes: &[u8]) -> Result<(Arc<PreparedCurve<FE>>, &[u8]), ApiError> {
+    #[cfg(feature = "curve_cache")]
+    {
+        if let Some(prefix_len) = cache::g1_prefix_len(bytes) {
+            return cache::get_or_parse(cache::CurveKind::G1, bytes, prefix_len, PreparedCurve::parse_g1);
+        }
+    }
+
+    let (curve, rest) = PreparedCurve::parse_g1(bytes)?;
+
+    Ok((Arc::new(curve), rest))
+}
+
+/// Parses the pairing ABI input up to the number of pairs
+pub(crate) fn parse_pairing_cached<FE: ElementRepr>(family: PairingFamily, bytes: &[u8]) -> Result<(Arc<PreparedCurve<FE>>, &[u8]), ApiError> {
+    #[cfg(feature = "curve_cache")]
+    {
+        if let Some(prefix_len) = cache::pairing_prefix_len(bytes) {
+            return cache::get_or_parse(cache::CurveKind::Pairing(family), bytes, prefix_len, |bytes| PreparedCurve::parse_pairing(family, bytes));
+        }
+    }
+
+    let (curve, rest) = PreparedCurve::parse_pairing(family, bytes)?;
+
+    Ok((Arc::new(curve), rest))
+}
+
+/// Sets the maximum number of cached curves, zero disables the cache
+#[cfg(feature = "curve_cache")]
+pub fn set_curve_cache_capacity(capacity: usize) {
+    if let Ok(mut cache) = cache::CACHE.lock() {
+        cache.set_capacity(capacity);
+    }
+}
+
+#[cfg(feature = "curve_cache")]
+pub fn clear_curve_cache() {
+    if let Ok(mut cache) = cache::CACHE.lock() {
+        cache.clear();
+    }
+}
+
+#[cfg(feature = "curve_cache")]
+mod cache {
+    use std::any::{Any, TypeId};
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    use once_cell::sync::Lazy;
+
+    use crate::representation::ElementRepr;
+    use crate::public_interface::constants::*;
+    use crate::public_interface::prepared_curve::PreparedCurve;
+    use crate::public_interface::curve_builder::PairingFamily;
+    use crate::errors::ApiError;
+
+    use super::DEFAULT_CURVE_CACHE_CAPACITY;
+
+    pub(super) static CACHE: Lazy<Mutex<CurveCache>> = Lazy::new(|| {
+        Mutex::new(CurveCache::new(DEFAULT_CURVE_CACHE_CAPACITY))
+    });
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub(super) enum CurveKind {
+        G1,
+        Pairing(PairingFamily),
+    }
+
+    struct CacheEntry {
+        kind: CurveKind,
+        repr: TypeId,
+        prefix: Vec<u8>,
+        curve: Arc<dyn Any + Send + Sync>,
+    }
+
+    // least recently used entries are at the front
+    pub(super) struct CurveCache {
+        capacity: usize,
+        entries: VecDeque<CacheEntry>,
+    }
+
+    impl CurveCache {
+        pub(super) fn new(capacity: usize) -> Self {
+            Self {
+                capacity,
+                entries: VecDeque::with_capacity(capacity),
+            }
+        }
+
+        pub(super) fn set_capacity(&mut self, capacity: usize) {
+            self.capacity = capacity;
+            while self.entries.len() > capacity {
+                self.entries.pop_front();
+            }
+        }
+
+        pub(super) fn clear(&mut self) {
+            self.entries.clear();
+        }
+
+        fn position(&self, kind: CurveKind, repr: TypeId, prefix: &[u8]) -> Option<usize> {
+            self.entries.iter().position(|e| e.kind == kind && e.repr == repr && e.prefix == prefix)
+        }
+
+        fn get(&mut self, kind: CurveKind, repr: TypeId, prefix: &[u8]) -> Option<Arc<dyn Any + Send + Sync>> {
+            let idx = self.position(kind, repr, prefix)?;
+            let entry = self.entries.remove(idx)?;
+            let curve = Arc::clone(&entry.curve);
+            self.entries.push_back(entry);
+
+            Some(curve)
+        }
+
+        fn insert(&mut self, kind: CurveKind, repr: TypeId, prefix: &[u8], curve: Arc<dyn Any + Send + Sync>) {
+            if self.capacity == 0 || self.position(kind, repr, prefix).is_some() {
+                return;
+            }
+            while self.entries.len() >= self.capacity {
+                self.entries.pop_front();
+            }
+            self.entries.push_back(CacheEntry {
+                kind,
+                repr,
+                prefix: prefix.to_vec(),
+                curve,
+            });
+        }
+    }
+
+    // byte length of modulus, A, B and group order if the input is long enough to contain them
+    pub(super) fn g1_prefix_len(bytes: &[u8]) -> Option<usize> {
+        let modulus_len = *bytes.get(0)? as usize;
+        let order_len_position = BYTES_FOR_LENGTH_ENCODING + 3 * modulus_len;
+        let order_len = *bytes.get(order_len_position)? as usize;
+        let len = order_len_position + BYTES_FOR_LENGTH_ENCODING + order_len;
+
+        if len <= bytes.len() { Some(len) } else { None }
+    }
+
+    // same as above followed by non-residues, twist type, loop parameter and its sign
+    pub(super) fn pairing_prefix_len(bytes: &[u8]) -> Option<usize> {
+        let modulus_len = *bytes.get(0)? as usize;
+        let x_len_position = g1_prefix_len(bytes)? + 3 * modulus_len + TWIST_TYPE_LENGTH;
+        let x_len = *bytes.get(x_len_position)? as usize;
+        let len = x_len_position + BYTES_FOR_LENGTH_ENCODING + x_len + SIGN_ENCODING_LENGTH;
+
+        if len <= bytes.len() { Some(len) } else { None }
+    }
+
+    pub(super) fn get_or_parse<'b, FE: ElementRepr, F>(
+        kind: CurveKind,
+        bytes: &'b [u8],
+        prefix_len: usize,
+        parse: F
+    ) -> Result<(Arc<PreparedCurve<FE>>, &'b [u8]), ApiError>
+        where F: FnOnce(&'b [u8]) -> Result<(PreparedCurve<FE>, &'b [u8]), ApiError>
+    {
+        if crate::features::in_fuzzing_or_gas_metering() {
+            let (curve, rest) = parse(bytes)?;
+
+            return Ok((Arc::new(curve), rest));
+        }
+
+        let repr = TypeId::of::<FE>();
+        let (prefix, rest) = bytes.split_at(prefix_len);
+
+        let cached = CACHE.lock().ok().and_then(|mut cache| cache.get(kind, repr, prefix));
+        if let Some(curve) = cached.and_then(|c| c.downcast::<PreparedCurve<FE>>().ok()) {
+            return Ok((curve, rest));
+        }
+
+        let (curve, parsed_rest) = parse(bytes)?;
+        debug_assert_eq!(parsed_rest.len(), rest.len());
+        let curve = Arc::new(curve);
+        if let Ok(mut cache) = CACHE.lock() {
+            cache.insert(kind, repr, prefix, curve.clone());
+        }
+
+        Ok((curve, parsed_rest))
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+        use crate::field::U256Repr;
+
+        #[test]
+        fn test_lru_eviction() {
+            let repr = TypeId::of::<U256Repr>();
+            let value: Arc<dyn Any + Send + Sync> = Arc::new(0u8);
+            let mut cache = CurveCache::new(2);
+
+            cache.insert(CurveKind::G1, repr, &[1], value.clone());
+            cache.insert(CurveKind::G1, repr, &[2], value.clone());
+            assert!(cache.get(CurveKind::G1, repr, &[1]).is_some());
+            cache.insert(CurveKind::G1, repr, &[3], value.clone());
+            assert_eq!(cache.entries.len(), 2);
+            assert!(cache.get(CurveKind::G1, repr, &[2]).is_none());
+            assert!(cache.get(CurveKind::G1, repr, &[1]).is_some());
+            assert!(cache.get(CurveKind::Pairing(PairingFamily::Bn), repr, &[1]).is_none());
+
+            cache.set_capacity(0);
+            assert!(cache.entries.is_empty());
+            cache.insert(CurveKind::G1, repr, &[1], value);
+            assert!(cache.entries.is_empty());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "curve_cache"))]
+mod test {
+    extern crate hex;
+
+    use super::*;
+    use crate::field::U256Repr;
+
+    // BN254 modulus, A, B and group order followed by some point encoding
+    fn bn254_g1_input(b: u8) -> Vec<u8> {
+        let modulus = hex::decode("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47").unwrap();
+        let order = hex::decode("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001").unwrap();
+        let mut input = vec![32u8];
+        input.extend(modulus);
+        input.extend(vec![0u8; 32]);
+        input.extend(vec![0u8; 31]);
+        input.push(b);
+        input.push(32u8);
+        input.extend(order);
+        input.extend(vec![1u8, 2, 3]);
+
+        input
+    }
+
+    #[test]
+    fn test_cached_curve_matches_parsing() {
+        let input = bn254_g1_input(3);
+        let (expected, expected_rest) = PreparedCurve::<U256Repr>::parse_g1(&input).unwrap();
+        for _ in 0..2 {
+            let (curve, rest) = parse_g1_cached::<U256Repr>(&input).unwrap();
+            assert_eq!(rest, expected_rest);
+            assert_eq!(curve.modulus_len, expected.modulus_len);
+            assert_eq!(curve.order_len, expected.order_len);
+            assert!(curve.a == expected.a && curve.b == expected.b && curve.order == expected.order);
+        }
+
+        // errors are never cached
+        let input = bn254_g1_input(0);
+        let expected = PreparedCurve::<U256Repr>::parse_g1(&input).err().unwrap();
+        for _ in 0..2 {
+            assert_eq!(parse_g1_cached::<U256Repr>(&input).err().unwrap(), expected);
+        }
+    }
+}
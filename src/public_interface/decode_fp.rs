@@ -2,14 +2,20 @@ use crate::field::{SizedPrimeField};
 use crate::fp::Fp;
 use crate::extension_towers::fp2;
 use crate::extension_towers::fp3;
+use crate::extension_towers::fp4_as_2_over_2;
+use crate::extension_towers::fp6_as_2_over_3;
+use crate::extension_towers::fp6_as_3_over_2;
+use crate::extension_towers::fp12_as_2_over3_over_2;
+use crate::extension_towers::fp12_as_3_over_2_over_2;
+use crate::extension_towers::fp24_as_2_over_3_over_2_over_2;
 use crate::representation::ElementRepr;
 use crate::traits::ZeroAndOne;
 use crate::integers::MaxFieldUint;
 use crate::field::PrimeField;
 
-use crate::errors::ApiError;
+use crate::errors::{ApiError, ErrorCode};
 use super::decode_utils::*;
-use crate::field::field_from_modulus;
+use crate::field::field_from_modulus_without_square_roots;
 
 pub(crate) fn parse_base_field_from_encoding<
     'a,
@@ -17,16 +23,25 @@ pub(crate) fn parse_base_field_from_encoding<
     >(encoding: &'a [u8]) -> Result<(PrimeField<FE>, usize, MaxFieldUint, &'a [u8]), ApiError>
 {
     let ((modulus, modulus_len), rest) = get_base_field_params(&encoding)?;
-    let field = field_from_modulus::<FE>(&modulus).map_err(|_| {
+    let field = field_from_modulus_without_square_roots::<FE>(&modulus).map_err(|_| {
         ApiError::InputError("Failed to create prime field from modulus".to_owned())
     })?;
     if rest.len() < modulus_len {
-        return Err(ApiError::InputError("Input is not long enough".to_owned()));
+        return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input is not long enough", rest));
     }
 
     Ok((field, modulus_len, modulus, rest))
 }
 
+// element `encoding` is followed by the `rest` of the input
+fn not_in_field<S: Into<String>>(description: S, encoding: &[u8], rest: &[u8]) -> ApiError {
+    ApiError::Coded {
+        code: ErrorCode::NonCanonicalFieldElement,
+        remaining: Some(encoding.len() + rest.len()),
+        description: description.into(),
+    }
+}
+
 pub(crate) fn decode_fp<
     'a,
     'b,
@@ -41,7 +56,7 @@ pub(crate) fn decode_fp<
 {
     let (x_encoding, rest) = split(bytes, field_byte_len, "Input is not long enough to get Fp element")?;
     let x = Fp::from_be_bytes(base_field, x_encoding, true).map_err(|e| {
-        ApiError::InputError(format!("Failed to parse Fp element, {}", e))
+        not_in_field(format!("Failed to parse Fp element, {}", e), x_encoding, rest)
     })?;
 
     Ok((x, rest))
@@ -61,7 +76,7 @@ pub(crate) fn decode_fp_oversized<
 {
     let (encoding, rest) = split(bytes, encoding_length, "Input is not long enough to get Fp element")?;
     let x = Fp::from_be_bytes_with_padding(base_field, encoding, false, true).map_err(|e| {
-        ApiError::InputError(format!("Failed to parse Fp element, {}", e))
+        not_in_field(format!("Failed to parse Fp element, {}", e), encoding, rest)
     })?;
 
     Ok((x, rest))
@@ -81,12 +96,12 @@ pub(crate) fn decode_fp2<
 {
     let (c0_encoding, rest) = split(bytes, field_byte_len, "Input is not long enough to Fp2_c0")?;
     let c0 = Fp::from_be_bytes(extension_field.field, c0_encoding, true).map_err(|e| {
-        ApiError::InputError(format!("Failed to parse Fp2.c0 element, {}", e))
+        not_in_field(format!("Failed to parse Fp2.c0 element, {}", e), c0_encoding, rest)
     })?;
 
     let (c1_encoding, rest) = split(rest, field_byte_len, "Input is not long enough to get Fp2_c1")?;
     let c1 = Fp::from_be_bytes(extension_field.field, c1_encoding, true).map_err(|e| {
-        ApiError::InputError(format!("Failed to parse Fp2.c1 element, {}", e))
+        not_in_field(format!("Failed to parse Fp2.c1 element, {}", e), c1_encoding, rest)
     })?;
 
     let mut x = fp2::Fp2::zero(extension_field);
@@ -110,12 +125,12 @@ pub(crate) fn decode_fp2_oversized<
 {
     let (c0_encoding, rest) = split(bytes, encoding_length, "Input is not long enough to Fp2_c0")?;
     let c0 = Fp::from_be_bytes_with_padding(extension_field.field, c0_encoding, false, true).map_err(|e| {
-        ApiError::InputError(format!("Failed to parse Fp.c0 element, {}", e))
+        not_in_field(format!("Failed to parse Fp.c0 element, {}", e), c0_encoding, rest)
     })?;
 
     let (c1_encoding, rest) = split(rest, encoding_length, "Input is not long enough to get Fp2_c1")?;
     let c1 = Fp::from_be_bytes_with_padding(extension_field.field, c1_encoding, false, true).map_err(|e| {
-        ApiError::InputError(format!("Failed to parse Fp2.c1 element, {}", e))
+        not_in_field(format!("Failed to parse Fp2.c1 element, {}", e), c1_encoding, rest)
     })?;
 
     let mut x = fp2::Fp2::zero(extension_field);
@@ -139,17 +154,17 @@ pub(crate) fn decode_fp3<
 {
     let (c0_encoding, rest) = split(bytes, field_byte_len, "Input is not long enough to Fp3_c0")?;
     let c0 = Fp::from_be_bytes(extension_field.field, c0_encoding, true).map_err(|_| {
-        ApiError::InputError("Failed to parse Fp3 element".to_owned())
+        not_in_field("Failed to parse Fp3 element", c0_encoding, rest)
     })?;
 
     let (c1_encoding, rest) = split(rest, field_byte_len, "Input is not long enough to Fp3_c1")?;
     let c1 = Fp::from_be_bytes(extension_field.field, c1_encoding, true).map_err(|_| {
-        ApiError::InputError("Failed to parse Fp3 element".to_owned())
+        not_in_field("Failed to parse Fp3 element", c1_encoding, rest)
     })?;
 
     let (c2_encoding, rest) = split(rest, field_byte_len, "Input is not long enough to Fp3_c2")?;
     let c2 = Fp::from_be_bytes(extension_field.field, c2_encoding, true).map_err(|_| {
-        ApiError::InputError("Failed to parse Fp3element".to_owned())
+        not_in_field("Failed to parse Fp3element", c2_encoding, rest)
     })?;
 
     let mut x = fp3::Fp3::zero(extension_field);
@@ -160,6 +175,146 @@ pub(crate) fn decode_fp3<
     Ok((x, rest))
 }
 
+// Elements of extension towers are encoded as a concatenation of their coefficients
+// starting from c0, recursively down to the base field elements, so for Fp12 the order is
+// c0.c0.c0, c0.c0.c1, c0.c1.c0, c0.c1.c1, c0.c2.c0, c0.c2.c1, c1.c0.c0, ..., c1.c2.c1
+
+pub(crate) fn decode_fp4<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+(
+    bytes: &'b [u8], 
+    field_byte_len: usize,
+    extension_field: &'a fp4_as_2_over_2::Extension2Over2<'a, FE, F>
+) -> Result<(fp4_as_2_over_2::Fp4<'a, FE, F>, &'b [u8]), ApiError>
+{
+    let (c0, rest) = decode_fp2(bytes, field_byte_len, extension_field.field)?;
+    let (c1, rest) = decode_fp2(rest, field_byte_len, extension_field.field)?;
+
+    let mut x = fp4_as_2_over_2::Fp4::zero(extension_field);
+    x.c0 = c0;
+    x.c1 = c1;
+
+    Ok((x, rest))
+}
+
+pub(crate) fn decode_fp6_as_2_over_3<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+(
+    bytes: &'b [u8], 
+    field_byte_len: usize,
+    extension_field: &'a fp6_as_2_over_3::Extension2Over3<'a, FE, F>
+) -> Result<(fp6_as_2_over_3::Fp6<'a, FE, F>, &'b [u8]), ApiError>
+{
+    let (c0, rest) = decode_fp3(bytes, field_byte_len, extension_field.field)?;
+    let (c1, rest) = decode_fp3(rest, field_byte_len, extension_field.field)?;
+
+    let mut x = fp6_as_2_over_3::Fp6::zero(extension_field);
+    x.c0 = c0;
+    x.c1 = c1;
+
+    Ok((x, rest))
+}
+
+pub(crate) fn decode_fp6_as_3_over_2<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+(
+    bytes: &'b [u8], 
+    field_byte_len: usize,
+    extension_field: &'a fp6_as_3_over_2::Extension3Over2<'a, FE, F>
+) -> Result<(fp6_as_3_over_2::Fp6<'a, FE, F>, &'b [u8]), ApiError>
+{
+    let (c0, rest) = decode_fp2(bytes, field_byte_len, extension_field.field)?;
+    let (c1, rest) = decode_fp2(rest, field_byte_len, extension_field.field)?;
+    let (c2, rest) = decode_fp2(rest, field_byte_len, extension_field.field)?;
+
+    let mut x = fp6_as_3_over_2::Fp6::zero(extension_field);
+    x.c0 = c0;
+    x.c1 = c1;
+    x.c2 = c2;
+
+    Ok((x, rest))
+}
+
+pub(crate) fn decode_fp12<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+(
+    bytes: &'b [u8], 
+    field_byte_len: usize,
+    extension_field: &'a fp12_as_2_over3_over_2::Extension2Over3Over2<'a, FE, F>
+) -> Result<(fp12_as_2_over3_over_2::Fp12<'a, FE, F>, &'b [u8]), ApiError>
+{
+    let (c0, rest) = decode_fp6_as_3_over_2(bytes, field_byte_len, extension_field.field)?;
+    let (c1, rest) = decode_fp6_as_3_over_2(rest, field_byte_len, extension_field.field)?;
+
+    let mut x = fp12_as_2_over3_over_2::Fp12::zero(extension_field);
+    x.c0 = c0;
+    x.c1 = c1;
+
+    Ok((x, rest))
+}
+
+pub(crate) fn decode_fp6_as_3_over_2_oversized<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+(
+    bytes: &'b [u8], 
+    encoding_length: usize,
+    extension_field: &'a fp6_as_3_over_2::Extension3Over2<'a, FE, F>
+) -> Result<(fp6_as_3_over_2::Fp6<'a, FE, F>, &'b [u8]), ApiError>
+{
+    let (c0, rest) = decode_fp2_oversized(bytes, encoding_length, extension_field.field)?;
+    let (c1, rest) = decode_fp2_oversized(rest, encoding_length, extension_field.field)?;
+    let (c2, rest) = decode_fp2_oversized(rest, encoding_length, extension_field.field)?;
+
+    let mut x = fp6_as_3_over_2::Fp6::zero(extension_field);
+    x.c0 = c0;
+    x.c1 = c1;
+    x.c2 = c2;
+
+    Ok((x, rest))
+}
+
+pub(crate) fn decode_fp12_oversized<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+(
+    bytes: &'b [u8], 
+    encoding_length: usize,
+    extension_field: &'a fp12_as_2_over3_over_2::Extension2Over3Over2<'a, FE, F>
+) -> Result<(fp12_as_2_over3_over_2::Fp12<'a, FE, F>, &'b [u8]), ApiError>
+{
+    let (c0, rest) = decode_fp6_as_3_over_2_oversized(bytes, encoding_length, extension_field.field)?;
+    let (c1, rest) = decode_fp6_as_3_over_2_oversized(rest, encoding_length, extension_field.field)?;
+
+    let mut x = fp12_as_2_over3_over_2::Fp12::zero(extension_field);
+    x.c0 = c0;
+    x.c1 = c1;
+
+    Ok((x, rest))
+}
+
 pub(crate) fn serialize_fp_fixed_len<
     'a,
     FE: ElementRepr,
@@ -225,4 +380,107 @@ pub(crate) fn serialize_fp3_fixed_len<
     bytes.extend(serialize_fp_fixed_len(encoding_byte_len, &element.c2)?);
 
     Ok(bytes)
-}
\ No newline at end of file
+}
+pub(crate) fn serialize_fp4_fixed_len<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+    (
+        encoding_byte_len: usize,
+        element: &'a fp4_as_2_over_2::Fp4<'a, FE, F>
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let mut bytes = Vec::with_capacity(4*encoding_byte_len);
+    bytes.extend(serialize_fp2_fixed_len(encoding_byte_len, &element.c0)?);
+    bytes.extend(serialize_fp2_fixed_len(encoding_byte_len, &element.c1)?);
+
+    Ok(bytes)
+}
+
+pub(crate) fn serialize_fp6_as_2_over_3_fixed_len<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+    (
+        encoding_byte_len: usize,
+        element: &'a fp6_as_2_over_3::Fp6<'a, FE, F>
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let mut bytes = Vec::with_capacity(6*encoding_byte_len);
+    bytes.extend(serialize_fp3_fixed_len(encoding_byte_len, &element.c0)?);
+    bytes.extend(serialize_fp3_fixed_len(encoding_byte_len, &element.c1)?);
+
+    Ok(bytes)
+}
+
+pub(crate) fn serialize_fp6_as_3_over_2_fixed_len<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+    (
+        encoding_byte_len: usize,
+        element: &'a fp6_as_3_over_2::Fp6<'a, FE, F>
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let mut bytes = Vec::with_capacity(6*encoding_byte_len);
+    bytes.extend(serialize_fp2_fixed_len(encoding_byte_len, &element.c0)?);
+    bytes.extend(serialize_fp2_fixed_len(encoding_byte_len, &element.c1)?);
+    bytes.extend(serialize_fp2_fixed_len(encoding_byte_len, &element.c2)?);
+
+    Ok(bytes)
+}
+
+pub(crate) fn serialize_fp12_fixed_len<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+    (
+        encoding_byte_len: usize,
+        element: &'a fp12_as_2_over3_over_2::Fp12<'a, FE, F>
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let mut bytes = Vec::with_capacity(12*encoding_byte_len);
+    bytes.extend(serialize_fp6_as_3_over_2_fixed_len(encoding_byte_len, &element.c0)?);
+    bytes.extend(serialize_fp6_as_3_over_2_fixed_len(encoding_byte_len, &element.c1)?);
+
+    Ok(bytes)
+}
+
+pub(crate) fn serialize_fp12_as_3_over_2_over_2_fixed_len<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+    (
+        encoding_byte_len: usize,
+        element: &'a fp12_as_3_over_2_over_2::Fp12<'a, FE, F>
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let mut bytes = Vec::with_capacity(12*encoding_byte_len);
+    bytes.extend(serialize_fp4_fixed_len(encoding_byte_len, &element.c0)?);
+    bytes.extend(serialize_fp4_fixed_len(encoding_byte_len, &element.c1)?);
+    bytes.extend(serialize_fp4_fixed_len(encoding_byte_len, &element.c2)?);
+
+    Ok(bytes)
+}
+
+pub(crate) fn serialize_fp24_fixed_len<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+    (
+        encoding_byte_len: usize,
+        element: &'a fp24_as_2_over_3_over_2_over_2::Fp24<'a, FE, F>
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let mut bytes = Vec::with_capacity(24*encoding_byte_len);
+    bytes.extend(serialize_fp12_as_3_over_2_over_2_fixed_len(encoding_byte_len, &element.c0)?);
+    bytes.extend(serialize_fp12_as_3_over_2_over_2_fixed_len(encoding_byte_len, &element.c1)?);
+
+    Ok(bytes)
+}
@@ -25,13 +25,14 @@ pub(crate) fn parse_group_order_from_encoding<
 
 pub(crate) fn parse_ab_in_base_field_from_encoding<
     'a,
+    'b,
     FE: ElementRepr,
     F: SizedPrimeField<Repr = FE>
     >(
-        encoding: &'a [u8], 
+        encoding: &'b [u8], 
         modulus_len: usize,
         base_field: &'a F
-    ) -> Result<(Fp<'a, FE, F>, Fp<'a, FE, F>, &'a [u8]), ApiError>
+    ) -> Result<(Fp<'a, FE, F>, Fp<'a, FE, F>, &'b [u8]), ApiError>
 {
     let (a, rest) = decode_fp(&encoding, modulus_len, base_field)?;
     let (b, rest) = decode_fp(&rest, modulus_len, base_field)?;
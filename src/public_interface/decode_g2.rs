@@ -3,6 +3,7 @@ use crate::fp::Fp;
 use crate::extension_towers::*;
 use crate::extension_towers::fp2;
 use crate::extension_towers::fp3;
+use crate::extension_towers::fp4_as_2_over_2;
 use crate::representation::{ElementRepr};
 use crate::weierstrass::curve::{WeierstrassCurve, CurvePoint};
 use crate::traits::FieldElement;
@@ -13,7 +14,7 @@ use super::decode_fp::*;
 use super::constants::*;
 use super::decode_utils::split;
 
-use crate::errors::ApiError;
+use crate::errors::{ApiError, ErrorCode};
 
 pub(crate) fn create_fp2_extension<
     'a,
@@ -31,19 +32,20 @@ pub(crate) fn create_fp2_extension<
 {
     let (extension_degree, rest) = split(bytes, EXTENSION_DEGREE_ENCODING_LENGTH, "Input is not long enough to get extension degree")?;
     if extension_degree[0] != EXTENSION_DEGREE_2 {
-        return Err(ApiError::UnknownParameter("Extension degree expected to be 2".to_owned()));
+        return Err(ApiError::coded_at(ErrorCode::InvalidEncoding, "Extension degree expected to be 2", bytes));
     }
 
+    let fp_non_residue_encoding = rest;
     let (fp_non_residue, rest): (Fp<'a, FE, F>, _) = decode_fp(&rest, field_byte_len, base_field)?;
     if fp_non_residue.is_zero() {
-        return Err(ApiError::UnexpectedZero("Fp2 non-residue can not be zero".to_owned()));
+        return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, "Fp2 non-residue can not be zero", fp_non_residue_encoding));
     }
 
     {
         let not_a_square = is_non_nth_root(&fp_non_residue, modulus, 2);
         if !not_a_square {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Non-residue for Fp2 is actually a residue, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp2 is actually a residue, file {}, line {}", file!(), line!()), fp_non_residue_encoding));
             }
         }
     }
@@ -74,19 +76,20 @@ pub(crate) fn create_fp3_extension<
 {
     let (extension_degree, rest) = split(bytes, EXTENSION_DEGREE_ENCODING_LENGTH, "Input is not long enough to get extension degree")?;
     if extension_degree[0] != EXTENSION_DEGREE_3 {
-        return Err(ApiError::UnknownParameter("Extension degree expected to be 3".to_owned()));
+        return Err(ApiError::coded_at(ErrorCode::InvalidEncoding, "Extension degree expected to be 3", bytes));
     }
 
+    let fp_non_residue_encoding = rest;
     let (fp_non_residue, rest): (Fp<'a, FE, F>, _) = decode_fp(&rest, field_byte_len, base_field)?;
     if fp_non_residue.is_zero() {
-        return Err(ApiError::UnexpectedZero("Fp3 non-residue can not be zero".to_owned()));
+        return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, "Fp3 non-residue can not be zero", fp_non_residue_encoding));
     }
 
     {
         let not_a_cube = is_non_nth_root(&fp_non_residue, modulus, 3);
         if !not_a_cube {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Non-residue for Fp3 is actually a residue, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp3 is actually a residue, file {}, line {}", file!(), line!()), fp_non_residue_encoding));
             }
         }
     }
@@ -164,6 +167,27 @@ pub(crate) fn decode_g2_point_from_xy_in_fp3<
     Ok((p, rest))
 }
 
+pub(crate) fn decode_g2_point_from_xy_in_fp4<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = fp4_as_2_over_2::Fp4<'a, FE, F>>
+    >
+    (
+        bytes: &'b [u8], 
+        field_byte_len: usize,
+        curve: &'a WeierstrassCurve<'a, C>
+    ) -> Result<(CurvePoint<'a, C>, &'b [u8]), ApiError>
+{
+    let (x, rest) = decode_fp4(&bytes, field_byte_len, curve.params.params())?;
+    let (y, rest) = decode_fp4(&rest, field_byte_len, curve.params.params())?;
+    
+    let p: CurvePoint<'a, C> = CurvePoint::point_from_xy(&curve, x, y);
+    
+    Ok((p, rest))
+}
+
 pub(crate) fn serialize_g2_point_in_fp2<
     'a,
     FE: ElementRepr,
@@ -4,13 +4,13 @@ use crate::integers::{MaxFieldUint, MaxGroupSizeUint, MaxLoopParametersUint};
 
 use crate::public_interface::constants::*;
 
-use crate::errors::ApiError;
+use crate::errors::{ApiError, ErrorCode};
 
 pub(crate) fn split<'a>(bytes: &'a [u8], at: usize, err: &'static str) 
     -> Result<(&'a [u8], &'a [u8]), ApiError> 
 {
     if bytes.len() < at {
-        Err(ApiError::InputError(err.to_owned()))
+        Err(ApiError::coded_at(ErrorCode::InvalidLength, err, bytes))
     } else {
         Ok(bytes.split_at(at))
     }
@@ -32,10 +32,10 @@ pub(crate) fn decode_group_order_with_length<
     let (length_encoding, rest) = split(bytes, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get modulus length")?;
     let length = length_encoding[0] as usize;
     if length == 0 {
-        return Err(ApiError::InputError(format!("Encoded group length is zero, file {}, line {}", file!(), line!())));
+        return Err(ApiError::coded_at(ErrorCode::InvalidLength, format!("Encoded group length is zero, file {}, line {}", file!(), line!()), bytes));
     }
     if length > MAX_GROUP_BYTE_LEN {
-        return Err(ApiError::InputError(format!("Encoded group length is too large, file {}, line {}", file!(), line!())));
+        return Err(ApiError::coded_at(ErrorCode::LimitExceeded, format!("Encoded group length is too large, file {}, line {}", file!(), line!()), bytes));
     }
     let (be_encoding, rest) = split(rest, length, "Input is not long enough to get modulus")?;
     // let first_byte = be_encoding[0];
@@ -59,7 +59,7 @@ pub(crate) fn decode_sign_is_negative<
         SIGN_PLUS => false,
         SIGN_MINUS => true,
         _ => {
-            return Err(ApiError::InputError("sign is not encoded properly".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidEncoding, "sign is not encoded properly", bytes));
         },
     };
 
@@ -81,7 +81,7 @@ pub(crate) fn decode_twist_type<
         TWIST_TYPE_D => TwistType::D,
         TWIST_TYPE_M => TwistType::M, 
         _ => {
-            return Err(ApiError::UnknownParameter("Unknown twist type supplied".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidEncoding, "Unknown twist type supplied", bytes));
         },
     };
 
@@ -100,7 +100,7 @@ pub(crate) fn decode_boolean<
         BOOLEAN_FALSE => false,
         BOOLEAN_TRUE => true,
         _ => {
-            return Err(ApiError::InputError("boolean is not encoded properly".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidEncoding, "boolean is not encoded properly", bytes));
         },
     };
 
@@ -119,7 +119,7 @@ pub(crate) fn parse_modulus_and_length<
     let (length_encoding, rest) = split(bytes, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get modulus length")?;
     let length = length_encoding[0] as usize;
     if length > MAX_MODULUS_BYTE_LEN {
-        return Err(ApiError::InputError(format!("Encoded modulus length is too large, file {}, line {}", file!(), line!())));
+        return Err(ApiError::coded_at(ErrorCode::LimitExceeded, format!("Encoded modulus length is too large, file {}, line {}", file!(), line!()), bytes));
     }
     let (be_encoding, rest) = split(rest, length, "Input is not long enough to get modulus")?;
     let x = MaxFieldUint::from_big_endian(&be_encoding);
@@ -140,15 +140,16 @@ pub(crate) fn parse_modulus_and_extension_degree<'a>(bytes: &'a [u8]) -> Result<
         &'a [u8],
         &'a [u8]), ApiError> {
     let ((modulus, modulus_len), rest) = get_base_field_params(&bytes)?;
+    let extension_degree_position = rest;
     let (extension_degree, rest) = split(rest, EXTENSION_DEGREE_ENCODING_LENGTH, "Input is not long enough to get extension degree")?;
-    let extension_degree = extension_degree[0];
-    if !(extension_degree == EXTENSION_DEGREE_2 || extension_degree == EXTENSION_DEGREE_3) {
-        return Err(ApiError::InputError("Extension degree must be 2 or 3".to_owned()));
+    if !(extension_degree[0] == EXTENSION_DEGREE_2 || extension_degree[0] == EXTENSION_DEGREE_3) {
+        return Err(ApiError::coded_at(ErrorCode::InvalidEncoding, "Extension degree must be 2 or 3", extension_degree_position));
     }
+    let extension_degree = extension_degree[0];
 
     let (nonresidue_encoding, rest) = split(rest, modulus_len, "Input is not long enough to Fp non-residue")?;
     if rest.len() == 0 {
-        return Err(ApiError::InputError("Input is not long enough".to_owned()));
+        return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input is not long enough", rest));
     }
 
     Ok(
@@ -171,7 +172,7 @@ pub(crate) fn get_base_field_params(bytes: &[u8]) -> Result<((MaxFieldUint, usiz
         return Err(ApiError::InputError(format!("Modulus is length is zero, file {}, line {}", file!(), line!())));
     }
     if modulus_len > MAX_MODULUS_BYTE_LEN {
-        return Err(ApiError::InputError(format!("Encoded modulus length is too large, file {}, line {}", file!(), line!())));
+        return Err(ApiError::coded_at(ErrorCode::LimitExceeded, format!("Encoded modulus length is too large, file {}, line {}", file!(), line!()), bytes));
     }
     let (modulus_encoding, rest) = split(rest, modulus_len, "Input is not long enough to get modulus")?;
     if modulus_encoding[0] == 0u8 {
@@ -247,7 +248,7 @@ pub(crate) fn decode_loop_parameter_scalar_with_bit_limit<
         return Err(ApiError::InputError(format!("Loop parameter scalar has zero length, file {}, line {}", file!(), line!())));
     }
     if length > max_length_for_bits {
-        return Err(ApiError::InputError(format!("Loop parameter is too large for bit length, max {} bits, got {} bytes, file {}, line {}", bit_limit, length, file!(), line!())));
+        return Err(ApiError::coded_at(ErrorCode::LimitExceeded, format!("Loop parameter is too large for bit length, max {} bits, got {} bytes, file {}, line {}", bit_limit, length, file!(), line!()), bytes));
     }
     let (be_encoding, rest) = split(rest, length, "Input is not long enough to get modulus")?;
     let first_byte = be_encoding[0];
@@ -257,7 +258,7 @@ pub(crate) fn decode_loop_parameter_scalar_with_bit_limit<
     let x = MaxLoopParametersUint::from_big_endian(&be_encoding);
     let num_bits = x.bits();
     if num_bits > bit_limit {
-        return Err(ApiError::InputError(format!("Number of bits for scalar is too large, file {}, line {}", file!(), line!())));
+        return Err(ApiError::coded_at(ErrorCode::LimitExceeded, format!("Number of bits for scalar is too large, file {}, line {}", file!(), line!()), bytes));
     }
 
     Ok((x, rest))
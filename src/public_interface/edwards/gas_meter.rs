@@ -0,0 +1,91 @@
+use crate::public_interface::ApiError;
+use crate::public_interface::multiexp_discounts::meter_multiexp;
+
+use super::{
+    SCALAR_BYTE_LENGTH,
+    SERIALIZED_POINT_BYTE_LENGTH,
+};
+
+pub const JUBJUB_ADD_GAS: u64 = 180;
+pub const JUBJUB_MUL_GAS: u64 = 9000;
+pub const BANDERSNATCH_ADD_GAS: u64 = 200;
+pub const BANDERSNATCH_MUL_GAS: u64 = 9000;
+pub const BANDERSNATCH_SUBGROUP_CHECK_GAS: u64 = 6000;
+pub const BABY_JUBJUB_ADD_GAS: u64 = 170;
+pub const BABY_JUBJUB_MUL_GAS: u64 = 8500;
+
+/// Prices `EdwardsExecutor` calls. Constants are calibrated against ECADD and ECMUL from the
+/// Istanbul schedule by relative running time, multiexponentiations get the same discounts
+/// as in EIP-2537. Subgroup checks of Bandersnatch inputs are charged per point without discounts
+pub struct EdwardsGasMeter;
+
+fn meter_add<'a>(input: &'a [u8], add_cost: u64) -> Result<u64, ApiError> {
+    if input.len() != SERIALIZED_POINT_BYTE_LENGTH * 2 {
+        return Err(ApiError::InputError("invalid input length for addition".to_owned()));
+    }
+
+    Ok(add_cost)
+}
+
+fn meter_mul<'a>(input: &'a [u8], mul_cost: u64) -> Result<u64, ApiError> {
+    if input.len() != SERIALIZED_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
+        return Err(ApiError::InputError("invalid input length for multiplication".to_owned()));
+    }
+
+    Ok(mul_cost)
+}
+
+fn meter_multiexp_input<'a>(input: &'a [u8], mul_cost: u64) -> Result<u64, ApiError> {
+    if input.len() % (SERIALIZED_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
+        return Err(ApiError::InputError("invalid input length for multiexp".to_owned()));
+    }
+    let num_pairs = input.len() / (SERIALIZED_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
+
+    if num_pairs == 0 {
+        return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+    }
+
+    meter_multiexp(num_pairs, mul_cost)
+}
+
+impl EdwardsGasMeter {
+    pub fn jubjub_add<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        meter_add(input, JUBJUB_ADD_GAS)
+    }
+
+    pub fn jubjub_mul<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        meter_mul(input, JUBJUB_MUL_GAS)
+    }
+
+    pub fn jubjub_multiexp<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        meter_multiexp_input(input, JUBJUB_MUL_GAS)
+    }
+
+    pub fn bandersnatch_add<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        meter_add(input, BANDERSNATCH_ADD_GAS + 2 * BANDERSNATCH_SUBGROUP_CHECK_GAS)
+    }
+
+    pub fn bandersnatch_mul<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        meter_mul(input, BANDERSNATCH_MUL_GAS + BANDERSNATCH_SUBGROUP_CHECK_GAS)
+    }
+
+    pub fn bandersnatch_multiexp<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        let cost = meter_multiexp_input(input, BANDERSNATCH_MUL_GAS)?;
+        let num_pairs = (input.len() / (SERIALIZED_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH)) as u64;
+        let checks_cost = num_pairs.checked_mul(BANDERSNATCH_SUBGROUP_CHECK_GAS).ok_or(ApiError::Overflow)?;
+
+        cost.checked_add(checks_cost).ok_or(ApiError::Overflow)
+    }
+
+    pub fn baby_jubjub_add<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        meter_add(input, BABY_JUBJUB_ADD_GAS)
+    }
+
+    pub fn baby_jubjub_mul<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        meter_mul(input, BABY_JUBJUB_MUL_GAS)
+    }
+
+    pub fn baby_jubjub_multiexp<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        meter_multiexp_input(input, BABY_JUBJUB_MUL_GAS)
+    }
+}
@@ -0,0 +1,303 @@
+//! Executor for addition, multiplication and multiexponentiation on twisted Edwards curves
+//! defined over scalar fields of pairing friendly curves: Jubjub and Bandersnatch over the
+//! BLS12-381 one and Baby Jubjub over the BN254 one. Field elements are encoded as 32 bytes
+//! big endian, points as x || y in affine coordinates (so the neutral element is (0, 1)) and
+//! scalars as 32 bytes big endian. Points must be on the curve. Bandersnatch points are also
+//! required to be in the prime order subgroup, since addition formulas are not complete there
+
+pub struct EdwardsExecutor;
+
+use crate::engines::jubjub::JUBJUB_CURVE;
+use crate::engines::bandersnatch::BANDERSNATCH_CURVE;
+use crate::engines::baby_jubjub::BABY_JUBJUB_CURVE;
+use crate::public_interface::{ApiError, ErrorCode};
+
+pub const SCALAR_BYTE_LENGTH: usize = 32;
+
+pub const SERIALIZED_FP_BYTE_LENGTH: usize = 32;
+pub const SERIALIZED_POINT_BYTE_LENGTH: usize = SERIALIZED_FP_BYTE_LENGTH * 2;
+
+use crate::public_interface::decode_fp;
+use crate::public_interface::decode_g1;
+
+use crate::weierstrass::Group;
+use crate::edwards::curve::{EdwardsCurve, EdwardsPoint};
+use crate::multiexp::edwards_peppinger;
+use crate::field::{U256Repr, PrimeField};
+
+mod gas_meter;
+pub use self::gas_meter::*;
+
+type Curve = EdwardsCurve<'static, U256Repr, PrimeField<U256Repr>>;
+type Point = EdwardsPoint<'static, U256Repr, PrimeField<U256Repr>>;
+
+fn decode_point<'a>(bytes: &'a [u8], curve: &'static Curve, check_subgroup: bool) -> Result<(Point, &'a [u8]), ApiError> {
+    let (x, rest) = decode_fp::decode_fp(bytes, SERIALIZED_FP_BYTE_LENGTH, curve.field)?;
+    let (y, rest) = decode_fp::decode_fp(rest, SERIALIZED_FP_BYTE_LENGTH, curve.field)?;
+    let p = EdwardsPoint::point_from_xy(curve, x, y);
+    if !p.is_on_curve() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "point is not on curve", bytes));
+        }
+    }
+
+    if check_subgroup && !p.check_correct_subgroup() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "point is not in the expected subgroup", bytes));
+        }
+    }
+
+    Ok((p, rest))
+}
+
+fn serialize_point_output(point: &Point) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+    let (x, y) = point.into_xy();
+    let mut output = [0u8; SERIALIZED_POINT_BYTE_LENGTH];
+    output[..SERIALIZED_FP_BYTE_LENGTH].copy_from_slice(&decode_fp::serialize_fp_fixed_len(SERIALIZED_FP_BYTE_LENGTH, &x)?);
+    output[SERIALIZED_FP_BYTE_LENGTH..].copy_from_slice(&decode_fp::serialize_fp_fixed_len(SERIALIZED_FP_BYTE_LENGTH, &y)?);
+
+    Ok(output)
+}
+
+fn add<'a>(input: &'a [u8], curve: &'static Curve, check_subgroup: bool) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+    if input.len() != SERIALIZED_POINT_BYTE_LENGTH * 2 {
+        return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for addition", input));
+    }
+
+    let (mut p_0, rest) = decode_point(input, curve, check_subgroup)?;
+    let (p_1, _) = decode_point(rest, curve, check_subgroup)?;
+
+    p_0.add_assign_mixed(&p_1);
+
+    serialize_point_output(&p_0)
+}
+
+fn mul<'a>(input: &'a [u8], curve: &'static Curve, check_subgroup: bool) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+    if input.len() != SERIALIZED_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
+        return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for multiplication", input));
+    }
+
+    let (p_0, rest) = decode_point(input, curve, check_subgroup)?;
+    let (scalar, _) = decode_g1::decode_scalar_representation(rest, SCALAR_BYTE_LENGTH)?;
+
+    let p = p_0.mul(&scalar);
+
+    serialize_point_output(&p)
+}
+
+fn multiexp<'a>(input: &'a [u8], curve: &'static Curve, check_subgroup: bool) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+    if input.len() % (SERIALIZED_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
+        return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for multiexp", input));
+    }
+    let num_pairs = input.len() / (SERIALIZED_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
+
+    if num_pairs == 0 {
+        return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid number of pairs", input));
+    }
+
+    let mut global_rest = input;
+    let mut bases = Vec::with_capacity(num_pairs);
+    let mut scalars = Vec::with_capacity(num_pairs);
+
+    for _ in 0..num_pairs {
+        let (p, local_rest) = decode_point(global_rest, curve, check_subgroup)?;
+        let (scalar, local_rest) = decode_g1::decode_scalar_representation(local_rest, SCALAR_BYTE_LENGTH)?;
+        bases.push(p);
+        scalars.push(scalar);
+        global_rest = local_rest;
+    }
+
+    let result = edwards_peppinger(&bases, scalars);
+
+    serialize_point_output(&result)
+}
+
+impl EdwardsExecutor {
+    pub fn jubjub_add<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+        add(input, &JUBJUB_CURVE, false)
+    }
+
+    pub fn jubjub_mul<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+        mul(input, &JUBJUB_CURVE, false)
+    }
+
+    pub fn jubjub_multiexp<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+        multiexp(input, &JUBJUB_CURVE, false)
+    }
+
+    pub fn bandersnatch_add<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+        add(input, &BANDERSNATCH_CURVE, true)
+    }
+
+    pub fn bandersnatch_mul<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+        mul(input, &BANDERSNATCH_CURVE, true)
+    }
+
+    pub fn bandersnatch_multiexp<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+        multiexp(input, &BANDERSNATCH_CURVE, true)
+    }
+
+    pub fn baby_jubjub_add<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+        add(input, &BABY_JUBJUB_CURVE, false)
+    }
+
+    pub fn baby_jubjub_mul<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+        mul(input, &BABY_JUBJUB_CURVE, false)
+    }
+
+    pub fn baby_jubjub_multiexp<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError> {
+        multiexp(input, &BABY_JUBJUB_CURVE, false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hex;
+
+    // EIP-2494 test vector for addition
+    const BABY_JUBJUB_P1: &str = "274dbce8d15179969bc0d49fa725bddf9de555e0ba6a693c6adb52fc9ee7a82c05ce98c61b05f47fe2eae9a542bd99f6b2e78246231640b54595febfd51eb853";
+    const BABY_JUBJUB_P2: &str = "2491aba8d3a191a76e35bc47bd9afe6cc88fee14d607cbe779f2349047d5c1572e07297f8d3c3d7818dbddfd24c35583f9a9d4ed0cb0c1d1348dd8f7f99152d7";
+    const BABY_JUBJUB_P1_PLUS_P2: &str = "11805510440a3488b3b811eaacd0ec7c72dded51978190e19067a2afaebaf3611f07aa1b3c598e2ff9ff77744a39298a0a89a9027777af9fa100dd448e072c13";
+
+    const SCALAR: &str = "000000000000000000000000000000001234567890abcdef1234567890abcdef";
+
+    // generator, its double and multiple by SCALAR
+    const JUBJUB_VECTORS: [&str; 3] = [
+        "11dafe5d23e1218086a365b99fbf3d3be72f6afd7d1f72623e6b071492d1122b1d523cf1ddab1a1793132e78c866c0c33e26ba5cc220fed7cc3f870e59d292aa",
+        "422aa5019e2b74d23b9f975158ab150bc4cc70d281a909df8a8a9a5debe99dcd010605562d77b78bc4b7ca1ea62681c850b71e55c81be7bdb8c9285cc60c9d31",
+        "6bc398ba23ec1cd3d12373ce7c275c4ce9a7a0da72114a662539012a9cb4666b10f3e79365ff8226483f24f16ceb7a0c7209645cfbf1c6319447cb79b8cb7e3b",
+    ];
+
+    const BANDERSNATCH_VECTORS: [&str; 3] = [
+        "29c132cc2c0b34c5743711777bbe42f32b79c022ad998465e1e71866a252ae182a6c669eda123e0f157d8b50badcd586358cad81eee464605e3167b6cc974166",
+        "30433263b93777d7d9afef0ad0c2917e183ef5a9de026eeda53626c7c6631b2c2a2c8f6465887ceee9ee3185f32b42829e0dfa7f6c65f0071039026018903b8b",
+        "5802a6cb03d0d98c96f2e91aed8d643c88f1146b12a12e87a1fe2a7ba662469a5d271c8350be0bec19a8221dc88f042e830d38dbffdd9660089bc58a718d3c19",
+    ];
+
+    const BABY_JUBJUB_VECTORS: [&str; 3] = [
+        "0bb77a6ad63e739b4eacb2e09d6277c12ab8d8010534e0b62893f3f6bb95705125797203f7a0b24925572e1cd16bf9edfce0051fb9e133774b3c257a872d7d8b",
+        "162d7e417903fa1c82f2d227e35b846b1133cfca4f558b5feb9fdcd5f81dd90201666cafbf0a30da8b9ebeaf848a1da067a892296f1043188e1705402b6d6853",
+        "11c14c02496050ed3e4d1c2f77ecaefa95bb1dbdc01334ca6a53a74a5ae737f604fbb3a6dc710d28f86fa9c654c0c5ad4fcc450b4d9e907ec4e780754aab60d5",
+    ];
+
+    type Op = fn(&[u8]) -> Result<[u8; SERIALIZED_POINT_BYTE_LENGTH], ApiError>;
+
+    fn run_vectors(vectors: &[&str; 3], add: Op, mul: Op, multiexp: Op) {
+        let g = hex::decode(vectors[0]).unwrap();
+        let two_g = hex::decode(vectors[1]).unwrap();
+        let k_g = hex::decode(vectors[2]).unwrap();
+        let scalar = hex::decode(SCALAR).unwrap();
+
+        let mut input = g.clone();
+        input.extend(g.clone());
+        assert_eq!(&add(&input).unwrap()[..], &two_g[..]);
+
+        let mut input = g.clone();
+        input.extend(scalar.clone());
+        assert_eq!(&mul(&input).unwrap()[..], &k_g[..]);
+
+        // k*G + 2*(2*G) = (k + 4)*G
+        let mut k_plus_four = scalar.clone();
+        k_plus_four[SCALAR_BYTE_LENGTH - 1] += 4;
+        let mut two = vec![0u8; SCALAR_BYTE_LENGTH];
+        two[SCALAR_BYTE_LENGTH - 1] = 2;
+        let mut input = g.clone();
+        input.extend(scalar.clone());
+        input.extend(two_g.clone());
+        input.extend(two);
+        let mut expected_input = g.clone();
+        expected_input.extend(k_plus_four);
+        assert_eq!(&multiexp(&input).unwrap()[..], &mul(&expected_input).unwrap()[..]);
+
+        // neutral element is (0, 1)
+        let mut zero = vec![0u8; SERIALIZED_POINT_BYTE_LENGTH];
+        zero[SERIALIZED_POINT_BYTE_LENGTH - 1] = 1;
+        let mut input = g.clone();
+        input.extend(zero.clone());
+        assert_eq!(&add(&input).unwrap()[..], &g[..]);
+
+        let mut input = g.clone();
+        input.extend(vec![0u8; SCALAR_BYTE_LENGTH]);
+        assert_eq!(&mul(&input).unwrap()[..], &zero[..]);
+    }
+
+    #[test]
+    fn test_jubjub() {
+        run_vectors(&JUBJUB_VECTORS, EdwardsExecutor::jubjub_add, EdwardsExecutor::jubjub_mul, EdwardsExecutor::jubjub_multiexp);
+    }
+
+    #[test]
+    fn test_bandersnatch() {
+        run_vectors(&BANDERSNATCH_VECTORS, EdwardsExecutor::bandersnatch_add, EdwardsExecutor::bandersnatch_mul, EdwardsExecutor::bandersnatch_multiexp);
+    }
+
+    #[test]
+    fn test_baby_jubjub() {
+        run_vectors(&BABY_JUBJUB_VECTORS, EdwardsExecutor::baby_jubjub_add, EdwardsExecutor::baby_jubjub_mul, EdwardsExecutor::baby_jubjub_multiexp);
+
+        let mut input = hex::decode(BABY_JUBJUB_P1).unwrap();
+        input.extend(hex::decode(BABY_JUBJUB_P2).unwrap());
+        let result = EdwardsExecutor::baby_jubjub_add(&input).unwrap();
+        assert_eq!(hex::encode(&result[..]), BABY_JUBJUB_P1_PLUS_P2);
+    }
+
+    fn point_of_order_two() -> Vec<u8> {
+        // (0, -1)
+        let mut encoding = vec![0u8; SERIALIZED_FP_BYTE_LENGTH];
+        encoding.extend(hex::decode("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000").unwrap());
+
+        encoding
+    }
+
+    #[test]
+    fn test_small_order_points() {
+        let mut input = point_of_order_two();
+        input.extend(point_of_order_two());
+        let mut zero = vec![0u8; SERIALIZED_POINT_BYTE_LENGTH];
+        zero[SERIALIZED_POINT_BYTE_LENGTH - 1] = 1;
+        assert_eq!(&EdwardsExecutor::jubjub_add(&input).unwrap()[..], &zero[..]);
+
+        let err = EdwardsExecutor::bandersnatch_add(&input).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::PointNotInSubgroup);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let g = hex::decode(JUBJUB_VECTORS[0]).unwrap();
+
+        let err = EdwardsExecutor::jubjub_add(&g).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidLength);
+
+        let err = EdwardsExecutor::jubjub_multiexp(&[]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidLength);
+
+        let mut not_on_curve = g.clone();
+        not_on_curve[SERIALIZED_POINT_BYTE_LENGTH - 1] ^= 1;
+        let mut input = g.clone();
+        input.extend(not_on_curve);
+        let err = EdwardsExecutor::jubjub_add(&input).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::PointNotOnCurve);
+
+        let mut input = vec![0xffu8; SERIALIZED_POINT_BYTE_LENGTH];
+        input.extend(vec![0u8; SCALAR_BYTE_LENGTH]);
+        assert!(EdwardsExecutor::jubjub_mul(&input).is_err());
+    }
+
+    #[test]
+    fn test_gas_meter() {
+        let mut input = hex::decode(JUBJUB_VECTORS[0]).unwrap();
+        input.extend(hex::decode(SCALAR).unwrap());
+        assert_eq!(EdwardsGasMeter::jubjub_mul(&input).unwrap(), JUBJUB_MUL_GAS);
+        assert!(EdwardsGasMeter::jubjub_add(&input).is_err());
+
+        let mut multiexp_input = input.clone();
+        multiexp_input.extend(input.clone());
+        let gas = EdwardsGasMeter::jubjub_multiexp(&multiexp_input).unwrap();
+        assert!(gas < 2 * JUBJUB_MUL_GAS);
+        assert!(gas > JUBJUB_MUL_GAS);
+
+        let bandersnatch_gas = EdwardsGasMeter::bandersnatch_multiexp(&multiexp_input).unwrap();
+        assert_eq!(bandersnatch_gas, gas + 2 * BANDERSNATCH_SUBGROUP_CHECK_GAS);
+    }
+}
@@ -0,0 +1,108 @@
+// For C style API caller has to preallocate some buffers for results
+pub const EIP196_PREALLOCATE_FOR_ERROR_BYTES: usize = 256;
+pub const EIP196_PREALLOCATE_FOR_RESULT_BYTES: usize = 64; // maximum for G1 point
+
+use static_assertions::const_assert;
+
+use super::{EIP196Executor, EIP196GasMeter};
+use crate::errors::{ApiError, ErrorCode};
+use crate::public_interface::ffi_utils::{perform_with_buffers, meter_with_buffers};
+const_assert!(EIP196_PREALLOCATE_FOR_RESULT_BYTES == super::SERIALIZED_G1_POINT_BYTE_LENGTH);
+
+/// Operation types are equal to the addresses of the corresponding precompiles
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Eip196OperationType {
+    BN254_ECADD = BN254_ECADD_OPERATION_RAW_VALUE,
+    BN254_ECMUL = BN254_ECMUL_OPERATION_RAW_VALUE,
+    BN254_ECPAIRING = BN254_ECPAIRING_OPERATION_RAW_VALUE,
+}
+
+impl Eip196OperationType {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            BN254_ECADD_OPERATION_RAW_VALUE => {
+                Some(Eip196OperationType::BN254_ECADD)
+            },
+            BN254_ECMUL_OPERATION_RAW_VALUE => {
+                Some(Eip196OperationType::BN254_ECMUL)
+            },
+            BN254_ECPAIRING_OPERATION_RAW_VALUE => {
+                Some(Eip196OperationType::BN254_ECPAIRING)
+            },
+            _ => {
+                None
+            }
+        }
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
+// Raw values are literals so that cbindgen can export them into the header
+pub const BN254_ECADD_OPERATION_RAW_VALUE: u8 = 6;
+pub const BN254_ECMUL_OPERATION_RAW_VALUE: u8 = 7;
+pub const BN254_ECPAIRING_OPERATION_RAW_VALUE: u8 = 8;
+
+fn perform(operation: Eip196OperationType, input: &[u8]) -> Result<Vec<u8>, ApiError> {
+    match operation {
+        Eip196OperationType::BN254_ECADD => EIP196Executor::ecadd(&input).map(|r| r[..].to_vec()),
+        Eip196OperationType::BN254_ECMUL => EIP196Executor::ecmul(&input).map(|r| r[..].to_vec()),
+        Eip196OperationType::BN254_ECPAIRING => EIP196Executor::ecpairing(&input).map(|r| r[..].to_vec()),
+    }
+}
+
+fn meter(operation: Eip196OperationType, input: &[u8]) -> Result<u64, ApiError> {
+    match operation {
+        Eip196OperationType::BN254_ECADD => EIP196GasMeter::ecadd(&input),
+        Eip196OperationType::BN254_ECMUL => EIP196GasMeter::ecmul(&input),
+        Eip196OperationType::BN254_ECPAIRING => EIP196GasMeter::ecpairing(&input),
+    }
+}
+
+/// Performs ECADD, ECMUL or ECPAIRING, `op` is the address of the precompile. Bounds-checked in
+/// the same way as `eip2537_perform_operation_v2`. Returns zero on success or
+/// `ApiError::packed_code` of the failure
+#[no_mangle]
+pub unsafe extern "C" fn eip196_perform_operation(
+    op: u8,
+    i: *const u8,
+    i_len: u32,
+    o: *mut u8,
+    o_capacity: u32,
+    o_len: *mut u32,
+    err: *mut u8,
+    err_capacity: u32,
+    err_len: *mut u32) -> u32
+{
+    perform_with_buffers(i, i_len, o, o_capacity, o_len, err, err_capacity, err_len, |input| {
+        let operation = Eip196OperationType::from_u8(op).ok_or_else(|| {
+            ApiError::coded(ErrorCode::InvalidEncoding, "Unknown operation type")
+        })?;
+
+        perform(operation, input)
+    })
+}
+
+/// Gas cost of `eip196_perform_operation` under the Istanbul schedule
+#[no_mangle]
+pub unsafe extern "C" fn eip196_meter_operation(
+    op: u8,
+    i: *const u8,
+    i_len: u32,
+    o: *mut u64,
+    err: *mut u8,
+    err_capacity: u32,
+    err_len: *mut u32) -> u32
+{
+    meter_with_buffers(i, i_len, o, err, err_capacity, err_len, |input| {
+        let operation = Eip196OperationType::from_u8(op).ok_or_else(|| {
+            ApiError::coded(ErrorCode::InvalidEncoding, "Unknown operation type")
+        })?;
+
+        meter(operation, input)
+    })
+}
@@ -0,0 +1,36 @@
+use crate::public_interface::{ApiError, ErrorCode};
+
+use super::ECPAIRING_PAIR_BYTE_LENGTH;
+
+// Istanbul prices from EIP-1108
+pub const BN254_ECADD_GAS: u64 = 150;
+pub const BN254_ECMUL_GAS: u64 = 6000;
+pub const BN254_PAIRING_BASE_GAS: u64 = 45000;
+pub const BN254_PAIRING_PER_PAIR_GAS: u64 = 34000;
+
+/// Prices EIP-196 and EIP-197 precompile calls according to the Istanbul gas schedule.
+/// ECADD and ECMUL accept inputs of any length, pairing input length is checked the same
+/// way as in `EIP196Executor::ecpairing`
+pub struct EIP196GasMeter;
+
+impl EIP196GasMeter {
+    pub fn ecadd<'a>(_input: &'a [u8]) -> Result<u64, ApiError> {
+        Ok(BN254_ECADD_GAS)
+    }
+
+    pub fn ecmul<'a>(_input: &'a [u8]) -> Result<u64, ApiError> {
+        Ok(BN254_ECMUL_GAS)
+    }
+
+    pub fn ecpairing<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        if input.len() % ECPAIRING_PAIR_BYTE_LENGTH != 0 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for pairing", input));
+        }
+        let num_pairs = input.len() / ECPAIRING_PAIR_BYTE_LENGTH;
+
+        let mut result = (num_pairs as u64).checked_mul(BN254_PAIRING_PER_PAIR_GAS).ok_or(ApiError::Overflow)?;
+        result = result.checked_add(BN254_PAIRING_BASE_GAS).ok_or(ApiError::Overflow)?;
+
+        Ok(result)
+    }
+}
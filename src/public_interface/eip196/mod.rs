@@ -0,0 +1,338 @@
+//! Executor for the BN254 precompiles of EIP-196 (ECADD, ECMUL) and EIP-197 (ECPAIRING).
+//! Field elements are big endian 32 byte values, Fp2 elements are encoded as c1 || c0 and the
+//! point at infinity is encoded as all zeroes. Inputs of ECADD and ECMUL are padded with zeroes
+//! or truncated to the expected length, ECPAIRING input must be a multiple of 192 bytes
+
+pub struct EIP196Executor;
+
+use crate::engines::bn254;
+use crate::public_interface::{ApiError, ErrorCode};
+
+pub const SCALAR_BYTE_LENGTH: usize = 32;
+
+pub const SERIALIZED_FP_BYTE_LENGTH: usize = 32;
+pub const SERIALIZED_G1_POINT_BYTE_LENGTH: usize = SERIALIZED_FP_BYTE_LENGTH * 2;
+
+pub const SERIALIZED_FP2_BYTE_LENGTH: usize = SERIALIZED_FP_BYTE_LENGTH * 2;
+pub const SERIALIZED_G2_POINT_BYTE_LENGTH: usize = SERIALIZED_FP2_BYTE_LENGTH * 2;
+
+pub const SERIALIZED_PAIRING_RESULT_BYTE_LENGTH: usize = 32;
+
+pub const ECADD_INPUT_BYTE_LENGTH: usize = SERIALIZED_G1_POINT_BYTE_LENGTH * 2;
+pub const ECMUL_INPUT_BYTE_LENGTH: usize = SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH;
+pub const ECPAIRING_PAIR_BYTE_LENGTH: usize = SERIALIZED_G1_POINT_BYTE_LENGTH + SERIALIZED_G2_POINT_BYTE_LENGTH;
+
+use crate::public_interface::decode_fp;
+use crate::public_interface::decode_g1;
+
+use crate::weierstrass::Group;
+use crate::weierstrass::curve::CurvePoint;
+use crate::pairings::PairingEngine;
+use crate::extension_towers::fp2::Fp2;
+use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+use crate::field::{U256Repr, PrimeField};
+use crate::traits::ZeroAndOne;
+
+mod gas_meter;
+pub use self::gas_meter::*;
+
+#[cfg(feature = "eip_196_c_api")]
+pub mod c_api;
+
+type G1 = CurvePoint<'static, crate::weierstrass::CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>>;
+type G2 = CurvePoint<'static, crate::weierstrass::CurveOverFp2Parameters<'static, U256Repr, PrimeField<U256Repr>>>;
+
+fn pairing_result_false() -> [u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH] {
+    [0u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH]
+}
+
+fn pairing_result_true() -> [u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH] {
+    let mut res = [0u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH];
+    res[31] = 1u8;
+
+    res
+}
+
+// Runs `f` over the input that is right padded with zeroes up to `length` bytes. Longer inputs
+// are passed as is, `f` only reads the first `length` bytes
+fn with_padded_input<T, F>(input: &[u8], length: usize, f: F) -> Result<T, ApiError>
+    where F: FnOnce(&[u8]) -> Result<T, ApiError>
+{
+    if input.len() >= length {
+        return f(input);
+    }
+
+    let padding = length - input.len();
+    let mut padded = Vec::with_capacity(length);
+    padded.extend_from_slice(input);
+    padded.resize(length, 0u8);
+
+    f(&padded).map_err(|e| e.without_padding(padding))
+}
+
+fn decode_g1_point<'a>(bytes: &'a [u8]) -> Result<(G1, &'a [u8]), ApiError> {
+    let encoding = bytes;
+    let (p, rest) = decode_g1::decode_g1_point_from_xy(bytes, SERIALIZED_FP_BYTE_LENGTH, &bn254::BN254_G1_CURVE)?;
+    if !p.is_on_curve() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("G1 point is not on curve, file {}, line {}", file!(), line!()), encoding));
+        }
+    }
+
+    Ok((p, rest))
+}
+
+fn decode_fp2_c1_first<'a>(bytes: &'a [u8]) -> Result<(Fp2<'static, U256Repr, PrimeField<U256Repr>>, &'a [u8]), ApiError> {
+    let (c1, rest) = decode_fp::decode_fp(bytes, SERIALIZED_FP_BYTE_LENGTH, &bn254::BN254_FIELD)?;
+    let (c0, rest) = decode_fp::decode_fp(rest, SERIALIZED_FP_BYTE_LENGTH, &bn254::BN254_FIELD)?;
+
+    let mut el = Fp2::zero(&bn254::BN254_EXTENSION_2_FIELD);
+    el.c0 = c0;
+    el.c1 = c1;
+
+    Ok((el, rest))
+}
+
+fn decode_g2_point<'a>(bytes: &'a [u8]) -> Result<(G2, &'a [u8]), ApiError> {
+    let encoding = bytes;
+    let (x, rest) = decode_fp2_c1_first(bytes)?;
+    let (y, rest) = decode_fp2_c1_first(rest)?;
+    let p = CurvePoint::point_from_xy(&bn254::BN254_G2_CURVE, x, y);
+
+    if !p.is_on_curve() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("G2 point is not on curve, file {}, line {}", file!(), line!()), encoding));
+        }
+    }
+    // G1 of BN254 has prime order, but G2 has a cofactor
+    if !p.check_correct_subgroup() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G2 point is not in the expected subgroup", encoding));
+        }
+    }
+
+    Ok((p, rest))
+}
+
+fn serialize_g1_output(point: &G1) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+    let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+    let as_vec = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, point)?;
+
+    output.copy_from_slice(&as_vec[..]);
+
+    Ok(output)
+}
+
+impl EIP196Executor {
+    /// ECADD precompile (address 0x06)
+    pub fn ecadd<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        with_padded_input(input, ECADD_INPUT_BYTE_LENGTH, |input| {
+            let (mut p_0, rest) = decode_g1_point(input)?;
+            let (p_1, _) = decode_g1_point(rest)?;
+
+            p_0.add_assign(&p_1);
+
+            serialize_g1_output(&p_0)
+        })
+    }
+
+    /// ECMUL precompile (address 0x07). Scalar is not reduced modulo the group order
+    pub fn ecmul<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        with_padded_input(input, ECMUL_INPUT_BYTE_LENGTH, |input| {
+            let (p_0, rest) = decode_g1_point(input)?;
+            let (scalar, _) = decode_g1::decode_scalar_representation(rest, SCALAR_BYTE_LENGTH)?;
+
+            let p = p_0.mul(&scalar);
+
+            serialize_g1_output(&p)
+        })
+    }
+
+    /// ECPAIRING precompile (address 0x08). Returns 32 bytes encoding 1 if the product of
+    /// pairings is one and 0 otherwise, empty input is valid and results in 1
+    pub fn ecpairing<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH], ApiError> {
+        if input.len() % ECPAIRING_PAIR_BYTE_LENGTH != 0 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for pairing", input));
+        }
+        let num_pairs = input.len() / ECPAIRING_PAIR_BYTE_LENGTH;
+
+        let mut global_rest = input;
+
+        let mut g1_points = Vec::with_capacity(num_pairs);
+        let mut g2_points = Vec::with_capacity(num_pairs);
+
+        for _ in 0..num_pairs {
+            let (g1, rest) = decode_g1_point(global_rest)?;
+            let (g2, rest) = decode_g2_point(rest)?;
+
+            global_rest = rest;
+
+            if !g1.is_zero() && !g2.is_zero() {
+                g1_points.push(g1);
+                g2_points.push(g2);
+            }
+        }
+
+        debug_assert!(g1_points.len() == g2_points.len());
+
+        if g1_points.len() == 0 {
+            return Ok(pairing_result_true());
+        }
+
+        let engine = &bn254::BN254_PAIRING_ENGINE;
+
+        let pairing_result = engine.pair(&g1_points, &g2_points);
+
+        if pairing_result.is_none() {
+            return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
+        }
+
+        let one_fp12 = Fp12::one(&bn254::BN254_EXTENSION_12_FIELD);
+        let result = if pairing_result.unwrap() == one_fp12 {
+            pairing_result_true()
+        } else {
+            pairing_result_false()
+        };
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hex;
+
+    const G1_GENERATOR: &str = "00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002";
+    const G1_GENERATOR_DOUBLED: &str = "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd315ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4";
+    const G1_GENERATOR_NEGATED: &str = "000000000000000000000000000000000000000000000000000000000000000130644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45";
+    const G2_GENERATOR: &str = "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa";
+
+    fn scalar(value: u8) -> Vec<u8> {
+        let mut s = vec![0u8; SCALAR_BYTE_LENGTH];
+        s[31] = value;
+
+        s
+    }
+
+    #[test]
+    fn test_ecadd_and_ecmul() {
+        let g = hex::decode(G1_GENERATOR).unwrap();
+        let doubled = hex::decode(G1_GENERATOR_DOUBLED).unwrap();
+
+        let mut input = g.clone();
+        input.extend_from_slice(&g);
+        assert_eq!(&EIP196Executor::ecadd(&input).unwrap()[..], &doubled[..]);
+
+        let mut input = g.clone();
+        input.extend(scalar(2));
+        assert_eq!(&EIP196Executor::ecmul(&input).unwrap()[..], &doubled[..]);
+
+        // G + (-G) is infinity
+        let mut input = g.clone();
+        input.extend(hex::decode(G1_GENERATOR_NEGATED).unwrap());
+        assert_eq!(&EIP196Executor::ecadd(&input).unwrap()[..], &[0u8; 64][..]);
+
+        // multiplication by the group order gives infinity
+        let mut input = g.clone();
+        input.extend(hex::decode("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001").unwrap());
+        assert_eq!(&EIP196Executor::ecmul(&input).unwrap()[..], &[0u8; 64][..]);
+    }
+
+    #[test]
+    fn test_padding_and_truncation() {
+        let g = hex::decode(G1_GENERATOR).unwrap();
+
+        // empty input is infinity plus infinity
+        assert_eq!(&EIP196Executor::ecadd(&[]).unwrap()[..], &[0u8; 64][..]);
+        assert_eq!(&EIP196Executor::ecmul(&[]).unwrap()[..], &[0u8; 64][..]);
+
+        // G + infinity with the second point omitted
+        assert_eq!(&EIP196Executor::ecadd(&g).unwrap()[..], &g[..]);
+
+        // 31 byte scalar is padded on the right, so 0x00..01 becomes 256
+        let mut input = g.clone();
+        input.extend_from_slice(&scalar(1)[1..]);
+        let mut expected_input = g.clone();
+        expected_input.extend_from_slice(&[0u8; 30]);
+        expected_input.extend_from_slice(&[1u8, 0u8]);
+        assert_eq!(EIP196Executor::ecmul(&input).unwrap()[..], EIP196Executor::ecmul(&expected_input).unwrap()[..]);
+        assert!(EIP196Executor::ecmul(&input).unwrap()[..] != g[..]);
+
+        // everything after the expected length is ignored
+        let mut input = g.clone();
+        input.extend(scalar(1));
+        input.extend_from_slice(&[0xffu8; 100]);
+        assert_eq!(&EIP196Executor::ecmul(&input).unwrap()[..], &g[..]);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let g = hex::decode(G1_GENERATOR).unwrap();
+
+        // (1, 3) is not on curve, offset points to the second point
+        let mut input = g.clone();
+        input.extend_from_slice(&g);
+        input[127] = 3;
+        let err = EIP196Executor::ecadd(&input).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::PointNotOnCurve);
+        assert_eq!(err.offset(&input), Some(64));
+
+        // the same with the second point padded
+        let err = EIP196Executor::ecadd(&input[..96]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::PointNotOnCurve);
+        assert_eq!(err.offset(&input[..96]), Some(64));
+
+        // coordinate equal to the modulus is not canonical
+        let mut input = hex::decode("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47").unwrap();
+        input.extend_from_slice(&g[32..]);
+        input.extend(scalar(1));
+        let err = EIP196Executor::ecmul(&input).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::NonCanonicalFieldElement);
+
+        let err = EIP196Executor::ecpairing(&[0u8; 191]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidLength);
+    }
+
+    #[test]
+    fn test_ecpairing() {
+        let g1 = hex::decode(G1_GENERATOR).unwrap();
+        let g1_neg = hex::decode(G1_GENERATOR_NEGATED).unwrap();
+        let g2 = hex::decode(G2_GENERATOR).unwrap();
+
+        assert_eq!(EIP196Executor::ecpairing(&[]).unwrap(), pairing_result_true());
+
+        // e(G1, G2) * e(-G1, G2) == 1
+        let mut input = g1.clone();
+        input.extend_from_slice(&g2);
+        input.extend_from_slice(&g1_neg);
+        input.extend_from_slice(&g2);
+        assert_eq!(EIP196Executor::ecpairing(&input).unwrap(), pairing_result_true());
+
+        // e(G1, G2) != 1
+        assert_eq!(EIP196Executor::ecpairing(&input[..ECPAIRING_PAIR_BYTE_LENGTH]).unwrap(), pairing_result_false());
+
+        // pairs with infinity are skipped
+        let mut input = vec![0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+        input.extend_from_slice(&g2);
+        assert_eq!(EIP196Executor::ecpairing(&input).unwrap(), pairing_result_true());
+
+        // swapping c0 and c1 of x makes the G2 point invalid
+        let mut input = g1.clone();
+        input.extend_from_slice(&g2[32..64]);
+        input.extend_from_slice(&g2[0..32]);
+        input.extend_from_slice(&g2[64..]);
+        let err = EIP196Executor::ecpairing(&input).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::PointNotOnCurve);
+        assert_eq!(err.offset(&input), Some(64));
+    }
+
+    #[test]
+    fn test_gas() {
+        assert_eq!(EIP196GasMeter::ecadd(&[]).unwrap(), 150);
+        assert_eq!(EIP196GasMeter::ecmul(&[0u8; 1000]).unwrap(), 6000);
+        assert_eq!(EIP196GasMeter::ecpairing(&[]).unwrap(), 45000);
+        assert_eq!(EIP196GasMeter::ecpairing(&[0u8; 384]).unwrap(), 45000 + 2 * 34000);
+        assert_eq!(EIP196GasMeter::ecpairing(&[0u8; 100]).unwrap_err().code(), ErrorCode::InvalidLength);
+    }
+}
@@ -3,21 +3,25 @@ pub const EIP2537_PREALLOCATE_FOR_ERROR_BYTES: usize = 256;
 pub const EIP2537_PREALLOCATE_FOR_RESULT_BYTES: usize = 64 * 2 * 2; // maximum for G2 point
 
 use static_assertions::const_assert;
+
+use super::{EIP2537Executor, EIP2537GasMeter};
+use crate::errors::{ApiError, ErrorCode};
+use crate::public_interface::ffi_utils::{perform_with_buffers, meter_with_buffers};
 const_assert!(EIP2537_PREALLOCATE_FOR_RESULT_BYTES == super::SERIALIZED_G2_POINT_BYTE_LENGTH);
 
 #[allow(non_camel_case_types)]
 #[repr(u8)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Eip2537OperationType {
-    BLS12_G1ADD = 1,
-    BLS12_G1MUL = 2,
-    BLS12_G1MULTIEXP = 3,
-    BLS12_G2ADD = 4,
-    BLS12_G2MUL = 5,
-    BLS12_G2MULTIEXP = 6,
-    BLS12_PAIR = 7,
-    BLS12_FP_TO_G1 = 8,
-    BLS12_FP2_TO_G2 = 9,
+    BLS12_G1ADD = BLS12_G1ADD_OPERATION_RAW_VALUE,
+    BLS12_G1MUL = BLS12_G1MUL_OPERATION_RAW_VALUE,
+    BLS12_G1MULTIEXP = BLS12_G1MULTIEXP_OPERATION_RAW_VALUE,
+    BLS12_G2ADD = BLS12_G2ADD_OPERATION_RAW_VALUE,
+    BLS12_G2MUL = BLS12_G2MUL_OPERATION_RAW_VALUE,
+    BLS12_G2MULTIEXP = BLS12_G2MULTIEXP_OPERATION_RAW_VALUE,
+    BLS12_PAIR = BLS12_PAIR_OPERATION_RAW_VALUE,
+    BLS12_FP_TO_G1 = BLS12_MAP_FP_TO_G1_OPERATION_RAW_VALUE,
+    BLS12_FP2_TO_G2 = BLS12_MAP_FP2_TO_G2_OPERATION_RAW_VALUE,
 }
 
 impl Eip2537OperationType {
@@ -61,19 +65,48 @@ impl Eip2537OperationType {
     }
 }
 
-pub const BLS12_G1ADD_OPERATION_RAW_VALUE: u8 = Eip2537OperationType::BLS12_G1ADD as u8;
-pub const BLS12_G1MUL_OPERATION_RAW_VALUE: u8 = Eip2537OperationType::BLS12_G1MUL as u8;
-pub const BLS12_G1MULTIEXP_OPERATION_RAW_VALUE: u8 = Eip2537OperationType::BLS12_G1MULTIEXP as u8;
-
-pub const BLS12_G2ADD_OPERATION_RAW_VALUE: u8 = Eip2537OperationType::BLS12_G2ADD as u8;
-pub const BLS12_G2MUL_OPERATION_RAW_VALUE: u8 = Eip2537OperationType::BLS12_G2MUL as u8;
-pub const BLS12_G2MULTIEXP_OPERATION_RAW_VALUE: u8 = Eip2537OperationType::BLS12_G2MULTIEXP as u8;
+// Raw values are literals so that cbindgen can export them into the header
+pub const BLS12_G1ADD_OPERATION_RAW_VALUE: u8 = 1;
+pub const BLS12_G1MUL_OPERATION_RAW_VALUE: u8 = 2;
+pub const BLS12_G1MULTIEXP_OPERATION_RAW_VALUE: u8 = 3;
+
+pub const BLS12_G2ADD_OPERATION_RAW_VALUE: u8 = 4;
+pub const BLS12_G2MUL_OPERATION_RAW_VALUE: u8 = 5;
+pub const BLS12_G2MULTIEXP_OPERATION_RAW_VALUE: u8 = 6;
+
+pub const BLS12_PAIR_OPERATION_RAW_VALUE: u8 = 7;
+pub const BLS12_MAP_FP_TO_G1_OPERATION_RAW_VALUE: u8 = 8;
+pub const BLS12_MAP_FP2_TO_G2_OPERATION_RAW_VALUE: u8 = 9;
+
+fn perform(operation: Eip2537OperationType, input: &[u8]) -> Result<Vec<u8>, ApiError> {
+    match operation {
+        Eip2537OperationType::BLS12_G1ADD => EIP2537Executor::g1_add(&input).map(|r| r[..].to_vec()),
+        Eip2537OperationType::BLS12_G1MUL => EIP2537Executor::g1_mul(&input).map(|r| r[..].to_vec()),
+        Eip2537OperationType::BLS12_G1MULTIEXP => EIP2537Executor::g1_multiexp(&input).map(|r| r[..].to_vec()),
+        Eip2537OperationType::BLS12_G2ADD => EIP2537Executor::g2_add(&input).map(|r| r[..].to_vec()),
+        Eip2537OperationType::BLS12_G2MUL => EIP2537Executor::g2_mul(&input).map(|r| r[..].to_vec()),
+        Eip2537OperationType::BLS12_G2MULTIEXP => EIP2537Executor::g2_multiexp(&input).map(|r| r[..].to_vec()),
+        Eip2537OperationType::BLS12_PAIR => EIP2537Executor::pair(&input).map(|r| r[..].to_vec()),
+        Eip2537OperationType::BLS12_FP_TO_G1 => EIP2537Executor::map_fp_to_g1(&input).map(|r| r[..].to_vec()),
+        Eip2537OperationType::BLS12_FP2_TO_G2 => EIP2537Executor::map_fp2_to_g2(&input).map(|r| r[..].to_vec()),
+    }
+}
 
-pub const BLS12_PAIR_OPERATION_RAW_VALUE: u8 = Eip2537OperationType::BLS12_PAIR as u8;
-pub const BLS12_MAP_FP_TO_G1_OPERATION_RAW_VALUE: u8 = Eip2537OperationType::BLS12_FP_TO_G1 as u8;
-pub const BLS12_MAP_FP2_TO_G2_OPERATION_RAW_VALUE: u8 = Eip2537OperationType::BLS12_FP2_TO_G2 as u8;
+fn meter(operation: Eip2537OperationType, input: &[u8]) -> Result<u64, ApiError> {
+    match operation {
+        Eip2537OperationType::BLS12_G1ADD => EIP2537GasMeter::g1_add(&input),
+        Eip2537OperationType::BLS12_G1MUL => EIP2537GasMeter::g1_mul(&input),
+        Eip2537OperationType::BLS12_G1MULTIEXP => EIP2537GasMeter::g1_multiexp(&input),
+        Eip2537OperationType::BLS12_G2ADD => EIP2537GasMeter::g2_add(&input),
+        Eip2537OperationType::BLS12_G2MUL => EIP2537GasMeter::g2_mul(&input),
+        Eip2537OperationType::BLS12_G2MULTIEXP => EIP2537GasMeter::g2_multiexp(&input),
+        Eip2537OperationType::BLS12_PAIR => EIP2537GasMeter::pair(&input),
+        Eip2537OperationType::BLS12_FP_TO_G1 => EIP2537GasMeter::map_fp_to_g1(&input),
+        Eip2537OperationType::BLS12_FP2_TO_G2 => EIP2537GasMeter::map_fp2_to_g2(&input),
+    }
+}
 
-// this is C interface
+// this is C interface, returns zero on success or `ApiError::packed_code` of the failure
 #[no_mangle]
 pub extern "C" fn eip2537_perform_operation(
     op: ::std::os::raw::c_char,
@@ -111,17 +144,7 @@ pub extern "C" fn eip2537_perform_operation(
     let raw_out_i8: &mut [i8] = unsafe { std::slice::from_raw_parts_mut(o, EIP2537_PREALLOCATE_FOR_RESULT_BYTES) };
     let mut raw_out: &mut [u8] = unsafe { std::mem::transmute(raw_out_i8) };
 
-    let result = match operation {
-        Eip2537OperationType::BLS12_G1ADD => super::EIP2537Executor::g1_add(&input).map(|r| r[..].to_vec()),
-        Eip2537OperationType::BLS12_G1MUL => super::EIP2537Executor::g1_mul(&input).map(|r| r[..].to_vec()),
-        Eip2537OperationType::BLS12_G1MULTIEXP => super::EIP2537Executor::g1_multiexp(&input).map(|r| r[..].to_vec()),
-        Eip2537OperationType::BLS12_G2ADD => super::EIP2537Executor::g2_add(&input).map(|r| r[..].to_vec()),
-        Eip2537OperationType::BLS12_G2MUL => super::EIP2537Executor::g2_mul(&input).map(|r| r[..].to_vec()),
-        Eip2537OperationType::BLS12_G2MULTIEXP => super::EIP2537Executor::g2_multiexp(&input).map(|r| r[..].to_vec()),
-        Eip2537OperationType::BLS12_PAIR => super::EIP2537Executor::pair(&input).map(|r| r[..].to_vec()),
-        Eip2537OperationType::BLS12_FP_TO_G1 => super::EIP2537Executor::map_fp_to_g1(&input).map(|r| r[..].to_vec()),
-        Eip2537OperationType::BLS12_FP2_TO_G2 => super::EIP2537Executor::map_fp2_to_g2(&input).map(|r| r[..].to_vec()),
-    };
+    let result = perform(operation, input);
 
     match result {
         Ok(result) => {
@@ -149,7 +172,54 @@ pub extern "C" fn eip2537_perform_operation(
                 unsafe { *char_len = 0u32 };
             }
 
-            return 1u32;
+            return error.packed_code(input);
         }
     }
-} 
\ No newline at end of file
+} 
+
+/// Bounds-checked version of `eip2537_perform_operation`. Capacities of the output and error
+/// buffers are passed explicitly and nothing is written past them. If the output buffer is too
+/// small `o_len` is set to the required length and `ERROR_CODE_BUFFER_TOO_SMALL` is returned.
+/// Error description is truncated to `err_capacity`, `err_len` is set to its full length. Returns
+/// zero on success or `ApiError::packed_code` of the failure
+#[no_mangle]
+pub unsafe extern "C" fn eip2537_perform_operation_v2(
+    op: u8,
+    i: *const u8,
+    i_len: u32,
+    o: *mut u8,
+    o_capacity: u32,
+    o_len: *mut u32,
+    err: *mut u8,
+    err_capacity: u32,
+    err_len: *mut u32) -> u32
+{
+    perform_with_buffers(i, i_len, o, o_capacity, o_len, err, err_capacity, err_len, |input| {
+        let operation = Eip2537OperationType::from_u8(op).ok_or_else(|| {
+            ApiError::coded(ErrorCode::InvalidEncoding, "Unknown operation type")
+        })?;
+
+        perform(operation, input)
+    })
+}
+
+/// C interface for gas metering, writes the gas cost of the operation into `o`. Buffers are
+/// bounds-checked in the same way as in `eip2537_perform_operation_v2`
+#[no_mangle]
+pub unsafe extern "C" fn eip2537_meter_operation_v2(
+    op: u8,
+    i: *const u8,
+    i_len: u32,
+    o: *mut u64,
+    err: *mut u8,
+    err_capacity: u32,
+    err_len: *mut u32) -> u32
+{
+    meter_with_buffers(i, i_len, o, err, err_capacity, err_len, |input| {
+        let operation = Eip2537OperationType::from_u8(op).ok_or_else(|| {
+            ApiError::coded(ErrorCode::InvalidEncoding, "Unknown operation type")
+        })?;
+
+        meter(operation, input)
+    })
+}
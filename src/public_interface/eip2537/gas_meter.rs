@@ -0,0 +1,143 @@
+use crate::public_interface::ApiError;
+use crate::public_interface::multiexp_discounts::meter_multiexp;
+pub use crate::public_interface::multiexp_discounts::{MULTIEXP_DISCOUNT_MULTIPLIER, MULTIEXP_MAX_DISCOUNT};
+
+use super::{
+    SCALAR_BYTE_LENGTH,
+    SERIALIZED_FP_BYTE_LENGTH,
+    SERIALIZED_FP2_BYTE_LENGTH,
+    SERIALIZED_G1_POINT_BYTE_LENGTH,
+    SERIALIZED_G2_POINT_BYTE_LENGTH,
+};
+
+pub const BLS12_G1ADD_GAS: u64 = 600;
+pub const BLS12_G1MUL_GAS: u64 = 12000;
+pub const BLS12_G2ADD_GAS: u64 = 4500;
+pub const BLS12_G2MUL_GAS: u64 = 55000;
+pub const BLS12_PAIRING_BASE_GAS: u64 = 115000;
+pub const BLS12_PAIRING_PER_PAIR_GAS: u64 = 23000;
+pub const BLS12_MAP_FP_TO_G1_GAS: u64 = 5500;
+pub const BLS12_MAP_FP2_TO_G2_GAS: u64 = 110000;
+
+/// Prices EIP-2537 precompile calls according to the fixed gas schedule from the EIP.
+/// Every function performs the same input length checks as the corresponding
+/// `EIP2537Executor` function, but does not parse the input any further
+pub struct EIP2537GasMeter;
+
+impl EIP2537GasMeter {
+    pub fn g1_add<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        if input.len() != SERIALIZED_G1_POINT_BYTE_LENGTH * 2 {
+            return Err(ApiError::InputError("invalid input length for G1 addition".to_owned()));
+        }
+
+        Ok(BLS12_G1ADD_GAS)
+    }
+
+    pub fn g1_mul<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        if input.len() != SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid input length for G1 multiplication".to_owned()));
+        }
+
+        Ok(BLS12_G1MUL_GAS)
+    }
+
+    pub fn g1_multiexp<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        if input.len() % (SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
+            return Err(ApiError::InputError("invalid input length for G1 multiexp".to_owned()));
+        }
+        let num_pairs = input.len() / (SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+        }
+
+        meter_multiexp(num_pairs, BLS12_G1MUL_GAS)
+    }
+
+    pub fn g2_add<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        if input.len() != SERIALIZED_G2_POINT_BYTE_LENGTH * 2 {
+            return Err(ApiError::InputError("invalid input length for G2 addition".to_owned()));
+        }
+
+        Ok(BLS12_G2ADD_GAS)
+    }
+
+    pub fn g2_mul<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        if input.len() != SERIALIZED_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid input length for G2 multiplication".to_owned()));
+        }
+
+        Ok(BLS12_G2MUL_GAS)
+    }
+
+    pub fn g2_multiexp<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        if input.len() % (SERIALIZED_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
+            return Err(ApiError::InputError("invalid input length for G2 multiexp".to_owned()));
+        }
+        let num_pairs = input.len() / (SERIALIZED_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+        }
+
+        meter_multiexp(num_pairs, BLS12_G2MUL_GAS)
+    }
+
+    pub fn pair<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        if input.len() % (SERIALIZED_G2_POINT_BYTE_LENGTH + SERIALIZED_G1_POINT_BYTE_LENGTH) != 0 {
+            return Err(ApiError::InputError("invalid input length for pairing".to_owned()));
+        }
+        let num_pairs = input.len() / (SERIALIZED_G2_POINT_BYTE_LENGTH + SERIALIZED_G1_POINT_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+        }
+
+        let mut result = (num_pairs as u64).checked_mul(BLS12_PAIRING_PER_PAIR_GAS).ok_or(ApiError::Overflow)?;
+        result = result.checked_add(BLS12_PAIRING_BASE_GAS).ok_or(ApiError::Overflow)?;
+
+        Ok(result)
+    }
+
+    pub fn map_fp_to_g1<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        if input.len() != SERIALIZED_FP_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid input length for Fp to G1 to curve mapping".to_owned()));
+        }
+
+        Ok(BLS12_MAP_FP_TO_G1_GAS)
+    }
+
+    pub fn map_fp2_to_g2<'a>(input: &'a [u8]) -> Result<u64, ApiError> {
+        if input.len() != SERIALIZED_FP2_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid input length for Fp2 to G2 to curve mapping".to_owned()));
+        }
+
+        Ok(BLS12_MAP_FP2_TO_G2_GAS)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_example_prices() {
+        let g1_pair_len = SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH;
+        let g2_pair_len = SERIALIZED_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH;
+        let pairing_pair_len = SERIALIZED_G1_POINT_BYTE_LENGTH + SERIALIZED_G2_POINT_BYTE_LENGTH;
+
+        assert_eq!(EIP2537GasMeter::g1_add(&vec![0u8; SERIALIZED_G1_POINT_BYTE_LENGTH * 2]).unwrap(), 600);
+        assert_eq!(EIP2537GasMeter::g2_mul(&vec![0u8; g2_pair_len]).unwrap(), 55000);
+
+        assert_eq!(EIP2537GasMeter::g1_multiexp(&vec![0u8; g1_pair_len]).unwrap(), 14400);
+        assert_eq!(EIP2537GasMeter::g1_multiexp(&vec![0u8; g1_pair_len * 2]).unwrap(), 21312);
+        assert_eq!(EIP2537GasMeter::g1_multiexp(&vec![0u8; g1_pair_len * 200]).unwrap(), 417600);
+        assert_eq!(EIP2537GasMeter::g2_multiexp(&vec![0u8; g2_pair_len * 16]).unwrap(), 293920);
+
+        assert_eq!(EIP2537GasMeter::pair(&vec![0u8; pairing_pair_len * 2]).unwrap(), 161000);
+
+        assert!(EIP2537GasMeter::g1_multiexp(&[]).is_err());
+        assert!(EIP2537GasMeter::pair(&vec![0u8; pairing_pair_len + 1]).is_err());
+        assert!(EIP2537GasMeter::map_fp_to_g1(&vec![0u8; SERIALIZED_FP2_BYTE_LENGTH]).is_err());
+    }
+}
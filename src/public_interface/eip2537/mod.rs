@@ -1,7 +1,7 @@
 pub struct EIP2537Executor;
 
 use crate::engines::bls12_381::{self, mapping};
-use crate::public_interface::ApiError;
+use crate::public_interface::{ApiError, ErrorCode};
 
 pub const SCALAR_BYTE_LENGTH: usize = 32;
 
@@ -13,13 +13,24 @@ pub const SERIALIZED_G2_POINT_BYTE_LENGTH: usize = SERIALIZED_FP2_BYTE_LENGTH *
 
 pub const SERIALIZED_PAIRING_RESULT_BYTE_LENGTH: usize = 32;
 
+// Fp12 element, coefficients are encoded from c0.c0.c0 to c1.c2.c1
+pub const SERIALIZED_GT_ELEMENT_BYTE_LENGTH: usize = SERIALIZED_FP_BYTE_LENGTH * 12;
+
 use crate::public_interface::decode_fp;
 use crate::public_interface::decode_g1;
 use crate::public_interface::decode_g2;
+use crate::public_interface::zcash_encoding;
 
 use crate::weierstrass::Group;
 use crate::multiexp::peppinger;
 use crate::pairings::PairingEngine;
+use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+use crate::field::{U384Repr, PrimeField};
+use crate::traits::ZeroAndOne;
+use crate::typed_api::bls12_381::Gt;
+
+mod gas_meter;
+pub use self::gas_meter::*;
 
 #[cfg(feature = "eip_2357_c_api")]
 pub mod c_api;
@@ -38,20 +49,21 @@ fn pairing_result_true() -> [u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH] {
 impl EIP2537Executor {
     pub fn g1_add<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
         if input.len() != SERIALIZED_G1_POINT_BYTE_LENGTH * 2 {
-            return Err(ApiError::InputError("invalid input length for G1 addition".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G1 addition", input));
         }
 
         let (mut p_0, rest) = decode_g1::decode_g1_point_from_xy_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_G1_CURVE)?;
+        let p_1_encoding = rest;
         let (p_1, _) = decode_g1::decode_g1_point_from_xy_oversized(rest, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_G1_CURVE)?;
 
         if !p_0.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point 0 is not on curve, file {}, line {}", file!(), line!()), input));
             }
         }
         if !p_1.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point 1 is not on curve, file {}, line {}", file!(), line!()), p_1_encoding));
             }
         }
 
@@ -68,7 +80,7 @@ impl EIP2537Executor {
 
     pub fn g1_mul<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
         if input.len() != SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
-            return Err(ApiError::InputError("invalid input length for G1 multiplication".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G1 multiplication", input));
         }
 
         let (p_0, rest) = decode_g1::decode_g1_point_from_xy_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_G1_CURVE)?;
@@ -76,10 +88,12 @@ impl EIP2537Executor {
 
         if !p_0.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point is not on curve, file {}, line {}", file!(), line!()), input));
             }
         }
 
+        // input is not required to be in the main subgroup, and proving membership costs
+        // about as much as GLV saves, so plain multiplication is used
         let p = p_0.mul(&scalar);
 
         let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
@@ -93,12 +107,12 @@ impl EIP2537Executor {
 
     pub fn g1_multiexp<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
         if input.len() % (SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
-            return Err(ApiError::InputError("invalid input length for G1 multiplication".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G1 multiplication", input));
         }
         let num_pairs = input.len() / (SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
 
         if num_pairs == 0 {
-            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid number of pairs", input));
         }
 
         let mut global_rest = input;
@@ -110,7 +124,7 @@ impl EIP2537Executor {
             let (scalar, local_rest) = decode_g1::decode_scalar_representation(local_rest, SCALAR_BYTE_LENGTH)?;
             if !p.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point is not on curve, file {}, line {}", file!(), line!()), global_rest));
                 }
             }
             bases.push(p);
@@ -135,20 +149,21 @@ impl EIP2537Executor {
 
     pub fn g2_add<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
         if input.len() != SERIALIZED_G2_POINT_BYTE_LENGTH * 2 {
-            return Err(ApiError::InputError("invalid input length for G2 addition".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G2 addition", input));
         }
 
         let (mut p_0, rest) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_G2_CURVE)?;
+        let p_1_encoding = rest;
         let (p_1, _) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(rest, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_G2_CURVE)?;
 
         if !p_0.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point 0 is not on curve, file {}, line {}", file!(), line!()), input));
             }
         }
         if !p_1.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point 1 is not on curve, file {}, line {}", file!(), line!()), p_1_encoding));
             }
         }
 
@@ -165,7 +180,7 @@ impl EIP2537Executor {
 
     pub fn g2_mul<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
         if input.len() != SERIALIZED_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
-            return Err(ApiError::InputError("invalid input length for G1 multiplication".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G1 multiplication", input));
         }
 
         let (p_0, rest) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_G2_CURVE)?;
@@ -173,7 +188,7 @@ impl EIP2537Executor {
 
         if !p_0.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point is not on curve, file {}, line {}", file!(), line!()), input));
             }
         }
 
@@ -190,12 +205,12 @@ impl EIP2537Executor {
 
     pub fn g2_multiexp<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
         if input.len() % (SERIALIZED_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
-            return Err(ApiError::InputError("invalid input length for G1 multiplication".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G1 multiplication", input));
         }
         let num_pairs = input.len() / (SERIALIZED_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
 
         if num_pairs == 0 {
-            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid number of pairs", input));
         }
 
         let mut global_rest = input;
@@ -207,7 +222,7 @@ impl EIP2537Executor {
             let (scalar, local_rest) = decode_g1::decode_scalar_representation(local_rest, SCALAR_BYTE_LENGTH)?;
             if !p.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point is not on curve, file {}, line {}", file!(), line!()), global_rest));
                 }
             }
             bases.push(p);
@@ -231,13 +246,44 @@ impl EIP2537Executor {
     }
 
     pub fn pair<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH], ApiError> {
+        let pairing_result = Self::pairing_result(input)?;
+
+        let one_fp12 = Fp12::one(&bls12_381::BLS12_381_EXTENSION_12_FIELD);
+        let result = if pairing_result == one_fp12 {
+            pairing_result_true()
+        } else {
+            pairing_result_false()
+        };
+
+        Ok(result)
+    }
+
+    /// Same input as for `pair`, but returns the resulting element of the target group
+    pub fn pair_raw<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_GT_ELEMENT_BYTE_LENGTH], ApiError> {
+        let pairing_result = Self::pairing_result(input)?;
+
+        let mut output = [0u8; SERIALIZED_GT_ELEMENT_BYTE_LENGTH];
+
+        let as_vec = decode_fp::serialize_fp12_fixed_len(SERIALIZED_FP_BYTE_LENGTH, &pairing_result)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    /// Decodes an output of `pair_raw`, the element must be in the target group
+    pub fn decode_gt_element<'a>(input: &'a [u8]) -> Result<Gt, ApiError> {
+        Gt::from_bytes(input)
+    }
+
+    fn pairing_result<'a>(input: &'a [u8]) -> Result<Fp12<'static, U384Repr, PrimeField<U384Repr>>, ApiError> {
         if input.len() % (SERIALIZED_G2_POINT_BYTE_LENGTH + SERIALIZED_G1_POINT_BYTE_LENGTH) != 0 {
-            return Err(ApiError::InputError("invalid input length for pairing".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for pairing", input));
         }
         let num_pairs = input.len() / (SERIALIZED_G2_POINT_BYTE_LENGTH + SERIALIZED_G1_POINT_BYTE_LENGTH);
 
         if num_pairs == 0 {
-            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid number of pairs", input));
         }
 
         let mut global_rest = input;
@@ -246,32 +292,33 @@ impl EIP2537Executor {
         let mut g2_points = Vec::with_capacity(num_pairs);
 
         for _ in 0..num_pairs {
+            let g1_encoding = global_rest;
             let (g1, rest) = decode_g1::decode_g1_point_from_xy_oversized(global_rest, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_G1_CURVE)?;
+            let g2_encoding = rest;
             let (g2, rest) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(rest, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_G2_CURVE)?;
 
             global_rest = rest;
 
             if !g1.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G1 point is not on curve".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G1 point is not on curve", g1_encoding));
                 }
             }
 
             if !g2.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G2 point is not on curve".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G2 point is not on curve", g2_encoding));
                 }
             }
-            // "fast" subgroup checks using empirical data
-            if g1.wnaf_mul_with_window_size(&bls12_381::BLS12_381_SUBGROUP_ORDER[..], 5).is_zero() == false {
+            if bls12_381::check_correct_subgroup_fast_g1(&g1) == false {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G1 point is not in the expected subgroup".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 point is not in the expected subgroup", g1_encoding));
                 }
             }
 
-            if g2.wnaf_mul_with_window_size(&bls12_381::BLS12_381_SUBGROUP_ORDER[..], 5).is_zero() == false {
+            if bls12_381::check_correct_subgroup_fast_g2(&g2) == false {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G2 point is not in the expected subgroup".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G2 point is not in the expected subgroup", g2_encoding));
                 }
             }
 
@@ -284,7 +331,7 @@ impl EIP2537Executor {
         debug_assert!(g1_points.len() == g2_points.len());
 
         if g1_points.len() == 0 {
-            return Ok(pairing_result_true());
+            return Ok(Fp12::one(&bls12_381::BLS12_381_EXTENSION_12_FIELD));
         }
 
         let engine = &bls12_381::BLS12_381_PAIRING_ENGINE;
@@ -295,23 +342,12 @@ impl EIP2537Executor {
             return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
         }
 
-        use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
-        use crate::traits::ZeroAndOne;
-
-        let one_fp12 = Fp12::one(&bls12_381::BLS12_381_EXTENSION_12_FIELD);
-        let pairing_result = pairing_result.unwrap();
-        let result = if pairing_result == one_fp12 {
-            pairing_result_true()
-        } else {
-            pairing_result_false()
-        };
-
-        Ok(result)
+        Ok(pairing_result.unwrap())
     }
 
     pub fn map_fp_to_g1<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
         if input.len() != SERIALIZED_FP_BYTE_LENGTH {
-            return Err(ApiError::InputError("invalid input length for Fp to G1 to curve mapping".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for Fp to G1 to curve mapping", input));
         }
         let (fe, _) = decode_fp::decode_fp_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_FIELD)?;
         let point = mapping::fp_to_g1(&fe)?;
@@ -326,7 +362,7 @@ impl EIP2537Executor {
 
     pub fn map_fp2_to_g2<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
         if input.len() != SERIALIZED_FP2_BYTE_LENGTH {
-            return Err(ApiError::InputError("invalid input length for Fp2 to G2 to curve mapping".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for Fp2 to G2 to curve mapping", input));
         }
         let (fe, _) = decode_fp::decode_fp2_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_EXTENSION_2_FIELD)?;
         let point = mapping::fp2_to_g2(&fe)?;
@@ -338,6 +374,154 @@ impl EIP2537Executor {
 
         Ok(output)
     }
+
+    /// Encodes a G1 point given in EIP-2537 format into 48 bytes of the compressed ZCash format
+    pub fn g1_to_zcash_compressed<'a>(input: &'a [u8]) -> Result<[u8; zcash_encoding::COMPRESSED_G1_BYTE_LENGTH], ApiError> {
+        let mut output = [0u8; zcash_encoding::COMPRESSED_G1_BYTE_LENGTH];
+        let point = Self::decode_g1_in_subgroup(input)?;
+        output.copy_from_slice(&zcash_encoding::serialize_g1(&point, true)?);
+
+        Ok(output)
+    }
+
+    /// Encodes a G1 point given in EIP-2537 format into 96 bytes of the uncompressed ZCash format
+    pub fn g1_to_zcash_uncompressed<'a>(input: &'a [u8]) -> Result<[u8; zcash_encoding::UNCOMPRESSED_G1_BYTE_LENGTH], ApiError> {
+        let mut output = [0u8; zcash_encoding::UNCOMPRESSED_G1_BYTE_LENGTH];
+        let point = Self::decode_g1_in_subgroup(input)?;
+        output.copy_from_slice(&zcash_encoding::serialize_g1(&point, false)?);
+
+        Ok(output)
+    }
+
+    /// Decodes a G1 point in the compressed (48 bytes) or uncompressed (96 bytes) ZCash format
+    /// and returns it in EIP-2537 format. Point must be in the main subgroup
+    pub fn g1_from_zcash<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        let point = zcash_encoding::deserialize_g1(input)?;
+
+        let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+        let as_vec = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &point)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    /// Encodes a G2 point given in EIP-2537 format into 96 bytes of the compressed ZCash format
+    pub fn g2_to_zcash_compressed<'a>(input: &'a [u8]) -> Result<[u8; zcash_encoding::COMPRESSED_G2_BYTE_LENGTH], ApiError> {
+        let mut output = [0u8; zcash_encoding::COMPRESSED_G2_BYTE_LENGTH];
+        let point = Self::decode_g2_in_subgroup(input)?;
+        output.copy_from_slice(&zcash_encoding::serialize_g2(&point, true)?);
+
+        Ok(output)
+    }
+
+    /// Encodes a G2 point given in EIP-2537 format into 192 bytes of the uncompressed ZCash format
+    pub fn g2_to_zcash_uncompressed<'a>(input: &'a [u8]) -> Result<[u8; zcash_encoding::UNCOMPRESSED_G2_BYTE_LENGTH], ApiError> {
+        let mut output = [0u8; zcash_encoding::UNCOMPRESSED_G2_BYTE_LENGTH];
+        let point = Self::decode_g2_in_subgroup(input)?;
+        output.copy_from_slice(&zcash_encoding::serialize_g2(&point, false)?);
+
+        Ok(output)
+    }
+
+    /// Decodes a G2 point in the compressed (96 bytes) or uncompressed (192 bytes) ZCash format
+    /// and returns it in EIP-2537 format. Point must be in the main subgroup
+    pub fn g2_from_zcash<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
+        let point = zcash_encoding::deserialize_g2(input)?;
+
+        let mut output = [0u8; SERIALIZED_G2_POINT_BYTE_LENGTH];
+        let as_vec = decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, &point)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    fn decode_g1_in_subgroup<'a>(input: &'a [u8]) -> Result<zcash_encoding::G1, ApiError> {
+        if input.len() != SERIALIZED_G1_POINT_BYTE_LENGTH {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G1 point encoding", input));
+        }
+        let (point, _) = decode_g1::decode_g1_point_from_xy_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_G1_CURVE)?;
+        if !point.is_on_curve() {
+            return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("G1 point is not on curve, file {}, line {}", file!(), line!()), input));
+        }
+        if !bls12_381::check_correct_subgroup_fast_g1(&point) {
+            return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 point is not in the expected subgroup", input));
+        }
+
+        Ok(point)
+    }
+
+    fn decode_g2_in_subgroup<'a>(input: &'a [u8]) -> Result<zcash_encoding::G2, ApiError> {
+        if input.len() != SERIALIZED_G2_POINT_BYTE_LENGTH {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G2 point encoding", input));
+        }
+        let (point, _) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &bls12_381::BLS12_381_G2_CURVE)?;
+        if !point.is_on_curve() {
+            return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("G2 point is not on curve, file {}, line {}", file!(), line!()), input));
+        }
+        if !bls12_381::check_correct_subgroup_fast_g2(&point) {
+            return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G2 point is not in the expected subgroup", input));
+        }
+
+        Ok(point)
+    }
+
+    /// Hashes an arbitrary message to G1 using the BLS12381G1_XMD:SHA-256_SSWU_RO_ suite with a given domain separation tag
+    #[cfg(feature = "hash_to_curve")]
+    pub fn hash_to_g1<'a>(msg: &'a [u8], dst: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        let point = mapping::hash_to_g1(msg, dst)?;
+
+        Self::serialize_g1_output(&point)
+    }
+
+    /// Encodes an arbitrary message to G1 using the BLS12381G1_XMD:SHA-256_SSWU_NU_ suite with a given domain separation tag
+    #[cfg(feature = "hash_to_curve")]
+    pub fn encode_to_g1<'a>(msg: &'a [u8], dst: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        let point = mapping::encode_to_g1(msg, dst)?;
+
+        Self::serialize_g1_output(&point)
+    }
+
+    /// Hashes an arbitrary message to G2 using the BLS12381G2_XMD:SHA-256_SSWU_RO_ suite with a given domain separation tag
+    #[cfg(feature = "hash_to_curve")]
+    pub fn hash_to_g2<'a>(msg: &'a [u8], dst: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
+        let point = mapping::hash_to_g2(msg, dst)?;
+
+        Self::serialize_g2_output(&point)
+    }
+
+    /// Encodes an arbitrary message to G2 using the BLS12381G2_XMD:SHA-256_SSWU_NU_ suite with a given domain separation tag
+    #[cfg(feature = "hash_to_curve")]
+    pub fn encode_to_g2<'a>(msg: &'a [u8], dst: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
+        let point = mapping::encode_to_g2(msg, dst)?;
+
+        Self::serialize_g2_output(&point)
+    }
+
+    #[cfg(feature = "hash_to_curve")]
+    fn serialize_g1_output(
+        point: &crate::weierstrass::curve::CurvePoint<'static, crate::weierstrass::CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>
+    ) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+        let as_vec = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, point)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    #[cfg(feature = "hash_to_curve")]
+    fn serialize_g2_output(
+        point: &crate::weierstrass::curve::CurvePoint<'static, crate::weierstrass::CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>
+    ) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
+        let mut output = [0u8; SERIALIZED_G2_POINT_BYTE_LENGTH];
+        let as_vec = decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, point)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
 }
 
 #[cfg(test)]
@@ -608,6 +792,105 @@ mod test {
         }
     }  
 
+    #[test]
+    fn test_fast_subgroup_checks() {
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+        assert!(bls12_381::check_correct_subgroup_fast_g1(&G1::zero(&bls12_381::BLS12_381_G1_CURVE)));
+        assert!(bls12_381::check_correct_subgroup_fast_g2(&G2::zero(&bls12_381::BLS12_381_G2_CURVE)));
+
+        for _ in 0..NUM_TESTS {
+            let (p, _) = make_random_g1_with_encoding(&mut rng);
+            assert!(bls12_381::check_correct_subgroup_fast_g1(&p));
+
+            let p = make_g1_in_invalid_subgroup(&mut rng);
+            assert!(!bls12_381::check_correct_subgroup_fast_g1(&p));
+
+            let (p, _) = make_random_g2_with_encoding(&mut rng);
+            assert!(bls12_381::check_correct_subgroup_fast_g2(&p));
+
+            let p = make_g2_in_invalid_subgroup(&mut rng);
+            assert!(!bls12_381::check_correct_subgroup_fast_g2(&p));
+        }
+    }
+
+    #[test]
+    fn test_pair_raw() {
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+        for _ in 0..10 {
+            let (g1, g1_enc) = make_random_g1_with_encoding(&mut rng);
+            let (g2, g2_enc) = make_random_g2_with_encoding(&mut rng);
+
+            let mut input = vec![];
+            input.extend(g1_enc.clone());
+            input.extend(g2_enc.clone());
+
+            let expected = bls12_381::BLS12_381_PAIRING_ENGINE.pair(&[g1], &[g2]).unwrap();
+
+            let api_result = EIP2537Executor::pair_raw(&input).unwrap();
+            let decoded = EIP2537Executor::decode_gt_element(&api_result[..]).unwrap();
+            assert!(decoded == Gt::from_element_unchecked(expected.clone()));
+            assert_eq!(&decoded.to_bytes()[..], &api_result[..]);
+
+            // e(P, Q)^2 == e(2P, Q) == e(P, Q) * e(P, Q)
+            input.extend(g1_enc);
+            input.extend(g2_enc);
+            let mut expected_squared = expected.clone();
+            expected_squared.square();
+            let api_result = EIP2537Executor::pair_raw(&input).unwrap();
+            let decoded = EIP2537Executor::decode_gt_element(&api_result[..]).unwrap();
+            assert!(decoded == Gt::from_element_unchecked(expected_squared));
+        }
+
+        let mut input = vec![];
+        input.extend(encode_g1(&G1::zero(&bls12_381::BLS12_381_G1_CURVE)));
+        input.extend(encode_g2(&bls12_381::BLS12_381_G2_GENERATOR));
+        let api_result = EIP2537Executor::pair_raw(&input).unwrap();
+        let mut expected = [0u8; SERIALIZED_GT_ELEMENT_BYTE_LENGTH];
+        expected[SERIALIZED_FP_BYTE_LENGTH - 1] = 1;
+        assert_eq!(&api_result[..], &expected[..]);
+
+        assert!(EIP2537Executor::decode_gt_element(&api_result[..]).unwrap().is_identity());
+        assert!(EIP2537Executor::decode_gt_element(&api_result[1..]).is_err());
+        let mut invalid = api_result;
+        invalid[0] = 1;
+        assert!(EIP2537Executor::decode_gt_element(&invalid[..]).is_err());
+
+        // valid field element that is not in the target group
+        let mut not_in_gt = [0u8; SERIALIZED_GT_ELEMENT_BYTE_LENGTH];
+        not_in_gt[SERIALIZED_FP_BYTE_LENGTH - 1] = 2;
+        let err = EIP2537Executor::decode_gt_element(&not_in_gt[..]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::PointNotInSubgroup);
+    }
+
+    #[test]
+    fn test_zcash_encoding() {
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+        for _ in 0..10 {
+            let (_, g1_enc) = make_random_g1_with_encoding(&mut rng);
+            let compressed = EIP2537Executor::g1_to_zcash_compressed(&g1_enc).unwrap();
+            assert_eq!(&EIP2537Executor::g1_from_zcash(&compressed).unwrap()[..], &g1_enc[..]);
+            let uncompressed = EIP2537Executor::g1_to_zcash_uncompressed(&g1_enc).unwrap();
+            assert_eq!(&EIP2537Executor::g1_from_zcash(&uncompressed).unwrap()[..], &g1_enc[..]);
+
+            let (_, g2_enc) = make_random_g2_with_encoding(&mut rng);
+            let compressed = EIP2537Executor::g2_to_zcash_compressed(&g2_enc).unwrap();
+            assert_eq!(&EIP2537Executor::g2_from_zcash(&compressed).unwrap()[..], &g2_enc[..]);
+            let uncompressed = EIP2537Executor::g2_to_zcash_uncompressed(&g2_enc).unwrap();
+            assert_eq!(&EIP2537Executor::g2_from_zcash(&uncompressed).unwrap()[..], &g2_enc[..]);
+        }
+
+        let zero = encode_g1(&G1::zero(&bls12_381::BLS12_381_G1_CURVE));
+        let compressed = EIP2537Executor::g1_to_zcash_compressed(&zero).unwrap();
+        assert_eq!(compressed[0], 0xc0);
+        assert_eq!(&EIP2537Executor::g1_from_zcash(&compressed).unwrap()[..], &zero[..]);
+
+        assert!(EIP2537Executor::g1_from_zcash(&[0u8; SERIALIZED_G1_POINT_BYTE_LENGTH]).is_err());
+        assert!(EIP2537Executor::g1_to_zcash_compressed(&zero[1..]).is_err());
+    }
+
     const NUM_TESTS: usize = 100;
     const MULTIEXP_INPUT: usize = 16;
     const WRITE_VECTORS: bool = true;
@@ -1142,6 +1425,40 @@ mod test {
         pb.finish_with_message("Completed");
     }
 
+    #[test]
+    fn test_error_codes_and_offsets() {
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+        let modulus = BigUint::from_str_radix("4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787", 10).unwrap();
+
+        let (_, p0_encoding) = make_random_g1_with_encoding(&mut rng);
+        let (mut p1, _) = make_random_g1_with_encoding(&mut rng);
+        make_point_not_on_curve_g1(&mut p1);
+
+        let mut input = p0_encoding.clone();
+        input.extend(encode_g1(&p1));
+        let err = EIP2537Executor::g1_add(&input).err().unwrap();
+        assert_eq!(err.code(), ErrorCode::PointNotOnCurve);
+        assert_eq!(err.offset(&input), Some(SERIALIZED_G1_POINT_BYTE_LENGTH));
+        assert_eq!(err.packed_code(&input), (((SERIALIZED_G1_POINT_BYTE_LENGTH + 1) as u32) << 8) | 4);
+
+        // y coordinate of the second point is not less than the modulus
+        let mut input = p0_encoding.clone();
+        input.extend(&p0_encoding[..SERIALIZED_FP_BYTE_LENGTH]);
+        input.extend(make_invalid_encoding_fp(&mut rng, &modulus, true));
+        let err = EIP2537Executor::g1_add(&input).err().unwrap();
+        assert_eq!(err.code(), ErrorCode::NonCanonicalFieldElement);
+        assert_eq!(err.offset(&input), Some(SERIALIZED_G1_POINT_BYTE_LENGTH + SERIALIZED_FP_BYTE_LENGTH));
+
+        let err = EIP2537Executor::g1_add(&input[1..]).err().unwrap();
+        assert_eq!(err.code(), ErrorCode::InvalidLength);
+        assert_eq!(err.offset(&input[1..]), Some(0));
+        assert_eq!(err.packed_code(&input[1..]), (1 << 8) | 2);
+
+        let err = ApiError::InputError("legacy".to_owned());
+        assert_eq!(err.code(), ErrorCode::Other);
+        assert_eq!(err.packed_code(&input), 1);
+    }
+
     #[test]
     fn test_not_on_curve_g2() {
         let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
@@ -0,0 +1,693 @@
+//! Executor for the BLS12-377 precompiles of EIP-2539. ABI is the same as in EIP-2537: field
+//! elements are encoded as 64 bytes big endian, scalars as 32 bytes, Fp2 elements as c0 || c1
+//! and the point at infinity as all zeroes
+
+pub struct EIP2539Executor;
+
+use crate::engines::bls12_377::{self, mapping};
+use crate::public_interface::{ApiError, ErrorCode};
+
+pub const SCALAR_BYTE_LENGTH: usize = 32;
+
+pub const SERIALIZED_FP_BYTE_LENGTH: usize = 64;
+pub const SERIALIZED_G1_POINT_BYTE_LENGTH: usize = SERIALIZED_FP_BYTE_LENGTH * 2;
+
+pub const SERIALIZED_FP2_BYTE_LENGTH: usize = SERIALIZED_FP_BYTE_LENGTH * 2;
+pub const SERIALIZED_G2_POINT_BYTE_LENGTH: usize = SERIALIZED_FP2_BYTE_LENGTH * 2;
+
+pub const SERIALIZED_PAIRING_RESULT_BYTE_LENGTH: usize = 32;
+
+use crate::public_interface::decode_fp;
+use crate::public_interface::decode_g1;
+use crate::public_interface::decode_g2;
+
+use crate::weierstrass::Group;
+use crate::weierstrass::curve::CurvePoint;
+use crate::weierstrass::{CurveOverFpParameters, CurveOverFp2Parameters};
+use crate::multiexp::peppinger;
+use crate::pairings::PairingEngine;
+use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+use crate::field::{U384Repr, PrimeField};
+use crate::traits::ZeroAndOne;
+
+mod gas_meter;
+pub use self::gas_meter::*;
+
+type G1 = CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>;
+type G2 = CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>;
+
+fn pairing_result_false() -> [u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH] {
+    [0u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH]
+}
+
+fn pairing_result_true() -> [u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH] {
+    let mut res = [0u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH];
+    res[31] = 1u8;
+
+    res
+}
+
+fn decode_g1_on_curve<'a>(bytes: &'a [u8]) -> Result<(G1, &'a [u8]), ApiError> {
+    let (p, rest) = decode_g1::decode_g1_point_from_xy_oversized(bytes, SERIALIZED_FP_BYTE_LENGTH, &bls12_377::BLS12_377_G1_CURVE)?;
+    if !p.is_on_curve() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G1 point is not on curve", bytes));
+        }
+    }
+
+    Ok((p, rest))
+}
+
+fn decode_g2_on_curve<'a>(bytes: &'a [u8]) -> Result<(G2, &'a [u8]), ApiError> {
+    let (p, rest) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(bytes, SERIALIZED_FP_BYTE_LENGTH, &bls12_377::BLS12_377_G2_CURVE)?;
+    if !p.is_on_curve() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G2 point is not on curve", bytes));
+        }
+    }
+
+    Ok((p, rest))
+}
+
+fn serialize_g1_output(point: &G1) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+    let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+    let as_vec = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, point)?;
+
+    output.copy_from_slice(&as_vec[..]);
+
+    Ok(output)
+}
+
+fn serialize_g2_output(point: &G2) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
+    let mut output = [0u8; SERIALIZED_G2_POINT_BYTE_LENGTH];
+    let as_vec = decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, point)?;
+
+    output.copy_from_slice(&as_vec[..]);
+
+    Ok(output)
+}
+
+impl EIP2539Executor {
+    pub fn g1_add<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_G1_POINT_BYTE_LENGTH * 2 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G1 addition", input));
+        }
+
+        let (mut p_0, rest) = decode_g1_on_curve(input)?;
+        let (p_1, _) = decode_g1_on_curve(rest)?;
+
+        p_0.add_assign(&p_1);
+
+        serialize_g1_output(&p_0)
+    }
+
+    pub fn g1_mul<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G1 multiplication", input));
+        }
+
+        let (p_0, rest) = decode_g1_on_curve(input)?;
+        let (scalar, _) = decode_g1::decode_scalar_representation(rest, SCALAR_BYTE_LENGTH)?;
+
+        let p = p_0.mul(&scalar);
+
+        serialize_g1_output(&p)
+    }
+
+    pub fn g1_multiexp<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() % (SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G1 multiexp", input));
+        }
+        let num_pairs = input.len() / (SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid number of pairs", input));
+        }
+
+        let mut global_rest = input;
+        let mut bases = Vec::with_capacity(num_pairs);
+        let mut scalars = Vec::with_capacity(num_pairs);
+
+        for _ in 0..num_pairs {
+            let (p, local_rest) = decode_g1_on_curve(global_rest)?;
+            let (scalar, local_rest) = decode_g1::decode_scalar_representation(local_rest, SCALAR_BYTE_LENGTH)?;
+            bases.push(p);
+            scalars.push(scalar);
+            global_rest = local_rest;
+        }
+
+        let result = peppinger(&bases, scalars);
+
+        serialize_g1_output(&result)
+    }
+
+    pub fn g2_add<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_G2_POINT_BYTE_LENGTH * 2 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G2 addition", input));
+        }
+
+        let (mut p_0, rest) = decode_g2_on_curve(input)?;
+        let (p_1, _) = decode_g2_on_curve(rest)?;
+
+        p_0.add_assign(&p_1);
+
+        serialize_g2_output(&p_0)
+    }
+
+    pub fn g2_mul<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G2 multiplication", input));
+        }
+
+        let (p_0, rest) = decode_g2_on_curve(input)?;
+        let (scalar, _) = decode_g1::decode_scalar_representation(rest, SCALAR_BYTE_LENGTH)?;
+
+        let p = p_0.mul(&scalar);
+
+        serialize_g2_output(&p)
+    }
+
+    pub fn g2_multiexp<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() % (SERIALIZED_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for G2 multiexp", input));
+        }
+        let num_pairs = input.len() / (SERIALIZED_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid number of pairs", input));
+        }
+
+        let mut global_rest = input;
+        let mut bases = Vec::with_capacity(num_pairs);
+        let mut scalars = Vec::with_capacity(num_pairs);
+
+        for _ in 0..num_pairs {
+            let (p, local_rest) = decode_g2_on_curve(global_rest)?;
+            let (scalar, local_rest) = decode_g1::decode_scalar_representation(local_rest, SCALAR_BYTE_LENGTH)?;
+            bases.push(p);
+            scalars.push(scalar);
+            global_rest = local_rest;
+        }
+
+        let result = peppinger(&bases, scalars);
+
+        serialize_g2_output(&result)
+    }
+
+    /// Both G1 and G2 points must be in the main subgroup, pairs with a point at infinity are skipped
+    pub fn pair<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH], ApiError> {
+        if input.len() % (SERIALIZED_G2_POINT_BYTE_LENGTH + SERIALIZED_G1_POINT_BYTE_LENGTH) != 0 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for pairing", input));
+        }
+        let num_pairs = input.len() / (SERIALIZED_G2_POINT_BYTE_LENGTH + SERIALIZED_G1_POINT_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid number of pairs", input));
+        }
+
+        let mut global_rest = input;
+
+        let mut g1_points = Vec::with_capacity(num_pairs);
+        let mut g2_points = Vec::with_capacity(num_pairs);
+
+        for _ in 0..num_pairs {
+            let g1_encoding = global_rest;
+            let (g1, rest) = decode_g1_on_curve(global_rest)?;
+            let g2_encoding = rest;
+            let (g2, rest) = decode_g2_on_curve(rest)?;
+
+            global_rest = rest;
+
+            if !g1.check_correct_subgroup() {
+                if !crate::features::in_fuzzing_or_gas_metering() {
+                    return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 point is not in the expected subgroup", g1_encoding));
+                }
+            }
+
+            if !g2.check_correct_subgroup() {
+                if !crate::features::in_fuzzing_or_gas_metering() {
+                    return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G2 point is not in the expected subgroup", g2_encoding));
+                }
+            }
+
+            if !g1.is_zero() && !g2.is_zero() {
+                g1_points.push(g1);
+                g2_points.push(g2);
+            }
+        }
+
+        debug_assert!(g1_points.len() == g2_points.len());
+
+        if g1_points.len() == 0 {
+            return Ok(pairing_result_true());
+        }
+
+        let engine = &bls12_377::BLS12_377_PAIRING_ENGINE;
+
+        let pairing_result = engine.pair(&g1_points, &g2_points);
+
+        if pairing_result.is_none() {
+            return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
+        }
+
+        let one_fp12 = Fp12::one(&bls12_377::BLS12_377_EXTENSION_12_FIELD);
+        let result = if pairing_result.unwrap() == one_fp12 {
+            pairing_result_true()
+        } else {
+            pairing_result_false()
+        };
+
+        Ok(result)
+    }
+
+    /// Maps with the Shallue-van de Woestijne method and clears the cofactor, see `bls12_377::mapping`
+    pub fn map_fp_to_g1<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_FP_BYTE_LENGTH {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for Fp to G1 to curve mapping", input));
+        }
+        let (fe, _) = decode_fp::decode_fp_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &bls12_377::BLS12_377_FIELD)?;
+        let point = mapping::fp_to_g1(&fe)?;
+
+        serialize_g1_output(&point)
+    }
+
+    /// Maps with the Shallue-van de Woestijne method and clears the cofactor, see `bls12_377::mapping`
+    pub fn map_fp2_to_g2<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_FP2_BYTE_LENGTH {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for Fp2 to G2 to curve mapping", input));
+        }
+        let (fe, _) = decode_fp::decode_fp2_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &bls12_377::BLS12_377_EXTENSION_2_FIELD)?;
+        let point = mapping::fp2_to_g2(&fe)?;
+
+        serialize_g2_output(&point)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    use csv::Writer;
+    use hex;
+
+    use num_bigint::BigUint;
+    use num_traits::Num;
+    use crate::fp::Fp;
+    use crate::traits::FieldElement;
+    use crate::square_root::*;
+
+    type Scalar = crate::integers::MaxGroupSizeUint;
+
+    type FpElement = crate::fp::Fp<'static, U384Repr, PrimeField<U384Repr>>;
+    type Fp2Element = crate::extension_towers::fp2::Fp2<'static, U384Repr, PrimeField<U384Repr>>;
+
+    const NUM_TESTS: usize = 100;
+    const MULTIEXP_INPUT: usize = 16;
+    const WRITE_VECTORS: bool = false;
+
+    const MODULUS_HEX: &str = "1ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508c00000000001";
+
+    fn make_rng() -> XorShiftRng {
+        XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+    }
+
+    fn modulus() -> BigUint {
+        BigUint::from_str_radix(MODULUS_HEX, 16).unwrap()
+    }
+
+    fn make_random_fp_with_encoding<R: Rng>(rng: &mut R) -> (FpElement, Vec<u8>) {
+        let mut buff = vec![0u8; 48*3];
+        rng.fill_bytes(&mut buff);
+
+        let num = BigUint::from_bytes_be(&buff) % modulus();
+        let x = Fp::from_be_bytes(&bls12_377::BLS12_377_FIELD, &num.to_bytes_be(), true).unwrap();
+        let as_vec = decode_fp::serialize_fp_fixed_len(SERIALIZED_FP_BYTE_LENGTH, &x).unwrap();
+
+        (x, as_vec)
+    }
+
+    fn make_random_fp2_with_encoding<R: Rng>(rng: &mut R) -> (Fp2Element, Vec<u8>) {
+        let (c0, mut encoding) = make_random_fp_with_encoding(rng);
+        let (c1, c1_encoding) = make_random_fp_with_encoding(rng);
+        encoding.extend(c1_encoding);
+
+        let mut fe = bls12_377::BLS12_377_FP2_ZERO.clone();
+        fe.c0 = c0;
+        fe.c1 = c1;
+
+        (fe, encoding)
+    }
+
+    fn make_random_scalar_with_encoding<R: Rng>(rng: &mut R) -> (Scalar, Vec<u8>) {
+        let mut buff = vec![0u8; SCALAR_BYTE_LENGTH];
+        rng.fill_bytes(&mut buff);
+
+        let (scalar, _) = decode_g1::decode_scalar_representation(&buff, SCALAR_BYTE_LENGTH).unwrap();
+
+        (scalar, buff)
+    }
+
+    fn make_random_g1<R: Rng>(rng: &mut R) -> G1 {
+        let (scalar, _) = make_random_scalar_with_encoding(rng);
+        let mut p = bls12_377::BLS12_377_G1_GENERATOR.mul(&scalar);
+        p.normalize();
+
+        p
+    }
+
+    fn make_random_g2<R: Rng>(rng: &mut R) -> G2 {
+        let (scalar, _) = make_random_scalar_with_encoding(rng);
+        let mut p = bls12_377::BLS12_377_G2_GENERATOR.mul(&scalar);
+        p.normalize();
+
+        p
+    }
+
+    fn encode_g1(p: &G1) -> Vec<u8> {
+        decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, p).unwrap()
+    }
+
+    fn encode_g2(p: &G2) -> Vec<u8> {
+        decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, p).unwrap()
+    }
+
+    fn make_g1_in_invalid_subgroup<R: Rng>(rng: &mut R) -> G1 {
+        let (mut x, _) = make_random_fp_with_encoding(rng);
+        let one = FpElement::one(&bls12_377::BLS12_377_FIELD);
+        loop {
+            let mut rhs = x.clone();
+            rhs.square();
+            rhs.mul_assign(&x);
+            rhs.add_assign(&bls12_377::BLS12_377_G1_CURVE.b);
+
+            if let Some(y) = sqrt(&rhs) {
+                let p = G1::point_from_xy(&bls12_377::BLS12_377_G1_CURVE, x.clone(), y);
+                if !p.check_correct_subgroup() {
+                    return p;
+                }
+            }
+            x.add_assign(&one);
+        }
+    }
+
+    fn make_g2_in_invalid_subgroup<R: Rng>(rng: &mut R) -> G2 {
+        let (mut x, _) = make_random_fp2_with_encoding(rng);
+        let one = Fp2Element::one(&bls12_377::BLS12_377_EXTENSION_2_FIELD);
+        loop {
+            let mut rhs = x.clone();
+            rhs.square();
+            rhs.mul_assign(&x);
+            rhs.add_assign(&bls12_377::BLS12_377_G2_CURVE.b);
+
+            if let Some(y) = sqrt_ext2(&rhs) {
+                let p = G2::point_from_xy(&bls12_377::BLS12_377_G2_CURVE, x.clone(), y);
+                if !p.check_correct_subgroup() {
+                    return p;
+                }
+            }
+            x.add_assign(&one);
+        }
+    }
+
+    // Writes vectors in the same format as for EIP-2537 if `WRITE_VECTORS` is set, otherwise
+    // checks that the committed vectors are reproduced
+    fn write_or_check_vectors(name: &str, records: Vec<(Vec<u8>, String)>) {
+        let path = format!("src/test/test_vectors/eip2539/{}", name);
+        if WRITE_VECTORS {
+            let mut writer = Writer::from_path(&path).expect("must open a test file");
+            writer.write_record(&["input", "result"]).expect("must write header");
+            for (input, result) in records.iter() {
+                writer.write_record(&[&hex::encode(input), result]).expect("must write a test vector");
+            }
+        } else {
+            let mut reader = csv::Reader::from_path(&path).expect("must open a test file");
+            let stored: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+            assert_eq!(stored.len(), records.len(), "number of vectors in {}", name);
+            for (r, (input, result)) in stored.iter().zip(records.iter()) {
+                assert_eq!(r.get(0).unwrap(), hex::encode(input));
+                assert_eq!(r.get(1).unwrap(), result);
+            }
+        }
+    }
+
+    fn generate<F: FnMut(&mut XorShiftRng) -> Vec<u8>>(
+        num_tests: usize,
+        mut make_input: F,
+        operation: fn(&[u8]) -> Result<Vec<u8>, ApiError>
+    ) -> Vec<(Vec<u8>, String)> {
+        let mut rng = make_rng();
+        (0..num_tests).map(|_| {
+            let input = make_input(&mut rng);
+            let result = match operation(&input) {
+                Ok(output) => hex::encode(&output),
+                Err(err) => err.to_string(),
+            };
+
+            (input, result)
+        }).collect()
+    }
+
+    #[test]
+    fn test_g1_operations() {
+        let mut rng = make_rng();
+        for _ in 0..10 {
+            let p0 = make_random_g1(&mut rng);
+            let p1 = make_random_g1(&mut rng);
+            let (scalar, scalar_encoding) = make_random_scalar_with_encoding(&mut rng);
+
+            let mut input = encode_g1(&p0);
+            input.extend(encode_g1(&p1));
+            let mut expected = p0.clone();
+            expected.add_assign(&p1);
+            assert_eq!(&EIP2539Executor::g1_add(&input).unwrap()[..], &encode_g1(&expected)[..]);
+
+            let mut input = encode_g1(&p0);
+            input.extend(scalar_encoding.clone());
+            let expected = p0.mul(&scalar);
+            assert_eq!(&EIP2539Executor::g1_mul(&input).unwrap()[..], &encode_g1(&expected)[..]);
+            assert_eq!(&EIP2539Executor::g1_multiexp(&input).unwrap()[..], &encode_g1(&expected)[..]);
+        }
+    }
+
+    #[test]
+    fn test_g2_operations() {
+        let mut rng = make_rng();
+        for _ in 0..10 {
+            let p0 = make_random_g2(&mut rng);
+            let p1 = make_random_g2(&mut rng);
+            let (scalar, scalar_encoding) = make_random_scalar_with_encoding(&mut rng);
+
+            let mut input = encode_g2(&p0);
+            input.extend(encode_g2(&p1));
+            let mut expected = p0.clone();
+            expected.add_assign(&p1);
+            assert_eq!(&EIP2539Executor::g2_add(&input).unwrap()[..], &encode_g2(&expected)[..]);
+
+            let mut input = encode_g2(&p0);
+            input.extend(scalar_encoding.clone());
+            let expected = p0.mul(&scalar);
+            assert_eq!(&EIP2539Executor::g2_mul(&input).unwrap()[..], &encode_g2(&expected)[..]);
+            assert_eq!(&EIP2539Executor::g2_multiexp(&input).unwrap()[..], &encode_g2(&expected)[..]);
+        }
+    }
+
+    #[test]
+    fn test_subgroup_checks_and_errors() {
+        let mut rng = make_rng();
+
+        let g1 = make_random_g1(&mut rng);
+        let g2 = make_random_g2(&mut rng);
+
+        let mut input = encode_g1(&make_g1_in_invalid_subgroup(&mut rng));
+        input.extend(encode_g2(&g2));
+        let err = EIP2539Executor::pair(&input).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::PointNotInSubgroup);
+        assert_eq!(err.offset(&input), Some(0));
+
+        let mut input = encode_g1(&g1);
+        input.extend(encode_g2(&make_g2_in_invalid_subgroup(&mut rng)));
+        let err = EIP2539Executor::pair(&input).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::PointNotInSubgroup);
+        assert_eq!(err.offset(&input), Some(SERIALIZED_G1_POINT_BYTE_LENGTH));
+
+        let mut not_on_curve = g1.clone();
+        not_on_curve.y.add_assign(&bls12_377::BLS12_377_FP_ONE);
+        let mut input = encode_g1(&g1);
+        input.extend(encode_g1(&not_on_curve));
+        let err = EIP2539Executor::g1_add(&input).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::PointNotOnCurve);
+        assert_eq!(err.offset(&input), Some(SERIALIZED_G1_POINT_BYTE_LENGTH));
+
+        assert_eq!(EIP2539Executor::g1_add(&input[1..]).unwrap_err().code(), ErrorCode::InvalidLength);
+        assert_eq!(EIP2539Executor::g2_multiexp(&[]).unwrap_err().code(), ErrorCode::InvalidLength);
+        assert_eq!(EIP2539Executor::map_fp_to_g1(&[0xff; SERIALIZED_FP_BYTE_LENGTH]).unwrap_err().code(), ErrorCode::NonCanonicalFieldElement);
+    }
+
+    #[test]
+    fn test_pairing_and_mapping() {
+        let mut rng = make_rng();
+
+        let g1 = make_random_g1(&mut rng);
+        let g2 = make_random_g2(&mut rng);
+        let mut minus_g1 = g1.clone();
+        minus_g1.negate();
+
+        let mut input = encode_g1(&g1);
+        input.extend(encode_g2(&g2));
+        assert_eq!(EIP2539Executor::pair(&input).unwrap(), pairing_result_false());
+
+        input.extend(encode_g1(&minus_g1));
+        input.extend(encode_g2(&g2));
+        assert_eq!(EIP2539Executor::pair(&input).unwrap(), pairing_result_true());
+
+        for _ in 0..10 {
+            let (_, encoding) = make_random_fp_with_encoding(&mut rng);
+            let output = EIP2539Executor::map_fp_to_g1(&encoding).unwrap();
+            let (p, _) = decode_g1::decode_g1_point_from_xy_oversized(&output, SERIALIZED_FP_BYTE_LENGTH, &bls12_377::BLS12_377_G1_CURVE).unwrap();
+            assert!(p.is_on_curve() && p.check_correct_subgroup());
+
+            let (_, encoding) = make_random_fp2_with_encoding(&mut rng);
+            let output = EIP2539Executor::map_fp2_to_g2(&encoding).unwrap();
+            let (p, _) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(&output, SERIALIZED_FP_BYTE_LENGTH, &bls12_377::BLS12_377_G2_CURVE).unwrap();
+            assert!(p.is_on_curve() && p.check_correct_subgroup());
+        }
+    }
+
+    #[test]
+    fn test_gas() {
+        let g1_pair_len = SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH;
+        let pairing_pair_len = SERIALIZED_G1_POINT_BYTE_LENGTH + SERIALIZED_G2_POINT_BYTE_LENGTH;
+
+        assert_eq!(EIP2539GasMeter::g1_multiexp(&vec![0u8; g1_pair_len * 2]).unwrap(), 21312);
+        assert_eq!(EIP2539GasMeter::pair(&vec![0u8; pairing_pair_len * 2]).unwrap(), 65000 + 2 * 55000);
+        assert_eq!(EIP2539GasMeter::map_fp2_to_g2(&vec![0u8; SERIALIZED_FP2_BYTE_LENGTH]).unwrap(), 75000);
+        assert!(EIP2539GasMeter::pair(&[]).is_err());
+    }
+
+    #[test]
+    fn generate_g1_vectors() {
+        write_or_check_vectors("g1_add.csv", generate(NUM_TESTS, |rng| {
+            let mut input = encode_g1(&make_random_g1(rng));
+            input.extend(encode_g1(&make_random_g1(rng)));
+            input
+        }, |i| EIP2539Executor::g1_add(i).map(|r| r.to_vec())));
+
+        write_or_check_vectors("g1_mul.csv", generate(NUM_TESTS, |rng| {
+            let mut input = encode_g1(&make_random_g1(rng));
+            input.extend(make_random_scalar_with_encoding(rng).1);
+            input
+        }, |i| EIP2539Executor::g1_mul(i).map(|r| r.to_vec())));
+
+        write_or_check_vectors("g1_multiexp.csv", generate(NUM_TESTS, |rng| {
+            let mut input = vec![];
+            for _ in 0..MULTIEXP_INPUT {
+                input.extend(encode_g1(&make_random_g1(rng)));
+                input.extend(make_random_scalar_with_encoding(rng).1);
+            }
+            input
+        }, |i| EIP2539Executor::g1_multiexp(i).map(|r| r.to_vec())));
+    }
+
+    #[test]
+    fn generate_g2_vectors() {
+        write_or_check_vectors("g2_add.csv", generate(NUM_TESTS, |rng| {
+            let mut input = encode_g2(&make_random_g2(rng));
+            input.extend(encode_g2(&make_random_g2(rng)));
+            input
+        }, |i| EIP2539Executor::g2_add(i).map(|r| r.to_vec())));
+
+        write_or_check_vectors("g2_mul.csv", generate(NUM_TESTS, |rng| {
+            let mut input = encode_g2(&make_random_g2(rng));
+            input.extend(make_random_scalar_with_encoding(rng).1);
+            input
+        }, |i| EIP2539Executor::g2_mul(i).map(|r| r.to_vec())));
+
+        write_or_check_vectors("g2_multiexp.csv", generate(NUM_TESTS, |rng| {
+            let mut input = vec![];
+            for _ in 0..MULTIEXP_INPUT {
+                input.extend(encode_g2(&make_random_g2(rng)));
+                input.extend(make_random_scalar_with_encoding(rng).1);
+            }
+            input
+        }, |i| EIP2539Executor::g2_multiexp(i).map(|r| r.to_vec())));
+    }
+
+    #[test]
+    fn generate_pairing_and_mapping_vectors() {
+        // pairs are (P, Q), (-P, Q), (P, -Q), (-P, -Q), ..., so the result is one for even number of pairs
+        let num_pairs = [1, 2, 3, 4, 5, 8];
+        let mut counter = 0;
+        let vectors = generate(NUM_TESTS / num_pairs.len() * num_pairs.len(), |rng| {
+            let pairs = num_pairs[counter / (NUM_TESTS / num_pairs.len())];
+            counter += 1;
+
+            let g1 = make_random_g1(rng);
+            let g2 = make_random_g2(rng);
+            let mut minus_g1 = g1.clone();
+            minus_g1.negate();
+            let mut minus_g2 = g2.clone();
+            minus_g2.negate();
+
+            let mut input = vec![];
+            for i in 0..pairs {
+                input.extend(encode_g1(if i & 1 == 0 { &g1 } else { &minus_g1 }));
+                input.extend(encode_g2(if i & 2 == 0 { &g2 } else { &minus_g2 }));
+            }
+            input
+        }, |i| EIP2539Executor::pair(i).map(|r| r.to_vec()));
+        for (input, result) in vectors.iter() {
+            let pairs = input.len() / (SERIALIZED_G1_POINT_BYTE_LENGTH + SERIALIZED_G2_POINT_BYTE_LENGTH);
+            if pairs % 2 == 0 {
+                assert_eq!(result, &hex::encode(&pairing_result_true()));
+            }
+        }
+        write_or_check_vectors("pairing.csv", vectors);
+
+        write_or_check_vectors("fp_to_g1.csv", generate(NUM_TESTS, |rng| {
+            make_random_fp_with_encoding(rng).1
+        }, |i| EIP2539Executor::map_fp_to_g1(i).map(|r| r.to_vec())));
+
+        write_or_check_vectors("fp2_to_g2.csv", generate(NUM_TESTS, |rng| {
+            make_random_fp2_with_encoding(rng).1
+        }, |i| EIP2539Executor::map_fp2_to_g2(i).map(|r| r.to_vec())));
+    }
+
+    #[test]
+    fn generate_negative_vectors() {
+        write_or_check_vectors("negative/g1_not_on_curve.csv", generate(NUM_TESTS, |rng| {
+            let mut p = make_random_g1(rng);
+            while p.is_on_curve() {
+                p.y.add_assign(&bls12_377::BLS12_377_FP_ONE);
+            }
+            let mut input = encode_g1(&p);
+            input.extend(make_random_scalar_with_encoding(rng).1);
+            input
+        }, |i| EIP2539Executor::g1_mul(i).map(|r| r.to_vec())));
+
+        write_or_check_vectors("negative/g2_not_on_curve.csv", generate(NUM_TESTS, |rng| {
+            let mut p = make_random_g2(rng);
+            while p.is_on_curve() {
+                p.y.add_assign(&bls12_377::BLS12_377_FP2_ONE);
+            }
+            let mut input = encode_g2(&p);
+            input.extend(make_random_scalar_with_encoding(rng).1);
+            input
+        }, |i| EIP2539Executor::g2_mul(i).map(|r| r.to_vec())));
+
+        let mut counter = 0;
+        write_or_check_vectors("negative/invalid_subgroup_for_pairing.csv", generate(NUM_TESTS, |rng| {
+            counter += 1;
+            let mut input = vec![];
+            if counter & 1 == 0 {
+                input.extend(encode_g1(&make_g1_in_invalid_subgroup(rng)));
+                input.extend(encode_g2(&make_random_g2(rng)));
+            } else {
+                input.extend(encode_g1(&make_random_g1(rng)));
+                input.extend(encode_g2(&make_g2_in_invalid_subgroup(rng)));
+            }
+            input
+        }, |i| EIP2539Executor::pair(i).map(|r| r.to_vec())));
+    }
+}
@@ -0,0 +1,595 @@
+//! Human readable breakdown of an EIP-1962 input for debugging of calldata.
+//!
+//! `decode_and_explain` walks the input of `API::run` with the same decoding functions as the
+//! operations themselves and records every field it finds with its offset. If the layout is valid
+//! the input is also metered and executed, so checks that require arithmetic (e.g. points being on
+//! curve or non-residues being non-residues) are reported too.
+
+use std::fmt;
+
+use crate::errors::{ApiError, ErrorCode};
+
+use super::constants::*;
+use super::sane_limits::*;
+use super::decode_utils::*;
+use super::decode_g1::{parse_group_order_from_encoding, decode_scalar_representation};
+use super::unified_api::OperationType;
+use super::API;
+
+use crate::pairings::TwistType;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldValue {
+    /// Field that only groups other fields
+    Group,
+    /// Big endian integer or field element as encoded
+    Integer(Vec<u8>),
+    Length(usize),
+    Boolean(bool),
+    Label(&'static str),
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            FieldValue::Group => Ok(()),
+            FieldValue::Integer(bytes) => {
+                write!(f, "0x")?;
+                for b in bytes.iter() {
+                    write!(f, "{:02x}", b)?;
+                }
+
+                Ok(())
+            },
+            FieldValue::Length(len) => write!(f, "{}", len),
+            FieldValue::Boolean(value) => write!(f, "{}", value),
+            FieldValue::Label(label) => write!(f, "{}", label),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExplainedField {
+    pub name: String,
+    pub offset: usize,
+    pub length: usize,
+    pub value: FieldValue,
+    pub children: Vec<ExplainedField>,
+}
+
+impl ExplainedField {
+    /// Finds a field by the path of names, e.g. `["pair 0", "g1", "x"]`
+    pub fn find(&self, path: &[&str]) -> Option<&ExplainedField> {
+        match path.split_first() {
+            None => Some(self),
+            Some((name, rest)) => {
+                self.children.iter().find(|c| c.name == *name).and_then(|c| c.find(rest))
+            }
+        }
+    }
+
+    fn write_indented(&self, f: &mut fmt::Formatter, depth: usize) -> Result<(), fmt::Error> {
+        let indent = "  ".repeat(depth);
+        match self.value {
+            FieldValue::Group => writeln!(f, "{:>6} {}{} ({} bytes)", self.offset, indent, self.name, self.length)?,
+            _ => writeln!(f, "{:>6} {}{}: {}", self.offset, indent, self.name, self.value)?,
+        }
+        for child in self.children.iter() {
+            child.write_indented(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExplainedFailure {
+    pub code: ErrorCode,
+    /// Offset in the input if the failure is tied to some field
+    pub offset: Option<usize>,
+    pub description: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    pub operation: Option<OperationType>,
+    pub fields: Vec<ExplainedField>,
+    /// Number of 64 bit limbs used for the base field
+    pub modulus_limbs: Option<usize>,
+    /// Gas estimate, available if the layout is valid and the crate is built with `gas_metering`
+    pub gas: Option<u64>,
+    /// First validation failure, if any
+    pub failure: Option<ExplainedFailure>,
+}
+
+impl Explanation {
+    pub fn find(&self, path: &[&str]) -> Option<&ExplainedField> {
+        let (name, rest) = path.split_first()?;
+
+        self.fields.iter().find(|f| f.name == *name).and_then(|f| f.find(rest))
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        for field in self.fields.iter() {
+            field.write_indented(f, 0)?;
+        }
+        if let Some(limbs) = self.modulus_limbs {
+            writeln!(f, "modulus limbs: {}", limbs)?;
+        }
+        if let Some(gas) = self.gas {
+            writeln!(f, "gas: {}", gas)?;
+        }
+        match &self.failure {
+            Some(failure) => {
+                match failure.offset {
+                    Some(offset) => write!(f, "failure at offset {}, {:?}: {}", offset, failure.code, failure.description),
+                    None => write!(f, "failure, {:?}: {}", failure.code, failure.description),
+                }
+            },
+            None => write!(f, "no failures"),
+        }
+    }
+}
+
+/// Parses the input of `API::run` and explains its layout. Never fails, problems with the input
+/// are reported in `Explanation::failure`
+pub fn decode_and_explain(bytes: &[u8]) -> Explanation {
+    let mut explainer = Explainer {
+        input: bytes,
+        position: 0,
+        operation: None,
+        modulus_limbs: None,
+    };
+    let mut fields = vec![];
+    let layout = explainer.explain_operation(&mut fields, bytes);
+
+    let mut gas = None;
+    let failure = match layout {
+        Err(err) => {
+            // layout failures without an offset are attributed to the first unexplained byte
+            let offset = err.offset(bytes).unwrap_or(explainer.position);
+            Some(explain_failure(err, Some(offset)))
+        },
+        Ok(()) => {
+            let result = meter(bytes).and_then(|metered| {
+                gas = metered;
+                API::run(bytes)
+            });
+
+            result.err().map(|err| {
+                let offset = err.offset(bytes);
+                explain_failure(err, offset)
+            })
+        }
+    };
+
+    Explanation {
+        operation: explainer.operation,
+        fields,
+        modulus_limbs: explainer.modulus_limbs,
+        gas,
+        failure,
+    }
+}
+
+fn explain_failure(err: ApiError, offset: Option<usize>) -> ExplainedFailure {
+    ExplainedFailure {
+        code: err.code(),
+        offset,
+        description: err.to_string(),
+    }
+}
+
+#[cfg(feature = "gas_metering")]
+fn meter(bytes: &[u8]) -> Result<Option<u64>, ApiError> {
+    crate::gas_meter::GasMeter::meter(bytes).map(|gas| Some(gas))
+}
+
+#[cfg(not(feature = "gas_metering"))]
+fn meter(_bytes: &[u8]) -> Result<Option<u64>, ApiError> {
+    Ok(None)
+}
+
+type Fields = Vec<ExplainedField>;
+
+struct Explainer<'a> {
+    input: &'a [u8],
+    // end of the last explained field, used as an offset of failures that don't carry one
+    position: usize,
+    operation: Option<OperationType>,
+    modulus_limbs: Option<usize>,
+}
+
+impl<'a> Explainer<'a> {
+    fn offset(&self, bytes: &[u8]) -> usize {
+        self.input.len() - bytes.len()
+    }
+
+    // records a field spanning from `start` to `rest`
+    fn push(&mut self, fields: &mut Fields, name: &str, start: &[u8], rest: &[u8], value: FieldValue) {
+        let offset = self.offset(start);
+        self.position = self.offset(rest);
+        fields.push(ExplainedField {
+            name: name.to_owned(),
+            offset,
+            length: start.len() - rest.len(),
+            value,
+            children: vec![],
+        });
+    }
+
+    // records a group even if parsing of its children fails
+    fn group<F>(&mut self, fields: &mut Fields, name: &str, bytes: &'a [u8], f: F) -> Result<&'a [u8], ApiError>
+        where F: FnOnce(&mut Self, &mut Fields, &'a [u8]) -> Result<&'a [u8], ApiError>
+    {
+        let offset = self.offset(bytes);
+        let mut children = vec![];
+        let result = f(self, &mut children, bytes);
+        fields.push(ExplainedField {
+            name: name.to_owned(),
+            offset,
+            length: self.position.saturating_sub(offset),
+            value: FieldValue::Group,
+            children,
+        });
+
+        result
+    }
+
+    fn integer(&mut self, fields: &mut Fields, name: &str, bytes: &'a [u8], len: usize, err: &'static str) -> Result<&'a [u8], ApiError> {
+        let (encoding, rest) = split(bytes, len, err)?;
+        self.push(fields, name, bytes, rest, FieldValue::Integer(encoding.to_vec()));
+
+        Ok(rest)
+    }
+
+    // Fp element as an integer, or Fp2/Fp3 element as a group of coefficients
+    fn element(&mut self, fields: &mut Fields, name: &str, bytes: &'a [u8], modulus_len: usize, degree: usize) -> Result<&'a [u8], ApiError> {
+        if degree == 1 {
+            return self.integer(fields, name, bytes, modulus_len, "Input is not long enough to get field element");
+        }
+
+        self.group(fields, name, bytes, |this, fields, mut rest| {
+            for i in 0..degree {
+                rest = this.integer(fields, &format!("c{}", i), rest, modulus_len, "Input is not long enough to get field element")?;
+            }
+
+            Ok(rest)
+        })
+    }
+
+    fn point(&mut self, fields: &mut Fields, name: &str, bytes: &'a [u8], modulus_len: usize, degree: usize) -> Result<&'a [u8], ApiError> {
+        self.group(fields, name, bytes, |this, fields, rest| {
+            let rest = this.element(fields, "x", rest, modulus_len, degree)?;
+
+            this.element(fields, "y", rest, modulus_len, degree)
+        })
+    }
+
+    fn length(&mut self, fields: &mut Fields, name: &str, bytes: &'a [u8], err: &'static str) -> Result<(usize, &'a [u8]), ApiError> {
+        let (encoding, rest) = split(bytes, BYTES_FOR_LENGTH_ENCODING, err)?;
+        let len = encoding[0] as usize;
+        self.push(fields, name, bytes, rest, FieldValue::Length(len));
+
+        Ok((len, rest))
+    }
+
+    fn sign(&mut self, fields: &mut Fields, name: &str, bytes: &'a [u8]) -> Result<&'a [u8], ApiError> {
+        let (is_negative, rest) = decode_sign_is_negative(bytes)?;
+        self.push(fields, name, bytes, rest, FieldValue::Label(if is_negative { "negative" } else { "positive" }));
+
+        Ok(rest)
+    }
+
+    fn boolean(&mut self, fields: &mut Fields, name: &str, bytes: &'a [u8]) -> Result<&'a [u8], ApiError> {
+        let (value, rest) = decode_boolean(bytes)?;
+        self.push(fields, name, bytes, rest, FieldValue::Boolean(value));
+
+        Ok(rest)
+    }
+
+    // length prefixed loop parameter as in pairing ABIs
+    fn loop_parameter(&mut self, fields: &mut Fields, name: &str, bytes: &'a [u8], bit_limit: usize) -> Result<&'a [u8], ApiError> {
+        let (_, rest) = decode_loop_parameter_scalar_with_bit_limit(bytes, bit_limit)?;
+        let encoding = &bytes[BYTES_FOR_LENGTH_ENCODING..];
+        self.push(fields, &format!("{}_length", name), bytes, encoding, FieldValue::Length(encoding.len() - rest.len()));
+        self.push(fields, name, encoding, rest, FieldValue::Integer(encoding[..(encoding.len() - rest.len())].to_vec()));
+
+        Ok(rest)
+    }
+
+    fn modulus(&mut self, fields: &mut Fields, bytes: &'a [u8]) -> Result<(usize, &'a [u8]), ApiError> {
+        let ((modulus, modulus_len), rest) = get_base_field_params(bytes)?;
+        let encoding = &bytes[BYTES_FOR_LENGTH_ENCODING..];
+        self.push(fields, "modulus_length", bytes, encoding, FieldValue::Length(modulus_len));
+        self.push(fields, "modulus", encoding, rest, FieldValue::Integer(encoding[..modulus_len].to_vec()));
+        self.modulus_limbs = Some(num_limbs_for_modulus(&modulus)?);
+
+        Ok((modulus_len, rest))
+    }
+
+    fn group_order(&mut self, fields: &mut Fields, bytes: &'a [u8]) -> Result<(usize, &'a [u8]), ApiError> {
+        let (order_len, _, rest) = parse_group_order_from_encoding(bytes)?;
+        let encoding = &bytes[BYTES_FOR_LENGTH_ENCODING..];
+        self.push(fields, "order_length", bytes, encoding, FieldValue::Length(order_len));
+        self.push(fields, "order", encoding, rest, FieldValue::Integer(encoding[..order_len].to_vec()));
+
+        Ok((order_len, rest))
+    }
+
+    // returns modulus and group order lengths
+    fn g1_curve(&mut self, fields: &mut Fields, bytes: &'a [u8]) -> Result<(usize, usize, &'a [u8]), ApiError> {
+        let mut lengths = (0, 0);
+        let rest = self.group(fields, "curve", bytes, |this, fields, rest| {
+            let (modulus_len, rest) = this.modulus(fields, rest)?;
+            let rest = this.integer(fields, "a", rest, modulus_len, "Input is not long enough to get A")?;
+            let rest = this.integer(fields, "b", rest, modulus_len, "Input is not long enough to get B")?;
+            let (order_len, rest) = this.group_order(fields, rest)?;
+            lengths = (modulus_len, order_len);
+
+            Ok(rest)
+        })?;
+
+        Ok((lengths.0, lengths.1, rest))
+    }
+
+    // returns modulus length, extension degree and group order length
+    fn g2_curve(&mut self, fields: &mut Fields, bytes: &'a [u8]) -> Result<(usize, usize, usize, &'a [u8]), ApiError> {
+        let mut lengths = (0, 0, 0);
+        let rest = self.group(fields, "curve", bytes, |this, fields, rest| {
+            let start = rest;
+            let (modulus_len, rest) = this.modulus(fields, rest)?;
+            let (_, _, degree, _, _) = parse_modulus_and_extension_degree(start)?;
+            let degree = degree as usize;
+            let (_, after_degree) = split(rest, EXTENSION_DEGREE_ENCODING_LENGTH, "Input is not long enough to get extension degree")?;
+            this.push(fields, "extension_degree", rest, after_degree, FieldValue::Length(degree));
+            let rest = this.integer(fields, "fp_non_residue", after_degree, modulus_len, "Input is not long enough to Fp non-residue")?;
+            let rest = this.element(fields, "a", rest, modulus_len, degree)?;
+            let rest = this.element(fields, "b", rest, modulus_len, degree)?;
+            let (order_len, rest) = this.group_order(fields, rest)?;
+            lengths = (modulus_len, degree, order_len);
+
+            Ok(rest)
+        })?;
+
+        Ok((lengths.0, lengths.1, lengths.2, rest))
+    }
+
+    fn num_pairs(&mut self, fields: &mut Fields, bytes: &'a [u8], err: &'static str) -> Result<(usize, &'a [u8]), ApiError> {
+        let (num_pairs, rest) = self.length(fields, "num_pairs", bytes, "Input is not long enough to get number of pairs")?;
+        if num_pairs == 0 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, err, bytes));
+        }
+
+        Ok((num_pairs, rest))
+    }
+
+    fn explain_operation(&mut self, fields: &mut Fields, bytes: &'a [u8]) -> Result<(), ApiError> {
+        let (op_type, rest) = split(bytes, OPERATION_ENCODING_LENGTH, "Input should be longer than operation type encoding")?;
+        let (operation, label) = match op_type[0] {
+            OPERATION_G1_ADD => (Some(OperationType::G1ADD), "G1ADD"),
+            OPERATION_G1_MUL => (Some(OperationType::G1MUL), "G1MUL"),
+            OPERATION_G1_MULTIEXP => (Some(OperationType::G1MULTIEXP), "G1MULTIEXP"),
+            OPERATION_G2_ADD => (Some(OperationType::G2ADD), "G2ADD"),
+            OPERATION_G2_MUL => (Some(OperationType::G2MUL), "G2MUL"),
+            OPERATION_G2_MULTIEXP => (Some(OperationType::G2MULTIEXP), "G2MULTIEXP"),
+            OPERATION_PAIRING => (None, "PAIRING"),
+            _ => {
+                return Err(ApiError::coded_at(ErrorCode::InvalidEncoding, "Unknown operation type", bytes));
+            }
+        };
+        self.operation = operation;
+        self.push(fields, "operation", bytes, rest, FieldValue::Label(label));
+
+        let rest = match op_type[0] {
+            OPERATION_G1_ADD | OPERATION_G1_MUL | OPERATION_G1_MULTIEXP => {
+                let (modulus_len, order_len, rest) = self.g1_curve(fields, rest)?;
+                self.explain_points(fields, op_type[0], rest, modulus_len, 1, order_len)?
+            },
+            OPERATION_G2_ADD | OPERATION_G2_MUL | OPERATION_G2_MULTIEXP => {
+                let (modulus_len, degree, order_len, rest) = self.g2_curve(fields, rest)?;
+                let g1_operation = match op_type[0] {
+                    OPERATION_G2_ADD => OPERATION_G1_ADD,
+                    OPERATION_G2_MUL => OPERATION_G1_MUL,
+                    _ => OPERATION_G1_MULTIEXP,
+                };
+                self.explain_points(fields, g1_operation, rest, modulus_len, degree, order_len)?
+            },
+            _ => {
+                self.explain_pairing(fields, rest)?
+            }
+        };
+
+        if !rest.is_empty() {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", rest));
+        }
+
+        Ok(())
+    }
+
+    // points and scalars of G1 operations and their G2 counterparts
+    fn explain_points(&mut self, fields: &mut Fields, g1_operation: u8, bytes: &'a [u8], modulus_len: usize, degree: usize, order_len: usize) -> Result<&'a [u8], ApiError> {
+        match g1_operation {
+            OPERATION_G1_ADD => {
+                let rest = self.point(fields, "p0", bytes, modulus_len, degree)?;
+
+                self.point(fields, "p1", rest, modulus_len, degree)
+            },
+            OPERATION_G1_MUL => {
+                let rest = self.point(fields, "point", bytes, modulus_len, degree)?;
+
+                self.scalar(fields, "scalar", rest, order_len)
+            },
+            _ => {
+                let (num_pairs, mut rest) = self.num_pairs(fields, bytes, "Invalid number of pairs")?;
+                for i in 0..num_pairs {
+                    rest = self.group(fields, &format!("pair {}", i), rest, |this, fields, rest| {
+                        let rest = this.point(fields, "point", rest, modulus_len, degree)?;
+
+                        this.scalar(fields, "scalar", rest, order_len)
+                    })?;
+                }
+
+                Ok(rest)
+            }
+        }
+    }
+
+    fn scalar(&mut self, fields: &mut Fields, name: &str, bytes: &'a [u8], order_len: usize) -> Result<&'a [u8], ApiError> {
+        let (_, rest) = decode_scalar_representation(bytes, order_len)?;
+        self.push(fields, name, bytes, rest, FieldValue::Integer(bytes[..order_len].to_vec()));
+
+        Ok(rest)
+    }
+
+    fn twist_type(&mut self, fields: &mut Fields, bytes: &'a [u8]) -> Result<&'a [u8], ApiError> {
+        let (twist_type, rest) = decode_twist_type(bytes)?;
+        let label = match twist_type {
+            TwistType::M => "M",
+            TwistType::D => "D",
+        };
+        self.push(fields, "twist_type", bytes, rest, FieldValue::Label(label));
+
+        Ok(rest)
+    }
+
+    fn explain_pairing(&mut self, fields: &mut Fields, bytes: &'a [u8]) -> Result<&'a [u8], ApiError> {
+        let (curve_type, rest) = split(bytes, CURVE_TYPE_LENGTH, "Input should be longer than curve type encoding")?;
+        let (operation, label) = match curve_type[0] {
+            BLS12 => (OperationType::BLS12PAIR, "BLS12"),
+            BN => (OperationType::BNPAIR, "BN"),
+            MNT4 => (OperationType::MNT4PAIR, "MNT4"),
+            MNT6 => (OperationType::MNT6PAIR, "MNT6"),
+            BW6 => (OperationType::BW6PAIR, "BW6"),
+            BLS24 => (OperationType::BLS24PAIR, "BLS24"),
+            _ => {
+                return Err(ApiError::coded_at(ErrorCode::InvalidEncoding, "Unknown curve type", bytes));
+            }
+        };
+        self.operation = Some(operation);
+        self.push(fields, "curve_type", bytes, rest, FieldValue::Label(label));
+
+        let (modulus_len, _, rest) = self.g1_curve(fields, rest)?;
+        let rest = self.integer(fields, "fp_non_residue", rest, modulus_len, "Input is not long enough to get Fp non-residue")?;
+
+        let (rest, g2_degree) = match operation {
+            OperationType::BLS12PAIR | OperationType::BNPAIR => {
+                let rest = self.element(fields, "fp2_non_residue", rest, modulus_len, 2)?;
+                let after_twist = self.twist_type(fields, rest)?;
+                let (name, bit_limit) = if operation == OperationType::BLS12PAIR {
+                    ("x", MAX_BLS12_X_BIT_LENGTH)
+                } else {
+                    ("u", MAX_BN_U_BIT_LENGTH)
+                };
+                let rest = self.loop_parameter(fields, name, after_twist, bit_limit)?;
+                let rest = self.sign(fields, &format!("{}_sign", name), rest)?;
+
+                (rest, 2)
+            },
+            OperationType::BLS24PAIR => {
+                let rest = self.element(fields, "fp4_non_residue", rest, modulus_len, 4)?;
+                let rest = self.twist_type(fields, rest)?;
+                let rest = self.loop_parameter(fields, "x", rest, MAX_BLS24_X_BIT_LENGTH)?;
+                let rest = self.sign(fields, "x_sign", rest)?;
+
+                (rest, 4)
+            },
+            OperationType::BW6PAIR => {
+                let rest = self.twist_type(fields, rest)?;
+                let rest = self.loop_parameter(fields, "ate_loop_1", rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
+                let rest = self.sign(fields, "ate_loop_1_sign", rest)?;
+                let rest = self.loop_parameter(fields, "ate_loop_2", rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
+                let rest = self.sign(fields, "ate_loop_2_sign", rest)?;
+                let rest = self.loop_parameter(fields, "exp_w0", rest, MAX_ATE_PAIRING_FINAL_EXP_W0_BIT_LENGTH)?;
+                let rest = self.loop_parameter(fields, "exp_w1", rest, MAX_ATE_PAIRING_FINAL_EXP_W1_BIT_LENGTH)?;
+                let rest = self.sign(fields, "exp_w0_sign", rest)?;
+
+                (rest, 1)
+            },
+            _ => {
+                let rest = self.loop_parameter(fields, "x", rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
+                let rest = self.sign(fields, "x_sign", rest)?;
+                let rest = self.loop_parameter(fields, "exp_w0", rest, MAX_ATE_PAIRING_FINAL_EXP_W0_BIT_LENGTH)?;
+                let rest = self.loop_parameter(fields, "exp_w1", rest, MAX_ATE_PAIRING_FINAL_EXP_W1_BIT_LENGTH)?;
+                let rest = self.sign(fields, "exp_w0_sign", rest)?;
+
+                (rest, if operation == OperationType::MNT4PAIR { 2 } else { 3 })
+            }
+        };
+
+        let (num_pairs, mut rest) = self.num_pairs(fields, rest, "Zero pairs encoded")?;
+        for i in 0..num_pairs {
+            rest = self.group(fields, &format!("pair {}", i), rest, |this, fields, rest| {
+                let rest = this.boolean(fields, "g1_subgroup_check", rest)?;
+                let rest = this.point(fields, "g1", rest, modulus_len, 1)?;
+                let rest = this.boolean(fields, "g2_subgroup_check", rest)?;
+
+                this.point(fields, "g2", rest, modulus_len, g2_degree)
+            })?;
+        }
+
+        Ok(rest)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_explain_truncated_and_unknown_inputs() {
+        let explanation = decode_and_explain(&[]);
+        assert!(explanation.fields.is_empty());
+        assert_eq!(explanation.failure.unwrap().code, ErrorCode::InvalidLength);
+
+        let explanation = decode_and_explain(&[0xff]);
+        let failure = explanation.failure.unwrap();
+        assert_eq!(failure.code, ErrorCode::InvalidEncoding);
+        assert_eq!(failure.offset, Some(0));
+
+        // modulus length is there, the modulus itself is cut
+        let explanation = decode_and_explain(&[OPERATION_G1_ADD, 32, 0xff]);
+        assert_eq!(explanation.operation, Some(OperationType::G1ADD));
+        assert_eq!(explanation.find(&["operation"]).unwrap().value, FieldValue::Label("G1ADD"));
+        let curve = explanation.find(&["curve"]).unwrap();
+        assert_eq!(curve.offset, 1);
+        assert!(curve.children.is_empty());
+        let failure = explanation.failure.unwrap();
+        assert_eq!(failure.code, ErrorCode::InvalidLength);
+        assert_eq!(failure.offset, Some(2));
+    }
+
+    #[test]
+    fn test_explain_pairing() {
+        use crate::test::pairings::bls12::assemble_bls12_381;
+
+        let mut input = vec![OPERATION_PAIRING];
+        input.extend(assemble_bls12_381(2));
+
+        let explanation = decode_and_explain(&input);
+        assert_eq!(explanation.operation, Some(OperationType::BLS12PAIR));
+        assert_eq!(explanation.modulus_limbs, Some(6));
+        assert!(explanation.failure.is_none());
+        #[cfg(feature = "gas_metering")]
+        assert!(explanation.gas.is_some());
+        assert_eq!(explanation.find(&["twist_type"]).unwrap().value, FieldValue::Label("M"));
+        assert_eq!(explanation.find(&["x_sign"]).unwrap().value, FieldValue::Label("negative"));
+        let g1_y = explanation.find(&["pair 1", "g1", "y"]).unwrap().clone();
+        assert_eq!(explanation.find(&["pair 1", "g2", "y", "c1"]).unwrap().offset + 48, input.len());
+
+        // move the point out of the curve
+        input[g1_y.offset + g1_y.length - 1] ^= 1;
+        let explanation = decode_and_explain(&input);
+        let failure = explanation.failure.clone().unwrap();
+        assert_eq!(failure.code, ErrorCode::PointNotOnCurve);
+        assert_eq!(failure.offset, Some(explanation.find(&["pair 1", "g1"]).unwrap().offset));
+
+        // garbage at the end
+        input.push(0);
+        let failure = decode_and_explain(&input).failure.unwrap();
+        assert_eq!(failure.code, ErrorCode::InvalidLength);
+        assert_eq!(failure.offset, Some(input.len() - 1));
+    }
+}
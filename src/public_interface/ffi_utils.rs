@@ -0,0 +1,198 @@
+//! Helpers shared by the bounds-checked (v2) C interfaces. Caller passes capacities of all the
+//! buffers, nothing is written past them and panics never cross the FFI boundary
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::errors::{ApiError, ErrorCode};
+
+/// Runs `operation` over the input and copies the result into the output buffer. On success
+/// `output_len` is set to the length of the result, if the output buffer is too small nothing is
+/// written, `output_len` is set to the required length and `ErrorCode::BufferTooSmall` is returned
+pub(crate) unsafe fn perform_with_buffers<F>(
+    input: *const u8,
+    input_len: u32,
+    output: *mut u8,
+    output_capacity: u32,
+    output_len: *mut u32,
+    error: *mut u8,
+    error_capacity: u32,
+    error_len: *mut u32,
+    operation: F
+) -> u32
+    where F: FnOnce(&[u8]) -> Result<Vec<u8>, ApiError>
+{
+    if output_len.is_null() || error_len.is_null() {
+        return ErrorCode::NullPointer.as_u8() as u32;
+    }
+    *output_len = 0;
+    *error_len = 0;
+
+    if output.is_null() && output_capacity != 0 {
+        let err = ApiError::coded(ErrorCode::NullPointer, "Output buffer is null");
+        return write_error(&err, &[], error, error_capacity, error_len);
+    }
+
+    let input = match input_slice(input, input_len) {
+        Ok(input) => input,
+        Err(err) => {
+            return write_error(&err, &[], error, error_capacity, error_len);
+        }
+    };
+
+    let result = run_catching_panics(input, operation);
+
+    match result {
+        Ok(result) => {
+            *output_len = result.len() as u32;
+            if result.len() > output_capacity as usize {
+                let err = ApiError::coded(
+                    ErrorCode::BufferTooSmall,
+                    format!("Output buffer is too small, {} bytes required", result.len())
+                );
+
+                return write_error(&err, input, error, error_capacity, error_len);
+            }
+            if !result.is_empty() {
+                std::ptr::copy_nonoverlapping(result.as_ptr(), output, result.len());
+            }
+
+            0u32
+        },
+        Err(err) => {
+            write_error(&err, input, error, error_capacity, error_len)
+        }
+    }
+}
+
+/// Same as `perform_with_buffers` for operations returning a single integer, e.g. gas metering
+pub(crate) unsafe fn meter_with_buffers<F>(
+    input: *const u8,
+    input_len: u32,
+    output: *mut u64,
+    error: *mut u8,
+    error_capacity: u32,
+    error_len: *mut u32,
+    operation: F
+) -> u32
+    where F: FnOnce(&[u8]) -> Result<u64, ApiError>
+{
+    if output.is_null() || error_len.is_null() {
+        return ErrorCode::NullPointer.as_u8() as u32;
+    }
+    *output = 0;
+    *error_len = 0;
+
+    let input = match input_slice(input, input_len) {
+        Ok(input) => input,
+        Err(err) => {
+            return write_error(&err, &[], error, error_capacity, error_len);
+        }
+    };
+
+    match run_catching_panics(input, operation) {
+        Ok(result) => {
+            *output = result;
+
+            0u32
+        },
+        Err(err) => {
+            write_error(&err, input, error, error_capacity, error_len)
+        }
+    }
+}
+
+unsafe fn input_slice<'a>(input: *const u8, input_len: u32) -> Result<&'a [u8], ApiError> {
+    if input_len == 0 {
+        return Ok(&[]);
+    }
+    if input.is_null() {
+        return Err(ApiError::coded(ErrorCode::NullPointer, "Input buffer is null"));
+    }
+
+    Ok(std::slice::from_raw_parts(input, input_len as usize))
+}
+
+fn run_catching_panics<T, F>(input: &[u8], operation: F) -> Result<T, ApiError>
+    where F: FnOnce(&[u8]) -> Result<T, ApiError>
+{
+    match catch_unwind(AssertUnwindSafe(|| operation(input))) {
+        Ok(result) => result,
+        Err(_) => {
+            Err(ApiError::coded(ErrorCode::Panic, "Operation panicked"))
+        }
+    }
+}
+
+// error description is truncated to the capacity, `error_len` is always set to its full length
+unsafe fn write_error(err: &ApiError, input: &[u8], error: *mut u8, error_capacity: u32, error_len: *mut u32) -> u32 {
+    let description = err.to_string();
+    let description = description.as_bytes();
+    *error_len = description.len() as u32;
+    if !error.is_null() {
+        let to_write = std::cmp::min(description.len(), error_capacity as usize);
+        std::ptr::copy_nonoverlapping(description.as_ptr(), error, to_write);
+    }
+
+    err.packed_code(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_buffer_capacities() {
+        let input = [1u8, 2, 3];
+        let echo = |input: &[u8]| Ok(input.to_vec());
+
+        let mut output = [0u8; 3];
+        let mut output_len = 0u32;
+        let mut error = [0u8; 64];
+        let mut error_len = 0u32;
+
+        let code = unsafe {
+            perform_with_buffers(input.as_ptr(), 3, output.as_mut_ptr(), 2, &mut output_len, error.as_mut_ptr(), 64, &mut error_len, echo)
+        };
+        assert_eq!(code, ErrorCode::BufferTooSmall.as_u8() as u32);
+        assert_eq!(output_len, 3);
+        assert_eq!(output, [0u8; 3]);
+
+        let code = unsafe {
+            perform_with_buffers(input.as_ptr(), 3, output.as_mut_ptr(), 3, &mut output_len, std::ptr::null_mut(), 0, &mut error_len, echo)
+        };
+        assert_eq!(code, 0);
+        assert_eq!(output, input);
+
+        // error description doesn't fit, but its full length is reported
+        let mut short_error = [0u8; 8];
+        let code = unsafe {
+            perform_with_buffers(input.as_ptr(), 3, output.as_mut_ptr(), 3, &mut output_len, short_error.as_mut_ptr(), 4, &mut error_len, |_| -> Result<Vec<u8>, ApiError> {
+                Err(ApiError::coded_at(ErrorCode::InvalidLength, "too short", &input[1..]))
+            })
+        };
+        assert_eq!(code, (2 << 8) | (ErrorCode::InvalidLength.as_u8() as u32));
+        assert_eq!(error_len as usize, "invalid input parameters, too short".len());
+        assert_eq!(&short_error[..4], b"inva");
+        assert_eq!(short_error[4], 0);
+    }
+
+    #[test]
+    fn test_panics_are_caught() {
+        let mut output = 0u64;
+        let mut error = [0u8; 64];
+        let mut error_len = 0u32;
+
+        let code = unsafe {
+            meter_with_buffers(std::ptr::null(), 0, &mut output, error.as_mut_ptr(), 64, &mut error_len, |_| -> Result<u64, ApiError> {
+                panic!("must be caught")
+            })
+        };
+        assert_eq!(code, ErrorCode::Panic.as_u8() as u32);
+        assert!(error_len > 0);
+
+        let code = unsafe {
+            meter_with_buffers(std::ptr::null(), 1, &mut output, error.as_mut_ptr(), 64, &mut error_len, |_| Ok(1u64))
+        };
+        assert_eq!(code, ErrorCode::NullPointer.as_u8() as u32);
+    }
+}
@@ -16,7 +16,8 @@
 /// - one byte for length encoding
 
 use crate::weierstrass::{Group, CurveOverFpParameters};
-use crate::weierstrass::curve::{CurvePoint, WeierstrassCurve};
+use crate::weierstrass::curve::CurvePoint;
+use crate::weierstrass::glv::glv_parameters_for_known_curve;
 use crate::representation::ElementRepr;
 use crate::multiexp::peppinger;
 use crate::field::*;
@@ -24,9 +25,9 @@ use super::constants::*;
 
 use super::decode_g1::*;
 use super::decode_utils::*;
-use super::decode_fp::*;
+use super::curve_cache::parse_g1_cached;
 
-use crate::errors::ApiError;
+use crate::errors::{ApiError, ErrorCode};
 
 pub trait G1Api {
     fn add_points(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
@@ -40,31 +41,30 @@ pub struct G1ApiImplementation<FE: ElementRepr> {
 
 impl<FE: ElementRepr> G1Api for G1ApiImplementation<FE> {
     fn add_points(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
-        let (field, modulus_len, _, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
-        let (a, b, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &field)?;
-        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+        let (prepared, rest) = parse_g1_cached::<FE>(&bytes)?;
+        let modulus_len = prepared.modulus_len;
 
-        let fp_params = CurveOverFpParameters::new(&field);
+        let fp_params = CurveOverFpParameters::new(&prepared.base_field);
 
-        let curve = WeierstrassCurve::new(&order.as_ref(), a, b, &fp_params).map_err(|_| {
-            ApiError::InputError("Curve shape is not supported".to_owned())
-        })?;
+        let curve = prepared.g1_curve(&fp_params)?;
 
+        let p_0_encoding = rest;
         let (mut p_0, rest) = decode_g1_point_from_xy(rest, modulus_len, &curve)?;
+        let p_1_encoding = rest;
         let (p_1, rest) = decode_g1_point_from_xy(rest, modulus_len, &curve)?;
 
         if rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", rest));
         }
 
         if !p_0.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point 0 is not on curve, file {}, line {}", file!(), line!()), p_0_encoding));
             }
         }
         if !p_1.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point 1 is not on curve, file {}, line {}", file!(), line!()), p_1_encoding));
             }
         }
 
@@ -74,55 +74,58 @@ impl<FE: ElementRepr> G1Api for G1ApiImplementation<FE> {
     }
 
     fn mul_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
-        let (field, modulus_len, _, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
-        let (a, b, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &field)?;
-        let (order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+        let (prepared, rest) = parse_g1_cached::<FE>(&bytes)?;
+        let modulus_len = prepared.modulus_len;
+        let order_len = prepared.order_len;
 
-        let fp_params = CurveOverFpParameters::new(&field);
+        let fp_params = CurveOverFpParameters::new(&prepared.base_field);
 
-        let curve = WeierstrassCurve::new(&order.as_ref(), a, b, &fp_params).map_err(|_| {
-            ApiError::InputError("Curve shape is not supported".to_owned())
-        })?;
+        let mut curve = prepared.g1_curve(&fp_params)?;
 
+        if let Some(glv) = glv_parameters_for_known_curve(&curve) {
+            curve.set_glv_parameters(glv);
+        }
+
+        let p_0_encoding = rest;
         let (p_0, rest) = decode_g1_point_from_xy(rest, modulus_len, &curve)?;
         let (scalar, rest) = decode_scalar_representation(rest, order_len)?;
 
         if rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", rest));
         }
 
-        if !p_0.is_on_curve() {
+        let p = if !p_0.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point is not on curve, file {}, line {}", file!(), line!()), p_0_encoding));
             }
-        }
-
-        let p = p_0.mul(&scalar);
+            p_0.mul(&scalar)
+        } else {
+            p_0.glv_mul(&scalar, false)
+        };
 
         serialize_g1_point(modulus_len, &p)   
     }
 
     fn multiexp(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
-        let (field, modulus_len, _, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
-        let (a, b, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &field)?;
-        let (order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+        let (prepared, rest) = parse_g1_cached::<FE>(&bytes)?;
+        let modulus_len = prepared.modulus_len;
+        let order_len = prepared.order_len;
 
-        let fp_params = CurveOverFpParameters::new(&field);
+        let fp_params = CurveOverFpParameters::new(&prepared.base_field);
 
-        let curve = WeierstrassCurve::new(&order.as_ref(), a, b, &fp_params).map_err(|_| {
-            ApiError::InputError("Curve shape is not supported".to_owned())
-        })?;
+        let curve = prepared.g1_curve(&fp_params)?;
 
+        let num_pairs_position = rest;
         let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
         let num_pairs = num_pairs_encoding[0] as usize;
 
         if num_pairs == 0 {
-            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid number of pairs", num_pairs_position));
         }
 
         let expected_pair_len = 2*modulus_len + order_len;
         if rest.len() != expected_pair_len * num_pairs {
-            return Err(ApiError::InputError("Input length is invalid for number of pairs".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input length is invalid for number of pairs", rest));
         }
 
         let mut global_rest = rest;
@@ -134,7 +137,7 @@ impl<FE: ElementRepr> G1Api for G1ApiImplementation<FE> {
             let (scalar, local_rest) = decode_scalar_representation(local_rest, order_len)?;
             if !p.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point is not on curve, file {}, line {}", file!(), line!()), global_rest));
                 }
             }
             bases.push(p);
@@ -143,7 +146,7 @@ impl<FE: ElementRepr> G1Api for G1ApiImplementation<FE> {
         }
 
         if global_rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", global_rest));
         }
 
         if bases.len() != scalars.len() || bases.len() == 0 {
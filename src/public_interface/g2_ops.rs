@@ -11,7 +11,7 @@ use super::decode_g1::*;
 use super::constants::*;
 use super::decode_fp::*;
 
-use crate::errors::ApiError;
+use crate::errors::{ApiError, ErrorCode};
 
 /// Every call has common parameters (may be redundant):
 /// - Lengths of modulus (in bytes)
@@ -46,21 +46,23 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp2<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
+        let p_0_encoding = rest;
         let (mut p_0, rest) = decode_g2_point_from_xy_in_fp2(rest, modulus_len, &curve)?;
+        let p_1_encoding = rest;
         let (p_1, rest) = decode_g2_point_from_xy_in_fp2(rest, modulus_len, &curve)?;
 
         if rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", rest));
         }
 
         if !p_0.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point 0 is not on curve, file {}, line {}", file!(), line!()), p_0_encoding));
             }
         }
         if !p_1.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point 1 is not on curve, file {}, line {}", file!(), line!()), p_1_encoding));
             }
         }
 
@@ -81,16 +83,17 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp2<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
+        let p_0_encoding = rest;
         let (p_0, rest) = decode_g2_point_from_xy_in_fp2(rest, modulus_len, &curve)?;
         let (scalar, rest) = decode_scalar_representation(rest, order_len)?;
 
         if rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", rest));
         }
 
         if !p_0.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point is not on curve, file {}, line {}", file!(), line!()), p_0_encoding));
             }
         }
 
@@ -111,16 +114,17 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp2<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
+        let num_pairs_position = rest;
         let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
         let num_pairs = num_pairs_encoding[0] as usize;
 
         if num_pairs == 0 {
-            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid number of pairs", num_pairs_position));
         }
 
         let expected_pair_len = 4*modulus_len + order_len;
         if rest.len() != expected_pair_len * num_pairs {
-            return Err(ApiError::InputError("Input length is invalid for number of pairs".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input length is invalid for number of pairs", rest));
         }
 
         let mut global_rest = rest;
@@ -131,7 +135,7 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp2<FE> {
             let (p, local_rest) = decode_g2_point_from_xy_in_fp2(global_rest, modulus_len, &curve)?;
             if !p.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point is not on curve, file {}, line {}", file!(), line!()), global_rest));
                 }
             }
             let (scalar, local_rest) = decode_scalar_representation(local_rest, order_len)?;
@@ -141,7 +145,7 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp2<FE> {
         }
 
         if global_rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", global_rest));
         }
 
         if bases.len() != scalars.len() || bases.len() == 0 {
@@ -176,21 +180,23 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp3<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
+        let p_0_encoding = rest;
         let (mut p_0, rest) = decode_g2_point_from_xy_in_fp3(rest, modulus_len, &curve)?;
+        let p_1_encoding = rest;
         let (p_1, rest) = decode_g2_point_from_xy_in_fp3(rest, modulus_len, &curve)?;
 
         if rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", rest));
         }
 
         if !p_0.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point 0 is not on curve, file {}, line {}", file!(), line!()), p_0_encoding));
             }
         }
         if !p_1.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point 1 is not on curve, file {}, line {}", file!(), line!()), p_1_encoding));
             }
         }
 
@@ -211,16 +217,17 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp3<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
+        let p_0_encoding = rest;
         let (p_0, rest) = decode_g2_point_from_xy_in_fp3(rest, modulus_len, &curve)?;
         let (scalar, rest) = decode_scalar_representation(rest, order_len)?;
 
         if rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", rest));
         }
 
         if !p_0.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point is not on curve, file {}, line {}", file!(), line!()), p_0_encoding));
             }
         }
 
@@ -241,16 +248,17 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp3<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
+        let num_pairs_position = rest;
         let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
         let num_pairs = num_pairs_encoding[0] as usize;
 
         if num_pairs == 0 {
-            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid number of pairs", num_pairs_position));
         }
 
         let expected_pair_len = 6*modulus_len + order_len;
         if rest.len() != expected_pair_len * num_pairs {
-            return Err(ApiError::InputError("Input length is invalid for number of pairs".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input length is invalid for number of pairs", rest));
         }
 
         let mut global_rest = rest;
@@ -261,7 +269,7 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp3<FE> {
             let (p, local_rest) = decode_g2_point_from_xy_in_fp3(global_rest, modulus_len, &curve)?;
             if !p.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, format!("Point is not on curve, file {}, line {}", file!(), line!()), global_rest));
                 }
             }
             let (scalar, local_rest) = decode_scalar_representation(local_rest, order_len)?;
@@ -271,7 +279,7 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp3<FE> {
         }
 
         if global_rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", global_rest));
         }
 
         if bases.len() != scalars.len() || bases.len() == 0 {
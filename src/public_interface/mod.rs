@@ -3,32 +3,54 @@ pub(crate) mod decode_g2;
 pub(crate) mod decode_fp;
 pub(crate) mod decode_utils;
 
+pub mod zcash_encoding;
+
 #[macro_use]
 pub(crate) mod api_specialization_macro;
 
 mod g1_ops;
 mod g2_ops;
 mod pairing_ops;
+pub(crate) mod prepared_curve;
 
 pub mod sane_limits;
 pub mod constants;
 
-pub use pairing_ops::{PairingApi, PublicPairingApi};
+pub use pairing_ops::{PairingApi, PublicPairingApi, TargetGroupElement};
 pub use g1_ops::{G1Api, PublicG1Api};
 pub use g2_ops::{G2Api, PublicG2Api};
 
+pub mod curve_builder;
+pub mod curve_cache;
+pub mod abi_encoder;
+pub mod explain;
+pub(crate) mod multiexp_discounts;
+
 mod unified_api;
 pub use self::unified_api::{OperationType, perform_operation, PREALLOCATE_FOR_ERROR_BYTES, PREALLOCATE_FOR_RESULT_BYTES};
-pub use crate::errors::ApiError;
+pub use crate::errors::{ApiError, ErrorCode};
+pub use self::explain::decode_and_explain;
+
+#[cfg(any(feature = "c_api", feature = "eip_2357_c_api", feature = "eip_196_c_api"))]
+pub(crate) mod ffi_utils;
 
 #[cfg(feature = "c_api")]
 mod c_api;
 #[cfg(feature = "c_api")]
-pub use self::c_api::{c_perform_operation};
+pub use self::c_api::{c_perform_operation, c_perform_operation_v2};
 
 #[cfg(feature = "eip_2537")]
 pub mod eip2537;
 
+#[cfg(feature = "eip_196")]
+pub mod eip196;
+
+#[cfg(feature = "eip_2539")]
+pub mod eip2539;
+
+#[cfg(feature = "edwards")]
+pub mod edwards;
+
 pub struct API;
 
 impl API {
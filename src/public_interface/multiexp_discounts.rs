@@ -0,0 +1,53 @@
+//! Discounts for multiexponentiations from EIP-2537. EIP-2539 and the Edwards curves price
+//! their multiexponentiations with the same table
+
+use crate::public_interface::ApiError;
+
+pub const MULTIEXP_DISCOUNT_MULTIPLIER: u64 = 1000;
+pub const MULTIEXP_MAX_DISCOUNT: u64 = 174;
+
+// discounts for k = 1..=128 pairs, larger k use MULTIEXP_MAX_DISCOUNT
+const MULTIEXP_DISCOUNTS: [u64; 128] = [
+    1200, 888, 764, 641, 594, 547, 500, 453, 438, 423, 408, 394, 379, 364, 349, 334,
+    330, 326, 322, 318, 314, 310, 306, 302, 298, 294, 289, 285, 281, 277, 273, 269,
+    268, 266, 265, 263, 262, 260, 259, 257, 256, 254, 253, 251, 250, 248, 247, 245,
+    244, 242, 241, 239, 238, 236, 235, 233, 232, 231, 229, 228, 226, 225, 223, 222,
+    221, 220, 219, 219, 218, 217, 216, 216, 215, 214, 213, 213, 212, 211, 211, 210,
+    209, 208, 208, 207, 206, 205, 205, 204, 203, 202, 202, 201, 200, 199, 199, 198,
+    197, 196, 196, 195, 194, 193, 193, 192, 191, 191, 190, 189, 188, 188, 187, 186,
+    185, 185, 184, 183, 182, 182, 181, 180, 179, 179, 178, 177, 176, 176, 175, 174,
+];
+
+fn multiexp_discount(num_pairs: usize) -> u64 {
+    debug_assert!(num_pairs != 0);
+    if num_pairs > MULTIEXP_DISCOUNTS.len() {
+        MULTIEXP_MAX_DISCOUNT
+    } else {
+        MULTIEXP_DISCOUNTS[num_pairs - 1]
+    }
+}
+
+pub(crate) fn meter_multiexp(num_pairs: usize, multiplication_cost: u64) -> Result<u64, ApiError> {
+    let discount = multiexp_discount(num_pairs);
+
+    let mut result = (num_pairs as u64).checked_mul(multiplication_cost).ok_or(ApiError::Overflow)?;
+    result = result.checked_mul(discount).ok_or(ApiError::Overflow)?;
+    result = result / MULTIEXP_DISCOUNT_MULTIPLIER;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_multiexp_discounts() {
+        assert_eq!(multiexp_discount(1), 1200);
+        assert_eq!(multiexp_discount(128), 174);
+        assert_eq!(multiexp_discount(129), MULTIEXP_MAX_DISCOUNT);
+        for w in MULTIEXP_DISCOUNTS.windows(2) {
+            assert!(w[0] >= w[1]);
+        }
+    }
+}
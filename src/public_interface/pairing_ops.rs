@@ -16,17 +16,19 @@
 /// 
 
 use crate::weierstrass::curve::WeierstrassCurve;
-use crate::weierstrass::{Group, CurveOverFpParameters, CurveOverFp2Parameters, CurveOverFp3Parameters};
+use crate::weierstrass::{Group, CurveOverFpParameters, CurveOverFp2Parameters, CurveOverFp3Parameters, CurveOverFp4Parameters};
 use crate::pairings::*;
 use crate::pairings::bls12::{Bls12Instance, Bls12InstanceParams};
 use crate::pairings::bn::{BnInstance, BnInstanceParams};
 use crate::pairings::mnt4::{MNT4Instance, MNT4InstanceParams};
 use crate::pairings::mnt6::{MNT6Instance, MNT6InstanceParams};
+use crate::pairings::bw6::{BW6Instance, BW6InstanceParams};
+use crate::pairings::bls24::{Bls24Instance, Bls24InstanceParams};
 use crate::representation::{ElementRepr};
 use crate::traits::{FieldElement, ZeroAndOne};
 use crate::extension_towers::*;
 use crate::fp::Fp;
-use crate::integers::*;
+use crate::integers::MaxFieldUint;
 
 use super::decode_g1::*;
 use super::decode_utils::*;
@@ -34,8 +36,11 @@ use super::decode_fp::*;
 use super::decode_g2::*;
 use super::constants::*;
 use super::sane_limits::*;
+use super::prepared_curve::{Tower, fp2_from_reprs};
+use super::curve_cache::parse_pairing_cached;
+use super::curve_builder::PairingFamily;
 
-use crate::errors::ApiError;
+use crate::errors::{ApiError, ErrorCode};
 
 fn pairing_result_false() -> Vec<u8> {
     vec![0u8]
@@ -45,6 +50,14 @@ fn pairing_result_true() -> Vec<u8> {
     vec![1u8]
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PairingOutput {
+    // single byte that is 1 if the pairing result is equal to one and 0 otherwise
+    Boolean,
+    // serialized element of the target group
+    TargetGroupElement
+}
+
 pub struct PublicPairingApi;
 
 impl PairingApi for PublicPairingApi {
@@ -58,10 +71,93 @@ impl PairingApi for PublicPairingApi {
 
         result
     }
+
+    fn pair_raw(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        use crate::field::*;
+        let (_curve_type, rest) = split(bytes, CURVE_TYPE_LENGTH, "Input should be longer than curve type encoding")?;
+        let (_, modulus, _) = parse_modulus_and_length(&rest)?;
+        let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+
+        let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, bytes, pair_raw); 
+
+        result
+    }
+}
+
+/// Element of the target group decoded from an output of `PairingApi::pair_raw`, kept as a list
+/// of base field coefficients in the order of the encoding. Every coefficient is big-endian and has
+/// the length of the modulus
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TargetGroupElement {
+    coefficients: Vec<Vec<u8>>,
+}
+
+impl TargetGroupElement {
+    /// Degree of the extension over the base field: 4 for MNT4, 6 for MNT6 and BW6, 12 for BLS12 and BN
+    /// and 24 for BLS24 curves
+    pub fn extension_degree(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    pub fn coefficients(&self) -> &[Vec<u8>] {
+        &self.coefficients
+    }
+
+    pub fn is_one(&self) -> bool {
+        let (c0, rest) = self.coefficients.split_first().expect("element has at least one coefficient");
+        let (last, high) = c0.split_last().expect("coefficient is not empty");
+
+        *last == 1 && high.iter().all(|b| *b == 0) && rest.iter().all(|c| c.iter().all(|b| *b == 0))
+    }
+
+    /// Same encoding as the output of `pair_raw`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.coefficients.concat()
+    }
+}
+
+impl PublicPairingApi {
+    /// Decodes an output of `pair_raw`. Curve type and modulus are taken from `input`, that is the same
+    /// byte string as was given to `pair_raw`, and every coefficient must be below the modulus
+    pub fn decode_raw_output(input: &[u8], output: &[u8]) -> Result<TargetGroupElement, ApiError> {
+        let (curve_type, rest) = split(input, CURVE_TYPE_LENGTH, "Input should be longer than curve type encoding")?;
+        let (modulus_len, modulus, _) = parse_modulus_and_length(&rest)?;
+        if modulus_len == 0 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Encoded modulus length is zero", rest));
+        }
+
+        let extension_degree = match curve_type[0] {
+            BLS12 | BN => 12,
+            MNT4 => 4,
+            MNT6 | BW6 => 6,
+            BLS24 => 24,
+            _ => {
+                return Err(ApiError::InputError("Unknown curve type".to_owned()));
+            }
+        };
+
+        if output.len() != extension_degree * modulus_len {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Invalid length of the pairing result encoding", output));
+        }
+
+        let mut coefficients = Vec::with_capacity(extension_degree);
+        for (i, encoding) in output.chunks(modulus_len).enumerate() {
+            if MaxFieldUint::from_big_endian(encoding) >= modulus {
+                return Err(ApiError::coded_at(ErrorCode::NonCanonicalFieldElement, "Coefficient is not less than modulus", &output[i * modulus_len..]));
+            }
+            coefficients.push(encoding.to_vec());
+        }
+
+        Ok(TargetGroupElement { coefficients })
+    }
 }
 
 pub trait PairingApi {
     fn pair(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
+    /// Returns the pairing result itself: Fp24 for BLS24, Fp12 for BLS12 and BN, Fp6 for MNT6 and BW6 and Fp4 for MNT4 curves.
+    /// Every base field element is encoded big-endian using the modulus length, and coefficients
+    /// go from c0 to c1 (c2) recursively down the extension tower
+    fn pair_raw(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
 }
 
 pub(crate) struct PairingApiImplementation<FE: ElementRepr> {
@@ -85,6 +181,40 @@ impl<FE: ElementRepr> PairingApi for PairingApiImplementation<FE> {
             MNT6 => {
                 PairingApiImplementation::<FE>::pair_mnt6(&rest)
             },
+            BW6 => {
+                PairingApiImplementation::<FE>::pair_bw6(&rest)
+            },
+            BLS24 => {
+                PairingApiImplementation::<FE>::pair_bls24(&rest)
+            },
+            _ => {
+                return Err(ApiError::InputError("Unknown curve type".to_owned()));
+            }
+        }
+    }
+
+    fn pair_raw(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (curve_type, rest) = split(bytes, CURVE_TYPE_LENGTH, "Input should be longer than curve type encoding")?;
+
+        match curve_type[0] {
+            BLS12 => {
+                PairingApiImplementation::<FE>::pair_bls12_raw(&rest)
+            },
+            BN => {
+                PairingApiImplementation::<FE>::pair_bn_raw(&rest)
+            },
+            MNT4 => {
+                PairingApiImplementation::<FE>::pair_mnt4_raw(&rest)
+            },
+            MNT6 => {
+                PairingApiImplementation::<FE>::pair_mnt6_raw(&rest)
+            },
+            BW6 => {
+                PairingApiImplementation::<FE>::pair_bw6_raw(&rest)
+            },
+            BLS24 => {
+                PairingApiImplementation::<FE>::pair_bls24_raw(&rest)
+            },
             _ => {
                 return Err(ApiError::InputError("Unknown curve type".to_owned()));
             }
@@ -94,22 +224,14 @@ impl<FE: ElementRepr> PairingApi for PairingApiImplementation<FE> {
 
 impl<FE: ElementRepr>PairingApiImplementation<FE> {
     pub(crate) fn pair_bls12(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
-        use crate::extension_towers::fp2::{Fp2, Extension2};
-        use crate::extension_towers::fp6_as_3_over_2::{Fp6, Extension3Over2};
-        use crate::extension_towers::fp12_as_2_over3_over_2::{Fp12, Extension2Over3Over2};
-
-        let (base_field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
-        let (a_fp, b_fp, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &base_field)?;
-        if !a_fp.is_zero() {
-            return Err(ApiError::UnknownParameter("A parameter must be zero for BLS12 curve".to_owned()));
-        }
-        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
-        let fp_params = CurveOverFpParameters::new(&base_field);
-        let g1_curve = WeierstrassCurve::new(&order.as_ref(), a_fp, b_fp.clone(), &fp_params).map_err(|_| {
-            ApiError::InputError("Curve shape is not supported".to_owned())
-        })?;
+        Self::pair_bls12_with_output(bytes, PairingOutput::Boolean)
+    }
 
+    pub(crate) fn pair_bls12_raw(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        Self::pair_bls12_with_output(bytes, PairingOutput::TargetGroupElement)
+    }
 
+    fn pair_bls12_with_output(bytes: &[u8], output: PairingOutput) -> Result<Vec<u8>, ApiError> {
         // Now we need to expect:
         // - non-residue for Fp2
         // - non-residue for Fp6
@@ -118,104 +240,291 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         // - sign of X
         // - number of pairs
         // - list of encoded pairs
+        let (prepared, rest) = parse_pairing_cached::<FE>(PairingFamily::Bls12, &bytes)?;
 
-        let (fp_non_residue, rest) = decode_fp(&rest, modulus_len, &base_field)?;
+        prepared.with_tower(|tower| Self::pair_in_tower(tower, prepared.modulus_len, rest, output))
+    }
 
-        {
-            if fp_non_residue.is_zero() {
-                return Err(ApiError::InputError(format!("Non-residue for Fp2 is zero file {}, line {}", file!(), line!())));
+    pub(crate) fn pair_bn(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        Self::pair_bn_with_output(bytes, PairingOutput::Boolean)
+    }
+
+    pub(crate) fn pair_bn_raw(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        Self::pair_bn_with_output(bytes, PairingOutput::TargetGroupElement)
+    }
+
+    fn pair_bn_with_output(bytes: &[u8], output: PairingOutput) -> Result<Vec<u8>, ApiError> {
+        // Now we need to expect:
+        // - non-residue for Fp2
+        // - non-residue for Fp6
+        // - twist type M/D
+        // - parameter U
+        // - sign of U
+        // - number of pairs
+        // - list of encoded pairs
+        // U is used instead of x for convention of go-ethereum people :)
+        let (prepared, rest) = parse_pairing_cached::<FE>(PairingFamily::Bn, &bytes)?;
+
+        prepared.with_tower(|tower| Self::pair_in_tower(tower, prepared.modulus_len, rest, output))
+    }
+
+    // decodes pairs for BLS12 and BN curves and runs the pairing
+    fn pair_in_tower<'a>(tower: &Tower<'a, FE>, modulus_len: usize, bytes: &[u8], output: PairingOutput) -> Result<Vec<u8>, ApiError> {
+        use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+
+        let (num_pairs_encoding, rest) = split(bytes, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
+        let num_pairs = num_pairs_encoding[0] as usize;
+
+        if num_pairs == 0 {
+            if !crate::features::in_gas_metering() {
+                return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Zero pairs encoded", bytes));
             }
-            let is_not_a_square = is_non_nth_root(&fp_non_residue, &modulus, 2u64);
-            if !is_not_a_square {
+        }
+
+        let mut global_rest = rest;
+
+        let mut g1_points = vec![];
+        let mut g2_points = vec![];
+
+        for _ in 0..num_pairs {
+            let (check_g1_subgroup, rest) = decode_boolean(&global_rest)?;
+            let g1_encoding = rest;
+            let (g1, rest) = decode_g1_point_from_xy(&rest, modulus_len, tower.g1_curve)?;
+            let (check_g2_subgroup, rest) = decode_boolean(&rest)?;
+            let g2_encoding = rest;
+            let (g2, rest) = decode_g2_point_from_xy_in_fp2(&rest, modulus_len, tower.g2_curve)?;
+            global_rest = rest;
+
+            if !g1.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Non-residue for Fp2 is actually a residue file {}, line {}", file!(), line!())));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G1 point is not on curve", g1_encoding));
                 }
             }
+
+            if !g2.is_on_curve() {
+                if !crate::features::in_fuzzing_or_gas_metering() {
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G2 point is not on curve", g2_encoding));
+                }
+            }
+
+            if check_g1_subgroup {
+                if !g1.check_correct_subgroup() {
+                    if !crate::features::in_fuzzing_or_gas_metering() {
+                        return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 or G2 point is not in the expected subgroup", g1_encoding));
+                    }
+                }
+            }
+
+            if check_g2_subgroup {
+                if !g2.check_correct_subgroup() {
+                    if !crate::features::in_fuzzing_or_gas_metering() {
+                        return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 or G2 point is not in the expected subgroup", g2_encoding));
+                    }
+                }
+            }
+
+            if !g1.is_zero() && !g2.is_zero() {
+                g1_points.push(g1);
+                g2_points.push(g2);
+            }
         }
 
-        // build an extension field
-        let mut extension_2 = Extension2::new(fp_non_residue);
-        extension_2.calculate_frobenius_coeffs(&modulus).map_err(|_| {
-            ApiError::InputError("Failed to calculate Frobenius coeffs for Fp2".to_owned())
+        if global_rest.len() != 0 {
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", global_rest));
+        }
+
+        debug_assert!(g1_points.len() == g2_points.len());
+        let one_fp12 = Fp12::one(tower.fp12_extension);
+        if g1_points.len() == 0 {
+            return match output {
+                PairingOutput::Boolean => Ok(pairing_result_true()),
+                PairingOutput::TargetGroupElement => serialize_fp12_fixed_len(modulus_len, &one_fp12),
+            };
+        }
+
+        let params = tower.params;
+        let pairing_result = match params.family {
+            PairingFamily::Bls12 => {
+                let engine_params = Bls12InstanceParams {
+                    x: &params.x.as_ref(),
+                    x_is_negative: params.x_is_negative,
+                    twist_type: params.twist_type,
+                    base_field: tower.base_field,
+                    curve: tower.g1_curve,
+                    curve_twist: tower.g2_curve,
+                    fp2_extension: tower.fp2_extension,
+                    fp6_extension: tower.fp6_extension,
+                    fp12_extension: tower.fp12_extension,
+                    force_no_naf: true
+                };
+
+                let engine = Bls12Instance::from_params(engine_params);
+
+                engine.pair(&g1_points, &g2_points)
+            },
+            PairingFamily::Bn => {
+                let engine_params = BnInstanceParams {
+                    u: &params.x.as_ref(),
+                    six_u_plus_2: &params.six_u_plus_2.as_ref(),
+                    u_is_negative: params.x_is_negative,
+                    twist_type: params.twist_type,
+                    base_field: tower.base_field,
+                    curve: tower.g1_curve,
+                    curve_twist: tower.g2_curve,
+                    fp2_extension: tower.fp2_extension,
+                    fp6_extension: tower.fp6_extension,
+                    fp12_extension: tower.fp12_extension,
+                    non_residue_in_p_minus_one_over_2: fp2_from_reprs(&params.non_residue_in_p_minus_one_over_2, tower.fp2_extension),
+                    force_no_naf: true
+                };
+
+                let engine = BnInstance::from_params(engine_params);
+
+                engine.pair(&g1_points, &g2_points)
+            }
+        };
+
+        if pairing_result.is_none() {
+            return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
+        }
+
+        let pairing_result = pairing_result.unwrap();
+        let result = match output {
+            PairingOutput::Boolean => {
+                if pairing_result == one_fp12 {
+                    pairing_result_true()
+                } else {
+                    pairing_result_false()
+                }
+            },
+            PairingOutput::TargetGroupElement => {
+                serialize_fp12_fixed_len(modulus_len, &pairing_result)?
+            }
+        };
+
+        Ok(result)
+    }
+
+    pub(crate) fn pair_mnt6(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        Self::pair_mnt6_with_output(bytes, PairingOutput::Boolean)
+    }
+
+    pub(crate) fn pair_mnt6_raw(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        Self::pair_mnt6_with_output(bytes, PairingOutput::TargetGroupElement)
+    }
+
+    fn pair_mnt6_with_output(bytes: &[u8], output: PairingOutput) -> Result<Vec<u8>, ApiError> {
+        use crate::extension_towers::fp3::{Fp3, Extension3};
+        use crate::extension_towers::fp6_as_2_over_3::{Fp6, Extension2Over3};
+
+        let (base_field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
+        let (a_fp, b_fp, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &base_field)?;
+        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+        let fp_params = CurveOverFpParameters::new(&base_field);
+        let g1_curve = WeierstrassCurve::new(&order.as_ref(), a_fp.clone(), b_fp.clone(), &fp_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-        let (fp2_non_residue, rest) = decode_fp2(&rest, modulus_len, &extension_2)?;
+        // Now we need to expect:
+        // - non-residue for Fp3
+        // now separate Miller loop params
+        // - parameter X
+        // - sign of X 
+        // Final exp params
+        // - exp_w0
+        // - exp_w1
+        // - exp_w0_is_negative
+        // - number of pairs
+        // - list of encoded pairs
+
+        let fp_non_residue_encoding = rest;
+        let (fp_non_residue, rest) = decode_fp(&rest, modulus_len, &base_field)?;
 
         {
-            if fp2_non_residue.is_zero() {
-                return Err(ApiError::InputError(format!("Non-residue for Fp6(12) is zero, file {}, line {}", file!(), line!())));
+            if fp_non_residue.is_zero() {
+                return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp3 is zero file {}, line {}", file!(), line!()), fp_non_residue_encoding));
             }
-            let is_not_a_6th_root = is_non_nth_root_fp2(&fp2_non_residue, &modulus, 6u64);
-            if !is_not_a_6th_root {
+            let is_not_a_root = is_non_nth_root(&fp_non_residue, &modulus, 6u64);
+            if !is_not_a_root {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Non-residue for Fp6(12) is actually a residue, file {}, line {}", file!(), line!())));
+                    return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp3 is actually a residue, file {}, line {}", file!(), line!()), fp_non_residue_encoding));
                 }
             }
         }
 
-        let (twist_type, rest) = decode_twist_type(rest)?;
-
-        let base_precomp = Fp6Fp12FrobeniusBaseElements::construct(
-            &modulus, 
-            &fp2_non_residue
+        let base_precomp = Fp3Fp6FrobeniusBaseElements::construct(
+            &modulus, &fp_non_residue
         ).map_err(|_| {
-            ApiError::UnknownParameter("Can not make base precomputations for Fp6/Fp12 frobenius".to_owned())
+            ApiError::UnknownParameter("Can not make base precomputations for Fp3/Fp6 frobenius".to_owned())
+        })?;
+
+        // build an extension field
+        let mut extension_3 = Extension3::new(fp_non_residue);
+        extension_3.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
+            ApiError::InputError("Failed to calculate Frobenius coeffs for Fp3".to_owned())
         })?;
 
-        let mut extension_6 = Extension3Over2::new(fp2_non_residue.clone());
+        let mut extension_6 = Extension2Over3::new(Fp3::zero(&extension_3));
+
         {
             extension_6.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
                 ApiError::UnknownParameter("Can not calculate Frobenius coefficients for Fp6".to_owned())
             })?;
         }
 
-        let mut extension_12 = Extension2Over3Over2::new(Fp6::zero(&extension_6));
-        {
-            extension_12.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
-                ApiError::InputError("Can not calculate Frobenius coefficients for Fp12".to_owned())
-            })?;
-        }
+        let one = Fp::one(&base_field);
 
-        let fp2_non_residue_inv = fp2_non_residue.inverse().ok_or(ApiError::UnexpectedZero("Fp2 non-residue must be invertible".to_owned()))?;
-        let b_fp2 = match twist_type {
-            TwistType::D => {
-                let mut b_fp2 = fp2_non_residue_inv.clone();
-                b_fp2.mul_by_fp(&b_fp);
+        let mut twist = Fp3::zero(&extension_3);
+        twist.c1 = one.clone();
 
-                b_fp2
-            },
-            TwistType::M => {
-                let mut b_fp2 = fp2_non_residue.clone();
-                b_fp2.mul_by_fp(&b_fp);
+        let mut twist_squared = twist.clone();
+        twist_squared.square();
 
-                b_fp2
-            },
-        };
+        let mut twist_cubed = twist_squared.clone();
+        twist_cubed.mul_assign(&twist);
+
+        let mut a_fp3 = twist_squared.clone();
+        a_fp3.mul_by_fp(&a_fp);
 
-        let a_fp2 = Fp2::zero(&extension_2);
+        let mut b_fp3 = twist_cubed.clone();
+        b_fp3.mul_by_fp(&b_fp);
 
-        let fp2_params = CurveOverFp2Parameters::new(&extension_2);
-        let g2_curve = WeierstrassCurve::new(&order.as_ref(), a_fp2, b_fp2, &fp2_params).map_err(|_| {
+        let fp3_params = CurveOverFp3Parameters::new(&extension_3);
+        let g2_curve = WeierstrassCurve::new(&order.as_ref(), a_fp3, b_fp3, &fp3_params).map_err(|_| {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-        let (x, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_BLS12_X_BIT_LENGTH)?;
+        let x_encoding = rest;
+        let (x, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
         if x.is_zero() {
-            return Err(ApiError::InputError("Loop count parameters can not be zero".to_owned()));
+            return Err(ApiError::InputError("Ate loop count parameters can not be zero".to_owned()));
         }
 
-        if calculate_hamming_weight(&x.as_ref()) > MAX_BLS12_X_HAMMING {
-            return Err(ApiError::InputError("X has too large hamming weight".to_owned()));
+        if calculate_hamming_weight(&x.as_ref()) > MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING {
+            return Err(ApiError::coded_at(ErrorCode::LimitExceeded, "X has too large hamming weight", x_encoding));
         }
 
         let (x_is_negative, rest) = decode_sign_is_negative(rest)?;
 
+        let (exp_w0, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W0_BIT_LENGTH)?;
+        if exp_w0.is_zero() {
+            return Err(ApiError::InputError("Final exp w0 loop count parameters can not be zero".to_owned()));
+        }
+
+        let (exp_w1, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W1_BIT_LENGTH)?;
+        if exp_w1.is_zero() {
+            return Err(ApiError::InputError("Final exp w1 loop count parameters can not be zero".to_owned()));
+        }
+
+        let (exp_w0_is_negative, rest) = decode_sign_is_negative(rest)?;
+
+        let num_pairs_position = rest;
         let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
         let num_pairs = num_pairs_encoding[0] as usize;
 
         if num_pairs == 0 {
             if !crate::features::in_gas_metering() {
-                return Err(ApiError::InputError("Zero pairs encoded".to_owned()));
+                return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Zero pairs encoded", num_pairs_position));
             }
         }
 
@@ -226,27 +535,29 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         for _ in 0..num_pairs {
             let (check_g1_subgroup, rest) = decode_boolean(&global_rest)?;
+            let g1_encoding = rest;
             let (g1, rest) = decode_g1_point_from_xy(&rest, modulus_len, &g1_curve)?;
             let (check_g2_subgroup, rest) = decode_boolean(&rest)?;
-            let (g2, rest) = decode_g2_point_from_xy_in_fp2(&rest, modulus_len, &g2_curve)?;
+            let g2_encoding = rest;
+            let (g2, rest) = decode_g2_point_from_xy_in_fp3(&rest, modulus_len, &g2_curve)?;
             global_rest = rest;
 
             if !g1.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G1 point is not on curve".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G1 point is not on curve", g1_encoding));
                 }
             }
 
             if !g2.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G2 point is not on curve".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G2 point is not on curve", g2_encoding));
                 }
             }
 
             if check_g1_subgroup {
                 if !g1.check_correct_subgroup() {
                     if !crate::features::in_fuzzing_or_gas_metering() {
-                        return Err(ApiError::InputError("G1 or G2 point is not in the expected subgroup".to_owned()));
+                        return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 or G2 point is not in the expected subgroup", g1_encoding));
                     }
                 }
             }
@@ -254,7 +565,7 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             if check_g2_subgroup {
                 if !g2.check_correct_subgroup() {
                     if !crate::features::in_fuzzing_or_gas_metering() {
-                        return Err(ApiError::InputError("G1 or G2 point is not in the expected subgroup".to_owned()));
+                        return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 or G2 point is not in the expected subgroup", g2_encoding));
                     }
                 }
             }
@@ -266,28 +577,34 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         }
 
         if global_rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", global_rest));
         }
 
         debug_assert!(g1_points.len() == g2_points.len());
+        let one_fp6 = Fp6::one(&extension_6);
         if g1_points.len() == 0 {
-            return Ok(pairing_result_true());
+            return match output {
+                PairingOutput::Boolean => Ok(pairing_result_true()),
+                PairingOutput::TargetGroupElement => serialize_fp6_as_2_over_3_fixed_len(modulus_len, &one_fp6),
+            };
         }
 
-        let engine_params = Bls12InstanceParams {
+        let engine_params = MNT6InstanceParams {
             x: &x.as_ref(),
             x_is_negative: x_is_negative,
-            twist_type: twist_type,
+            exp_w0: exp_w0.as_ref(),
+            exp_w1: exp_w1.as_ref(),
+            exp_w0_is_negative: exp_w0_is_negative,
             base_field: &base_field,
             curve: &g1_curve,
             curve_twist: &g2_curve,
-            fp2_extension: &extension_2,
+            twist: twist,
+            fp3_extension: &extension_3,
             fp6_extension: &extension_6,
-            fp12_extension: &extension_12,
             force_no_naf: true
         };
 
-        let engine = Bls12Instance::from_params(engine_params);
+        let engine = MNT6Instance::from_params(engine_params);
 
         let pairing_result = engine.pair(&g1_points, &g2_points);
 
@@ -295,160 +612,150 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
         }
 
-        let one_fp12 = Fp12::one(&extension_12);
         let pairing_result = pairing_result.unwrap();
-        let result = if pairing_result == one_fp12 {
-            pairing_result_true()
-        } else {
-            pairing_result_false()
+        let result = match output {
+            PairingOutput::Boolean => {
+                if pairing_result == one_fp6 {
+                    pairing_result_true()
+                } else {
+                    pairing_result_false()
+                }
+            },
+            PairingOutput::TargetGroupElement => {
+                serialize_fp6_as_2_over_3_fixed_len(modulus_len, &pairing_result)?
+            }
         };
 
         Ok(result)
     }
 
-    pub(crate) fn pair_bn(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
-        use crate::extension_towers::fp2::{Fp2, Extension2};
-        use crate::extension_towers::fp6_as_3_over_2::{Fp6, Extension3Over2};
-        use crate::extension_towers::fp12_as_2_over3_over_2::{Fp12, Extension2Over3Over2};
+    pub(crate) fn pair_bw6(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        Self::pair_bw6_with_output(bytes, PairingOutput::Boolean)
+    }
+
+    pub(crate) fn pair_bw6_raw(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        Self::pair_bw6_with_output(bytes, PairingOutput::TargetGroupElement)
+    }
+
+    fn pair_bw6_with_output(bytes: &[u8], output: PairingOutput) -> Result<Vec<u8>, ApiError> {
+        use crate::extension_towers::fp3::{Fp3, Extension3};
+        use crate::extension_towers::fp6_as_2_over_3::{Fp6, Extension2Over3};
 
         let (base_field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
         let (a_fp, b_fp, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &base_field)?;
         if !a_fp.is_zero() {
-            return Err(ApiError::UnknownParameter("A parameter must be zero for BN curve".to_owned()));
+            return Err(ApiError::UnknownParameter("A parameter must be zero for BW6 curve".to_owned()));
         }
         let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
         let fp_params = CurveOverFpParameters::new(&base_field);
-        let g1_curve = WeierstrassCurve::new(&order.as_ref(), a_fp, b_fp.clone(), &fp_params).map_err(|_| {
+        let g1_curve = WeierstrassCurve::new(&order.as_ref(), a_fp.clone(), b_fp.clone(), &fp_params).map_err(|_| {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-
         // Now we need to expect:
-        // - non-residue for Fp2
-        // - non-residue for Fp6
+        // - non-residue for Fp3
         // - twist type M/D
-        // - parameter U
-        // - sign of U
+        // now separate Miller loop params
+        // - first ate loop parameter and its sign
+        // - second ate loop parameter and its sign
+        // Final exp params
+        // - exp_w0
+        // - exp_w1
+        // - exp_w0_is_negative
         // - number of pairs
         // - list of encoded pairs
-        // U is used instead of x for convention of go-ethereum people :)
 
+        let fp_non_residue_encoding = rest;
         let (fp_non_residue, rest) = decode_fp(&rest, modulus_len, &base_field)?;
 
         {
             if fp_non_residue.is_zero() {
-                return Err(ApiError::InputError(format!("Non-residue for Fp2 is zero file {}, line {}", file!(), line!())));
-            }
-            let is_not_a_square = is_non_nth_root(&fp_non_residue, &modulus, 2u64);
-            if !is_not_a_square {
-                if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Non-residue for Fp2 is actually a residue file {}, line {}", file!(), line!())));
-                }
-            }
-        }
-
-        // build an extension field
-        let mut extension_2 = Extension2::new(fp_non_residue);
-        extension_2.calculate_frobenius_coeffs(&modulus).map_err(|_| {
-            ApiError::InputError("Failed to calculate Frobenius coeffs for Fp2".to_owned())
-        })?;
-
-        let (fp2_non_residue, rest) = decode_fp2(&rest, modulus_len, &extension_2)?;
-
-        {
-            if fp2_non_residue.is_zero() {
-                return Err(ApiError::InputError(format!("Non-residue for Fp6(12) is zero, file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp3 is zero file {}, line {}", file!(), line!()), fp_non_residue_encoding));
             }
-            let is_not_a_6th_root = is_non_nth_root_fp2(&fp2_non_residue, &modulus, 6u64);
-            if !is_not_a_6th_root {
+            let is_not_a_root = is_non_nth_root(&fp_non_residue, &modulus, 6u64);
+            if !is_not_a_root {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Non-residue for Fp6(12) is actually a residue, file {}, line {}", file!(), line!())));
+                    return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp3 is actually a residue, file {}, line {}", file!(), line!()), fp_non_residue_encoding));
                 }
             }
         }
 
-        let (twist_type, rest) = decode_twist_type(&rest)?;
+        let (twist_type, rest) = decode_twist_type(rest)?;
 
-        let base_precomp = Fp6Fp12FrobeniusBaseElements::construct(
-            &modulus, 
-            &fp2_non_residue
+        let base_precomp = Fp3Fp6FrobeniusBaseElements::construct(
+            &modulus, &fp_non_residue
         ).map_err(|_| {
-            ApiError::UnknownParameter("Can not make base precomputations for Fp6/Fp12 frobenius".to_owned())
+            ApiError::UnknownParameter("Can not make base precomputations for Fp3/Fp6 frobenius".to_owned())
+        })?;
+
+        // build an extension field
+        let mut extension_3 = Extension3::new(fp_non_residue.clone());
+        extension_3.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
+            ApiError::InputError("Failed to calculate Frobenius coeffs for Fp3".to_owned())
         })?;
 
-        let mut extension_6 = Extension3Over2::new(fp2_non_residue.clone());
+        let mut extension_6 = Extension2Over3::new(Fp3::zero(&extension_3));
+
         {
             extension_6.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
                 ApiError::UnknownParameter("Can not calculate Frobenius coefficients for Fp6".to_owned())
             })?;
         }
 
-        let mut extension_12 = Extension2Over3Over2::new(Fp6::zero(&extension_6));
-        {
-            extension_12.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
-                ApiError::InputError("Can not calculate Frobenius coefficients for Fp12".to_owned())
-            })?;
-        }
-
-        let fp2_non_residue_inv = fp2_non_residue.inverse().ok_or(ApiError::UnexpectedZero("Fp2 non-residue must be invertible".to_owned()))?;
-
-        let b_fp2 = match twist_type {
-            TwistType::D => {
-                let mut b_fp2 = fp2_non_residue_inv.clone();
-                b_fp2.mul_by_fp(&b_fp);
-
-                b_fp2
-            },
-            TwistType::M => {
-                let mut b_fp2 = fp2_non_residue.clone();
-                b_fp2.mul_by_fp(&b_fp);
-
-                b_fp2
-            },
+        // sextic twist is defined over the base field itself
+        let mut b_twist = match twist_type {
+            TwistType::D => fp_non_residue.inverse().ok_or(ApiError::UnexpectedZero("Fp3 non-residue must be invertible".to_owned()))?,
+            TwistType::M => fp_non_residue.clone(),
         };
+        b_twist.mul_assign(&b_fp);
 
-        let a_fp2 = Fp2::zero(&extension_2);
-
-        let fp2_params = CurveOverFp2Parameters::new(&extension_2);
-        let g2_curve = WeierstrassCurve::new(&order.as_ref(), a_fp2, b_fp2, &fp2_params).map_err(|_| {
+        let g2_curve = WeierstrassCurve::new(&order.as_ref(), a_fp.clone(), b_twist, &fp_params).map_err(|_| {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-        let (u, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_BN_U_BIT_LENGTH)?;
-        if u.is_zero() {
-            return Err(ApiError::InputError("Loop count parameters can not be zero".to_owned()));
+        let ate_loop_1_encoding = rest;
+        let (ate_loop_1, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
+        if ate_loop_1.is_zero() {
+            return Err(ApiError::InputError("Ate loop count parameters can not be zero".to_owned()));
         }
 
-        let (u_is_negative, rest) = decode_sign_is_negative(rest)?;
+        if calculate_hamming_weight(&ate_loop_1.as_ref()) > MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING {
+            return Err(ApiError::coded_at(ErrorCode::LimitExceeded, "First ate loop parameter has too large hamming weight", ate_loop_1_encoding));
+        }
 
-        let two = MaxLoopParametersUint::from(2u64);
-        let six = MaxLoopParametersUint::from(6u64);
+        let (ate_loop_1_is_negative, rest) = decode_sign_is_negative(rest)?;
 
-        // we need only absolute value of 6u+2, so manually handle negative and positive U
-        let six_u_plus_two = if u_is_negative {
-            let six_u_plus_two = (six * u) - two;
+        let ate_loop_2_encoding = rest;
+        let (ate_loop_2, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
+        if ate_loop_2.is_zero() {
+            return Err(ApiError::InputError("Ate loop count parameters can not be zero".to_owned()));
+        }
 
-            six_u_plus_two
-        } else {
-            let six_u_plus_two = (six * u) + two;
+        if calculate_hamming_weight(&ate_loop_2.as_ref()) > MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING {
+            return Err(ApiError::coded_at(ErrorCode::LimitExceeded, "Second ate loop parameter has too large hamming weight", ate_loop_2_encoding));
+        }
 
-            six_u_plus_two
-        };
+        let (ate_loop_2_is_negative, rest) = decode_sign_is_negative(rest)?;
 
-        if calculate_hamming_weight(&six_u_plus_two.as_ref()) > MAX_BN_SIX_U_PLUS_TWO_HAMMING {
-            return Err(ApiError::InputError("|6*U + 2| has too large hamming weight".to_owned()));
+        let (exp_w0, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W0_BIT_LENGTH)?;
+        if exp_w0.is_zero() {
+            return Err(ApiError::InputError("Final exp w0 loop count parameters can not be zero".to_owned()));
         }
 
-        let p_minus_one_over_2 = (modulus - MaxFieldUint::from(1u64)) >> 1;
+        let (exp_w1, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W1_BIT_LENGTH)?;
+        if exp_w1.is_zero() {
+            return Err(ApiError::InputError("Final exp w1 loop count parameters can not be zero".to_owned()));
+        }
 
-        let fp2_non_residue_in_p_minus_one_over_2 = fp2_non_residue.pow(p_minus_one_over_2.as_ref());
+        let (exp_w0_is_negative, rest) = decode_sign_is_negative(rest)?;
 
+        let num_pairs_position = rest;
         let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
         let num_pairs = num_pairs_encoding[0] as usize;
 
         if num_pairs == 0 {
             if !crate::features::in_gas_metering() {
-                return Err(ApiError::InputError("Zero pairs encoded".to_owned()));
+                return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Zero pairs encoded", num_pairs_position));
             }
         }
 
@@ -459,27 +766,29 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         for _ in 0..num_pairs {
             let (check_g1_subgroup, rest) = decode_boolean(&global_rest)?;
+            let g1_encoding = rest;
             let (g1, rest) = decode_g1_point_from_xy(&rest, modulus_len, &g1_curve)?;
             let (check_g2_subgroup, rest) = decode_boolean(&rest)?;
-            let (g2, rest) = decode_g2_point_from_xy_in_fp2(&rest, modulus_len, &g2_curve)?;
+            let g2_encoding = rest;
+            let (g2, rest) = decode_g1_point_from_xy(&rest, modulus_len, &g2_curve)?;
             global_rest = rest;
 
             if !g1.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G1 point is not on curve".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G1 point is not on curve", g1_encoding));
                 }
             }
 
             if !g2.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G2 point is not on curve".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G2 point is not on curve", g2_encoding));
                 }
             }
 
             if check_g1_subgroup {
                 if !g1.check_correct_subgroup() {
                     if !crate::features::in_fuzzing_or_gas_metering() {
-                        return Err(ApiError::InputError("G1 or G2 point is not in the expected subgroup".to_owned()));
+                        return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 or G2 point is not in the expected subgroup", g1_encoding));
                     }
                 }
             }
@@ -487,7 +796,7 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             if check_g2_subgroup {
                 if !g2.check_correct_subgroup() {
                     if !crate::features::in_fuzzing_or_gas_metering() {
-                        return Err(ApiError::InputError("G1 or G2 point is not in the expected subgroup".to_owned()));
+                        return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 or G2 point is not in the expected subgroup", g2_encoding));
                     }
                 }
             }
@@ -499,30 +808,36 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         }
 
         if global_rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", global_rest));
         }
 
         debug_assert!(g1_points.len() == g2_points.len());
+        let one_fp6 = Fp6::one(&extension_6);
         if g1_points.len() == 0 {
-            return Ok(pairing_result_true());
+            return match output {
+                PairingOutput::Boolean => Ok(pairing_result_true()),
+                PairingOutput::TargetGroupElement => serialize_fp6_as_2_over_3_fixed_len(modulus_len, &one_fp6),
+            };
         }
 
-        let engine_params = BnInstanceParams {
-            u: &u.as_ref(),
-            six_u_plus_2: &six_u_plus_two.as_ref(),
-            u_is_negative: u_is_negative,
+        let engine_params = BW6InstanceParams {
+            ate_loop_1: &ate_loop_1.as_ref(),
+            ate_loop_1_is_negative: ate_loop_1_is_negative,
+            ate_loop_2: &ate_loop_2.as_ref(),
+            ate_loop_2_is_negative: ate_loop_2_is_negative,
+            exp_w0: exp_w0.as_ref(),
+            exp_w1: exp_w1.as_ref(),
+            exp_w0_is_negative: exp_w0_is_negative,
             twist_type: twist_type,
             base_field: &base_field,
             curve: &g1_curve,
             curve_twist: &g2_curve,
-            fp2_extension: &extension_2,
+            fp3_extension: &extension_3,
             fp6_extension: &extension_6,
-            fp12_extension: &extension_12,
-            non_residue_in_p_minus_one_over_2: fp2_non_residue_in_p_minus_one_over_2,
             force_no_naf: true
         };
 
-        let engine = BnInstance::from_params(engine_params);
+        let engine = BW6Instance::from_params(engine_params);
 
         let pairing_result = engine.pair(&g1_points, &g2_points);
 
@@ -530,23 +845,42 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
         }
 
-        let one_fp12 = Fp12::one(&extension_12);
         let pairing_result = pairing_result.unwrap();
-        let result = if pairing_result == one_fp12 {
-            pairing_result_true()
-        } else {
-            pairing_result_false()
+        let result = match output {
+            PairingOutput::Boolean => {
+                if pairing_result == one_fp6 {
+                    pairing_result_true()
+                } else {
+                    pairing_result_false()
+                }
+            },
+            PairingOutput::TargetGroupElement => {
+                serialize_fp6_as_2_over_3_fixed_len(modulus_len, &pairing_result)?
+            }
         };
 
         Ok(result)
     }
 
-    pub(crate) fn pair_mnt6(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
-        use crate::extension_towers::fp3::{Fp3, Extension3};
-        use crate::extension_towers::fp6_as_2_over_3::{Fp6, Extension2Over3};
+    pub(crate) fn pair_bls24(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        Self::pair_bls24_with_output(bytes, PairingOutput::Boolean)
+    }
+
+    pub(crate) fn pair_bls24_raw(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        Self::pair_bls24_with_output(bytes, PairingOutput::TargetGroupElement)
+    }
+
+    fn pair_bls24_with_output(bytes: &[u8], output: PairingOutput) -> Result<Vec<u8>, ApiError> {
+        use crate::extension_towers::fp2::{Fp2, Extension2};
+        use crate::extension_towers::fp4_as_2_over_2::{Fp4, Extension2Over2};
+        use crate::extension_towers::fp12_as_3_over_2_over_2::{Fp12, Extension3Over2Over2};
+        use crate::extension_towers::fp24_as_2_over_3_over_2_over_2::{Fp24, Extension2Over3Over2Over2};
 
         let (base_field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
         let (a_fp, b_fp, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &base_field)?;
+        if !a_fp.is_zero() {
+            return Err(ApiError::UnknownParameter("A parameter must be zero for BLS24 curve".to_owned()));
+        }
         let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
         let fp_params = CurveOverFpParameters::new(&base_field);
         let g1_curve = WeierstrassCurve::new(&order.as_ref(), a_fp.clone(), b_fp.clone(), &fp_params).map_err(|_| {
@@ -554,102 +888,112 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         })?;
 
         // Now we need to expect:
-        // - non-residue for Fp3
-        // now separate Miller loop params
+        // - non-residue for Fp2
+        // - non-residue for Fp12(24) that is an element of Fp4
+        // - twist type M/D
         // - parameter X
-        // - sign of X 
-        // Final exp params
-        // - exp_w0
-        // - exp_w1
-        // - exp_w0_is_negative
+        // - sign of X
         // - number of pairs
         // - list of encoded pairs
 
+        let fp_non_residue_encoding = rest;
         let (fp_non_residue, rest) = decode_fp(&rest, modulus_len, &base_field)?;
 
         {
             if fp_non_residue.is_zero() {
-                return Err(ApiError::InputError(format!("Non-residue for Fp3 is zero file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp2 is zero file {}, line {}", file!(), line!()), fp_non_residue_encoding));
             }
-            let is_not_a_root = is_non_nth_root(&fp_non_residue, &modulus, 6u64);
+            let is_not_a_root = is_non_nth_root(&fp_non_residue, &modulus, 2u64);
             if !is_not_a_root {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Non-residue for Fp3 is actually a residue, file {}, line {}", file!(), line!())));
+                    return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp2 is actually a residue, file {}, line {}", file!(), line!()), fp_non_residue_encoding));
                 }
             }
         }
 
-        let base_precomp = Fp3Fp6FrobeniusBaseElements::construct(
+        // Fp4 is built as Fp2[v] / (v^2 - u), so modulus must be 1 mod 4 for u to be a non-residue
+        let base_precomp = Fp2Fp4FrobeniusBaseElements::construct(
             &modulus, &fp_non_residue
         ).map_err(|_| {
-            ApiError::UnknownParameter("Can not make base precomputations for Fp3/Fp6 frobenius".to_owned())
+            ApiError::UnknownParameter("Can not make base precomputations for Fp2/Fp4 frobenius".to_owned())
         })?;
 
-        // build an extension field
-        let mut extension_3 = Extension3::new(fp_non_residue);
-        extension_3.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
-            ApiError::InputError("Failed to calculate Frobenius coeffs for Fp3".to_owned())
+        let mut extension_2 = Extension2::new(fp_non_residue);
+        extension_2.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
+            ApiError::InputError("Failed to calculate Frobenius coeffs for Fp2".to_owned())
         })?;
 
-        let mut extension_6 = Extension2Over3::new(Fp3::zero(&extension_3));
+        let mut extension_4 = Extension2Over2::new(Fp2::zero(&extension_2));
+        extension_4.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
+            ApiError::UnknownParameter("Can not calculate Frobenius coefficients for Fp4".to_owned())
+        })?;
+
+        let fp4_non_residue_encoding = rest;
+        let (fp4_non_residue, rest) = decode_fp4(&rest, modulus_len, &extension_4)?;
 
         {
-            extension_6.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
-                ApiError::UnknownParameter("Can not calculate Frobenius coefficients for Fp6".to_owned())
-            })?;
+            if fp4_non_residue.is_zero() {
+                return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp12(24) is zero, file {}, line {}", file!(), line!()), fp4_non_residue_encoding));
+            }
+            // w^3 - xi and z^2 - w are irreducible only if xi is neither a square nor a cube
+            let is_not_a_root = is_non_nth_root_fp4(&fp4_non_residue, &modulus, 2u64) && is_non_nth_root_fp4(&fp4_non_residue, &modulus, 3u64);
+            if !is_not_a_root {
+                if !crate::features::in_fuzzing_or_gas_metering() {
+                    return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp12(24) is actually a residue, file {}, line {}", file!(), line!()), fp4_non_residue_encoding));
+                }
+            }
         }
 
-        let one = Fp::one(&base_field);
+        let (twist_type, rest) = decode_twist_type(rest)?;
 
-        let mut twist = Fp3::zero(&extension_3);
-        twist.c1 = one.clone();
+        let fp24_precomp = Fp12Fp24FrobeniusBaseElements::construct(
+            &modulus, &fp4_non_residue
+        ).map_err(|_| {
+            ApiError::UnknownParameter("Can not make base precomputations for Fp12/Fp24 frobenius".to_owned())
+        })?;
 
-        let mut twist_squared = twist.clone();
-        twist_squared.square();
+        let mut extension_12 = Extension3Over2Over2::new(fp4_non_residue.clone());
+        extension_12.calculate_frobenius_coeffs_with_precomp(&fp24_precomp).map_err(|_| {
+            ApiError::UnknownParameter("Can not calculate Frobenius coefficients for Fp12".to_owned())
+        })?;
 
-        let mut twist_cubed = twist_squared.clone();
-        twist_cubed.mul_assign(&twist);
+        let mut extension_24 = Extension2Over3Over2Over2::new(Fp12::zero(&extension_12));
+        extension_24.calculate_frobenius_coeffs_with_precomp(&fp24_precomp).map_err(|_| {
+            ApiError::InputError("Can not calculate Frobenius coefficients for Fp24".to_owned())
+        })?;
 
-        let mut a_fp3 = twist_squared.clone();
-        a_fp3.mul_by_fp(&a_fp);
+        let fp4_non_residue_inv = fp4_non_residue.inverse().ok_or(ApiError::UnexpectedZero("Fp4 non-residue must be invertible".to_owned()))?;
+        let mut b_twist = match twist_type {
+            TwistType::D => fp4_non_residue_inv,
+            TwistType::M => fp4_non_residue.clone(),
+        };
+        b_twist.mul_by_fp(&b_fp);
 
-        let mut b_fp3 = twist_cubed.clone();
-        b_fp3.mul_by_fp(&b_fp);
+        let a_twist = Fp4::zero(&extension_4);
 
-        let fp3_params = CurveOverFp3Parameters::new(&extension_3);
-        let g2_curve = WeierstrassCurve::new(&order.as_ref(), a_fp3, b_fp3, &fp3_params).map_err(|_| {
+        let fp4_params = CurveOverFp4Parameters::new(&extension_4);
+        let g2_curve = WeierstrassCurve::new(&order.as_ref(), a_twist, b_twist, &fp4_params).map_err(|_| {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-        let (x, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
+        let x_encoding = rest;
+        let (x, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_BLS24_X_BIT_LENGTH)?;
         if x.is_zero() {
-            return Err(ApiError::InputError("Ate loop count parameters can not be zero".to_owned()));
+            return Err(ApiError::InputError("Loop count parameters can not be zero".to_owned()));
         }
-
-        if calculate_hamming_weight(&x.as_ref()) > MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING {
-            return Err(ApiError::InputError("X has too large hamming weight".to_owned()));
+        if calculate_hamming_weight(&x.as_ref()) > MAX_BLS24_X_HAMMING {
+            return Err(ApiError::coded_at(ErrorCode::LimitExceeded, "X has too large hamming weight", x_encoding));
         }
 
         let (x_is_negative, rest) = decode_sign_is_negative(rest)?;
 
-        let (exp_w0, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W0_BIT_LENGTH)?;
-        if exp_w0.is_zero() {
-            return Err(ApiError::InputError("Final exp w0 loop count parameters can not be zero".to_owned()));
-        }
-
-        let (exp_w1, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W1_BIT_LENGTH)?;
-        if exp_w1.is_zero() {
-            return Err(ApiError::InputError("Final exp w1 loop count parameters can not be zero".to_owned()));
-        }
-
-        let (exp_w0_is_negative, rest) = decode_sign_is_negative(rest)?;
-
+        let num_pairs_position = rest;
         let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
         let num_pairs = num_pairs_encoding[0] as usize;
 
         if num_pairs == 0 {
             if !crate::features::in_gas_metering() {
-                return Err(ApiError::InputError("Zero pairs encoded".to_owned()));
+                return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Zero pairs encoded", num_pairs_position));
             }
         }
 
@@ -660,27 +1004,29 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         for _ in 0..num_pairs {
             let (check_g1_subgroup, rest) = decode_boolean(&global_rest)?;
+            let g1_encoding = rest;
             let (g1, rest) = decode_g1_point_from_xy(&rest, modulus_len, &g1_curve)?;
             let (check_g2_subgroup, rest) = decode_boolean(&rest)?;
-            let (g2, rest) = decode_g2_point_from_xy_in_fp3(&rest, modulus_len, &g2_curve)?;
+            let g2_encoding = rest;
+            let (g2, rest) = decode_g2_point_from_xy_in_fp4(&rest, modulus_len, &g2_curve)?;
             global_rest = rest;
 
             if !g1.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G1 point is not on curve".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G1 point is not on curve", g1_encoding));
                 }
             }
 
             if !g2.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G2 point is not on curve".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G2 point is not on curve", g2_encoding));
                 }
             }
 
             if check_g1_subgroup {
                 if !g1.check_correct_subgroup() {
                     if !crate::features::in_fuzzing_or_gas_metering() {
-                        return Err(ApiError::InputError("G1 or G2 point is not in the expected subgroup".to_owned()));
+                        return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 or G2 point is not in the expected subgroup", g1_encoding));
                     }
                 }
             }
@@ -688,7 +1034,7 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             if check_g2_subgroup {
                 if !g2.check_correct_subgroup() {
                     if !crate::features::in_fuzzing_or_gas_metering() {
-                        return Err(ApiError::InputError("G1 or G2 point is not in the expected subgroup".to_owned()));
+                        return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 or G2 point is not in the expected subgroup", g2_encoding));
                     }
                 }
             }
@@ -700,30 +1046,33 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         }
 
         if global_rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", global_rest));
         }
 
         debug_assert!(g1_points.len() == g2_points.len());
+        let one_fp24 = Fp24::one(&extension_24);
         if g1_points.len() == 0 {
-            return Ok(pairing_result_true());
+            return match output {
+                PairingOutput::Boolean => Ok(pairing_result_true()),
+                PairingOutput::TargetGroupElement => serialize_fp24_fixed_len(modulus_len, &one_fp24),
+            };
         }
 
-        let engine_params = MNT6InstanceParams {
+        let engine_params = Bls24InstanceParams {
             x: &x.as_ref(),
             x_is_negative: x_is_negative,
-            exp_w0: exp_w0.as_ref(),
-            exp_w1: exp_w1.as_ref(),
-            exp_w0_is_negative: exp_w0_is_negative,
+            twist_type: twist_type,
             base_field: &base_field,
             curve: &g1_curve,
             curve_twist: &g2_curve,
-            twist: twist,
-            fp3_extension: &extension_3,
-            fp6_extension: &extension_6,
+            fp2_extension: &extension_2,
+            fp4_extension: &extension_4,
+            fp12_extension: &extension_12,
+            fp24_extension: &extension_24,
             force_no_naf: true
         };
 
-        let engine = MNT6Instance::from_params(engine_params);
+        let engine = Bls24Instance::from_params(engine_params);
 
         let pairing_result = engine.pair(&g1_points, &g2_points);
 
@@ -731,18 +1080,32 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
         }
 
-        let one_fp6 = Fp6::one(&extension_6);
         let pairing_result = pairing_result.unwrap();
-        let result = if pairing_result == one_fp6 {
-            pairing_result_true()
-        } else {
-            pairing_result_false()
+        let result = match output {
+            PairingOutput::Boolean => {
+                if pairing_result == one_fp24 {
+                    pairing_result_true()
+                } else {
+                    pairing_result_false()
+                }
+            },
+            PairingOutput::TargetGroupElement => {
+                serialize_fp24_fixed_len(modulus_len, &pairing_result)?
+            }
         };
 
         Ok(result)
     }
 
     pub(crate) fn pair_mnt4(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        Self::pair_mnt4_with_output(bytes, PairingOutput::Boolean)
+    }
+
+    pub(crate) fn pair_mnt4_raw(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        Self::pair_mnt4_with_output(bytes, PairingOutput::TargetGroupElement)
+    }
+
+    fn pair_mnt4_with_output(bytes: &[u8], output: PairingOutput) -> Result<Vec<u8>, ApiError> {
         use crate::extension_towers::fp2::{Fp2, Extension2};
         use crate::extension_towers::fp4_as_2_over_2::{Fp4, Extension2Over2};
 
@@ -766,16 +1129,17 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         // - number of pairs
         // - list of encoded pairs
 
+        let fp_non_residue_encoding = rest;
         let (fp_non_residue, rest) = decode_fp(&rest, modulus_len, &base_field)?;
 
         {
             if fp_non_residue.is_zero() {
-                return Err(ApiError::InputError(format!("Non-residue for Fp2 is zero file {}, line {}", file!(), line!())));
+                return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp2 is zero file {}, line {}", file!(), line!()), fp_non_residue_encoding));
             }
             let is_not_a_root = is_non_nth_root(&fp_non_residue, &modulus, 4u64);
             if !is_not_a_root {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Non-residue for Fp2 is actually a residue, file {}, line {}", file!(), line!())));
+                    return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp2 is actually a residue, file {}, line {}", file!(), line!()), fp_non_residue_encoding));
                 }
             }
         }
@@ -824,13 +1188,14 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
+        let x_encoding = rest;
         let (x, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
         if x.is_zero() {
             return Err(ApiError::InputError("Ate pairing loop count parameters can not be zero".to_owned()));
         }
 
         if calculate_hamming_weight(&x.as_ref()) > MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING {
-            return Err(ApiError::InputError("X has too large hamming weight".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::LimitExceeded, "X has too large hamming weight", x_encoding));
         }
 
         let (x_is_negative, rest) = decode_sign_is_negative(rest)?;
@@ -846,12 +1211,13 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         let (exp_w0_is_negative, rest) = decode_sign_is_negative(rest)?;
 
+        let num_pairs_position = rest;
         let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
         let num_pairs = num_pairs_encoding[0] as usize;
 
         if num_pairs == 0 {
             if !crate::features::in_gas_metering() {
-                return Err(ApiError::InputError("Zero pairs encoded".to_owned()));
+                return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Zero pairs encoded", num_pairs_position));
             }
         }
 
@@ -862,27 +1228,29 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         for _ in 0..num_pairs {
             let (check_g1_subgroup, rest) = decode_boolean(&global_rest)?;
+            let g1_encoding = rest;
             let (g1, rest) = decode_g1_point_from_xy(&rest, modulus_len, &g1_curve)?;
             let (check_g2_subgroup, rest) = decode_boolean(&rest)?;
+            let g2_encoding = rest;
             let (g2, rest) = decode_g2_point_from_xy_in_fp2(&rest, modulus_len, &g2_curve)?;
             global_rest = rest;
 
             if !g1.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G1 point is not on curve".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G1 point is not on curve", g1_encoding));
                 }
             }
 
             if !g2.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError("G2 point is not on curve".to_owned()));
+                    return Err(ApiError::coded_at(ErrorCode::PointNotOnCurve, "G2 point is not on curve", g2_encoding));
                 }
             }
 
             if check_g1_subgroup {
                 if !g1.check_correct_subgroup() {
                     if !crate::features::in_fuzzing_or_gas_metering() {
-                        return Err(ApiError::InputError("G1 or G2 point is not in the expected subgroup".to_owned()));
+                        return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 or G2 point is not in the expected subgroup", g1_encoding));
                     }
                 }
             }
@@ -890,7 +1258,7 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             if check_g2_subgroup {
                 if !g2.check_correct_subgroup() {
                     if !crate::features::in_fuzzing_or_gas_metering() {
-                        return Err(ApiError::InputError("G1 or G2 point is not in the expected subgroup".to_owned()));
+                        return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "G1 or G2 point is not in the expected subgroup", g2_encoding));
                     }
                 }
             }
@@ -902,12 +1270,16 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         }
 
         if global_rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+            return Err(ApiError::coded_at(ErrorCode::InvalidLength, "Input contains garbage at the end", global_rest));
         }
 
         debug_assert!(g1_points.len() == g2_points.len());
+        let one_fp4 = Fp4::one(&extension_4);
         if g1_points.len() == 0 {
-            return Ok(pairing_result_true());
+            return match output {
+                PairingOutput::Boolean => Ok(pairing_result_true()),
+                PairingOutput::TargetGroupElement => serialize_fp4_fixed_len(modulus_len, &one_fp4),
+            };
         }
 
         let engine = MNT4InstanceParams {
@@ -933,12 +1305,18 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
         }
 
-        let one_fp4 = Fp4::one(&extension_4);
         let pairing_result = pairing_result.unwrap();
-        let result = if pairing_result == one_fp4 {
-            pairing_result_true()
-        } else {
-            pairing_result_false()
+        let result = match output {
+            PairingOutput::Boolean => {
+                if pairing_result == one_fp4 {
+                    pairing_result_true()
+                } else {
+                    pairing_result_false()
+                }
+            },
+            PairingOutput::TargetGroupElement => {
+                serialize_fp4_fixed_len(modulus_len, &pairing_result)?
+            }
         };
 
         Ok(result)
@@ -0,0 +1,332 @@
+//! Curve parameters parsed from the ABI prefix (modulus, A, B, group order and pairing parameters)
+//! together with everything that doesn't depend on the points. Parsing performs exactly the
+//! same checks in the same order as the byte ABI did before, so cached and freshly parsed
+//! curves are indistinguishable to the caller.
+
+use crate::weierstrass::{CurveOverFpParameters, CurveOverFp2Parameters};
+use crate::weierstrass::curve::WeierstrassCurve;
+use crate::pairings::{TwistType, calculate_hamming_weight};
+use crate::extension_towers::{Fp6Fp12FrobeniusBaseElements, is_non_nth_root, is_non_nth_root_fp2};
+use crate::extension_towers::fp2::{Fp2, Extension2};
+use crate::extension_towers::fp6_as_3_over_2::{Fp6, Extension3Over2};
+use crate::extension_towers::fp12_as_2_over3_over_2::Extension2Over3Over2;
+use crate::representation::ElementRepr;
+use crate::traits::{FieldElement, ZeroAndOne};
+use crate::field::PrimeField;
+use crate::fp::Fp;
+use crate::integers::*;
+
+use super::decode_g1::*;
+use super::decode_fp::*;
+use super::decode_utils::*;
+use super::sane_limits::*;
+use super::curve_builder::PairingFamily;
+
+use crate::errors::{ApiError, ErrorCode};
+
+// Field and extension elements are stored as Montgomery form representations and are
+// bound to the fields again for every operation
+pub(crate) struct PreparedCurve<FE: ElementRepr> {
+    pub(crate) base_field: PrimeField<FE>,
+    pub(crate) modulus_len: usize,
+    pub(crate) a: FE,
+    pub(crate) b: FE,
+    pub(crate) order: MaxGroupSizeUint,
+    pub(crate) order_len: usize,
+    pub(crate) pairing: Option<PreparedPairing<FE>>,
+}
+
+pub(crate) struct PreparedPairing<FE: ElementRepr> {
+    pub(crate) family: PairingFamily,
+    pub(crate) twist_type: TwistType,
+    pub(crate) x: MaxLoopParametersUint,
+    pub(crate) x_is_negative: bool,
+    pub(crate) six_u_plus_2: MaxLoopParametersUint,
+    pub(crate) fp_non_residue: FE,
+    pub(crate) fp2_frobenius_c1: [FE; 2],
+    pub(crate) fp2_non_residue: [FE; 2],
+    pub(crate) fp6_frobenius_c1: [[FE; 2]; 6],
+    pub(crate) fp6_frobenius_c2: [[FE; 2]; 6],
+    pub(crate) fp12_frobenius_c1: [[FE; 2]; 12],
+    pub(crate) b_twist: [FE; 2],
+    // used only by BN curves, zero for BLS12
+    pub(crate) non_residue_in_p_minus_one_over_2: [FE; 2],
+}
+
+pub(crate) struct Tower<'a, FE: ElementRepr> {
+    pub(crate) params: &'a PreparedPairing<FE>,
+    pub(crate) base_field: &'a PrimeField<FE>,
+    pub(crate) g1_curve: &'a WeierstrassCurve<'a, CurveOverFpParameters<'a, FE, PrimeField<FE>>>,
+    pub(crate) g2_curve: &'a WeierstrassCurve<'a, CurveOverFp2Parameters<'a, FE, PrimeField<FE>>>,
+    pub(crate) fp2_extension: &'a Extension2<'a, FE, PrimeField<FE>>,
+    pub(crate) fp6_extension: &'a Extension3Over2<'a, FE, PrimeField<FE>>,
+    pub(crate) fp12_extension: &'a Extension2Over3Over2<'a, FE, PrimeField<FE>>,
+}
+
+pub(crate) fn fp2_into_reprs<FE: ElementRepr>(el: &Fp2<'_, FE, PrimeField<FE>>) -> [FE; 2] {
+    [el.c0.repr, el.c1.repr]
+}
+
+pub(crate) fn fp2_from_reprs<'a, FE: ElementRepr>(reprs: &[FE; 2], extension: &'a Extension2<'a, FE, PrimeField<FE>>) -> Fp2<'a, FE, PrimeField<FE>> {
+    let mut el = Fp2::zero(extension);
+    el.c0.repr = reprs[0];
+    el.c1.repr = reprs[1];
+
+    el
+}
+
+pub(crate) fn fp_from_repr<FE: ElementRepr>(repr: FE, field: &PrimeField<FE>) -> Fp<'_, FE, PrimeField<FE>> {
+    Fp { repr, field }
+}
+
+impl<FE: ElementRepr> PreparedCurve<FE> {
+    /// Parses modulus, A, B and group order as encoded in the G1 ABI
+    pub(crate) fn parse_g1(bytes: &[u8]) -> Result<(Self, &[u8]), ApiError> {
+        let (base_field, modulus_len, _, rest) = parse_base_field_from_encoding::<FE>(bytes)?;
+        let (a, b, rest) = parse_ab_in_base_field_from_encoding(rest, modulus_len, &base_field)?;
+        let (order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+        let (a, b) = (a.repr, b.repr);
+
+        let curve = Self {
+            base_field,
+            modulus_len,
+            a,
+            b,
+            order,
+            order_len,
+            pairing: None,
+        };
+
+        {
+            let fp_params = CurveOverFpParameters::new(&curve.base_field);
+            curve.g1_curve(&fp_params)?;
+        }
+
+        Ok((curve, rest))
+    }
+
+    /// Parses the pairing ABI input up to the number of pairs
+    pub(crate) fn parse_pairing(family: PairingFamily, bytes: &[u8]) -> Result<(Self, &[u8]), ApiError> {
+        let (base_field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(bytes)?;
+        let (a_fp, b_fp, rest) = parse_ab_in_base_field_from_encoding(rest, modulus_len, &base_field)?;
+        if !a_fp.is_zero() {
+            let name = match family {
+                PairingFamily::Bls12 => "BLS12",
+                PairingFamily::Bn => "BN",
+            };
+            return Err(ApiError::UnknownParameter(format!("A parameter must be zero for {} curve", name)));
+        }
+        let (order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+        let (a, b) = (a_fp.repr, b_fp.repr);
+
+        let mut curve = Self {
+            base_field,
+            modulus_len,
+            a,
+            b,
+            order,
+            order_len,
+            pairing: None,
+        };
+
+        {
+            let fp_params = CurveOverFpParameters::new(&curve.base_field);
+            curve.g1_curve(&fp_params)?;
+        }
+
+        let (pairing, rest) = Self::parse_pairing_parameters(family, rest, modulus_len, &curve.base_field, &modulus, b)?;
+        curve.pairing = Some(pairing);
+
+        Ok((curve, rest))
+    }
+
+    fn parse_pairing_parameters<'b>(
+        family: PairingFamily,
+        encoding: &'b [u8],
+        modulus_len: usize,
+        base_field: &PrimeField<FE>,
+        modulus: &MaxFieldUint,
+        b: FE
+    ) -> Result<(PreparedPairing<FE>, &'b [u8]), ApiError> {
+        let fp_non_residue_encoding = encoding;
+        let (fp_non_residue, rest) = decode_fp(encoding, modulus_len, base_field)?;
+        if fp_non_residue.is_zero() {
+            return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp2 is zero file {}, line {}", file!(), line!()), fp_non_residue_encoding));
+        }
+        if !is_non_nth_root(&fp_non_residue, modulus, 2u64) {
+            if !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp2 is actually a residue file {}, line {}", file!(), line!()), fp_non_residue_encoding));
+            }
+        }
+
+        let mut extension_2 = Extension2::new(fp_non_residue.clone());
+        extension_2.calculate_frobenius_coeffs(modulus).map_err(|_| {
+            ApiError::InputError("Failed to calculate Frobenius coeffs for Fp2".to_owned())
+        })?;
+
+        let fp2_non_residue_encoding = rest;
+        let (fp2_non_residue, rest) = decode_fp2(rest, modulus_len, &extension_2)?;
+        if fp2_non_residue.is_zero() {
+            return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp6(12) is zero, file {}, line {}", file!(), line!()), fp2_non_residue_encoding));
+        }
+        if !is_non_nth_root_fp2(&fp2_non_residue, modulus, 6u64) {
+            if !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::coded_at(ErrorCode::NonResidueCheckFailed, format!("Non-residue for Fp6(12) is actually a residue, file {}, line {}", file!(), line!()), fp2_non_residue_encoding));
+            }
+        }
+
+        let (twist_type, rest) = decode_twist_type(rest)?;
+
+        let base_precomp = Fp6Fp12FrobeniusBaseElements::construct(modulus, &fp2_non_residue).map_err(|_| {
+            ApiError::UnknownParameter("Can not make base precomputations for Fp6/Fp12 frobenius".to_owned())
+        })?;
+
+        let mut extension_6 = Extension3Over2::new(fp2_non_residue.clone());
+        extension_6.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
+            ApiError::UnknownParameter("Can not calculate Frobenius coefficients for Fp6".to_owned())
+        })?;
+
+        let mut extension_12 = Extension2Over3Over2::new(Fp6::zero(&extension_6));
+        extension_12.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
+            ApiError::InputError("Can not calculate Frobenius coefficients for Fp12".to_owned())
+        })?;
+
+        let fp2_non_residue_inv = fp2_non_residue.inverse().ok_or(ApiError::UnexpectedZero("Fp2 non-residue must be invertible".to_owned()))?;
+        let mut b_twist = match twist_type {
+            TwistType::D => fp2_non_residue_inv,
+            TwistType::M => fp2_non_residue.clone(),
+        };
+        b_twist.mul_by_fp(&fp_from_repr(b, base_field));
+
+        let two = MaxLoopParametersUint::from(2u64);
+        let six = MaxLoopParametersUint::from(6u64);
+
+        let (x, x_is_negative, six_u_plus_2, non_residue_in_p_minus_one_over_2, rest) = match family {
+            PairingFamily::Bls12 => {
+                let x_encoding = rest;
+                let (x, rest) = decode_loop_parameter_scalar_with_bit_limit(rest, MAX_BLS12_X_BIT_LENGTH)?;
+                if x.is_zero() {
+                    return Err(ApiError::InputError("Loop count parameters can not be zero".to_owned()));
+                }
+                if calculate_hamming_weight(&x.as_ref()) > MAX_BLS12_X_HAMMING {
+                    return Err(ApiError::coded_at(ErrorCode::LimitExceeded, "X has too large hamming weight", x_encoding));
+                }
+                let (x_is_negative, rest) = decode_sign_is_negative(rest)?;
+
+                (x, x_is_negative, MaxLoopParametersUint::zero(), [FE::default(); 2], rest)
+            },
+            PairingFamily::Bn => {
+                let u_encoding = rest;
+                let (u, rest) = decode_loop_parameter_scalar_with_bit_limit(rest, MAX_BN_U_BIT_LENGTH)?;
+                if u.is_zero() {
+                    return Err(ApiError::InputError("Loop count parameters can not be zero".to_owned()));
+                }
+                let (u_is_negative, rest) = decode_sign_is_negative(rest)?;
+
+                // we need only absolute value of 6u+2, so manually handle negative and positive U
+                let six_u_plus_two = if u_is_negative {
+                    (six * u) - two
+                } else {
+                    (six * u) + two
+                };
+                if calculate_hamming_weight(&six_u_plus_two.as_ref()) > MAX_BN_SIX_U_PLUS_TWO_HAMMING {
+                    return Err(ApiError::coded_at(ErrorCode::LimitExceeded, "|6*U + 2| has too large hamming weight", u_encoding));
+                }
+
+                let p_minus_one_over_2 = (*modulus - MaxFieldUint::from(1u64)) >> 1;
+                let non_residue_in_p_minus_one_over_2 = fp2_non_residue.pow(p_minus_one_over_2.as_ref());
+
+                (u, u_is_negative, six_u_plus_two, fp2_into_reprs(&non_residue_in_p_minus_one_over_2), rest)
+            }
+        };
+
+        let mut fp6_frobenius_c1 = [[FE::default(); 2]; 6];
+        let mut fp6_frobenius_c2 = [[FE::default(); 2]; 6];
+        for (i, (c1, c2)) in extension_6.frobenius_coeffs_c1.iter().zip(extension_6.frobenius_coeffs_c2.iter()).enumerate() {
+            fp6_frobenius_c1[i] = fp2_into_reprs(c1);
+            fp6_frobenius_c2[i] = fp2_into_reprs(c2);
+        }
+        let mut fp12_frobenius_c1 = [[FE::default(); 2]; 12];
+        for (i, c1) in extension_12.frobenius_coeffs_c1.iter().enumerate() {
+            fp12_frobenius_c1[i] = fp2_into_reprs(c1);
+        }
+
+        let pairing = PreparedPairing {
+            family,
+            twist_type,
+            x,
+            x_is_negative,
+            six_u_plus_2,
+            fp_non_residue: fp_non_residue.repr,
+            fp2_frobenius_c1: [extension_2.frobenius_coeffs_c1[0].repr, extension_2.frobenius_coeffs_c1[1].repr],
+            fp2_non_residue: fp2_into_reprs(&fp2_non_residue),
+            fp6_frobenius_c1,
+            fp6_frobenius_c2,
+            fp12_frobenius_c1,
+            b_twist: fp2_into_reprs(&b_twist),
+            non_residue_in_p_minus_one_over_2,
+        };
+
+        Ok((pairing, rest))
+    }
+
+    pub(crate) fn g1_curve<'a>(
+        &'a self, 
+        params: &'a CurveOverFpParameters<'a, FE, PrimeField<FE>>
+    ) -> Result<WeierstrassCurve<'a, CurveOverFpParameters<'a, FE, PrimeField<FE>>>, ApiError> {
+        let a = fp_from_repr(self.a, &self.base_field);
+        let b = fp_from_repr(self.b, &self.base_field);
+
+        WeierstrassCurve::new(self.order.as_ref(), a, b, params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })
+    }
+
+    // binds precomputed extension fields and curves for the duration of `f`
+    pub(crate) fn with_tower<R, F: for<'a> FnOnce(&Tower<'a, FE>) -> Result<R, ApiError>>(&self, f: F) -> Result<R, ApiError> {
+        let params = self.pairing.as_ref().ok_or_else(|| {
+            ApiError::InputError("Curve was built without pairing parameters".to_owned())
+        })?;
+        let base_field = &self.base_field;
+
+        let mut extension_2 = Extension2::new(fp_from_repr(params.fp_non_residue, base_field));
+        for (c, repr) in extension_2.frobenius_coeffs_c1.iter_mut().zip(params.fp2_frobenius_c1.iter()) {
+            c.repr = *repr;
+        }
+        extension_2.frobenius_coeffs_are_calculated = true;
+
+        let mut extension_6 = Extension3Over2::new(fp2_from_reprs(&params.fp2_non_residue, &extension_2));
+        for (c, reprs) in extension_6.frobenius_coeffs_c1.iter_mut().zip(params.fp6_frobenius_c1.iter()) {
+            *c = fp2_from_reprs(reprs, &extension_2);
+        }
+        for (c, reprs) in extension_6.frobenius_coeffs_c2.iter_mut().zip(params.fp6_frobenius_c2.iter()) {
+            *c = fp2_from_reprs(reprs, &extension_2);
+        }
+        extension_6.frobenius_coeffs_are_calculated = true;
+
+        let mut extension_12 = Extension2Over3Over2::new(Fp6::zero(&extension_6));
+        for (c, reprs) in extension_12.frobenius_coeffs_c1.iter_mut().zip(params.fp12_frobenius_c1.iter()) {
+            *c = fp2_from_reprs(reprs, &extension_2);
+        }
+        extension_12.frobenius_coeffs_are_calculated = true;
+
+        let fp_params = CurveOverFpParameters::new(base_field);
+        let g1_curve = self.g1_curve(&fp_params)?;
+
+        let fp2_params = CurveOverFp2Parameters::new(&extension_2);
+        let b_twist = fp2_from_reprs(&params.b_twist, &extension_2);
+        let g2_curve = WeierstrassCurve::new(self.order.as_ref(), Fp2::zero(&extension_2), b_twist, &fp2_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
+
+        f(&Tower {
+            params,
+            base_field,
+            g1_curve: &g1_curve,
+            g2_curve: &g2_curve,
+            fp2_extension: &extension_2,
+            fp6_extension: &extension_6,
+            fp12_extension: &extension_12,
+        })
+    }
+}
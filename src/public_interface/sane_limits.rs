@@ -1,7 +1,9 @@
 pub const MAX_BLS12_X_BIT_LENGTH: usize = 128;
+pub const MAX_BLS24_X_BIT_LENGTH: usize = 128;
 pub const MAX_BN_U_BIT_LENGTH: usize = 128;
 
 pub const MAX_BLS12_X_HAMMING: u32 = 128u32;
+pub const MAX_BLS24_X_HAMMING: u32 = 128u32;
 pub const MAX_BN_SIX_U_PLUS_TWO_HAMMING: u32 = 128u32;
 
 pub const MAX_ATE_PAIRING_ATE_LOOP_COUNT: usize = 2032;
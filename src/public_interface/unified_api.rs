@@ -24,6 +24,8 @@ pub enum OperationType {
     BNPAIR = 8,
     MNT4PAIR = 9,
     MNT6PAIR = 10,
+    BW6PAIR = 11,
+    BLS24PAIR = 12,
 }
 
 impl OperationType {
@@ -59,6 +61,12 @@ impl OperationType {
             MNT6PAIR_OPERATION_RAW_VALUE => {
                 Some(OperationType::MNT6PAIR)
             },
+            BW6PAIR_OPERATION_RAW_VALUE => {
+                Some(OperationType::BW6PAIR)
+            },
+            BLS24PAIR_OPERATION_RAW_VALUE => {
+                Some(OperationType::BLS24PAIR)
+            },
             _ => {
                 None
             }
@@ -82,6 +90,8 @@ pub const BLS12PAIR_OPERATION_RAW_VALUE: u8 = OperationType::BLS12PAIR as u8;
 pub const BNPAIR_OPERATION_RAW_VALUE: u8 = OperationType::BNPAIR as u8;
 pub const MNT4PAIR_OPERATION_RAW_VALUE: u8 = OperationType::MNT4PAIR as u8;
 pub const MNT6PAIR_OPERATION_RAW_VALUE: u8 = OperationType::MNT6PAIR as u8;
+pub const BW6PAIR_OPERATION_RAW_VALUE: u8 = OperationType::BW6PAIR as u8;
+pub const BLS24PAIR_OPERATION_RAW_VALUE: u8 = OperationType::BLS24PAIR as u8;
 
 // This is pure rust API
 pub fn perform_operation(operation: OperationType, input: &[u8]) -> Result<Vec<u8>, ApiError> {
@@ -106,7 +116,7 @@ pub fn perform_operation(operation: OperationType, input: &[u8]) -> Result<Vec<u
         OperationType::G2MULTIEXP => {
             PublicG2Api::multiexp(&input)
         },
-        OperationType::BLS12PAIR | OperationType::BNPAIR | OperationType::MNT4PAIR | OperationType::MNT6PAIR => {
+        OperationType::BLS12PAIR | OperationType::BNPAIR | OperationType::MNT4PAIR | OperationType::MNT6PAIR | OperationType::BW6PAIR | OperationType::BLS24PAIR => {
             use crate::field::*;
             use crate::public_interface::decode_utils::*;
 
@@ -138,6 +148,16 @@ pub fn perform_operation(operation: OperationType, input: &[u8]) -> Result<Vec<u
 
                     result
                 },
+                OperationType::BW6PAIR => {
+                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, input, pair_bw6); 
+
+                    result
+                },
+                OperationType::BLS24PAIR => {
+                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, input, pair_bls24); 
+
+                    result
+                },
 
                 _ => {
                     unreachable!()
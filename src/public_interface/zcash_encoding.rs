@@ -0,0 +1,328 @@
+//! Encoding of BLS12-381 points in the format of ZCash (also used by the IETF BLS signature draft):
+//! big endian 48 byte coordinates with three top bits of the first byte used as flags.
+//! G2 coordinates are encoded as c1 || c0
+
+use crate::weierstrass::Group;
+use crate::weierstrass::curve::CurvePoint;
+use crate::weierstrass::{CurveOverFpParameters, CurveOverFp2Parameters};
+use crate::field::{U384Repr, PrimeField};
+use crate::fp::Fp;
+use crate::extension_towers::fp2::Fp2;
+use crate::traits::{FieldElement, ZeroAndOne};
+use crate::square_root::{sqrt, sqrt_ext2};
+use crate::engines::bls12_381;
+use crate::errors::ApiError;
+
+use super::decode_fp::{decode_fp, serialize_fp_fixed_len};
+
+pub(crate) type G1 = CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>;
+pub(crate) type G2 = CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>;
+
+pub const FP_BYTE_LENGTH: usize = 48;
+
+pub const COMPRESSED_G1_BYTE_LENGTH: usize = FP_BYTE_LENGTH;
+pub const UNCOMPRESSED_G1_BYTE_LENGTH: usize = FP_BYTE_LENGTH * 2;
+pub const COMPRESSED_G2_BYTE_LENGTH: usize = FP_BYTE_LENGTH * 2;
+pub const UNCOMPRESSED_G2_BYTE_LENGTH: usize = FP_BYTE_LENGTH * 4;
+
+const COMPRESSION_FLAG: u8 = 0x80;
+const INFINITY_FLAG: u8 = 0x40;
+const SIGN_FLAG: u8 = 0x20;
+const FLAGS_MASK: u8 = COMPRESSION_FLAG | INFINITY_FLAG | SIGN_FLAG;
+
+// y is encoded as "negative" if it's larger than -y when both are interpreted as integers
+fn fp_is_lexicographically_largest(el: &Fp<'static, U384Repr, PrimeField<U384Repr>>) -> bool {
+    let mut negated = el.clone();
+    negated.negate();
+
+    el.into_repr() > negated.into_repr()
+}
+
+fn fp2_is_lexicographically_largest(el: &Fp2<'static, U384Repr, PrimeField<U384Repr>>) -> bool {
+    if el.c1.is_zero() {
+        fp_is_lexicographically_largest(&el.c0)
+    } else {
+        fp_is_lexicographically_largest(&el.c1)
+    }
+}
+
+fn infinity_encoding(byte_len: usize, compressed: bool) -> Vec<u8> {
+    let mut encoding = vec![0u8; byte_len];
+    encoding[0] = INFINITY_FLAG;
+    if compressed {
+        encoding[0] |= COMPRESSION_FLAG;
+    }
+
+    encoding
+}
+
+struct Flags {
+    compressed: bool,
+    infinity: bool,
+    sign: bool,
+}
+
+// validates flags against the encoding length and returns the encoding with flag bits cleared
+fn split_flags(bytes: &[u8], compressed_len: usize, uncompressed_len: usize) -> Result<(Flags, Vec<u8>), ApiError> {
+    if bytes.len() != compressed_len && bytes.len() != uncompressed_len {
+        return Err(ApiError::InputError(format!("invalid encoding length {}, expected {} or {} bytes", bytes.len(), compressed_len, uncompressed_len)));
+    }
+
+    let flags = Flags {
+        compressed: bytes[0] & COMPRESSION_FLAG != 0,
+        infinity: bytes[0] & INFINITY_FLAG != 0,
+        sign: bytes[0] & SIGN_FLAG != 0,
+    };
+
+    if flags.compressed != (bytes.len() == compressed_len) {
+        return Err(ApiError::InputError("compression flag doesn't match the encoding length".to_owned()));
+    }
+
+    let mut encoding = bytes.to_vec();
+    encoding[0] &= !FLAGS_MASK;
+
+    if flags.infinity {
+        if flags.sign || encoding.iter().any(|&b| b != 0) {
+            return Err(ApiError::InputError("invalid encoding of the point at infinity".to_owned()));
+        }
+    } else if !flags.compressed && flags.sign {
+        return Err(ApiError::InputError("sign flag is set for uncompressed encoding".to_owned()));
+    }
+
+    Ok((flags, encoding))
+}
+
+/// Encodes a G1 point into 48 (compressed) or 96 (uncompressed) bytes
+pub(crate) fn serialize_g1(point: &G1, compressed: bool) -> Result<Vec<u8>, ApiError> {
+    let byte_len = if compressed { COMPRESSED_G1_BYTE_LENGTH } else { UNCOMPRESSED_G1_BYTE_LENGTH };
+    if point.is_zero() {
+        return Ok(infinity_encoding(byte_len, compressed));
+    }
+
+    let (x, y) = point.into_xy();
+    let mut encoding = serialize_fp_fixed_len(FP_BYTE_LENGTH, &x)?;
+    if compressed {
+        encoding[0] |= COMPRESSION_FLAG;
+        if fp_is_lexicographically_largest(&y) {
+            encoding[0] |= SIGN_FLAG;
+        }
+    } else {
+        encoding.extend(serialize_fp_fixed_len(FP_BYTE_LENGTH, &y)?);
+    }
+
+    Ok(encoding)
+}
+
+/// Encodes a G2 point into 96 (compressed) or 192 (uncompressed) bytes
+pub(crate) fn serialize_g2(point: &G2, compressed: bool) -> Result<Vec<u8>, ApiError> {
+    let byte_len = if compressed { COMPRESSED_G2_BYTE_LENGTH } else { UNCOMPRESSED_G2_BYTE_LENGTH };
+    if point.is_zero() {
+        return Ok(infinity_encoding(byte_len, compressed));
+    }
+
+    let (x, y) = point.into_xy();
+    let mut encoding = serialize_fp_fixed_len(FP_BYTE_LENGTH, &x.c1)?;
+    encoding.extend(serialize_fp_fixed_len(FP_BYTE_LENGTH, &x.c0)?);
+    if compressed {
+        encoding[0] |= COMPRESSION_FLAG;
+        if fp2_is_lexicographically_largest(&y) {
+            encoding[0] |= SIGN_FLAG;
+        }
+    } else {
+        encoding.extend(serialize_fp_fixed_len(FP_BYTE_LENGTH, &y.c1)?);
+        encoding.extend(serialize_fp_fixed_len(FP_BYTE_LENGTH, &y.c0)?);
+    }
+
+    Ok(encoding)
+}
+
+/// Decodes a compressed or uncompressed G1 point, length of the input defines the expected form.
+/// Point is checked to be on curve and in the main subgroup
+pub(crate) fn deserialize_g1(bytes: &[u8]) -> Result<G1, ApiError> {
+    let (flags, encoding) = split_flags(bytes, COMPRESSED_G1_BYTE_LENGTH, UNCOMPRESSED_G1_BYTE_LENGTH)?;
+    let curve = &bls12_381::BLS12_381_G1_CURVE;
+    if flags.infinity {
+        return Ok(CurvePoint::zero(curve));
+    }
+
+    let (x, rest) = decode_fp(&encoding, FP_BYTE_LENGTH, &bls12_381::BLS12_381_FIELD)?;
+    let point = if flags.compressed {
+        // y^2 = x^3 + b
+        let mut rhs = x.clone();
+        rhs.square();
+        rhs.mul_assign(&x);
+        rhs.add_assign(&bls12_381::BLS12_381_B_FOR_G1);
+
+        let mut y = sqrt(&rhs).ok_or_else(|| {
+            ApiError::InputError("x is not a coordinate of a point on the curve".to_owned())
+        })?;
+        if fp_is_lexicographically_largest(&y) != flags.sign {
+            y.negate();
+        }
+
+        CurvePoint::point_from_xy(curve, x, y)
+    } else {
+        let (y, _) = decode_fp(rest, FP_BYTE_LENGTH, &bls12_381::BLS12_381_FIELD)?;
+        let point = CurvePoint::point_from_xy(curve, x, y);
+        if !point.is_on_curve() {
+            return Err(ApiError::InputError("point is not on curve".to_owned()));
+        }
+
+        point
+    };
+
+    if !bls12_381::check_correct_subgroup_fast_g1(&point) {
+        return Err(ApiError::InputError("point is not in the main subgroup".to_owned()));
+    }
+
+    Ok(point)
+}
+
+/// Decodes a compressed or uncompressed G2 point, length of the input defines the expected form.
+/// Point is checked to be on curve and in the main subgroup
+pub(crate) fn deserialize_g2(bytes: &[u8]) -> Result<G2, ApiError> {
+    let (flags, encoding) = split_flags(bytes, COMPRESSED_G2_BYTE_LENGTH, UNCOMPRESSED_G2_BYTE_LENGTH)?;
+    let curve = &bls12_381::BLS12_381_G2_CURVE;
+    if flags.infinity {
+        return Ok(CurvePoint::zero(curve));
+    }
+
+    let (x, rest) = decode_fp2_c1_first(&encoding)?;
+    let point = if flags.compressed {
+        // y^2 = x^3 + b
+        let mut rhs = x.clone();
+        rhs.square();
+        rhs.mul_assign(&x);
+        rhs.add_assign(&bls12_381::BLS12_381_B_FOR_G2);
+
+        let mut y = sqrt_ext2(&rhs).ok_or_else(|| {
+            ApiError::InputError("x is not a coordinate of a point on the curve".to_owned())
+        })?;
+        if fp2_is_lexicographically_largest(&y) != flags.sign {
+            y.negate();
+        }
+
+        CurvePoint::point_from_xy(curve, x, y)
+    } else {
+        let (y, _) = decode_fp2_c1_first(rest)?;
+        let point = CurvePoint::point_from_xy(curve, x, y);
+        if !point.is_on_curve() {
+            return Err(ApiError::InputError("point is not on curve".to_owned()));
+        }
+
+        point
+    };
+
+    if !bls12_381::check_correct_subgroup_fast_g2(&point) {
+        return Err(ApiError::InputError("point is not in the main subgroup".to_owned()));
+    }
+
+    Ok(point)
+}
+
+fn decode_fp2_c1_first(bytes: &[u8]) -> Result<(Fp2<'static, U384Repr, PrimeField<U384Repr>>, &[u8]), ApiError> {
+    let (c1, rest) = decode_fp(bytes, FP_BYTE_LENGTH, &bls12_381::BLS12_381_FIELD)?;
+    let (c0, rest) = decode_fp(rest, FP_BYTE_LENGTH, &bls12_381::BLS12_381_FIELD)?;
+
+    let mut el = Fp2::zero(&bls12_381::BLS12_381_EXTENSION_2_FIELD);
+    el.c0 = c0;
+    el.c1 = c1;
+
+    Ok((el, rest))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const G1_GENERATOR_COMPRESSED: &str = "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+    const G2_GENERATOR_COMPRESSED: &str = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+
+    #[test]
+    fn test_generators_encoding() {
+        let g1 = bls12_381::BLS12_381_G1_GENERATOR;
+        let encoding = serialize_g1(&g1, true).unwrap();
+        assert_eq!(hex::encode(&encoding), G1_GENERATOR_COMPRESSED);
+        assert_eq!(deserialize_g1(&encoding).unwrap().into_xy(), g1.into_xy());
+
+        let g2 = bls12_381::BLS12_381_G2_GENERATOR;
+        let encoding = serialize_g2(&g2, true).unwrap();
+        assert_eq!(hex::encode(&encoding), G2_GENERATOR_COMPRESSED);
+        assert_eq!(deserialize_g2(&encoding).unwrap().into_xy(), g2.into_xy());
+    }
+
+    #[test]
+    fn test_encoding_roundtrip() {
+        for scalar in 1u64..20 {
+            let mut p = bls12_381::BLS12_381_G1_GENERATOR.mul(&[scalar]);
+            p.normalize();
+            let mut q = bls12_381::BLS12_381_G2_GENERATOR.mul(&[scalar]);
+            q.normalize();
+
+            for &compressed in [true, false].iter() {
+                let encoding = serialize_g1(&p, compressed).unwrap();
+                assert_eq!(deserialize_g1(&encoding).unwrap().into_xy(), p.into_xy());
+
+                let encoding = serialize_g2(&q, compressed).unwrap();
+                assert_eq!(deserialize_g2(&encoding).unwrap().into_xy(), q.into_xy());
+            }
+        }
+    }
+
+    #[test]
+    fn test_infinity_encoding() {
+        let zero = CurvePoint::zero(&bls12_381::BLS12_381_G1_CURVE);
+        let encoding = serialize_g1(&zero, true).unwrap();
+        assert_eq!(encoding[0], 0xc0);
+        assert!(deserialize_g1(&encoding).unwrap().is_zero());
+
+        let encoding = serialize_g1(&zero, false).unwrap();
+        assert_eq!(encoding[0], 0x40);
+        assert!(deserialize_g1(&encoding).unwrap().is_zero());
+
+        let zero = CurvePoint::zero(&bls12_381::BLS12_381_G2_CURVE);
+        let encoding = serialize_g2(&zero, true).unwrap();
+        assert_eq!(encoding.len(), COMPRESSED_G2_BYTE_LENGTH);
+        assert!(deserialize_g2(&encoding).unwrap().is_zero());
+
+        let mut invalid = encoding.clone();
+        invalid[0] |= SIGN_FLAG;
+        assert!(deserialize_g2(&invalid).is_err());
+
+        let mut invalid = encoding.clone();
+        invalid[95] = 1;
+        assert!(deserialize_g2(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_invalid_encodings() {
+        let encoding = hex::decode(G1_GENERATOR_COMPRESSED).unwrap();
+
+        // flag doesn't match the length
+        let mut invalid = encoding.clone();
+        invalid[0] &= !COMPRESSION_FLAG;
+        assert!(deserialize_g1(&invalid).is_err());
+
+        assert!(deserialize_g1(&encoding[1..]).is_err());
+
+        // x is not reduced
+        let mut invalid = encoding.clone();
+        for b in invalid[1..].iter_mut() {
+            *b = 0xff;
+        }
+        assert!(deserialize_g1(&invalid).is_err());
+
+        // (0, 2) is on curve, but has order 3
+        let mut invalid = vec![0u8; COMPRESSED_G1_BYTE_LENGTH];
+        invalid[0] = COMPRESSION_FLAG;
+        assert!(deserialize_g1(&invalid).is_err());
+
+        // no point with x = 1 since 5 is not a square
+        invalid[47] = 1;
+        assert!(deserialize_g1(&invalid).is_err());
+
+        let mut uncompressed = serialize_g1(&bls12_381::BLS12_381_G1_GENERATOR, false).unwrap();
+        uncompressed[95] ^= 1;
+        assert!(deserialize_g1(&uncompressed).is_err());
+    }
+}
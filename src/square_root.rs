@@ -22,7 +22,7 @@ pub(crate) fn modulus_is_three_mod_four<E: ElementRepr, F: SizedPrimeField<Repr
 }
 
 pub(crate) fn modulus_is_one_mod_sixteen<E: ElementRepr, F: SizedPrimeField<Repr = E>>(field: &F) -> bool {
-    const MASK: u64 = 16; // last four bits
+    const MASK: u64 = 15; // last four bits
 
     let last_limb = field.modulus().as_ref()[0];
 
@@ -82,12 +82,95 @@ pub(crate) fn legendre_symbol_fp2<'a, E: ElementRepr, F: SizedPrimeField<Repr =
     // }
 }
 
-fn sqrt_for_one_mod_four<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(_element: &Fp<'a, E, F>) -> Option<Fp<'a, E, F>> {
-    // TODO, or consider to slow
-    
+// bound for a search of a quadratic non-residue, the smallest one is tiny for any practical modulus
+const MAX_NON_RESIDUE_SEARCH: u64 = 1000;
+
+/// Finds a primitive 2^s-th root of unity where 2^s is the largest power of two dividing modulus - 1.
+/// Such root is c = z^t for any quadratic non-residue z and odd t = (modulus - 1) / 2^s
+pub(crate) fn find_two_adic_root_of_unity<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(field: &'a F) -> Option<Fp<'a, E, F>> {
+    let two_adicity = field.two_adicity();
+    if two_adicity == 0 {
+        return None;
+    }
+
+    let mut minus_one = Fp::one(field);
+    minus_one.negate();
+
+    if two_adicity == 1 {
+        return Some(minus_one);
+    }
+
+    // modulus is odd, so shifting it is the same as shifting modulus - 1
+    let mut modulus_minus_one_by_two = *field.modulus();
+    modulus_minus_one_by_two.shr(1);
+
+    let mut t = *field.modulus();
+    t.shr(two_adicity as u32);
+
+    for z in 2..MAX_NON_RESIDUE_SEARCH {
+        let z = Fp::from_repr(field, E::from(z)).ok()?;
+        if z.pow(&modulus_minus_one_by_two.as_ref()) == minus_one {
+            return Some(z.pow(&t.as_ref()));
+        }
+    }
+
     None
 }
 
+fn sqrt_for_one_mod_four<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp<'a, E, F>) -> Option<Fp<'a, E, F>> {
+    // Tonelli-Shanks for modulus - 1 = 2^s * t with odd t
+
+    if element.is_zero() {
+        return Some(element.clone());
+    }
+
+    let field = element.field;
+    let mut c = if field.two_adic_root_of_unity().is_zero() {
+        find_two_adic_root_of_unity(field)?
+    } else {
+        Fp::from_raw_repr(field, *field.two_adic_root_of_unity()).ok()?
+    };
+
+    let mut t_minus_one_by_two = *field.modulus();
+    t_minus_one_by_two.shr(field.two_adicity() as u32 + 1);
+
+    // w = a^((t - 1)/2), x = a^((t + 1)/2), b = a^t
+    let mut w = element.pow(&t_minus_one_by_two.as_ref());
+    let mut x = element.clone();
+    x.mul_assign(&w);
+    let mut b = x.clone();
+    b.mul_assign(&w);
+
+    let one = Fp::one(field);
+    let mut v = field.two_adicity();
+
+    while b != one {
+        // find least k such that b^(2^k) == 1, for non-residues it's equal to v
+        let mut k = 0u64;
+        let mut b_power = b.clone();
+        while b_power != one {
+            b_power.square();
+            k += 1;
+            if k == v {
+                return None;
+            }
+        }
+
+        w = c.clone();
+        for _ in 0..(v - k - 1) {
+            w.square();
+        }
+        c = w.clone();
+        c.square();
+
+        x.mul_assign(&w);
+        b.mul_assign(&c);
+        v = k;
+    }
+
+    Some(x)
+}
+
 pub(crate) fn sqrt_for_three_mod_four<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp<'a, E, F>) -> Option<Fp<'a, E, F>> {
     // this is a simple case: we compute the power 
     // we know that it's 3 mod 4, so just bit shift
@@ -113,30 +196,11 @@ pub(crate) fn sqrt_for_three_mod_four<'a, E: ElementRepr, F: SizedPrimeField<Rep
     }
 }
 
-fn sqrt_for_one_mod_sixteen<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp<'a, E, F>) -> Option<Fp<'a, E, F>> {
-    // Requires to know a generator and roots of unity
-    // postpone for now
-    // we know that it's 1 mod 16, so we can use simple bit shifts
-
-    match legendre_symbol_fp(&element) {
-        LegendreSymbol::Zero => {
-            // it's zero, so clone
-            Some(element.clone())
-        },
-        LegendreSymbol::QuadraticNonResidue => {
-            None
-        },
-        LegendreSymbol::QuadraticResidue => {
-            None
-        }
-    }
-}
-
 pub(crate) fn sqrt<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp<'a, E, F>) -> Option<Fp<'a, E, F>> {
     if modulus_is_three_mod_four(element.field) {
         sqrt_for_three_mod_four(&element)
     } else {
-        None
+        sqrt_for_one_mod_four(&element)
     }
 }
 
@@ -188,10 +252,173 @@ pub(crate) fn sqrt_for_three_mod_four_ext2<'a, E: ElementRepr, F: SizedPrimeFiel
     }
 }
 
+pub(crate) fn sqrt_for_one_mod_four_ext2<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp2<'a, E, F>) -> Option<Fp2<'a, E, F>> {
+    // for a = a0 + a1*u with u^2 = beta we look for x0 + x1*u such that
+    // x0^2 + beta*x1^2 = a0 and 2*x0*x1 = a1, that reduces to square roots in the base field
+
+    if element.is_zero() {
+        return Some(element.clone());
+    }
+
+    let extension_field = element.extension_field;
+    let mut result = Fp2::zero(extension_field);
+
+    if element.c1.is_zero() {
+        if let Some(x0) = sqrt(&element.c0) {
+            result.c0 = x0;
+        } else {
+            // a0 is a non-residue, so a0/beta is a residue and the root is purely "imaginary"
+            let mut tmp = element.c0.clone();
+            tmp.mul_assign(&extension_field.non_residue.inverse()?);
+            result.c1 = sqrt(&tmp)?;
+        }
+
+        return Some(result);
+    }
+
+    let gamma = sqrt(&element.norm())?;
+
+    let mut two = Fp::one(extension_field.field);
+    two.double();
+    let two_inv = two.inverse()?;
+
+    let mut delta = element.c0.clone();
+    delta.add_assign(&gamma);
+    delta.mul_assign(&two_inv);
+
+    let x0 = match sqrt(&delta) {
+        Some(x0) => x0,
+        None => {
+            delta = element.c0.clone();
+            delta.sub_assign(&gamma);
+            delta.mul_assign(&two_inv);
+
+            sqrt(&delta)?
+        }
+    };
+
+    let mut x1 = x0.clone();
+    x1.double();
+    let mut x1 = x1.inverse()?;
+    x1.mul_assign(&element.c1);
+
+    result.c0 = x0;
+    result.c1 = x1;
+
+    let mut check = result.clone();
+    check.square();
+    if &check != element {
+        return None;
+    }
+
+    Some(result)
+}
+
 pub(crate) fn sqrt_ext2<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp2<'a, E, F>) -> Option<Fp2<'a, E, F>> {
     if modulus_is_three_mod_four_ext2(element.extension_field) {
         sqrt_for_three_mod_four_ext2(&element)
     } else {
-        None
+        sqrt_for_one_mod_four_ext2(&element)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::field::{U256Repr, U384Repr, new_field};
+    use crate::integers::MaxFieldUint;
+    use crate::engines::bls12_377::{BLS12_377_FIELD, BLS12_377_EXTENSION_2_FIELD};
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    const BLS12_377_MODULUS_DECIMAL: &str = "258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177";
+
+    fn random_fp<'a, R: Rng>(field: &'a PrimeField<U384Repr>, rng: &mut R) -> Fp<'a, U384Repr, PrimeField<U384Repr>> {
+        let mut limbs: [u64; 6] = rng.gen();
+        limbs[5] &= 0x00ffffffffffffff;
+
+        Fp::from_repr(field, U384Repr(limbs)).unwrap()
+    }
+
+    #[test]
+    fn test_two_adicity() {
+        assert_eq!(BLS12_377_FIELD.two_adicity, 46);
+
+        let field = new_field::<U384Repr>(BLS12_377_MODULUS_DECIMAL, 10).unwrap();
+        assert_eq!(field.two_adicity, 46);
+
+        let field = new_field::<U256Repr>("18446744069414584321", 10).unwrap();
+        assert_eq!(field.two_adicity, 32);
+    }
+
+    #[test]
+    fn test_precomputed_root_of_unity() {
+        let root = find_two_adic_root_of_unity(&BLS12_377_FIELD).unwrap();
+        assert_eq!(root.repr, BLS12_377_FIELD.two_adic_root_of_unity);
+
+        // field parsed at runtime comes with the root already found
+        let field = new_field::<U384Repr>(BLS12_377_MODULUS_DECIMAL, 10).unwrap();
+        assert_eq!(field.two_adic_root_of_unity, BLS12_377_FIELD.two_adic_root_of_unity);
+
+        let modulus = num_bigint::BigUint::parse_bytes(BLS12_377_MODULUS_DECIMAL.as_bytes(), 10).unwrap();
+        let modulus = MaxFieldUint::from_big_endian(&modulus.to_bytes_be());
+        let mut field = field_from_modulus_without_square_roots::<U384Repr>(&modulus).unwrap();
+        assert!(field.two_adic_root_of_unity.is_zero());
+        field.precompute_square_root_parameters();
+        assert_eq!(field.two_adic_root_of_unity, BLS12_377_FIELD.two_adic_root_of_unity);
+    }
+
+    #[test]
+    fn test_sqrt_one_mod_four() {
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+        let runtime_field = new_field::<U384Repr>(BLS12_377_MODULUS_DECIMAL, 10).unwrap();
+        let non_residue = BLS12_377_EXTENSION_2_FIELD.non_residue;
+
+        assert_eq!(sqrt(&Fp::zero(&BLS12_377_FIELD)).unwrap(), Fp::zero(&BLS12_377_FIELD));
+
+        for &field in [&BLS12_377_FIELD, &runtime_field].iter() {
+            for _ in 0..100 {
+                let mut a = random_fp(field, &mut rng);
+                a.square();
+
+                let mut root = sqrt(&a).unwrap();
+                root.square();
+                assert_eq!(root, a);
+
+                a.mul_assign(&Fp::from_repr(field, non_residue.into_repr()).unwrap());
+                assert!(sqrt(&a).is_none());
+            }
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_sqrt_one_mod_four_ext2() {
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+        let extension_field = &BLS12_377_EXTENSION_2_FIELD;
+
+        let mut checked_non_residues = 0;
+        for _ in 0..100 {
+            let mut a = Fp2::zero(extension_field);
+            a.c0 = random_fp(&BLS12_377_FIELD, &mut rng);
+            a.c1 = random_fp(&BLS12_377_FIELD, &mut rng);
+
+            if legendre_symbol_fp2(&a) == LegendreSymbol::QuadraticNonResidue {
+                assert!(sqrt_ext2(&a).is_none());
+                checked_non_residues += 1;
+            }
+
+            a.square();
+            let mut root = sqrt_ext2(&a).unwrap();
+            root.square();
+            assert_eq!(root, a);
+
+            // elements of the base field are always squares in the extension
+            a.c1 = Fp::zero(&BLS12_377_FIELD);
+            let mut root = sqrt_ext2(&a).unwrap();
+            root.square();
+            assert_eq!(root, a);
+        }
+
+        assert!(checked_non_residues > 0);
+    }
+}
@@ -0,0 +1,335 @@
+use num_bigint::BigUint;
+use num_traits::Num;
+
+use crate::public_interface::abi_encoder::*;
+use crate::public_interface::curve_builder::{PairingFamily, PairingParameters, TwistType};
+use crate::public_interface::{API, ErrorCode, OperationType, perform_operation};
+use crate::public_interface::constants::*;
+
+use crate::test::parsers::*;
+use crate::test::{g1_ops, g2_ops, pairings};
+
+fn bls12_curves() -> Vec<JsonBls12PairingCurveParameters> {
+    let curves = read_dir_and_grab_curves::<JsonBls12PairingCurveParameters>("src/test/test_vectors/bls12/");
+    assert!(curves.len() != 0);
+
+    curves.into_iter().map(|(curve, _)| curve).collect()
+}
+
+fn signed(value: (BigUint, bool), modulus: &BigUint) -> Vec<u8> {
+    let (value, is_positive) = value;
+    if is_positive { value.to_bytes_be() } else { (modulus - value).to_bytes_be() }
+}
+
+fn bls12_g1_curve(curve: &JsonBls12PairingCurveParameters) -> G1Curve {
+    G1Curve::new(&curve.q.to_bytes_be(), &curve.a.to_bytes_be(), &curve.b.to_bytes_be(), &curve.r.to_bytes_be())
+}
+
+fn bls12_g2_curve(curve: &JsonBls12PairingCurveParameters) -> G2Curve {
+    G2Curve::new(
+        &curve.q.to_bytes_be(),
+        &signed(curve.non_residue.clone(), &curve.q),
+        &[&curve.a_twist_0.to_bytes_be(), &curve.a_twist_1.to_bytes_be()],
+        &[&curve.b_twist_0.to_bytes_be(), &curve.b_twist_1.to_bytes_be()],
+        &curve.r.to_bytes_be()
+    )
+}
+
+fn bls12_g1_generator(curve: &JsonBls12PairingCurveParameters) -> G1Point {
+    G1Point::new(&curve.g1_x.to_bytes_be(), &curve.g1_y.to_bytes_be())
+}
+
+fn bls12_g2_generator(curve: &JsonBls12PairingCurveParameters) -> G2Point {
+    G2Point::new(
+        &[&curve.g2_x_0.to_bytes_be(), &curve.g2_x_1.to_bytes_be()],
+        &[&curve.g2_y_0.to_bytes_be(), &curve.g2_y_1.to_bytes_be()]
+    )
+}
+
+#[test]
+fn test_prefixes_match_reference_assembly() {
+    for curve in bls12_curves().into_iter() {
+        let (g1_prefix, _, _) = g1_ops::bls12::assemble_single_curve_params(curve.clone());
+        assert_eq!(bls12_g1_curve(&curve).encode_prefix().unwrap(), g1_prefix);
+
+        let (g2_prefix, _, _) = g2_ops::bls12::assemble_single_curve_params(curve.clone());
+        assert_eq!(bls12_g2_curve(&curve).encode_prefix().unwrap(), g2_prefix);
+    }
+}
+
+#[test]
+fn test_g1_and_g2_mul_vectors() {
+    for curve in bls12_curves().into_iter() {
+        let g1_curve = bls12_g1_curve(&curve);
+        for pair in curve.g1_mul_vectors.iter() {
+            let base = G1Point::new(&pair.base_x.to_bytes_be(), &pair.base_y.to_bytes_be());
+            let expected = g1_curve.encode_point(&G1Point::new(&pair.result_x.to_bytes_be(), &pair.result_y.to_bytes_be())).unwrap();
+
+            let builder = AbiEncoder::g1_mul(&g1_curve, &base, &pair.scalar.to_bytes_be());
+            assert_eq!(builder.operation(), OperationType::G1MUL);
+            assert_eq!(API::run(&builder.encode_with_operation().unwrap()).unwrap(), expected);
+        }
+
+        let g2_curve = bls12_g2_curve(&curve);
+        for pair in curve.g2_mul_vectors.iter() {
+            let base = G2Point::new(
+                &[&pair.base_x_0.to_bytes_be(), &pair.base_x_1.to_bytes_be()],
+                &[&pair.base_y_0.to_bytes_be(), &pair.base_y_1.to_bytes_be()]
+            );
+            let result = G2Point::new(
+                &[&pair.result_x_0.to_bytes_be(), &pair.result_x_1.to_bytes_be()],
+                &[&pair.result_y_0.to_bytes_be(), &pair.result_y_1.to_bytes_be()]
+            );
+
+            let builder = AbiEncoder::g2_mul(&g2_curve, &base, &pair.scalar.to_bytes_be());
+            let encoding = builder.encode().unwrap();
+            assert_eq!(perform_operation(OperationType::G2MUL, &encoding).unwrap(), g2_curve.encode_point(&result).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_add_and_multiexp_agree_with_mul() {
+    let curve = bls12_curves().into_iter().next().unwrap();
+
+    let g1_curve = bls12_g1_curve(&curve);
+    let g1 = bls12_g1_generator(&curve);
+    let doubled = API::run(&AbiEncoder::g1_mul(&g1_curve, &g1, &[2]).encode_with_operation().unwrap()).unwrap();
+    let added = API::run(&AbiEncoder::g1_add(&g1_curve, &g1, &g1).encode_with_operation().unwrap()).unwrap();
+    let multiexp = AbiEncoder::g1_multiexp(&g1_curve).with_pair(&g1, &[1]).with_pair(&g1, &[1]);
+    assert_eq!(added, doubled);
+    assert_eq!(API::run(&multiexp.encode_with_operation().unwrap()).unwrap(), doubled);
+
+    let g2_curve = bls12_g2_curve(&curve);
+    let g2 = bls12_g2_generator(&curve);
+    let doubled = API::run(&AbiEncoder::g2_mul(&g2_curve, &g2, &[2]).encode_with_operation().unwrap()).unwrap();
+    let added = API::run(&AbiEncoder::g2_add(&g2_curve, &g2, &g2).encode_with_operation().unwrap()).unwrap();
+    let multiexp = AbiEncoder::g2_multiexp(&g2_curve).with_pair(&g2, &[1]).with_pair(&g2, &[1]);
+    assert_eq!(added, doubled);
+    assert_eq!(API::run(&multiexp.encode_with_operation().unwrap()).unwrap(), doubled);
+
+    // point at infinity is a neutral element
+    let added = API::run(&AbiEncoder::g2_add(&g2_curve, &g2, &G2Point::infinity()).encode_with_operation().unwrap()).unwrap();
+    assert_eq!(added, g2_curve.encode_point(&g2).unwrap());
+}
+
+#[test]
+fn test_bls12_pairing() {
+    let mut num_paired = 0;
+    for curve in bls12_curves().into_iter() {
+        let params = PairingParameters {
+            family: PairingFamily::Bls12,
+            fp_non_residue: signed(curve.non_residue.clone(), &curve.q),
+            fp2_non_residue: (
+                signed(curve.quadratic_non_residue_0.clone(), &curve.q),
+                signed(curve.quadratic_non_residue_1.clone(), &curve.q)
+            ),
+            twist_type: if curve.is_d_type { TwistType::D } else { TwistType::M },
+            x: curve.x.0.to_bytes_be(),
+            x_is_negative: !curve.x.1,
+        };
+        let g1_curve = bls12_g1_curve(&curve);
+        let g1 = bls12_g1_generator(&curve);
+        let g1_negated = G1Point::new(&g1.x, &(curve.q.clone() - curve.g1_y.clone()).to_bytes_be());
+        let g2 = bls12_g2_generator(&curve);
+
+        // everything up to the number of pairs is deterministic in the reference assembly
+        let reference = pairings::bls12::assemble_single_curve_params(curve.clone(), 2, true).unwrap();
+        let empty = AbiEncoder::bls12_bn_pairing(&g1_curve, &params).encode_with_operation().unwrap();
+        assert_eq!(&empty[1..(empty.len() - 1)], &reference[..(empty.len() - 2)]);
+
+        let builder = AbiEncoder::bls12_bn_pairing(&g1_curve, &params)
+            .with_pair(&g1, &g2)
+            .with_pair_and_checks(&g1_negated, false, &g2, false);
+        assert_eq!(builder.operation(), OperationType::BLS12PAIR);
+        // some of the curves are over the sane limits of the decoder
+        match API::run(&builder.encode_with_operation().unwrap()) {
+            Ok(result) => {
+                assert_eq!(result, vec![1u8]);
+                num_paired += 1;
+            },
+            Err(err) => {
+                assert_eq!(err.code(), ErrorCode::LimitExceeded);
+            }
+        }
+    }
+    assert!(num_paired != 0);
+}
+
+#[test]
+fn test_mnt4_pairing_matches_reference_assembly() {
+    let dec = |s: &str| BigUint::from_str_radix(s, 10).unwrap().to_bytes_be();
+
+    let modulus = BigUint::from_str_radix("41898490967918953402344214791240637128170709919953949071783502921025352812571106773058893763790338921418070971888253786114353726529584385201591605722013126468931404347949840543007986327743462853720628051692141265303114721689601", 10).unwrap();
+    let curve = G1Curve::new(
+        &modulus.to_bytes_be(),
+        &[2],
+        &dec("28798803903456388891410036793299405764940372360099938340752576406393880372126970068421383312482853541572780087363938442377933706865252053507077543420534380486492786626556269083255657125025963825610840222568694137138741554679540"),
+        &dec("41898490967918953402344214791240637128170709919953949071783502921025352812571106773058893763790338921418070971888458477323173057491593855069696241854796396165721416325350064441470418137846398469611935719059908164220784476160001")
+    );
+    let params = MntPairingParameters {
+        fp_non_residue: vec![13],
+        x: dec("204691208819330962009469868104636132783269696790011977400223898462431810102935615891307667367766898917669754470400"),
+        x_is_negative: true,
+        exp_w0: dec("204691208819330962009469868104636132783269696790011977400223898462431810102935615891307667367766898917669754470399"),
+        exp_w1: vec![1],
+        exp_w0_is_negative: true,
+    };
+
+    let p_y = BigUint::from_str_radix("21091012152938225813050540665280291929032924333518476279110711148670464794818544820522390295209715531901248676888544060590943737249563733104806697968779796610374994498702698840169538725164956072726942500665132927942037078135054", 10).unwrap();
+    let p_x = dec("23803503838482697364219212396100314255266282256287758532210460958670711284501374254909249084643549104668878996224193897061976788052185662569738774028756446662400954817676947337090686257134874703224133183061214213216866019444443");
+    let p = G1Point::new(&p_x, &p_y.to_bytes_be());
+    let p_negated = G1Point::new(&p_x, &(modulus - p_y).to_bytes_be());
+    let q = G2Point::new(
+        &[
+            &dec("22367666623321080720060256844679369841450849258634485122226826668687008928557241162389052587294939105987791589807198701072089850184203060629036090027206884547397819080026926412256978135536735656049173059573120822105654153939204"),
+            &dec("19674349354065582663569886390557105215375764356464013910804136534831880915742161945711267871023918136941472003751075703860943205026648847064247080124670799190998395234694182621794580160576822167228187443851233972049521455293042"),
+        ],
+        &[
+            &dec("6945425020677398967988875731588951175743495235863391886533295045397037605326535330657361771765903175481062759367498970743022872494546449436815843306838794729313050998681159000579427733029709987073254733976366326071957733646574"),
+            &dec("17406100775489352738678485154027036191618283163679980195193677896785273172506466216232026037788788436442188057889820014276378772936042638717710384987239430912364681046070625200474931975266875995282055499803236813013874788622488"),
+        ]
+    );
+
+    let builder = AbiEncoder::mnt4_pairing(&curve, &params).with_pair(&p, &q).with_pair(&p_negated, &q);
+    let encoding = builder.encode_with_operation().unwrap();
+    assert_eq!(encoding[0], OPERATION_PAIRING);
+    assert_eq!(&encoding[1..], &pairings::mnt4::assemble_mnt4_753(2)[..]);
+    assert_eq!(perform_operation(OperationType::MNT4PAIR, &builder.encode().unwrap()).unwrap(), vec![1u8]);
+}
+
+#[test]
+fn test_bw6_761_pairing() {
+    use crate::engines::bw6_761::*;
+    use crate::pairings::PairingEngine;
+    use crate::public_interface::{PairingApi, PublicPairingApi};
+    use crate::public_interface::decode_fp::{serialize_fp_fixed_len, decode_fp6_as_2_over_3};
+    use crate::traits::FieldElement;
+
+    const MODULUS_LEN: usize = 96;
+    let limbs_to_bytes = |limbs: &[u64]| limbs.iter().rev().flat_map(|l| l.to_be_bytes().to_vec()).collect::<Vec<u8>>();
+    let fp_to_bytes = |el| serialize_fp_fixed_len(MODULUS_LEN, el).unwrap();
+
+    let mut b = BW6_761_FP_ONE.clone();
+    b.negate();
+    let curve = G1Curve::new(&limbs_to_bytes(&BW6_761_MODULUS.0), &[], &fp_to_bytes(&b), &limbs_to_bytes(&BW6_761_SUBGROUP_ORDER));
+    let params = Bw6PairingParameters {
+        fp_non_residue: fp_to_bytes(&BW6_761_EXTENSION_3_FIELD.non_residue),
+        twist_type: TwistType::M,
+        ate_loop_1: limbs_to_bytes(&BW6_761_ATE_LOOP_1),
+        ate_loop_1_is_negative: false,
+        ate_loop_2: limbs_to_bytes(&BW6_761_ATE_LOOP_2),
+        ate_loop_2_is_negative: false,
+        exp_w0: limbs_to_bytes(&BW6_761_EXP_W0),
+        exp_w1: limbs_to_bytes(&BW6_761_EXP_W1),
+        exp_w0_is_negative: false,
+    };
+
+    let (p_x, p_y) = BW6_761_G1_GENERATOR.into_xy();
+    let mut p_y_negated = p_y.clone();
+    p_y_negated.negate();
+    let p = G1Point::new(&fp_to_bytes(&p_x), &fp_to_bytes(&p_y));
+    let p_negated = G1Point::new(&fp_to_bytes(&p_x), &fp_to_bytes(&p_y_negated));
+    let (q_x, q_y) = BW6_761_G2_GENERATOR.into_xy();
+    let q = G1Point::new(&fp_to_bytes(&q_x), &fp_to_bytes(&q_y));
+
+    let builder = AbiEncoder::bw6_pairing(&curve, &params).with_pair(&p, &q).with_pair_and_checks(&p_negated, false, &q, false);
+    assert_eq!(builder.operation(), OperationType::BW6PAIR);
+    let encoding = builder.encode_with_operation().unwrap();
+    assert_eq!(&encoding[..2], &[OPERATION_PAIRING, BW6]);
+    assert_eq!(API::run(&encoding).unwrap(), vec![1u8]);
+    let explanation = crate::public_interface::decode_and_explain(&encoding);
+    assert!(explanation.failure.is_none());
+    assert_eq!(explanation.find(&["pair 1", "g2", "y"]).unwrap().offset + MODULUS_LEN, encoding.len());
+    assert_eq!(perform_operation(OperationType::BW6PAIR, &builder.encode().unwrap()).unwrap(), vec![1u8]);
+    #[cfg(feature = "gas_metering")]
+    assert!(crate::gas_meter::meter_operation(OperationType::BW6PAIR, &builder.encode().unwrap()).unwrap() > 0);
+
+    // raw result of a single pair is the one of the engine
+    let encoding = AbiEncoder::bw6_pairing(&curve, &params).with_pair(&p, &q).encode_with_operation().unwrap();
+    let result = PublicPairingApi::pair_raw(&encoding[1..]).unwrap();
+    assert_eq!(result.len(), 6 * MODULUS_LEN);
+    let (result, rest) = decode_fp6_as_2_over_3(&result, MODULUS_LEN, &BW6_761_EXTENSION_6_FIELD).unwrap();
+    assert!(rest.is_empty());
+    let expected = BW6_761_PAIRING_ENGINE.pair(&[BW6_761_G1_GENERATOR], &[BW6_761_G2_GENERATOR]).unwrap();
+    assert!(result == expected);
+}
+
+#[test]
+fn test_bls24_315_pairing() {
+    use crate::engines::bls24_315::*;
+    use crate::pairings::PairingEngine;
+    use crate::public_interface::{PairingApi, PublicPairingApi};
+    use crate::public_interface::decode_fp::{serialize_fp_fixed_len, serialize_fp4_fixed_len, serialize_fp24_fixed_len};
+    use crate::traits::FieldElement;
+
+    const MODULUS_LEN: usize = 40;
+    let limbs_to_bytes = |limbs: &[u64]| limbs.iter().rev().flat_map(|l| l.to_be_bytes().to_vec()).collect::<Vec<u8>>();
+    let fp_to_bytes = |el| serialize_fp_fixed_len(MODULUS_LEN, el).unwrap();
+    let fp4_to_coeffs = |el| serialize_fp4_fixed_len(MODULUS_LEN, el).unwrap().chunks(MODULUS_LEN).map(|c| c.to_vec()).collect::<Vec<_>>();
+
+    let curve = G1Curve::new(&limbs_to_bytes(&BLS24_315_MODULUS.0), &[], &[1u8], &limbs_to_bytes(&BLS24_315_SUBGROUP_ORDER));
+    let params = Bls24PairingParameters {
+        fp_non_residue: fp_to_bytes(&BLS24_315_EXTENSION_2_FIELD.non_residue),
+        fp4_non_residue: fp4_to_coeffs(&BLS24_315_EXTENSION_12_FIELD.non_residue),
+        twist_type: TwistType::D,
+        x: limbs_to_bytes(&BLS24_315_X),
+        x_is_negative: BLS24_315_X_IS_NEGATIVE,
+    };
+
+    let (p_x, p_y) = BLS24_315_G1_GENERATOR.into_xy();
+    let mut p_y_negated = p_y.clone();
+    p_y_negated.negate();
+    let p = G1Point::new(&fp_to_bytes(&p_x), &fp_to_bytes(&p_y));
+    let p_negated = G1Point::new(&fp_to_bytes(&p_x), &fp_to_bytes(&p_y_negated));
+    let (q_x, q_y) = BLS24_315_G2_GENERATOR.into_xy();
+    let q_x = fp4_to_coeffs(&q_x);
+    let q_y = fp4_to_coeffs(&q_y);
+    let q = G2Point { x: q_x, y: q_y };
+
+    let builder = AbiEncoder::bls24_pairing(&curve, &params).with_pair(&p, &q).with_pair_and_checks(&p_negated, false, &q, false);
+    assert_eq!(builder.operation(), OperationType::BLS24PAIR);
+    let encoding = builder.encode_with_operation().unwrap();
+    assert_eq!(&encoding[..2], &[OPERATION_PAIRING, BLS24]);
+    assert_eq!(API::run(&encoding).unwrap(), vec![1u8]);
+    let explanation = crate::public_interface::decode_and_explain(&encoding);
+    assert!(explanation.failure.is_none());
+    assert_eq!(explanation.find(&["pair 1", "g2", "y", "c3"]).unwrap().offset + MODULUS_LEN, encoding.len());
+    assert_eq!(perform_operation(OperationType::BLS24PAIR, &builder.encode().unwrap()).unwrap(), vec![1u8]);
+    #[cfg(feature = "gas_metering")]
+    assert!(crate::gas_meter::meter_operation(OperationType::BLS24PAIR, &builder.encode().unwrap()).unwrap() > 0);
+
+    // raw result of a single pair is the one of the engine
+    let encoding = AbiEncoder::bls24_pairing(&curve, &params).with_pair(&p, &q).encode_with_operation().unwrap();
+    let result = PublicPairingApi::pair_raw(&encoding[1..]).unwrap();
+    assert_eq!(result.len(), 24 * MODULUS_LEN);
+    let expected = BLS24_315_PAIRING_ENGINE.pair(&[BLS24_315_G1_GENERATOR], &[BLS24_315_G2_GENERATOR]).unwrap();
+    assert_eq!(result, serialize_fp24_fixed_len(MODULUS_LEN, &expected).unwrap());
+
+    // u = v^2 is a square in Fp4, so it can not be used to build Fp12 and Fp24
+    let mut params = params;
+    params.fp4_non_residue = vec![vec![0u8], vec![1u8], vec![0u8], vec![0u8]];
+    let encoding = AbiEncoder::bls24_pairing(&curve, &params).with_pair(&p, &q).encode_with_operation().unwrap();
+    assert_eq!(API::run(&encoding).unwrap_err().code(), ErrorCode::NonResidueCheckFailed);
+}
+
+#[test]
+fn test_invalid_builder_inputs() {
+    let curve = bls12_curves().into_iter().next().unwrap();
+    let g1_curve = bls12_g1_curve(&curve);
+    let g2_curve = bls12_g2_curve(&curve);
+    let g1 = bls12_g1_generator(&curve);
+
+    let too_long_scalar = vec![0xffu8; g1_curve.order_len() + 1];
+    assert!(AbiEncoder::g1_mul(&g1_curve, &g1, &too_long_scalar).encode().is_err());
+
+    let mut multiexp = AbiEncoder::g1_multiexp(&g1_curve);
+    for _ in 0..256 {
+        multiexp = multiexp.with_pair(&g1, &[1]);
+    }
+    assert!(multiexp.encode().is_err());
+
+    // Fp3 point on the curve over Fp2
+    let point = G2Point::new(&[&[1], &[2], &[3]], &[&[1], &[2], &[3]]);
+    assert!(AbiEncoder::g2_add(&g2_curve, &point, &point).encode().is_err());
+}
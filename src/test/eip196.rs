@@ -0,0 +1,114 @@
+// Cross checks of `EIP196Executor` against the `bn` crate used by the existing clients
+
+use bn::{AffineG1, AffineG2, Fr, G1, G2, Gt, Group, pairing_batch};
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+use crate::public_interface::eip196::*;
+
+const NUM_TESTS: usize = 100;
+
+fn make_rng() -> XorShiftRng {
+    XorShiftRng::from_seed([0x5d, 0xbe, 0x62, 0x59, 0x8d, 0x31, 0x3d, 0x76, 0x32, 0x37, 0xdb, 0x17, 0xe5, 0xbc, 0x06, 0x54])
+}
+
+// full width scalar as a product of random 64 bit values
+fn random_fr<R: Rng>(rng: &mut R) -> Fr {
+    let mut s = Fr::one();
+    for _ in 0..4 {
+        let v: u64 = rng.gen();
+        s = s * Fr::from_str(&v.to_string()).unwrap();
+    }
+
+    s
+}
+
+fn encode_fr(s: Fr) -> Vec<u8> {
+    let mut buf = vec![0u8; SCALAR_BYTE_LENGTH];
+    s.to_big_endian(&mut buf).unwrap();
+
+    buf
+}
+
+fn encode_g1(p: G1) -> Vec<u8> {
+    let mut buf = vec![0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+    if let Some(p) = AffineG1::from_jacobian(p) {
+        p.x().to_big_endian(&mut buf[0..32]).unwrap();
+        p.y().to_big_endian(&mut buf[32..64]).unwrap();
+    }
+
+    buf
+}
+
+fn encode_g2(p: G2) -> Vec<u8> {
+    let mut buf = vec![0u8; SERIALIZED_G2_POINT_BYTE_LENGTH];
+    if let Some(p) = AffineG2::from_jacobian(p) {
+        p.x().imaginary().to_big_endian(&mut buf[0..32]).unwrap();
+        p.x().real().to_big_endian(&mut buf[32..64]).unwrap();
+        p.y().imaginary().to_big_endian(&mut buf[64..96]).unwrap();
+        p.y().real().to_big_endian(&mut buf[96..128]).unwrap();
+    }
+
+    buf
+}
+
+#[test]
+fn test_ecadd_against_bn() {
+    let mut rng = make_rng();
+    for _ in 0..NUM_TESTS {
+        let p = G1::one() * random_fr(&mut rng);
+        let q = G1::one() * random_fr(&mut rng);
+
+        let mut input = encode_g1(p);
+        input.extend(encode_g1(q));
+
+        assert_eq!(&EIP196Executor::ecadd(&input).unwrap()[..], &encode_g1(p + q)[..]);
+        assert_eq!(&EIP196Executor::ecadd(&input[..64]).unwrap()[..], &encode_g1(p)[..]);
+    }
+}
+
+#[test]
+fn test_ecmul_against_bn() {
+    let mut rng = make_rng();
+    for _ in 0..NUM_TESTS {
+        let p = G1::one() * random_fr(&mut rng);
+        let s = random_fr(&mut rng);
+
+        let mut input = encode_g1(p);
+        input.extend(encode_fr(s));
+
+        assert_eq!(&EIP196Executor::ecmul(&input).unwrap()[..], &encode_g1(p * s)[..]);
+    }
+}
+
+#[test]
+fn test_ecpairing_against_bn() {
+    let mut rng = make_rng();
+    for num_pairs in 1..5 {
+        let mut pairs = vec![];
+        let mut input = vec![];
+        for _ in 0..num_pairs {
+            let p = G1::one() * random_fr(&mut rng);
+            let q = G2::one() * random_fr(&mut rng);
+            input.extend(encode_g1(p));
+            input.extend(encode_g2(q));
+            pairs.push((p, q));
+        }
+
+        let expected = pairing_batch(&pairs) == Gt::one();
+        assert_eq!(EIP196Executor::ecpairing(&input).unwrap()[31] == 1, expected);
+
+        // e(a*P, b*Q) * e(-(a*b)*P, Q) == 1
+        let a = random_fr(&mut rng);
+        let b = random_fr(&mut rng);
+        let pairs = vec![(G1::one() * a, G2::one() * b), (-(G1::one() * (a * b)), G2::one())];
+        let mut input = vec![];
+        for (p, q) in pairs.iter() {
+            input.extend(encode_g1(*p));
+            input.extend(encode_g2(*q));
+        }
+        assert!(pairing_batch(&pairs) == Gt::one());
+        assert_eq!(EIP196Executor::ecpairing(&input).unwrap()[31], 1);
+    }
+}
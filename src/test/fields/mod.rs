@@ -98,3 +98,139 @@ fn test_fp3_inversion() {
     assert_eq!(maybe_one, Fp3::one(&extension_3));
 }
 
+
+mod bls24_towers {
+    use crate::field::{U320Repr, PrimeField};
+    use crate::fp::Fp;
+    use crate::traits::{FieldElement, ZeroAndOne};
+    use crate::extension_towers::fp2::Fp2;
+    use crate::extension_towers::fp4_as_2_over_2::Fp4;
+    use crate::extension_towers::fp12_as_3_over_2_over_2::Fp12;
+    use crate::extension_towers::fp24_as_2_over_3_over_2_over_2::Fp24;
+    use crate::engines::bls24_315::*;
+
+    type Fp4Element = Fp4<'static, U320Repr, PrimeField<U320Repr>>;
+    type Fp12Element = Fp12<'static, U320Repr, PrimeField<U320Repr>>;
+    type Fp24Element = Fp24<'static, U320Repr, PrimeField<U320Repr>>;
+
+    const EXPONENT: [u64; 4] = [0x9c4e1a7b3d5f2e61, 0x5a7c3e9b1d4f6a28, 0xe3b5d7f9a1c2e4b6, 0x4d2f6b8a9c1e3f57];
+
+    fn make_fp4(seed: u64) -> Fp4Element {
+        let mut c0 = Fp2::zero(&BLS24_315_EXTENSION_2_FIELD);
+        c0.c0 = Fp::from_repr(&BLS24_315_FIELD, U320Repr::from(seed)).unwrap();
+        c0.c1 = Fp::from_repr(&BLS24_315_FIELD, U320Repr::from(seed + 1)).unwrap();
+        let mut c1 = Fp2::zero(&BLS24_315_EXTENSION_2_FIELD);
+        c1.c0 = Fp::from_repr(&BLS24_315_FIELD, U320Repr::from(seed + 2)).unwrap();
+        c1.c1 = Fp::from_repr(&BLS24_315_FIELD, U320Repr::from(seed + 3)).unwrap();
+
+        let mut el = Fp4::zero(&BLS24_315_EXTENSION_4_FIELD);
+        el.c0 = c0;
+        el.c1 = c1;
+
+        el
+    }
+
+    // small coefficients raised to a large power, so that no coefficient is special
+    fn make_fp12(seed: u64) -> Fp12Element {
+        let mut el = Fp12::zero(&BLS24_315_EXTENSION_12_FIELD);
+        el.c0 = make_fp4(seed);
+        el.c1 = make_fp4(seed + 4);
+        el.c2 = make_fp4(seed + 8);
+
+        el.pow(&EXPONENT)
+    }
+
+    fn make_fp24(seed: u64) -> Fp24Element {
+        let mut el = Fp24::zero(&BLS24_315_EXTENSION_24_FIELD);
+        el.c0 = make_fp12(seed);
+        el.c1 = make_fp12(seed + 12);
+
+        el.pow(&EXPONENT)
+    }
+
+    #[test]
+    fn test_fp12_as_3_over_2_over_2_arithmetic() {
+        let a = make_fp12(1);
+        let b = make_fp12(100);
+
+        let mut square = a.clone();
+        square.square();
+        let mut product = a.clone();
+        product.mul_assign(&a);
+        assert_eq!(square, product);
+
+        let mut ab = a.clone();
+        ab.mul_assign(&b);
+        let mut ba = b.clone();
+        ba.mul_assign(&a);
+        assert_eq!(ab, ba);
+
+        let mut maybe_one = a.inverse().unwrap();
+        maybe_one.mul_assign(&a);
+        assert_eq!(maybe_one, Fp12::one(&BLS24_315_EXTENSION_12_FIELD));
+        assert!(Fp12::zero(&BLS24_315_EXTENSION_12_FIELD).inverse().is_none());
+
+        let mut frobenius = a.clone();
+        frobenius.frobenius_map(1);
+        assert_eq!(frobenius, a.pow(&BLS24_315_MODULUS_UINT));
+
+        // Frobenius has order 12 and every power matches the stored coefficients
+        let mut repeated = a.clone();
+        for power in 1..12 {
+            repeated.frobenius_map(1);
+            let mut direct = a.clone();
+            direct.frobenius_map(power);
+            assert_eq!(repeated, direct);
+            assert!(repeated != a);
+        }
+        repeated.frobenius_map(1);
+        assert_eq!(repeated, a);
+    }
+
+    #[test]
+    fn test_fp24_as_2_over_3_over_2_over_2_arithmetic() {
+        let a = make_fp24(1);
+        let b = make_fp24(100);
+
+        let mut square = a.clone();
+        square.square();
+        let mut product = a.clone();
+        product.mul_assign(&a);
+        assert_eq!(square, product);
+
+        let mut ab = a.clone();
+        ab.mul_assign(&b);
+        let mut ba = b.clone();
+        ba.mul_assign(&a);
+        assert_eq!(ab, ba);
+
+        let mut maybe_one = a.inverse().unwrap();
+        maybe_one.mul_assign(&a);
+        assert_eq!(maybe_one, Fp24::one(&BLS24_315_EXTENSION_24_FIELD));
+        assert!(Fp24::zero(&BLS24_315_EXTENSION_24_FIELD).inverse().is_none());
+
+        let mut frobenius = a.clone();
+        frobenius.frobenius_map(1);
+        assert_eq!(frobenius, a.pow(&BLS24_315_MODULUS_UINT));
+
+        // coefficients are stored for powers below 12, the 12th power is the conjugation
+        let mut repeated = a.clone();
+        for power in 1..12 {
+            repeated.frobenius_map(1);
+            let mut direct = a.clone();
+            direct.frobenius_map(power);
+            assert_eq!(repeated, direct);
+        }
+        repeated.frobenius_map(1);
+        let mut conjugate = a.clone();
+        conjugate.conjugate();
+        assert_eq!(repeated, conjugate);
+        assert!(repeated != a);
+
+        // Frobenius has order 24
+        for _ in 0..12 {
+            repeated.frobenius_map(1);
+        }
+        assert_eq!(repeated, a);
+    }
+}
@@ -9,6 +9,9 @@ pub(crate) mod arithmetic_tests;
 mod fields;
 // mod fuzzing;
 mod gas_meter;
+mod abi_encoder;
+#[cfg(feature = "eip_196")]
+mod eip196;
 
 use num_bigint::BigUint;
 use num_traits::Zero;
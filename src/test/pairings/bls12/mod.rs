@@ -519,6 +519,55 @@ fn test_call_public_api_on_bls12_381() {
     assert!(result[0] == 1);
 }
 
+#[test]
+fn test_call_public_api_for_raw_result_on_bls12_381() {
+    use crate::public_interface::PairingApi;
+    use crate::public_interface::decode_fp::decode_fp12;
+    use crate::engines::bls12_381::*;
+    use crate::traits::ZeroAndOne;
+    use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+    use crate::pairings::PairingEngine;
+
+    let calldata = assemble_bls12_381(4);
+    let result = crate::public_interface::PublicPairingApi::pair_raw(&calldata).unwrap();
+    assert!(result.len() == 12 * 48);
+    let (result, rest) = decode_fp12(&result, 48, &BLS12_381_EXTENSION_12_FIELD).unwrap();
+    assert!(rest.len() == 0);
+    assert!(result == Fp12::one(&BLS12_381_EXTENSION_12_FIELD));
+
+    let calldata = assemble_bls12_381(1);
+    let result = crate::public_interface::PublicPairingApi::pair_raw(&calldata).unwrap();
+    let (result, _) = decode_fp12(&result, 48, &BLS12_381_EXTENSION_12_FIELD).unwrap();
+    let expected = BLS12_381_PAIRING_ENGINE.pair(&[BLS12_381_G1_GENERATOR], &[BLS12_381_G2_GENERATOR]).unwrap();
+    assert!(result == expected);
+}
+
+#[test]
+fn test_decode_raw_result_on_bls12_381() {
+    use crate::public_interface::{PairingApi, PublicPairingApi, ErrorCode};
+
+    let calldata = assemble_bls12_381(4);
+    let raw = PublicPairingApi::pair_raw(&calldata).unwrap();
+    let decoded = PublicPairingApi::decode_raw_output(&calldata, &raw).unwrap();
+    assert_eq!(decoded.extension_degree(), 12);
+    assert!(decoded.is_one());
+    assert_eq!(decoded.to_bytes(), raw);
+
+    let calldata = assemble_bls12_381(1);
+    let raw = PublicPairingApi::pair_raw(&calldata).unwrap();
+    let decoded = PublicPairingApi::decode_raw_output(&calldata, &raw).unwrap();
+    assert!(!decoded.is_one());
+    assert_eq!(&decoded.coefficients()[11][..], &raw[11 * 48..]);
+
+    assert!(PublicPairingApi::decode_raw_output(&calldata, &raw[1..]).is_err());
+    let mut invalid = raw.clone();
+    for b in invalid[48..96].iter_mut() {
+        *b = 0xff;
+    }
+    let err = PublicPairingApi::decode_raw_output(&calldata, &invalid).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::NonCanonicalFieldElement);
+}
+
 #[test]
 fn test_call_public_api_on_bls12_377() {
     let calldata = assemble_bls12_377(4);
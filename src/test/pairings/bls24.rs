@@ -0,0 +1,47 @@
+use crate::engines::bls24_315::*;
+use crate::public_interface::abi_encoder::*;
+use crate::public_interface::curve_builder::TwistType;
+use crate::public_interface::decode_fp::{serialize_fp_fixed_len, serialize_fp4_fixed_len};
+use crate::traits::FieldElement;
+
+const BLS24_315_MODULUS_LENGTH: usize = 40;
+
+/// Input of `PublicPairingApi::pair` for BLS24-315 with pairs (P, Q) and (-P, Q) repeated,
+/// the number of pairs must be even
+pub(crate) fn assemble_bls24_315(num_point_pairs: usize) -> Vec<u8> {
+    assert!(num_point_pairs % 2 == 0);
+
+    let limbs_to_bytes = |limbs: &[u64]| limbs.iter().rev().flat_map(|l| l.to_be_bytes().to_vec()).collect::<Vec<u8>>();
+    let fp_to_bytes = |el| serialize_fp_fixed_len(BLS24_315_MODULUS_LENGTH, el).unwrap();
+    let fp4_to_coeffs = |el| {
+        serialize_fp4_fixed_len(BLS24_315_MODULUS_LENGTH, el).unwrap()
+            .chunks(BLS24_315_MODULUS_LENGTH)
+            .map(|c| c.to_vec())
+            .collect::<Vec<_>>()
+    };
+
+    let curve = G1Curve::new(&limbs_to_bytes(&BLS24_315_MODULUS.0), &[], &[1u8], &limbs_to_bytes(&BLS24_315_SUBGROUP_ORDER));
+    let params = Bls24PairingParameters {
+        fp_non_residue: fp_to_bytes(&BLS24_315_EXTENSION_2_FIELD.non_residue),
+        fp4_non_residue: fp4_to_coeffs(&BLS24_315_EXTENSION_12_FIELD.non_residue),
+        twist_type: TwistType::D,
+        x: limbs_to_bytes(&BLS24_315_X),
+        x_is_negative: BLS24_315_X_IS_NEGATIVE,
+    };
+
+    let (p_x, p_y) = BLS24_315_G1_GENERATOR.into_xy();
+    let mut p_y_negated = p_y.clone();
+    p_y_negated.negate();
+    let p = G1Point::new(&fp_to_bytes(&p_x), &fp_to_bytes(&p_y));
+    let p_negated = G1Point::new(&fp_to_bytes(&p_x), &fp_to_bytes(&p_y_negated));
+    let (q_x, q_y) = BLS24_315_G2_GENERATOR.into_xy();
+    let q = G2Point { x: fp4_to_coeffs(&q_x), y: fp4_to_coeffs(&q_y) };
+
+    let mut builder = AbiEncoder::bls24_pairing(&curve, &params);
+    for _ in 0..(num_point_pairs / 2) {
+        builder = builder.with_pair(&p, &q).with_pair(&p_negated, &q);
+    }
+
+    // first byte is the operation
+    builder.encode_with_operation().unwrap()[1..].to_vec()
+}
@@ -1,4 +1,5 @@
 pub(crate) mod bls12;
+pub(crate) mod bls24;
 pub(crate) mod bn;
 pub(crate) mod mnt4;
 pub(crate) mod mnt6;
@@ -0,0 +1,165 @@
+//! BLS12-381 points are (de)serialized in the ZCash format, same as used by BLS signatures
+
+use crate::field::{U384Repr, PrimeField};
+use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+use crate::engines::bls12_381::*;
+use crate::public_interface::zcash_encoding::{self, G1, G2};
+use crate::public_interface::zcash_encoding::{COMPRESSED_G1_BYTE_LENGTH, UNCOMPRESSED_G1_BYTE_LENGTH};
+use crate::public_interface::zcash_encoding::{COMPRESSED_G2_BYTE_LENGTH, UNCOMPRESSED_G2_BYTE_LENGTH};
+
+/// Target group coefficients are padded to 64 bytes, same as in EIP-2537
+pub const GT_COEFFICIENT_BYTE_LENGTH: usize = 64;
+
+impl_typed_curve_api!(
+    g1 = G1,
+    g2 = G2,
+    gt = Fp12<'static, U384Repr, PrimeField<U384Repr>>,
+    g1_generator = BLS12_381_G1_GENERATOR,
+    g2_generator = BLS12_381_G2_GENERATOR,
+    subgroup_order = BLS12_381_SUBGROUP_ORDER,
+    pairing_engine = BLS12_381_PAIRING_ENGINE,
+    prepared_g2 = crate::pairings::bls12::PreparedTwistPoint<'static, U384Repr, PrimeField<U384Repr>>,
+    fp12_extension = BLS12_381_EXTENSION_12_FIELD,
+    gt_coefficient_byte_length = GT_COEFFICIENT_BYTE_LENGTH
+);
+
+impl G1Affine {
+    pub fn to_compressed(&self) -> [u8; COMPRESSED_G1_BYTE_LENGTH] {
+        let encoding = zcash_encoding::serialize_g1(&self.0, true).expect("valid point is always serializable");
+        let mut bytes = [0u8; COMPRESSED_G1_BYTE_LENGTH];
+        bytes.copy_from_slice(&encoding);
+
+        bytes
+    }
+
+    pub fn to_uncompressed(&self) -> [u8; UNCOMPRESSED_G1_BYTE_LENGTH] {
+        let encoding = zcash_encoding::serialize_g1(&self.0, false).expect("valid point is always serializable");
+        let mut bytes = [0u8; UNCOMPRESSED_G1_BYTE_LENGTH];
+        bytes.copy_from_slice(&encoding);
+
+        bytes
+    }
+
+    /// Accepts both compressed and uncompressed encodings
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ApiError> {
+        Ok(G1Affine(zcash_encoding::deserialize_g1(bytes)?))
+    }
+}
+
+impl G2Affine {
+    pub fn to_compressed(&self) -> [u8; COMPRESSED_G2_BYTE_LENGTH] {
+        let encoding = zcash_encoding::serialize_g2(&self.0, true).expect("valid point is always serializable");
+        let mut bytes = [0u8; COMPRESSED_G2_BYTE_LENGTH];
+        bytes.copy_from_slice(&encoding);
+
+        bytes
+    }
+
+    pub fn to_uncompressed(&self) -> [u8; UNCOMPRESSED_G2_BYTE_LENGTH] {
+        let encoding = zcash_encoding::serialize_g2(&self.0, false).expect("valid point is always serializable");
+        let mut bytes = [0u8; UNCOMPRESSED_G2_BYTE_LENGTH];
+        bytes.copy_from_slice(&encoding);
+
+        bytes
+    }
+
+    /// Accepts both compressed and uncompressed encodings
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ApiError> {
+        Ok(G2Affine(zcash_encoding::deserialize_g2(bytes)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate hex;
+
+    use super::*;
+
+    const G1_GENERATOR_COMPRESSED: &str = "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+
+    #[test]
+    fn test_group_arithmetic() {
+        let g = G1Projective::generator();
+        let a = Scalar::from_u64(12345);
+        let b = Scalar::from_u64(67890);
+
+        assert_eq!(g.double(), g.clone() + &g);
+        assert_eq!(&g * a * b, &g * (a * b));
+        assert_eq!(&g * (a + b), &g * a + &g * b);
+        assert_eq!(&g * (a - b), &g * a - &g * b);
+        assert_eq!(&g * -a, -(&g * a));
+        assert!((g.clone() - &g).is_identity());
+
+        let h = G2Affine::generator();
+        let mut sum = G2Projective::identity();
+        sum += &h;
+        sum += &h;
+        assert_eq!(sum, &h * Scalar::from_u64(2));
+    }
+
+    #[test]
+    fn test_multiexp() {
+        let bases: Vec<_> = (1..5u64).map(|i| (G1Affine::generator() * Scalar::from_u64(i)).to_affine()).collect();
+        let scalars: Vec<_> = (10..14u64).map(Scalar::from_u64).collect();
+
+        let mut expected = G1Projective::identity();
+        for (base, scalar) in bases.iter().zip(scalars.iter()) {
+            expected += &(base * *scalar);
+        }
+        assert_eq!(G1Projective::multiexp(&bases, &scalars).unwrap(), expected);
+        assert!(G1Projective::multiexp(&bases, &scalars[1..]).is_err());
+    }
+
+    #[test]
+    fn test_pairing() {
+        let p = G1Affine::generator();
+        let q = G2Affine::generator();
+        let a = Scalar::from_u64(3);
+        let b = Scalar::from_u64(5);
+
+        let e = pairing(&p, &q);
+        assert!(!e.is_identity());
+        assert_eq!(pairing(&(&p * a).to_affine(), &(&q * b).to_affine()), e.pow(&(a * b)));
+        assert!((e.clone() * e.inverse()).is_identity());
+        assert!(multi_pairing(&[(p.clone(), q.clone()), (-p, q)]).is_identity());
+    }
+
+    #[test]
+    fn test_serialization() {
+        let g = G1Affine::generator();
+        assert_eq!(hex::encode(&g.to_compressed()[..]), G1_GENERATOR_COMPRESSED);
+        assert_eq!(G1Affine::from_bytes(&g.to_uncompressed()).unwrap(), g);
+
+        let h = (G2Affine::generator() * Scalar::from_u64(7)).to_affine();
+        assert_eq!(G2Affine::from_bytes(&h.to_compressed()).unwrap(), h);
+        assert_eq!(G2Affine::from_bytes(&h.to_uncompressed()).unwrap(), h);
+
+        let s = Scalar::from_u64(0x0102030405060708);
+        assert_eq!(Scalar::from_bytes(&s.to_bytes()).unwrap(), s);
+
+        let mut order = [0u8; 32];
+        for (chunk, limb) in order.chunks_mut(8).zip(BLS12_381_SUBGROUP_ORDER.iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        assert!(Scalar::from_bytes(&order).is_err());
+        assert_eq!(Scalar::from_bytes_reduced(&order).unwrap(), Scalar::zero());
+    }
+
+    #[test]
+    fn test_gt_serialization() {
+        let e = pairing(&G1Affine::generator(), &G2Affine::generator()).pow(&Scalar::from_u64(5));
+        let encoding = e.to_bytes();
+        assert_eq!(encoding.len(), GT_BYTE_LENGTH);
+        assert_eq!(Gt::from_bytes(&encoding).unwrap(), e);
+
+        let mut identity = [0u8; GT_BYTE_LENGTH];
+        identity[GT_COEFFICIENT_BYTE_LENGTH - 1] = 1;
+        assert_eq!(&Gt::identity().to_bytes()[..], &identity[..]);
+        assert!(Gt::from_bytes(&identity).unwrap().is_identity());
+
+        assert_eq!(Gt::from_bytes(&encoding[1..]).unwrap_err().code(), crate::errors::ErrorCode::InvalidLength);
+        let mut not_in_gt = identity;
+        not_in_gt[GT_COEFFICIENT_BYTE_LENGTH - 1] = 2;
+        assert_eq!(Gt::from_bytes(&not_in_gt).unwrap_err().code(), crate::errors::ErrorCode::PointNotInSubgroup);
+    }
+}
@@ -0,0 +1,150 @@
+//! BN254 points are (de)serialized as in EIP-196 and EIP-197: big endian 32 byte coordinates,
+//! Fp2 elements are encoded as c1 || c0 and the point at infinity is encoded as all zeroes
+
+use crate::field::{U256Repr, PrimeField};
+use crate::weierstrass::curve::CurvePoint;
+use crate::weierstrass::{CurveOverFpParameters, CurveOverFp2Parameters};
+use crate::extension_towers::fp2::Fp2;
+use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+use crate::engines::bn254::*;
+use crate::public_interface::decode_fp::{decode_fp, serialize_fp_fixed_len};
+
+type G1 = CurvePoint<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>>;
+type G2 = CurvePoint<'static, CurveOverFp2Parameters<'static, U256Repr, PrimeField<U256Repr>>>;
+
+pub const FP_BYTE_LENGTH: usize = 32;
+pub const G1_BYTE_LENGTH: usize = FP_BYTE_LENGTH * 2;
+pub const G2_BYTE_LENGTH: usize = FP_BYTE_LENGTH * 4;
+
+impl_typed_curve_api!(
+    g1 = G1,
+    g2 = G2,
+    gt = Fp12<'static, U256Repr, PrimeField<U256Repr>>,
+    g1_generator = BN254_G1_GENERATOR,
+    g2_generator = BN254_G2_GENERATOR,
+    subgroup_order = BN254_SUBGROUP_ORDER,
+    pairing_engine = BN254_PAIRING_ENGINE,
+    prepared_g2 = crate::pairings::bn::PreparedTwistPoint<'static, U256Repr, PrimeField<U256Repr>>,
+    fp12_extension = BN254_EXTENSION_12_FIELD,
+    gt_coefficient_byte_length = FP_BYTE_LENGTH
+);
+
+impl G1Affine {
+    pub fn to_uncompressed(&self) -> [u8; G1_BYTE_LENGTH] {
+        let (x, y) = self.0.into_xy();
+        let mut bytes = [0u8; G1_BYTE_LENGTH];
+        for (chunk, el) in bytes.chunks_mut(FP_BYTE_LENGTH).zip([x, y].iter()) {
+            let encoding = serialize_fp_fixed_len(FP_BYTE_LENGTH, el).expect("Fp element always fits into 32 bytes");
+            chunk.copy_from_slice(&encoding);
+        }
+
+        bytes
+    }
+
+    /// Decodes x || y and checks that the point is on curve
+    pub fn from_uncompressed(bytes: &[u8]) -> Result<Self, ApiError> {
+        if bytes.len() != G1_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid G1 point encoding length".to_owned()));
+        }
+        let (x, rest) = decode_fp(bytes, FP_BYTE_LENGTH, &BN254_FIELD)?;
+        let (y, _) = decode_fp(rest, FP_BYTE_LENGTH, &BN254_FIELD)?;
+
+        Self::from_point(CurvePoint::point_from_xy(&BN254_G1_CURVE, x, y))
+    }
+}
+
+impl G2Affine {
+    pub fn to_uncompressed(&self) -> [u8; G2_BYTE_LENGTH] {
+        let (x, y) = self.0.into_xy();
+        let mut bytes = [0u8; G2_BYTE_LENGTH];
+        let coordinates = [x.c1, x.c0, y.c1, y.c0];
+        for (chunk, el) in bytes.chunks_mut(FP_BYTE_LENGTH).zip(coordinates.iter()) {
+            let encoding = serialize_fp_fixed_len(FP_BYTE_LENGTH, el).expect("Fp element always fits into 32 bytes");
+            chunk.copy_from_slice(&encoding);
+        }
+
+        bytes
+    }
+
+    /// Decodes x || y with coordinates encoded as c1 || c0, checks that the point is on curve
+    /// and in the main subgroup
+    pub fn from_uncompressed(bytes: &[u8]) -> Result<Self, ApiError> {
+        if bytes.len() != G2_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid G2 point encoding length".to_owned()));
+        }
+        let (x, rest) = decode_fp2_c1_first(bytes)?;
+        let (y, _) = decode_fp2_c1_first(rest)?;
+
+        Self::from_point(CurvePoint::point_from_xy(&BN254_G2_CURVE, x, y))
+    }
+}
+
+fn decode_fp2_c1_first(bytes: &[u8]) -> Result<(Fp2<'static, U256Repr, PrimeField<U256Repr>>, &[u8]), ApiError> {
+    let (c1, rest) = decode_fp(bytes, FP_BYTE_LENGTH, &BN254_FIELD)?;
+    let (c0, rest) = decode_fp(rest, FP_BYTE_LENGTH, &BN254_FIELD)?;
+
+    let mut el = Fp2::zero(&BN254_EXTENSION_2_FIELD);
+    el.c0 = c0;
+    el.c1 = c1;
+
+    Ok((el, rest))
+}
+
+#[cfg(test)]
+mod test {
+    extern crate hex;
+
+    use super::*;
+
+    #[test]
+    fn test_generator_encoding() {
+        let mut expected = [0u8; G1_BYTE_LENGTH];
+        expected[31] = 1;
+        expected[63] = 2;
+
+        let g = G1Affine::generator();
+        assert_eq!(&g.to_uncompressed()[..], &expected[..]);
+        assert_eq!(G1Affine::from_uncompressed(&expected).unwrap(), g);
+        assert!(G1Affine::from_uncompressed(&[0u8; G1_BYTE_LENGTH]).unwrap().is_identity());
+
+        // (1, 3) is not on curve
+        expected[63] = 3;
+        assert!(G1Affine::from_uncompressed(&expected).is_err());
+
+        // EIP-197 encodes x of the G2 generator as c1 || c0
+        let encoding = G2Affine::generator().to_uncompressed();
+        assert_eq!(hex::encode(&encoding[..4]), "198e9393");
+        assert_eq!(hex::encode(&encoding[32..36]), "1800deef");
+
+        let h = (G2Affine::generator() * Scalar::from_u64(11)).to_affine();
+        assert_eq!(G2Affine::from_uncompressed(&h.to_uncompressed()).unwrap(), h);
+    }
+
+    #[test]
+    fn test_pairing() {
+        let p = G1Affine::generator();
+        let q = G2Affine::generator();
+        let a = Scalar::from_u64(2);
+        let b = Scalar::from_u64(3);
+
+        let e = pairing(&p, &q);
+        assert_eq!(pairing(&(&p * a).to_affine(), &(&q * b).to_affine()), e.pow(&(a * b)));
+        assert!(multi_pairing(&[(p.clone(), q.clone()), (-p, q)]).is_identity());
+        assert!(multi_pairing(&[]).is_identity());
+    }
+
+    #[test]
+    fn test_gt_serialization() {
+        let e = pairing(&G1Affine::generator(), &G2Affine::generator());
+        let encoding = e.to_bytes();
+        assert_eq!(encoding.len(), 12 * FP_BYTE_LENGTH);
+        assert_eq!(Gt::from_bytes(&encoding).unwrap(), e);
+        assert_eq!(Gt::from_bytes(&e.inverse().to_bytes()).unwrap(), e.inverse());
+
+        let mut identity = [0u8; GT_BYTE_LENGTH];
+        identity[FP_BYTE_LENGTH - 1] = 1;
+        assert!(Gt::from_bytes(&identity).unwrap().is_identity());
+        identity[FP_BYTE_LENGTH - 1] = 2;
+        assert!(Gt::from_bytes(&identity).is_err());
+    }
+}
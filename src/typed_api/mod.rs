@@ -0,0 +1,598 @@
+//! Typed API over the precompiled curves. Points, scalars and pairing results are owned values
+//! with arithmetic operators, so Rust callers don't have to go through the byte ABI.
+//! Affine points are always on the curve and in the main subgroup, projective points are
+//! results of arithmetic over them
+
+pub use crate::errors::ApiError;
+#[cfg(feature = "batch_verification")]
+pub use crate::pairings::batch::BatchVerificationResult;
+
+pub(crate) const SCALAR_BYTE_LENGTH: usize = 32;
+
+macro_rules! impl_typed_curve_api {
+    (
+        g1 = $g1:ty,
+        g2 = $g2:ty,
+        gt = $gt:ty,
+        g1_generator = $g1_generator:expr,
+        g2_generator = $g2_generator:expr,
+        subgroup_order = $subgroup_order:expr,
+        pairing_engine = $pairing_engine:expr,
+        prepared_g2 = $prepared_g2:ty,
+        fp12_extension = $fp12_extension:expr,
+        gt_coefficient_byte_length = $gt_coefficient_byte_length:expr
+    ) => {
+        use crate::weierstrass::Group;
+        use crate::traits::{FieldElement, ZeroAndOne};
+        use crate::pairings::PairingEngine;
+        use crate::integers::MaxGroupSizeUint;
+        use crate::errors::{ApiError, ErrorCode};
+        use crate::typed_api::SCALAR_BYTE_LENGTH;
+
+        /// Length of the target group element encoding, twelve big endian Fp coefficients
+        pub const GT_BYTE_LENGTH: usize = 12 * $gt_coefficient_byte_length;
+
+        /// Element of the scalar field, stored as little endian limbs reduced modulo the group order
+        #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+        pub struct Scalar([u64; 4]);
+
+        impl Scalar {
+            pub fn zero() -> Self {
+                Scalar([0u64; 4])
+            }
+
+            pub fn one() -> Self {
+                Self::from_u64(1)
+            }
+
+            pub fn from_u64(value: u64) -> Self {
+                Self::from_uint(MaxGroupSizeUint::from(value))
+            }
+
+            /// Parses a 32 byte big endian encoding, value must be less than the group order
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self, ApiError> {
+                if bytes.len() != SCALAR_BYTE_LENGTH {
+                    return Err(ApiError::InputError("invalid scalar length".to_owned()));
+                }
+                let value = MaxGroupSizeUint::from_big_endian(bytes);
+                if value >= Self::order() {
+                    return Err(ApiError::InputError("scalar is not less than the group order".to_owned()));
+                }
+
+                Ok(Self::from_uint(value))
+            }
+
+            /// Interprets up to 64 big endian bytes as an integer and reduces it modulo the group order
+            pub fn from_bytes_reduced(bytes: &[u8]) -> Result<Self, ApiError> {
+                if bytes.len() > 2 * SCALAR_BYTE_LENGTH {
+                    return Err(ApiError::InputError("scalar encoding is too long".to_owned()));
+                }
+                let value = MaxGroupSizeUint::from_big_endian(bytes);
+
+                Ok(Self::from_uint(value % Self::order()))
+            }
+
+            pub fn to_bytes(&self) -> [u8; SCALAR_BYTE_LENGTH] {
+                let mut bytes = [0u8; SCALAR_BYTE_LENGTH];
+                for (chunk, limb) in bytes.chunks_mut(8).zip(self.0.iter().rev()) {
+                    <byteorder::BigEndian as byteorder::ByteOrder>::write_u64(chunk, *limb);
+                }
+
+                bytes
+            }
+
+            pub fn is_zero(&self) -> bool {
+                self.0 == [0u64; 4]
+            }
+
+            fn order() -> MaxGroupSizeUint {
+                MaxGroupSizeUint::from(&$subgroup_order[..])
+            }
+
+            fn to_uint(&self) -> MaxGroupSizeUint {
+                MaxGroupSizeUint::from(&self.0[..])
+            }
+
+            fn from_uint(value: MaxGroupSizeUint) -> Self {
+                debug_assert!(value < Self::order());
+                let mut limbs = [0u64; 4];
+                limbs.copy_from_slice(&value.as_ref()[..4]);
+
+                Scalar(limbs)
+            }
+        }
+
+        impl std::ops::Add for Scalar {
+            type Output = Scalar;
+
+            fn add(self, other: Scalar) -> Scalar {
+                Scalar::from_uint((self.to_uint() + other.to_uint()) % Scalar::order())
+            }
+        }
+
+        impl std::ops::Sub for Scalar {
+            type Output = Scalar;
+
+            fn sub(self, other: Scalar) -> Scalar {
+                self + (-other)
+            }
+        }
+
+        impl std::ops::Mul for Scalar {
+            type Output = Scalar;
+
+            fn mul(self, other: Scalar) -> Scalar {
+                Scalar::from_uint((self.to_uint() * other.to_uint()) % Scalar::order())
+            }
+        }
+
+        impl std::ops::Neg for Scalar {
+            type Output = Scalar;
+
+            fn neg(self) -> Scalar {
+                if self.is_zero() {
+                    return self;
+                }
+
+                Scalar::from_uint(Scalar::order() - self.to_uint())
+            }
+        }
+
+        impl_typed_curve_api!(@group G1Affine, G1Projective, $g1, $g1_generator);
+        impl_typed_curve_api!(@group G2Affine, G2Projective, $g2, $g2_generator);
+
+        /// Element of the target group, written multiplicatively
+        #[derive(Clone, PartialEq)]
+        pub struct Gt($gt);
+
+        impl Gt {
+            pub fn identity() -> Self {
+                Gt(<$gt>::one(&$fp12_extension))
+            }
+
+            // caller must ensure that the element is in the target group
+            #[cfg(test)]
+            pub(crate) fn from_element_unchecked(element: $gt) -> Self {
+                Gt(element)
+            }
+
+            fn is_in_target_group(element: &$gt) -> bool {
+                element.pow(&$subgroup_order[..]) == <$gt>::one(&$fp12_extension)
+            }
+
+            pub fn is_identity(&self) -> bool {
+                *self == Self::identity()
+            }
+
+            /// Encodes coefficients in the same order as `pair_raw`: c0.c0.c0, c0.c0.c1, c0.c1.c0, ..., c1.c2.c1
+            pub fn to_bytes(&self) -> [u8; GT_BYTE_LENGTH] {
+                let encoding = crate::public_interface::decode_fp::serialize_fp12_fixed_len($gt_coefficient_byte_length, &self.0)
+                    .expect("Fp element always fits into the coefficient length");
+                let mut bytes = [0u8; GT_BYTE_LENGTH];
+                bytes.copy_from_slice(&encoding);
+
+                bytes
+            }
+
+            /// Decodes an encoding produced by `to_bytes` or `pair_raw`, the element must be in the target group
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self, ApiError> {
+                if bytes.len() != GT_BYTE_LENGTH {
+                    return Err(ApiError::coded_at(ErrorCode::InvalidLength, "invalid input length for GT element decoding", bytes));
+                }
+                let (element, _) = crate::public_interface::decode_fp::decode_fp12_oversized(bytes, $gt_coefficient_byte_length, &$fp12_extension)?;
+                if !Self::is_in_target_group(&element) {
+                    return Err(ApiError::coded_at(ErrorCode::PointNotInSubgroup, "element is not in the target group", bytes));
+                }
+
+                Ok(Gt(element))
+            }
+
+            pub fn pow(&self, exp: &Scalar) -> Self {
+                Gt(self.0.pow(&exp.0))
+            }
+
+            pub fn inverse(&self) -> Self {
+                let mut inverse = self.0.clone();
+                // elements of the cyclotomic subgroup are inverted by conjugation
+                inverse.conjugate();
+
+                Gt(inverse)
+            }
+        }
+
+        impl std::fmt::Debug for Gt {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Gt({})", self.0)
+            }
+        }
+
+        impl std::ops::Mul for Gt {
+            type Output = Gt;
+
+            fn mul(mut self, other: Gt) -> Gt {
+                self.0.mul_assign(&other.0);
+
+                self
+            }
+        }
+
+        impl<'a> std::ops::Mul<&'a Gt> for Gt {
+            type Output = Gt;
+
+            fn mul(mut self, other: &'a Gt) -> Gt {
+                self.0.mul_assign(&other.0);
+
+                self
+            }
+        }
+
+        pub fn pairing(p: &G1Affine, q: &G2Affine) -> Gt {
+            multi_pairing(&[(p.clone(), q.clone())])
+        }
+
+        /// Product of pairings of all the pairs, sharing one final exponentiation
+        pub fn multi_pairing(pairs: &[(G1Affine, G2Affine)]) -> Gt {
+            if pairs.is_empty() {
+                return Gt::identity();
+            }
+
+            let g1_points: Vec<_> = pairs.iter().map(|(p, _)| p.0.clone()).collect();
+            let g2_points: Vec<_> = pairs.iter().map(|(_, q)| q.0.clone()).collect();
+
+            let result = $pairing_engine.pair(&g1_points, &g2_points)
+                .expect("pairing of the same number of valid points is always defined");
+
+            Gt(result)
+        }
+
+        /// G2 point with precomputed Miller loop coefficients, so pairings with a fixed point can reuse them
+        #[derive(Clone)]
+        pub struct G2Prepared($prepared_g2);
+
+        impl<'a> From<&'a G2Affine> for G2Prepared {
+            fn from(q: &'a G2Affine) -> Self {
+                let prepared = $pairing_engine.prepare_g2(&q.0).expect("valid point can always be prepared");
+
+                G2Prepared(prepared)
+            }
+        }
+
+        impl From<G2Affine> for G2Prepared {
+            fn from(q: G2Affine) -> Self {
+                G2Prepared::from(&q)
+            }
+        }
+
+        /// Output of the Miller loop before the final exponentiation. Outputs of separate Miller loops
+        /// can be multiplied together and then finalized once
+        #[derive(Clone, PartialEq)]
+        pub struct MillerLoopResult($gt);
+
+        impl MillerLoopResult {
+            pub fn final_exponentiation(&self) -> Gt {
+                let result = $pairing_engine.final_exponentiation(&self.0)
+                    .expect("Miller loop output is never zero");
+
+                Gt(result)
+            }
+        }
+
+        impl std::fmt::Debug for MillerLoopResult {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "MillerLoopResult({})", self.0)
+            }
+        }
+
+        impl std::ops::Mul for MillerLoopResult {
+            type Output = MillerLoopResult;
+
+            fn mul(mut self, other: MillerLoopResult) -> MillerLoopResult {
+                self.0.mul_assign(&other.0);
+
+                self
+            }
+        }
+
+        impl<'a> std::ops::Mul<&'a MillerLoopResult> for MillerLoopResult {
+            type Output = MillerLoopResult;
+
+            fn mul(mut self, other: &'a MillerLoopResult) -> MillerLoopResult {
+                self.0.mul_assign(&other.0);
+
+                self
+            }
+        }
+
+        /// Multi-Miller loop over all the pairs, `multi_pairing` is the same as this followed
+        /// by the final exponentiation
+        pub fn miller_loop(pairs: &[(&G1Affine, &G2Prepared)]) -> MillerLoopResult {
+            let g1_points: Vec<_> = pairs.iter().map(|(p, _)| p.0.clone()).collect();
+            let prepared: Vec<_> = pairs.iter().map(|(_, q)| q.0.clone()).collect();
+
+            let result = $pairing_engine.miller_loop(&g1_points, &prepared)
+                .expect("Miller loop of the same number of points is always defined");
+
+            MillerLoopResult(result)
+        }
+
+        /// Same as `multi_pairing`, but with G2 points prepared in advance
+        pub fn multi_pairing_prepared(pairs: &[(&G1Affine, &G2Prepared)]) -> Gt {
+            if pairs.is_empty() {
+                return Gt::identity();
+            }
+
+            let g1_points: Vec<_> = pairs.iter().map(|(p, _)| p.0.clone()).collect();
+            let prepared: Vec<_> = pairs.iter().map(|(_, q)| q.0.clone()).collect();
+
+            let result = $pairing_engine.pair_prepared(&g1_points, &prepared)
+                .expect("pairing of the same number of valid points is always defined");
+
+            Gt(result)
+        }
+
+        /// Checks every equation `e(P_0, Q_0) * ... * e(P_n, Q_n) == 1` from the list at once and reports
+        /// indexes of ones that do not hold. Random coefficients are taken from the OS random number
+        /// generator, so result for a set with a failing equation is wrong with probability of about 2^-128
+        #[cfg(feature = "batch_verification")]
+        pub fn batch_verify(equations: &[Vec<(G1Affine, G2Affine)>]) -> Result<crate::typed_api::BatchVerificationResult, ApiError> {
+            let equations: Vec<_> = equations.iter().map(|pairs| {
+                crate::pairings::batch::PairingEquation {
+                    g1_points: pairs.iter().map(|(p, _)| p.0.clone()).collect(),
+                    g2_points: pairs.iter().map(|(_, q)| q.0.clone()).collect(),
+                }
+            }).collect();
+
+            $pairing_engine.batch_verify(&equations)
+        }
+    };
+
+    (@group $affine:ident, $projective:ident, $point:ty, $generator:expr) => {
+        /// Normalized point in the main subgroup
+        #[derive(Clone)]
+        pub struct $affine($point);
+
+        /// Point in the main subgroup in Jacobian coordinates
+        #[derive(Clone)]
+        pub struct $projective($point);
+
+        impl $affine {
+            pub fn generator() -> Self {
+                $affine($generator)
+            }
+
+            pub fn identity() -> Self {
+                $affine(<$point>::zero($generator.curve))
+            }
+
+            pub fn is_identity(&self) -> bool {
+                self.0.is_zero()
+            }
+
+            pub fn to_projective(&self) -> $projective {
+                $projective(self.0.clone())
+            }
+
+            // Accepts only points that are on the curve and in the main subgroup
+            fn from_point(mut point: $point) -> Result<Self, ApiError> {
+                point.normalize();
+                if !point.is_on_curve() {
+                    return Err(ApiError::InputError("point is not on curve".to_owned()));
+                }
+                if !point.check_correct_subgroup() {
+                    return Err(ApiError::InputError("point is not in the main subgroup".to_owned()));
+                }
+
+                Ok($affine(point))
+            }
+        }
+
+        impl $projective {
+            pub fn generator() -> Self {
+                $projective($generator)
+            }
+
+            pub fn identity() -> Self {
+                $projective(<$point>::zero($generator.curve))
+            }
+
+            pub fn is_identity(&self) -> bool {
+                self.0.is_zero()
+            }
+
+            pub fn double(&self) -> Self {
+                let mut result = self.0.clone();
+                result.double();
+
+                $projective(result)
+            }
+
+            pub fn to_affine(&self) -> $affine {
+                let mut point = self.0.clone();
+                point.normalize();
+
+                $affine(point)
+            }
+
+            /// Computes sum of bases[i] * scalars[i]
+            pub fn multiexp(bases: &[$affine], scalars: &[Scalar]) -> Result<Self, ApiError> {
+                if bases.len() != scalars.len() {
+                    return Err(ApiError::InputError("number of bases and scalars must be equal".to_owned()));
+                }
+                if bases.is_empty() {
+                    return Ok(Self::identity());
+                }
+
+                let points: Vec<_> = bases.iter().map(|b| b.0.clone()).collect();
+                let scalars: Vec<_> = scalars.iter().map(|s| s.to_uint()).collect();
+
+                Ok($projective(crate::multiexp::peppinger(&points, scalars)))
+            }
+        }
+
+        impl PartialEq for $affine {
+            fn eq(&self, other: &Self) -> bool {
+                self.0.into_xy() == other.0.into_xy()
+            }
+        }
+
+        impl Eq for $affine {}
+
+        impl PartialEq for $projective {
+            fn eq(&self, other: &Self) -> bool {
+                self.0.into_xy() == other.0.into_xy()
+            }
+        }
+
+        impl Eq for $projective {}
+
+        impl std::fmt::Debug for $affine {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let (x, y) = self.0.into_xy();
+                write!(f, "{}(x = {}, y = {})", stringify!($affine), x, y)
+            }
+        }
+
+        impl std::fmt::Debug for $projective {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}({:?})", stringify!($projective), self.to_affine())
+            }
+        }
+
+        impl From<$affine> for $projective {
+            fn from(p: $affine) -> Self {
+                $projective(p.0)
+            }
+        }
+
+        impl From<$projective> for $affine {
+            fn from(p: $projective) -> Self {
+                p.to_affine()
+            }
+        }
+
+        impl<'a> std::ops::AddAssign<&'a $projective> for $projective {
+            fn add_assign(&mut self, other: &'a $projective) {
+                self.0.add_assign(&other.0);
+            }
+        }
+
+        impl<'a> std::ops::AddAssign<&'a $affine> for $projective {
+            fn add_assign(&mut self, other: &'a $affine) {
+                self.0.add_assign_mixed(&other.0);
+            }
+        }
+
+        impl<'a> std::ops::SubAssign<&'a $projective> for $projective {
+            fn sub_assign(&mut self, other: &'a $projective) {
+                self.0.sub_assign(&other.0);
+            }
+        }
+
+        impl<'a> std::ops::SubAssign<&'a $affine> for $projective {
+            fn sub_assign(&mut self, other: &'a $affine) {
+                let mut negated = other.0.clone();
+                negated.negate();
+                self.0.add_assign_mixed(&negated);
+            }
+        }
+
+        impl std::ops::MulAssign<Scalar> for $projective {
+            fn mul_assign(&mut self, scalar: Scalar) {
+                // points of the typed API are always in the main subgroup, so GLV can be used if the curve has it
+                self.0 = self.0.glv_mul(&scalar.0, true);
+            }
+        }
+
+        impl_typed_curve_api!(@binop $projective, $projective, Add, add, AddAssign, add_assign);
+        impl_typed_curve_api!(@binop $projective, $affine, Add, add, AddAssign, add_assign);
+        impl_typed_curve_api!(@binop $projective, $projective, Sub, sub, SubAssign, sub_assign);
+        impl_typed_curve_api!(@binop $projective, $affine, Sub, sub, SubAssign, sub_assign);
+
+        impl std::ops::Add<$projective> for $affine {
+            type Output = $projective;
+
+            fn add(self, other: $projective) -> $projective {
+                other + self
+            }
+        }
+
+        impl std::ops::Neg for $projective {
+            type Output = $projective;
+
+            fn neg(mut self) -> $projective {
+                self.0.negate();
+
+                self
+            }
+        }
+
+        impl std::ops::Neg for $affine {
+            type Output = $affine;
+
+            fn neg(mut self) -> $affine {
+                self.0.negate();
+
+                self
+            }
+        }
+
+        impl std::ops::Mul<Scalar> for $projective {
+            type Output = $projective;
+
+            fn mul(mut self, scalar: Scalar) -> $projective {
+                self *= scalar;
+
+                self
+            }
+        }
+
+        impl<'a> std::ops::Mul<Scalar> for &'a $projective {
+            type Output = $projective;
+
+            fn mul(self, scalar: Scalar) -> $projective {
+                $projective(self.0.glv_mul(&scalar.0, true))
+            }
+        }
+
+        impl std::ops::Mul<Scalar> for $affine {
+            type Output = $projective;
+
+            fn mul(self, scalar: Scalar) -> $projective {
+                $projective(self.0.glv_mul(&scalar.0, true))
+            }
+        }
+
+        impl<'a> std::ops::Mul<Scalar> for &'a $affine {
+            type Output = $projective;
+
+            fn mul(self, scalar: Scalar) -> $projective {
+                $projective(self.0.glv_mul(&scalar.0, true))
+            }
+        }
+    };
+
+    (@binop $lhs:ident, $rhs:ident, $op:ident, $method:ident, $assign_op:ident, $assign:ident) => {
+        impl std::ops::$op<$rhs> for $lhs {
+            type Output = $lhs;
+
+            fn $method(mut self, other: $rhs) -> $lhs {
+                std::ops::$assign_op::$assign(&mut self, &other);
+
+                self
+            }
+        }
+
+        impl<'a> std::ops::$op<&'a $rhs> for $lhs {
+            type Output = $lhs;
+
+            fn $method(mut self, other: &'a $rhs) -> $lhs {
+                std::ops::$assign_op::$assign(&mut self, other);
+
+                self
+            }
+        }
+    };
+}
+
+pub mod bls12_381;
+pub mod bn254;
@@ -1,6 +1,7 @@
 use crate::traits::{FieldElement, BitIterator};
 use super::{CurveType, Group};
 use super::CurveParameters;
+use super::glv::{GlvParameters, decompose_scalar};
 use crate::traits::ZeroAndOne;
 
 pub struct WeierstrassCurve<'a, C: CurveParameters> {
@@ -8,7 +9,8 @@ pub struct WeierstrassCurve<'a, C: CurveParameters> {
     pub(crate) b: C::BaseFieldElement,
     pub(crate) curve_type: CurveType,
     pub(crate) subgroup_order_repr: &'a [u64],
-    pub(crate) params: &'a C
+    pub(crate) params: &'a C,
+    pub(crate) glv: Option<GlvParameters<'a, C>>
 }
 
 impl<'a, C: CurveParameters> Clone for WeierstrassCurve<'a, C> {
@@ -18,7 +20,8 @@ impl<'a, C: CurveParameters> Clone for WeierstrassCurve<'a, C> {
             b: self.b.clone(),
             curve_type: self.curve_type,
             subgroup_order_repr: self.subgroup_order_repr,
-            params: self.params
+            params: self.params,
+            glv: self.glv.clone()
         }
     }
 }
@@ -46,9 +49,15 @@ impl<'a, C: CurveParameters> WeierstrassCurve<'a, C> {
             b: b,
             curve_type: curve_type,
             subgroup_order_repr: subgroup_order,
-            params: params
+            params: params,
+            glv: None
         })
     }
+
+    pub(crate) fn set_glv_parameters(&mut self, glv: GlvParameters<'a, C>) {
+        debug_assert!(self.curve_type == CurveType::AIsZero);
+        self.glv = Some(glv);
+    }
 }
 
 pub struct CurvePoint<'a, C: CurveParameters> {
@@ -526,6 +535,55 @@ impl<'a, C: CurveParameters> CurvePoint<'a, C> {
         res
     }
 
+    /// Multiplication by a scalar using GLV decomposition if the curve has an efficient endomorphism.
+    /// Endomorphism acts as multiplication by lambda only on the main subgroup, so if the cofactor
+    /// is not one caller should set `in_subgroup` after the subgroup check, otherwise falls back to `mul`
+    pub fn glv_mul<S: AsRef<[u64]>>(&self, exp: S, in_subgroup: bool) -> Self {
+        let glv = match self.curve.glv.as_ref() {
+            Some(glv) if glv.cofactor_is_one || in_subgroup => glv,
+            _ => {
+                return self.mul_impl(exp);
+            }
+        };
+
+        let (k1, k2) = match decompose_scalar(exp.as_ref(), self.curve.subgroup_order_repr, glv) {
+            Some(decomposition) => decomposition,
+            None => {
+                return self.mul_impl(exp);
+            }
+        };
+
+        let mut p = self.clone();
+        if k1.is_negative {
+            p.negate();
+        }
+
+        let mut endo = self.clone();
+        endo.x.mul_assign(&glv.beta);
+        if k2.is_negative {
+            endo.negate();
+        }
+
+        let mut sum = p.clone();
+        sum.add_assign(&endo);
+
+        let num_bits = std::cmp::max(k1.abs.bits(), k2.abs.bits());
+
+        // Straus-Shamir trick for joint double scalar multiplication
+        let mut res = Self::zero(&self.curve);
+        for i in (0..num_bits).rev() {
+            res.double();
+            match (k1.abs.bit(i), k2.abs.bit(i)) {
+                (true, true) => res.add_assign(&sum),
+                (true, false) => res.add_assign(&p),
+                (false, true) => res.add_assign(&endo),
+                (false, false) => {}
+            }
+        }
+
+        res
+    }
+
     fn mul_impl_mixed_addition<S: AsRef<[u64]>>(&self, exp: S) -> Self {
         let mut res = Self::zero(&self.curve);
 
@@ -688,6 +746,10 @@ impl<'a, C: CurveParameters> CurvePoint<'a, C> {
 }
 
 impl<'a, C: CurveParameters> Group for CurvePoint<'a, C> {
+    fn normalize(&mut self) {
+        CurvePoint::normalize(self);
+    }
+
     fn add_assign(&mut self, other: &Self) {
         match self.curve.curve_type {
             _ => {
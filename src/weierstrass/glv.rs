@@ -0,0 +1,262 @@
+use crate::integers::MaxGroupSizeUint;
+use crate::field::SizedPrimeField;
+use crate::representation::ElementRepr;
+use crate::fp::Fp;
+use crate::traits::FieldElement;
+use super::{CurveParameters, CurveOverFpParameters};
+use super::curve::WeierstrassCurve;
+use crate::engines::bn254::{BN254_MODULUS, BN254_SUBGROUP_ORDER, BN254_B_FOR_G1, BN254_G1_GLV_PARAMETERS};
+
+/// Parameters of the endomorphism (x, y) -> (beta * x, y) of a curve with a = 0
+/// that acts as multiplication by lambda on the main subgroup
+pub struct GlvParameters<'a, C: CurveParameters> {
+    // cube root of unity in the base field
+    pub(crate) beta: C::BaseFieldElement,
+    // short basis (a1, b1), (a2, b2) of the lattice {(a, b): a + b * lambda = 0 mod r},
+    // every element is encoded as (absolute value, is negative)
+    pub(crate) basis: [(&'a [u64], bool); 4],
+    // if the whole group of points is of prime order every point on the curve is in the main subgroup
+    pub(crate) cofactor_is_one: bool,
+}
+
+impl<'a, C: CurveParameters> Clone for GlvParameters<'a, C> {
+    fn clone(&self) -> Self {
+        Self {
+            beta: self.beta.clone(),
+            basis: self.basis,
+            cofactor_is_one: self.cofactor_is_one,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SignedScalar {
+    pub(crate) abs: MaxGroupSizeUint,
+    pub(crate) is_negative: bool,
+}
+
+impl SignedScalar {
+    fn new(abs: MaxGroupSizeUint, is_negative: bool) -> Self {
+        let is_negative = is_negative && !abs.is_zero();
+
+        Self {
+            abs,
+            is_negative
+        }
+    }
+
+    fn from_basis_element(el: &(&[u64], bool)) -> Self {
+        Self::new(MaxGroupSizeUint::from(el.0), el.1)
+    }
+
+    fn neg(&self) -> Self {
+        Self::new(self.abs, !self.is_negative)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        if self.is_negative == other.is_negative {
+            return Self::new(self.abs + other.abs, self.is_negative);
+        }
+
+        if self.abs >= other.abs {
+            Self::new(self.abs - other.abs, self.is_negative)
+        } else {
+            Self::new(other.abs - self.abs, other.is_negative)
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Self::new(self.abs * other.abs, self.is_negative ^ other.is_negative)
+    }
+
+    // rounds a fraction to the nearest integer, ties are rounded away from zero
+    fn div_round(&self, denominator: &Self) -> Self {
+        let two = MaxGroupSizeUint::from(2u64);
+        let numerator = self.abs * two + denominator.abs;
+        let (quotient, _) = numerator.div_mod(denominator.abs * two);
+
+        Self::new(quotient, self.is_negative ^ denominator.is_negative)
+    }
+}
+
+/// Splits a scalar k into (k1, k2) such that k = k1 + k2 * lambda mod r and both parts
+/// are roughly half of the bit length of r. Returns None if scalar is too long
+pub(crate) fn decompose_scalar<C: CurveParameters>(
+    scalar: &[u64],
+    subgroup_order: &[u64],
+    params: &GlvParameters<C>
+) -> Option<(SignedScalar, SignedScalar)> {
+    let max_limbs = MaxGroupSizeUint::default().as_ref().len();
+    if scalar.iter().skip(max_limbs).any(|el| *el != 0) {
+        return None;
+    }
+
+    let order = MaxGroupSizeUint::from(subgroup_order);
+    if order.is_zero() {
+        return None;
+    }
+    let (_, k) = MaxGroupSizeUint::from(scalar).div_mod(order);
+    let k = SignedScalar::new(k, false);
+
+    let a1 = SignedScalar::from_basis_element(&params.basis[0]);
+    let b1 = SignedScalar::from_basis_element(&params.basis[1]);
+    let a2 = SignedScalar::from_basis_element(&params.basis[2]);
+    let b2 = SignedScalar::from_basis_element(&params.basis[3]);
+
+    // determinant is +-r
+    let det = a1.mul(&b2).sub(&a2.mul(&b1));
+    if det.abs.is_zero() {
+        return None;
+    }
+
+    let c1 = b2.mul(&k).div_round(&det);
+    let c2 = b1.neg().mul(&k).div_round(&det);
+
+    let k1 = k.sub(&c1.mul(&a1)).sub(&c2.mul(&a2));
+    let k2 = c1.mul(&b1).add(&c2.mul(&b2)).neg();
+
+    Some((k1, k2))
+}
+
+fn limbs_are_equal(a: &[u64], b: &[u64]) -> bool {
+    let len = std::cmp::max(a.len(), b.len());
+    (0..len).all(|i| a.get(i).unwrap_or(&0) == b.get(i).unwrap_or(&0))
+}
+
+/// Returns GLV parameters if the curve parsed at runtime is one of the known curves
+/// where endomorphism can be applied without any subgroup checks
+pub(crate) fn glv_parameters_for_known_curve<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>>(
+    curve: &WeierstrassCurve<'a, CurveOverFpParameters<'a, FE, F>>
+) -> Option<GlvParameters<'a, CurveOverFpParameters<'a, FE, F>>> {
+    let field = curve.params.field;
+    if !curve.a.is_zero() {
+        return None;
+    }
+    if !limbs_are_equal(field.modulus().as_ref(), BN254_MODULUS.as_ref()) {
+        return None;
+    }
+    if !limbs_are_equal(curve.subgroup_order_repr, &BN254_SUBGROUP_ORDER) {
+        return None;
+    }
+    if !limbs_are_equal(curve.b.into_repr().as_ref(), BN254_B_FOR_G1.into_repr().as_ref()) {
+        return None;
+    }
+
+    let mut beta_repr = FE::default();
+    for (dst, src) in beta_repr.as_mut().iter_mut().zip(BN254_G1_GLV_PARAMETERS.beta.into_repr().as_ref().iter()) {
+        *dst = *src;
+    }
+    let beta = Fp::from_repr(field, beta_repr).ok()?;
+
+    Some(GlvParameters {
+        beta,
+        basis: BN254_G1_GLV_PARAMETERS.basis,
+        cofactor_is_one: BN254_G1_GLV_PARAMETERS.cofactor_is_one
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn test_bls12_381_scalar_decomposition() {
+        use crate::engines::bls12_381::*;
+
+        let glv = BLS12_381_G1_CURVE.glv.as_ref().unwrap();
+        let order = MaxGroupSizeUint::from(&BLS12_381_SUBGROUP_ORDER[..]);
+        // lambda = x^2 - 1
+        let lambda = MaxGroupSizeUint::from(&[0x00000000ffffffff, 0xac45a4010001a402][..]);
+
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+        for _ in 0..1000 {
+            let scalar: [u64; 4] = rng.gen();
+            let (k1, k2) = decompose_scalar(&scalar[..], &BLS12_381_SUBGROUP_ORDER[..], glv).unwrap();
+            assert!(k1.abs.bits() <= 130);
+            assert!(k2.abs.bits() <= 130);
+
+            let (_, expected) = MaxGroupSizeUint::from(&scalar[..]).div_mod(order);
+            let (_, k2_lambda) = (k2.abs * lambda).div_mod(order);
+            let k1 = if k1.is_negative { order - k1.abs } else { k1.abs };
+            let k2_lambda = if k2.is_negative { order - k2_lambda } else { k2_lambda };
+            let (_, sum) = (k1 + k2_lambda).div_mod(order);
+
+            assert_eq!(sum, expected);
+        }
+    }
+
+    fn assert_same_result<C: CurveParameters>(
+        p: &crate::weierstrass::curve::CurvePoint<C>, 
+        scalar: &[u64], 
+        in_subgroup: bool
+    ) {
+        use crate::weierstrass::Group;
+
+        let mut expected = p.mul(scalar);
+        expected.normalize();
+        let mut result = p.glv_mul(scalar, in_subgroup);
+        result.normalize();
+
+        assert!(result.into_xy() == expected.into_xy());
+    }
+
+    #[test]
+    fn test_glv_multiplication_bls12() {
+        use crate::weierstrass::Group;
+        use crate::engines::{bls12_381, bls12_377};
+
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+        for _ in 0..100 {
+            let scalar: [u64; 4] = rng.gen();
+            let base: [u64; 4] = rng.gen();
+
+            let mut p = bls12_381::BLS12_381_G1_GENERATOR.mul(&base);
+            p.normalize();
+            assert_same_result(&p, &scalar, true);
+            assert_same_result(&bls12_381::BLS12_381_G1_GENERATOR, &scalar, true);
+
+            let mut p = bls12_377::BLS12_377_G1_GENERATOR.mul(&base);
+            p.normalize();
+            assert_same_result(&p, &scalar, true);
+        }
+
+        // trivial scalars
+        for scalar in [vec![0u64], vec![1u64], bls12_381::BLS12_381_SUBGROUP_ORDER.to_vec()].iter() {
+            assert_same_result(&bls12_381::BLS12_381_G1_GENERATOR, scalar, true);
+        }
+    }
+
+    #[test]
+    fn test_glv_multiplication_bn254() {
+        use crate::field::{new_field, U256Repr};
+        use crate::traits::ZeroAndOne;
+        use crate::weierstrass::curve::CurvePoint;
+
+        let field = new_field::<U256Repr>("21888242871839275222246405745257275088696311157297823662689037894645226208583", 10).unwrap();
+        let one = Fp::one(&field);
+        let mut two = one.clone();
+        two.double();
+        let mut three = two.clone();
+        three.add_assign(&one);
+
+        let params = CurveOverFpParameters::new(&field);
+        let mut curve = WeierstrassCurve::new(&BN254_SUBGROUP_ORDER, Fp::zero(&field), three, &params).unwrap();
+        let glv = glv_parameters_for_known_curve(&curve).expect("must detect BN254");
+        curve.set_glv_parameters(glv);
+
+        let p = CurvePoint::point_from_xy(&curve, one, two);
+
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+        for _ in 0..100 {
+            let scalar: [u64; 4] = rng.gen();
+            assert_same_result(&p, &scalar, false);
+            assert_same_result(&crate::engines::bn254::BN254_G1_GENERATOR, &scalar, false);
+        }
+    }
+}
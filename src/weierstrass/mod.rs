@@ -104,6 +104,35 @@ impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> CurveOverFp3Parameters<
 }
 
 
+use crate::extension_towers::fp4_as_2_over_2;
+
+pub struct CurveOverFp4Parameters<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+    pub field: &'a fp4_as_2_over_2::Extension2Over2<'a, FE, F>,
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> Clone for CurveOverFp4Parameters<'a, FE, F> {
+    fn clone(&self) -> Self {
+        Self {
+            field: self.field
+        }
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> CurveParameters for CurveOverFp4Parameters<'a, FE, F> {
+    type BaseFieldElement = fp4_as_2_over_2::Fp4<'a, FE, F>;
+    fn params(&self) -> <Self::BaseFieldElement as ZeroAndOne>::Params {
+        self.field
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> CurveOverFp4Parameters<'a, FE, F> {
+    pub fn new(field: &'a fp4_as_2_over_2::Extension2Over2<'a, FE, F>) -> Self {
+        Self {
+            field
+        }
+    }
+}
+
 pub trait Group: Sized + Clone {
     fn add_assign(&mut self, other: &Self);
     fn add_assign_mixed(&mut self, other: &Self);
@@ -115,6 +144,9 @@ pub trait Group: Sized + Clone {
     fn wnaf_mul_with_window_size<S: crate::representation::IntoWnaf>(&self, exp: S, window_size: u32) -> Self;
     fn is_zero(&self) -> bool;
     fn check_correct_subgroup(&self) -> bool;
+    /// Brings the point into affine form, Miller loops expect normalized points
+    fn normalize(&mut self);
 }
 
-pub mod curve;
\ No newline at end of file
+pub mod curve;
+pub mod glv;
\ No newline at end of file
@@ -0,0 +1,57 @@
+// Compiles `c_harness/harness.c` against the static library and the headers of the EIP-2537 and
+// EIP-196 C interfaces and runs it. Cargo builds the static library next to this test binary.
+// With the `c_api` feature the unified interface is exercised too. A C compiler is required, its
+// name can be set with the CC environment variable
+
+#![cfg(all(feature = "eip_2357_c_api", feature = "eip_196_c_api"))]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn find_static_library() -> PathBuf {
+    let exe = std::env::current_exe().expect("must know the test binary path");
+    let deps_dir = exe.parent().expect("test binary is in the deps directory");
+
+    deps_dir.join("libeth_pairings.a")
+}
+
+fn compiler() -> String {
+    let cc = std::env::var("CC").unwrap_or("cc".to_owned());
+    let available = Command::new(&cc).arg("--version").output().map(|o| o.status.success()).unwrap_or(false);
+    assert!(available, "C compiler `{}` is not available, set CC to run the C harness", cc);
+
+    cc
+}
+
+#[test]
+fn test_c_harness() {
+    let cc = compiler();
+
+    let library = find_static_library();
+    assert!(library.exists(), "static library is expected at {}", library.display());
+
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let harness = manifest_dir.join("tests/c_harness/harness.c");
+    let binary = std::env::temp_dir().join(format!("eth_pairings_c_harness_{}", std::process::id()));
+
+    let mut command = Command::new(&cc);
+    if cfg!(feature = "c_api") {
+        command.arg("-DETH_PAIRINGS_C_API");
+    }
+
+    let compiled = command
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-I").arg(manifest_dir)
+        .arg(&harness)
+        .arg(&library)
+        .args(&["-lpthread", "-ldl", "-lm"])
+        .arg("-o").arg(&binary)
+        .output()
+        .expect("must run the C compiler");
+    assert!(compiled.status.success(), "failed to compile the C harness: {}", String::from_utf8_lossy(&compiled.stderr));
+
+    let run = Command::new(&binary).output().expect("must run the C harness");
+    let _ = std::fs::remove_file(&binary);
+    assert!(run.status.success(), "C harness failed: {}", String::from_utf8_lossy(&run.stderr));
+}
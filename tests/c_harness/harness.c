@@ -0,0 +1,227 @@
+/* Exercises the bounds-checked C interfaces through the generated headers. The unified
+   interface is only built with the `c_api` feature, the test passes ETH_PAIRINGS_C_API then */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "eip2537_header.h"
+#include "eip196_header.h"
+#ifdef ETH_PAIRINGS_C_API
+#include "eth_pairings_header.h"
+#endif
+
+#define CHECK(cond)                                                        \
+  do {                                                                     \
+    if (!(cond)) {                                                         \
+      fprintf(stderr, "check failed at line %d: %s\n", __LINE__, #cond);  \
+      return 1;                                                            \
+    }                                                                      \
+  } while (0)
+
+static const char *G1_GENERATOR_X =
+    "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+static const char *G1_GENERATOR_Y =
+    "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+
+static const char *BN254_G1_GENERATOR_DOUBLED =
+    "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3"
+    "15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4";
+
+/* BN254 in the unified ABI: modulus length, modulus, A, B, group order length and group order */
+static const char *BN254_MODULUS =
+    "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+static const char *BN254_GROUP_ORDER =
+    "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+
+static void decode_hex(const char *hex, uint8_t *out) {
+  size_t len = strlen(hex) / 2;
+  for (size_t i = 0; i < len; i++) {
+    unsigned int byte;
+    sscanf(hex + 2 * i, "%2x", &byte);
+    out[i] = (uint8_t)byte;
+  }
+}
+
+/* generator followed by the point at infinity, 64 bytes per coordinate */
+static void make_g1_add_input(uint8_t *input) {
+  memset(input, 0, 256);
+  decode_hex(G1_GENERATOR_X, input + 16);
+  decode_hex(G1_GENERATOR_Y, input + 64 + 16);
+}
+
+#ifdef ETH_PAIRINGS_C_API
+/* BN254 generator plus the point at infinity */
+static uint32_t make_unified_g1_add_input(uint8_t *input) {
+  uint8_t *cursor = input;
+  memset(input, 0, 1 + 32 * 3 + 1 + 32 + 64 * 2);
+  *cursor++ = 32;
+  decode_hex(BN254_MODULUS, cursor);
+  cursor += 32 * 2;
+  cursor += 31;
+  *cursor++ = 3;
+  *cursor++ = 32;
+  decode_hex(BN254_GROUP_ORDER, cursor);
+  cursor += 32;
+  cursor[31] = 1;
+  cursor[63] = 2;
+  cursor += 64 * 2;
+
+  return (uint32_t)(cursor - input);
+}
+
+static int check_unified_api(void) {
+  uint8_t input[512];
+  uint8_t output[PREALLOCATE_FOR_RESULT_BYTES];
+  uint32_t output_len = 0;
+  char error[PREALLOCATE_FOR_ERROR_BYTES];
+  uint32_t error_len = 0;
+  uint32_t result;
+  uint32_t input_len = make_unified_g1_add_input(input);
+  const uint8_t *generator = input + 1 + 32 * 3 + 1 + 32;
+
+  result = c_perform_operation_v2(G1ADD, input, input_len, output, sizeof(output), &output_len,
+                                  (uint8_t *)error, sizeof(error), &error_len);
+  CHECK(result == 0);
+  CHECK(output_len == 64);
+  CHECK(memcmp(output, generator, 64) == 0);
+
+  memset(output, 0xff, sizeof(output));
+  result = c_perform_operation_v2(G1ADD, input, input_len, output, 10, &output_len,
+                                  (uint8_t *)error, sizeof(error), &error_len);
+  CHECK(ERROR_CODE(result) == ERROR_CODE_BUFFER_TOO_SMALL);
+  CHECK(output_len == 64);
+  CHECK(output[0] == 0xff);
+
+  result = c_perform_operation_v2(0xff, input, input_len, output, sizeof(output), &output_len,
+                                  (uint8_t *)error, sizeof(error), &error_len);
+  CHECK(ERROR_CODE(result) == ERROR_CODE_INVALID_ENCODING);
+
+  result = c_perform_operation_v2(G1ADD, input, input_len - 1, output, sizeof(output),
+                                  &output_len, (uint8_t *)error, sizeof(error), &error_len);
+  CHECK(ERROR_CODE(result) != 0);
+  CHECK(error_len > 0);
+
+  uint64_t gas = 0;
+  result = c_meter_operation_v2(G1ADD, input, input_len, &gas, (uint8_t *)error, sizeof(error),
+                                &error_len);
+  CHECK(result == 0);
+  CHECK(gas > 0);
+
+  result = c_meter_operation_v2(G1ADD, NULL, input_len, &gas, (uint8_t *)error, sizeof(error),
+                                &error_len);
+  CHECK(ERROR_CODE(result) == ERROR_CODE_NULL_POINTER);
+
+  return 0;
+}
+#endif
+
+int main(void) {
+  uint8_t input[256];
+  uint8_t output[EIP2537_PREALLOCATE_FOR_RESULT_BYTES];
+  uint32_t output_len = 0;
+  char error[EIP2537_PREALLOCATE_FOR_ERROR_BYTES];
+  uint32_t error_len = 0;
+  uint32_t result;
+
+  make_g1_add_input(input);
+
+  /* generator plus infinity is the generator */
+  result = eip2537_perform_operation_v2(BLS12_G1ADD_OPERATION_RAW_VALUE, input, 256, output,
+                                        sizeof(output), &output_len, (uint8_t *)error,
+                                        sizeof(error), &error_len);
+  CHECK(result == 0);
+  CHECK(output_len == 128);
+  CHECK(memcmp(output, input, 128) == 0);
+
+  /* required length is reported and nothing is written if the buffer is too small */
+  memset(output, 0xff, sizeof(output));
+  result = eip2537_perform_operation_v2(BLS12_G1ADD_OPERATION_RAW_VALUE, input, 256, output, 10,
+                                        &output_len, (uint8_t *)error, sizeof(error), &error_len);
+  CHECK(ERROR_CODE(result) == ERROR_CODE_BUFFER_TOO_SMALL);
+  CHECK(output_len == 128);
+  CHECK(output[0] == 0xff);
+  CHECK(error_len > 0 && error_len <= sizeof(error));
+
+  /* size query without an output buffer */
+  result = eip2537_perform_operation_v2(BLS12_G1ADD_OPERATION_RAW_VALUE, input, 256, NULL, 0,
+                                        &output_len, NULL, 0, &error_len);
+  CHECK(ERROR_CODE(result) == ERROR_CODE_BUFFER_TOO_SMALL);
+  CHECK(output_len == 128);
+
+  /* invalid length is reported at the start of the input, description is truncated */
+  memset(error, 0, sizeof(error));
+  result = eip2537_perform_operation_v2(BLS12_G1ADD_OPERATION_RAW_VALUE, input, 255, output,
+                                        sizeof(output), &output_len, (uint8_t *)error, 8,
+                                        &error_len);
+  CHECK(ERROR_CODE(result) == ERROR_CODE_INVALID_LENGTH);
+  CHECK(ERROR_OFFSET(result) == 0);
+  CHECK(error_len > 8);
+  CHECK(error[7] != 0 && error[8] == 0);
+
+  /* point not on curve is reported with the offset of the second point */
+  input[255] = 1;
+  result = eip2537_perform_operation_v2(BLS12_G1ADD_OPERATION_RAW_VALUE, input, 256, output,
+                                        sizeof(output), &output_len, (uint8_t *)error,
+                                        sizeof(error), &error_len);
+  CHECK(ERROR_CODE(result) == ERROR_CODE_POINT_NOT_ON_CURVE);
+  CHECK(ERROR_OFFSET(result) == 128);
+  input[255] = 0;
+
+  result = eip2537_perform_operation_v2(0xff, input, 256, output, sizeof(output), &output_len,
+                                        (uint8_t *)error, sizeof(error), &error_len);
+  CHECK(ERROR_CODE(result) == ERROR_CODE_INVALID_ENCODING);
+
+  result = eip2537_perform_operation_v2(BLS12_G1ADD_OPERATION_RAW_VALUE, NULL, 256, output,
+                                        sizeof(output), &output_len, (uint8_t *)error,
+                                        sizeof(error), &error_len);
+  CHECK(ERROR_CODE(result) == ERROR_CODE_NULL_POINTER);
+
+  uint64_t gas = 0;
+  result = eip2537_meter_operation_v2(BLS12_G1ADD_OPERATION_RAW_VALUE, input, 256, &gas,
+                                      (uint8_t *)error, sizeof(error), &error_len);
+  CHECK(result == 0);
+  CHECK(gas > 0);
+
+  /* EIP-196: BN254 generator (1, 2) doubled, the second point is padded with zeroes */
+  uint8_t bn_input[64] = {0};
+  bn_input[31] = 1;
+  bn_input[63] = 2;
+  uint8_t doubled[64];
+  decode_hex(BN254_G1_GENERATOR_DOUBLED, doubled);
+  result = eip196_perform_operation(BN254_ECADD_OPERATION_RAW_VALUE, bn_input, 64, output,
+                                    sizeof(output), &output_len, (uint8_t *)error, sizeof(error),
+                                    &error_len);
+  CHECK(result == 0);
+  CHECK(output_len == 64);
+  CHECK(memcmp(output, bn_input, 64) == 0);
+
+  memcpy(input, bn_input, 64);
+  memcpy(input + 64, bn_input, 64);
+  result = eip196_perform_operation(BN254_ECADD_OPERATION_RAW_VALUE, input, 128, output,
+                                    sizeof(output), &output_len, (uint8_t *)error, sizeof(error),
+                                    &error_len);
+  CHECK(result == 0);
+  CHECK(memcmp(output, doubled, 64) == 0);
+
+  /* empty pairing input is true */
+  result = eip196_perform_operation(BN254_ECPAIRING_OPERATION_RAW_VALUE, NULL, 0, output,
+                                    sizeof(output), &output_len, (uint8_t *)error, sizeof(error),
+                                    &error_len);
+  CHECK(result == 0);
+  CHECK(output_len == 32 && output[31] == 1);
+
+  result = eip196_meter_operation(BN254_ECPAIRING_OPERATION_RAW_VALUE, input, 100, &gas,
+                                  (uint8_t *)error, sizeof(error), &error_len);
+  CHECK(ERROR_CODE(result) == ERROR_CODE_INVALID_LENGTH);
+
+  result = eip196_meter_operation(BN254_ECMUL_OPERATION_RAW_VALUE, input, 96, &gas,
+                                  (uint8_t *)error, sizeof(error), &error_len);
+  CHECK(result == 0);
+  CHECK(gas == 6000);
+
+#ifdef ETH_PAIRINGS_C_API
+  CHECK(check_unified_api() == 0);
+#endif
+
+  return 0;
+}
@@ -0,0 +1,74 @@
+// Uses the pairing engines directly, only through the public paths
+
+use eth_pairings::pairings::{PairingEngine, FieldElement, Group};
+
+// e([a]P, Q) * e(P, [b]Q) from separately run Miller loops with a single final exponentiation
+// must match the pairing of both pairs at once
+fn check_split_pairing<E: PairingEngine>(engine: &E, p: &E::G1, q: &E::G2) {
+    let mut p_a = p.mul(&[7u64]);
+    p_a.normalize();
+    let mut q_b = q.mul(&[11u64]);
+    q_b.normalize();
+
+    let prepared_q = engine.prepare_g2(q).unwrap();
+    let prepared_q_b = engine.prepare_g2(&q_b).unwrap();
+
+    let mut f = engine.miller_loop(&[p_a.clone()], &[prepared_q.clone()]).unwrap();
+    f.mul_assign(&engine.miller_loop(&[p.clone()], &[prepared_q_b.clone()]).unwrap());
+    let split = engine.final_exponentiation(&f).unwrap();
+
+    let expected = engine.pair(&[p_a.clone(), p.clone()], &[q.clone(), q_b.clone()]).unwrap();
+    assert_eq!(split, expected);
+    assert_eq!(engine.pair_prepared(&[p_a, p.clone()], &[prepared_q, prepared_q_b]).unwrap(), expected);
+
+    let single = engine.pair(&[p.clone()], &[q.clone()]).unwrap();
+    assert_eq!(expected, single.pow(&[7u64 + 11u64]));
+}
+
+#[test]
+fn test_bls12_381_engine() {
+    use eth_pairings::engines::bls12_381::*;
+
+    check_split_pairing(&BLS12_381_PAIRING_ENGINE, &BLS12_381_G1_GENERATOR, &BLS12_381_G2_GENERATOR);
+}
+
+#[test]
+fn test_bn254_engine() {
+    use eth_pairings::engines::bn254::*;
+
+    check_split_pairing(&BN254_PAIRING_ENGINE, &BN254_G1_GENERATOR, &BN254_G2_GENERATOR);
+}
+
+// a prepared G2 point is reused for several G1 points and every result must match the unprepared pairing
+#[cfg(feature = "mnt_engines")]
+fn check_reused_prepared_point<E: PairingEngine>(engine: &E, p: &E::G1, q: &E::G2) {
+    assert!(p.check_correct_subgroup());
+    assert!(q.check_correct_subgroup());
+
+    let prepared = engine.prepare_g2(q).unwrap();
+    for scalar in 1..4u64 {
+        let mut p_s = p.mul(&[scalar]);
+        p_s.normalize();
+
+        let expected = engine.pair(&[p_s.clone()], &[q.clone()]).unwrap();
+        assert_eq!(engine.pair_prepared(&[p_s], &[prepared.clone()]).unwrap(), expected);
+    }
+}
+
+#[cfg(feature = "mnt_engines")]
+#[test]
+fn test_mnt4_298_engine() {
+    use eth_pairings::engines::mnt4_298::*;
+
+    check_split_pairing(&*MNT4_298_PAIRING_ENGINE, &*MNT4_298_G1_GENERATOR, &*MNT4_298_G2_GENERATOR);
+    check_reused_prepared_point(&*MNT4_298_PAIRING_ENGINE, &*MNT4_298_G1_GENERATOR, &*MNT4_298_G2_GENERATOR);
+}
+
+#[cfg(feature = "mnt_engines")]
+#[test]
+fn test_mnt6_298_engine() {
+    use eth_pairings::engines::mnt6_298::*;
+
+    check_split_pairing(&*MNT6_298_PAIRING_ENGINE, &*MNT6_298_G1_GENERATOR, &*MNT6_298_G2_GENERATOR);
+    check_reused_prepared_point(&*MNT6_298_PAIRING_ENGINE, &*MNT6_298_G1_GENERATOR, &*MNT6_298_G2_GENERATOR);
+}
@@ -0,0 +1,76 @@
+// Uses the typed API the way a dependent crate does, only through the public paths
+
+use eth_pairings::typed_api::bls12_381::*;
+
+// e([a]P, [b]Q) * e(-[a * b]P, Q) == 1, broken by an extra +1 in the second term if not valid
+fn make_equation(a: u64, b: u64, valid: bool) -> Vec<(G1Affine, G2Affine)> {
+    let p = G1Affine::generator();
+    let q = G2Affine::generator();
+    let a = Scalar::from_u64(a);
+    let b = Scalar::from_u64(b);
+    let mut ab = a * b;
+    if !valid {
+        ab = ab + Scalar::from_u64(1);
+    }
+
+    vec![
+        ((&p * a).to_affine(), (&q * b).to_affine()),
+        ((&p * -ab).to_affine(), q),
+    ]
+}
+
+#[cfg(feature = "batch_verification")]
+#[test]
+fn test_batch_verify() {
+    use eth_pairings::typed_api::BatchVerificationResult;
+
+    let mut equations: Vec<_> = (1..6u64).map(|i| make_equation(i, i + 100, true)).collect();
+    assert_eq!(batch_verify(&equations).unwrap(), BatchVerificationResult::Valid);
+
+    equations[1] = make_equation(7, 11, false);
+    equations[4] = make_equation(13, 17, false);
+    assert_eq!(batch_verify(&equations).unwrap(), BatchVerificationResult::Invalid(vec![1, 4]));
+
+    assert!(batch_verify(&[]).is_err());
+}
+
+#[test]
+fn test_prepared_pairing() {
+    let p = G1Affine::generator();
+    let q = G2Affine::generator();
+    let a = Scalar::from_u64(5);
+    let q_a = (&q * a).to_affine();
+
+    let prepared = G2Prepared::from(&q);
+    let prepared_a = G2Prepared::from(&q_a);
+
+    assert_eq!(miller_loop(&[(&p, &prepared)]).final_exponentiation(), pairing(&p, &q));
+    assert_eq!(multi_pairing_prepared(&[(&p, &prepared)]), pairing(&p, &q));
+    assert!(multi_pairing_prepared(&[]).is_identity());
+
+    // e(P, [a]Q) * e(-[a]P, Q) == 1, also when Miller loops are run separately
+    let p_neg_a = (&p * -a).to_affine();
+    let f = miller_loop(&[(&p, &prepared_a), (&p_neg_a, &prepared)]);
+    assert!(f.final_exponentiation().is_identity());
+
+    let f = miller_loop(&[(&p, &prepared_a)]) * miller_loop(&[(&p_neg_a, &prepared)]);
+    assert!(f.final_exponentiation().is_identity());
+
+    // pairs with the identity on either side are skipped
+    let identity = G2Prepared::from(G2Affine::identity());
+    let f = miller_loop(&[(&p, &identity), (&G1Affine::identity(), &prepared)]);
+    assert!(f.final_exponentiation().is_identity());
+}
+
+#[test]
+fn test_prepared_pairing_bn254() {
+    use eth_pairings::typed_api::bn254;
+
+    let p = bn254::G1Affine::generator();
+    let q = bn254::G2Affine::generator();
+    let prepared = bn254::G2Prepared::from(&q);
+
+    let f = bn254::miller_loop(&[(&p, &prepared)]);
+    assert_eq!(f.final_exponentiation(), bn254::pairing(&p, &q));
+    assert!(!f.final_exponentiation().is_identity());
+}